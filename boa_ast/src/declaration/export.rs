@@ -13,6 +13,7 @@ use std::ops::ControlFlow;
 
 use super::{ModuleSpecifier, VarDeclaration};
 use crate::{
+    expression::Identifier,
     function::{AsyncFunction, AsyncGenerator, Class, Function, Generator},
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
@@ -23,10 +24,12 @@ use boa_interner::Sym;
 /// The kind of re-export in an [`ExportDeclaration`].
 #[derive(Debug, Clone)]
 pub enum ReExportKind {
+    /// Re-export of all the named exports (`export * from "module-name"`).
+    All,
     /// Namespaced Re-export (`export * as name from "module-name"`).
     Namespaced {
-        /// Reexported name for the imported module.
-        name: Option<Sym>,
+        /// Reexported name for the namespace object of the imported module.
+        name: Identifier,
     },
     /// Re-export list (`export { export1, export2 as alias2 } from "module-name"`).
     Named {
@@ -41,8 +44,8 @@ impl VisitWith for ReExportKind {
         V: Visitor<'a>,
     {
         match self {
-            Self::Namespaced { name: Some(name) } => visitor.visit_sym(name),
-            Self::Namespaced { name: None } => ControlFlow::Continue(()),
+            Self::All => ControlFlow::Continue(()),
+            Self::Namespaced { name } => visitor.visit_identifier(name),
             Self::Named { names } => {
                 for name in &**names {
                     try_break!(visitor.visit_export_specifier(name));
@@ -57,8 +60,8 @@ impl VisitWith for ReExportKind {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::Namespaced { name: Some(name) } => visitor.visit_sym_mut(name),
-            Self::Namespaced { name: None } => ControlFlow::Continue(()),
+            Self::All => ControlFlow::Continue(()),
+            Self::Namespaced { name } => visitor.visit_identifier_mut(name),
             Self::Named { names } => {
                 for name in &mut **names {
                     try_break!(visitor.visit_export_specifier_mut(name));
@@ -9,7 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-imports
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
 
-use std::ops::ControlFlow;
+use std::{error, fmt, ops::ControlFlow};
 
 use crate::{
     expression::Identifier,
@@ -85,6 +85,8 @@ pub struct ImportDeclaration {
     kind: ImportKind,
     /// Module specifier.
     specifier: ModuleSpecifier,
+    /// Attributes of the import, like `type: "json"`.
+    attributes: Box<[ImportAttribute]>,
 }
 
 impl ImportDeclaration {
@@ -95,14 +97,23 @@ impl ImportDeclaration {
         default: Option<Identifier>,
         kind: ImportKind,
         specifier: ModuleSpecifier,
+        attributes: Box<[ImportAttribute]>,
     ) -> Self {
         Self {
             default,
             kind,
             specifier,
+            attributes,
         }
     }
 
+    /// Creates a new [`ImportDeclarationBuilder`] to build an import declaration.
+    #[inline]
+    #[must_use]
+    pub fn builder() -> ImportDeclarationBuilder {
+        Default::default()
+    }
+
     /// Gets the binding for the default export of the module.
     #[inline]
     #[must_use]
@@ -123,6 +134,13 @@ impl ImportDeclaration {
     pub const fn kind(&self) -> &ImportKind {
         &self.kind
     }
+
+    /// Gets the attributes of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
 }
 
 impl VisitWith for ImportDeclaration {
@@ -134,7 +152,11 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier(default));
         }
         try_break!(visitor.visit_import_kind(&self.kind));
-        visitor.visit_module_specifier(&self.specifier)
+        try_break!(visitor.visit_module_specifier(&self.specifier));
+        for attribute in &*self.attributes {
+            try_break!(visitor.visit_import_attribute(attribute));
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
@@ -145,10 +167,146 @@ impl VisitWith for ImportDeclaration {
             try_break!(visitor.visit_identifier_mut(default));
         }
         try_break!(visitor.visit_import_kind_mut(&mut self.kind));
-        visitor.visit_module_specifier_mut(&mut self.specifier)
+        try_break!(visitor.visit_module_specifier_mut(&mut self.specifier));
+        for attribute in &mut *self.attributes {
+            try_break!(visitor.visit_import_attribute_mut(attribute));
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Builder for [`ImportDeclaration`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use boa_ast::declaration::{ImportDeclaration, ImportKind};
+/// # use boa_interner::Interner;
+/// let mut interner = Interner::default();
+/// let ns = interner.get_or_intern("ns");
+/// let module = interner.get_or_intern("module-name");
+///
+/// // import * as ns from "module-name";
+/// let import = ImportDeclaration::builder()
+///     .namespace(ns.into())
+///     .from(module.into())
+///     .build()
+///     .unwrap();
+///
+/// assert!(matches!(import.kind(), ImportKind::Namespaced { .. }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ImportDeclarationBuilder {
+    default: Option<Identifier>,
+    namespace: Option<Identifier>,
+    named: Option<Vec<ImportSpecifier>>,
+    specifier: Option<ModuleSpecifier>,
+    attributes: Vec<ImportAttribute>,
+}
+
+impl ImportDeclarationBuilder {
+    /// Sets the binding for the default export of the module (`import name from "module"`).
+    #[inline]
+    #[must_use]
+    #[allow(clippy::should_implement_trait)]
+    pub const fn default(mut self, binding: Identifier) -> Self {
+        self.default = Some(binding);
+        self
+    }
+
+    /// Sets the binding for the namespace object of the module (`import * as name from "module"`).
+    #[inline]
+    #[must_use]
+    pub const fn namespace(mut self, binding: Identifier) -> Self {
+        self.namespace = Some(binding);
+        self
+    }
+
+    /// Adds named imports (`import { export1, export2 as alias2 } from "module"`).
+    ///
+    /// Calling this with an empty iterator, like [`std::iter::empty`], produces an empty import
+    /// list (`import {} from "module"`).
+    #[must_use]
+    pub fn named<I>(mut self, specifiers: I) -> Self
+    where
+        I: IntoIterator<Item = ImportSpecifier>,
+    {
+        self.named.get_or_insert_with(Vec::new).extend(specifiers);
+        self
+    }
+
+    /// Sets the module specifier of the import declaration.
+    #[inline]
+    #[must_use]
+    pub const fn from(mut self, specifier: ModuleSpecifier) -> Self {
+        self.specifier = Some(specifier);
+        self
+    }
+
+    /// Adds attributes to the import declaration (`with { type: "json" }`).
+    #[must_use]
+    pub fn attributes<I>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator<Item = ImportAttribute>,
+    {
+        self.attributes.extend(attributes);
+        self
+    }
+
+    /// Builds the import declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if both a namespace binding and named imports were set, or if the module
+    /// specifier is missing.
+    pub fn build(self) -> Result<ImportDeclaration, ImportDeclarationBuilderError> {
+        let specifier = self
+            .specifier
+            .ok_or(ImportDeclarationBuilderError::MissingSpecifier)?;
+        let kind = match (self.namespace, self.named) {
+            (Some(_), Some(_)) => return Err(ImportDeclarationBuilderError::NamespaceAndNamed),
+            (Some(binding), None) => ImportKind::Namespaced { binding },
+            (None, Some(names)) => ImportKind::Named {
+                names: names.into_boxed_slice(),
+            },
+            (None, None) => ImportKind::DefaultOrUnnamed,
+        };
+        Ok(ImportDeclaration::new(
+            self.default,
+            kind,
+            specifier,
+            self.attributes.into_boxed_slice(),
+        ))
     }
 }
 
+/// The error type which is returned when an [`ImportDeclarationBuilder`] can't build a valid
+/// import declaration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportDeclarationBuilderError {
+    /// Both a namespace binding and named imports were set.
+    NamespaceAndNamed,
+
+    /// The module specifier was not set.
+    MissingSpecifier,
+}
+
+impl fmt::Display for ImportDeclarationBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NamespaceAndNamed => {
+                write!(
+                    f,
+                    "an import cannot have both a namespace and named imports"
+                )
+            }
+            Self::MissingSpecifier => write!(f, "an import must have a module specifier"),
+        }
+    }
+}
+
+impl error::Error for ImportDeclarationBuilderError {}
+
 /// Import specifier
 ///
 /// More information:
@@ -159,6 +317,7 @@ impl VisitWith for ImportDeclaration {
 pub struct ImportSpecifier {
     binding: Identifier,
     export_name: Sym,
+    string_literal_name: bool,
 }
 
 impl ImportSpecifier {
@@ -169,6 +328,19 @@ impl ImportSpecifier {
         Self {
             binding,
             export_name,
+            string_literal_name: false,
+        }
+    }
+
+    /// Creates a new [`ImportSpecifier`] whose export name is written as a string literal, like
+    /// `"some name"` in `import { "some name" as x } from "module-name"`.
+    #[inline]
+    #[must_use]
+    pub const fn from_string_literal(binding: Identifier, export_name: Sym) -> Self {
+        Self {
+            binding,
+            export_name,
+            string_literal_name: true,
         }
     }
 
@@ -185,6 +357,14 @@ impl ImportSpecifier {
     pub const fn export_name(self) -> Sym {
         self.export_name
     }
+
+    /// Returns `true` if the export name was written as a string literal instead of an
+    /// identifier, which means that it must be quoted when regenerating the code.
+    #[inline]
+    #[must_use]
+    pub const fn is_string_literal_name(self) -> bool {
+        self.string_literal_name
+    }
 }
 
 impl VisitWith for ImportSpecifier {
@@ -204,3 +384,124 @@ impl VisitWith for ImportSpecifier {
         visitor.visit_sym_mut(&mut self.export_name)
     }
 }
+
+/// An import attribute, like `type: "json"` in `import data from "./x.json" with { type: "json" }`.
+///
+/// More information:
+///  - [Import Attributes proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-import-attributes/#prod-WithEntries
+#[derive(Debug, Clone, Copy)]
+pub struct ImportAttribute {
+    key: Sym,
+    value: Sym,
+}
+
+impl ImportAttribute {
+    /// Creates a new [`ImportAttribute`].
+    #[inline]
+    #[must_use]
+    pub const fn new(key: Sym, value: Sym) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn key(self) -> Sym {
+        self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[inline]
+    #[must_use]
+    pub const fn value(self) -> Sym {
+        self.value
+    }
+}
+
+impl VisitWith for ImportAttribute {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_sym(&self.key));
+        visitor.visit_sym(&self.value)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_sym_mut(&mut self.key));
+        visitor.visit_sym_mut(&mut self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Identifier, ImportDeclaration, ImportDeclarationBuilderError, ImportKind, ImportSpecifier,
+    };
+    use boa_interner::Sym;
+
+    /// Checks that the builder produces the expected import kinds.
+    #[test]
+    fn builder_kinds() {
+        let import = ImportDeclaration::builder()
+            .default(Sym::NAME.into())
+            .namespace(Sym::AS.into())
+            .from(Sym::FROM.into())
+            .build()
+            .unwrap();
+        assert_eq!(import.default().map(Identifier::sym), Some(Sym::NAME));
+        assert!(
+            matches!(import.kind(), ImportKind::Namespaced { binding } if binding.sym() == Sym::AS)
+        );
+        assert_eq!(import.specifier().sym(), Sym::FROM);
+
+        let import = ImportDeclaration::builder()
+            .named([ImportSpecifier::new(Sym::AS.into(), Sym::GET)])
+            .named([ImportSpecifier::new(Sym::SET.into(), Sym::SET)])
+            .from(Sym::FROM.into())
+            .build()
+            .unwrap();
+        assert!(import.default().is_none());
+        assert!(matches!(import.kind(), ImportKind::Named { names } if names.len() == 2));
+
+        let import = ImportDeclaration::builder()
+            .named(std::iter::empty())
+            .from(Sym::FROM.into())
+            .build()
+            .unwrap();
+        assert!(matches!(import.kind(), ImportKind::Named { names } if names.is_empty()));
+
+        let import = ImportDeclaration::builder()
+            .from(Sym::FROM.into())
+            .build()
+            .unwrap();
+        assert!(import.default().is_none());
+        assert!(matches!(import.kind(), ImportKind::DefaultOrUnnamed));
+    }
+
+    /// Checks that the builder rejects invalid import declarations.
+    #[test]
+    fn builder_errors() {
+        assert_eq!(
+            ImportDeclaration::builder()
+                .namespace(Sym::AS.into())
+                .named([ImportSpecifier::new(Sym::GET.into(), Sym::GET)])
+                .from(Sym::FROM.into())
+                .build()
+                .unwrap_err(),
+            ImportDeclarationBuilderError::NamespaceAndNamed
+        );
+        assert_eq!(
+            ImportDeclaration::builder()
+                .default(Sym::NAME.into())
+                .build()
+                .unwrap_err(),
+            ImportDeclarationBuilderError::MissingSpecifier
+        );
+    }
+}
@@ -1,6 +1,6 @@
-use crate::join_nodes;
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{join_nodes, Span};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -22,10 +22,12 @@ use super::Expression;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    span: Option<Span>,
 }
 
 impl Call {
@@ -36,9 +38,19 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            span: None,
         }
     }
 
+    /// Sets the span of the source code from the start of the callee to the opening parenthesis
+    /// of the arguments.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Gets the target function of this call expression.
     #[inline]
     #[must_use]
@@ -52,6 +64,24 @@ impl Call {
     pub const fn args(&self) -> &[Expression] {
         &self.args
     }
+
+    /// Gets the span of the source code from the start of the callee to the opening parenthesis
+    /// of the arguments, if known.
+    ///
+    /// For the call of a [`New`](crate::expression::New) expression, the span starts at the `new`
+    /// keyword, and ends at the keyword itself if there are no arguments.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+// The span is not compared, since it's only metadata about the original source code.
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function && self.args == other.args
+    }
 }
 
 impl ToInternedString for Call {
@@ -162,3 +192,95 @@ impl VisitWith for SuperCall {
         ControlFlow::Continue(())
     }
 }
+
+/// The `import()` syntax, commonly called dynamic import, is a function-like expression that allows
+/// loading an ECMAScript module asynchronously and dynamically into a potentially non-module
+/// environment.
+///
+/// Unlike a [`Call`], the `import` keyword is not an identifier that can be looked up; the
+/// expression evaluates to a promise for the module namespace object of the imported module.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportCall
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/import
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImportCall {
+    arg: Box<Expression>,
+    options: Option<Box<Expression>>,
+}
+
+impl ImportCall {
+    /// Creates a new `ImportCall` AST node.
+    pub fn new<A>(arg: A, options: Option<Expression>) -> Self
+    where
+        A: Into<Expression>,
+    {
+        Self {
+            arg: Box::new(arg.into()),
+            options: options.map(Box::new),
+        }
+    }
+
+    /// Retrieves the single argument of the import call.
+    #[must_use]
+    pub const fn argument(&self) -> &Expression {
+        &self.arg
+    }
+
+    /// Retrieves the options argument of the import call, if any.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
+}
+
+impl ToInternedString for ImportCall {
+    #[inline]
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
+    }
+}
+
+impl From<ImportCall> for Expression {
+    #[inline]
+    fn from(call: ImportCall) -> Self {
+        Self::ImportCall(call)
+    }
+}
+
+impl VisitWith for ImportCall {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_expression(&self.arg));
+        if let Some(options) = &self.options {
+            try_break!(visitor.visit_expression(options));
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_expression_mut(&mut self.arg));
+        if let Some(options) = &mut self.options {
+            try_break!(visitor.visit_expression_mut(options));
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -28,18 +28,20 @@ mod identifier;
 mod new;
 mod optional;
 mod parenthesized;
+mod regexp;
 mod spread;
 mod tagged_template;
 mod r#yield;
 
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
-pub use call::{Call, SuperCall};
+pub use call::{Call, ImportCall, SuperCall};
 pub use identifier::{Identifier, RESERVED_IDENTIFIERS_STRICT};
 pub use new::New;
 pub use optional::{Optional, OptionalOperation, OptionalOperationKind};
 pub use parenthesized::Parenthesized;
 pub use r#await::Await;
 pub use r#yield::Yield;
+pub use regexp::RegExpLiteral;
 pub use spread::Spread;
 pub use tagged_template::TaggedTemplate;
 
@@ -80,6 +82,9 @@ pub enum Expression {
     /// See [`ObjectLiteral`].
     ObjectLiteral(ObjectLiteral),
 
+    /// See [`RegExpLiteral`].
+    RegExpLiteral(RegExpLiteral),
+
     /// See [`Spread`],
     Spread(Spread),
 
@@ -121,16 +126,21 @@ pub enum Expression {
     /// See [`SuperCall`].
     SuperCall(SuperCall),
 
+    /// See [`ImportCall`].
+    ImportCall(ImportCall),
+
     /// See [`Optional`].
     Optional(Optional),
 
-    // TODO: Import calls
     /// See [`TaggedTemplate`].
     TaggedTemplate(TaggedTemplate),
 
     /// The `new.target` pseudo-property expression.
     NewTarget,
 
+    /// The `import.meta` pseudo-property expression, which is only valid in module code.
+    ImportMeta,
+
     // TODO: import.meta
     /// See [`Assign`].
     Assign(Assign),
@@ -179,6 +189,7 @@ impl Expression {
             Self::Literal(lit) => lit.to_interned_string(interner),
             Self::ArrayLiteral(arr) => arr.to_interned_string(interner),
             Self::ObjectLiteral(o) => o.to_indented_string(interner, indentation),
+            Self::RegExpLiteral(regexp) => regexp.to_interned_string(interner),
             Self::Spread(sp) => sp.to_interned_string(interner),
             Self::Function(f) => f.to_indented_string(interner, indentation),
             Self::AsyncArrowFunction(f) => f.to_indented_string(interner, indentation),
@@ -192,8 +203,10 @@ impl Expression {
             Self::New(new) => new.to_interned_string(interner),
             Self::Call(call) => call.to_interned_string(interner),
             Self::SuperCall(supc) => supc.to_interned_string(interner),
+            Self::ImportCall(impc) => impc.to_interned_string(interner),
             Self::Optional(opt) => opt.to_interned_string(interner),
             Self::NewTarget => "new.target".to_owned(),
+            Self::ImportMeta => "import.meta".to_owned(),
             Self::TaggedTemplate(tag) => tag.to_interned_string(interner),
             Self::Assign(assign) => assign.to_interned_string(interner),
             Self::Unary(unary) => unary.to_interned_string(interner),
@@ -287,6 +300,7 @@ impl VisitWith for Expression {
             Self::Literal(lit) => visitor.visit_literal(lit),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal(olit),
+            Self::RegExpLiteral(regexp) => visitor.visit_reg_exp_literal(regexp),
             Self::Spread(sp) => visitor.visit_spread(sp),
             Self::Function(f) => visitor.visit_function(f),
             Self::ArrowFunction(af) => visitor.visit_arrow_function(af),
@@ -300,6 +314,7 @@ impl VisitWith for Expression {
             Self::New(n) => visitor.visit_new(n),
             Self::Call(c) => visitor.visit_call(c),
             Self::SuperCall(sc) => visitor.visit_super_call(sc),
+            Self::ImportCall(ic) => visitor.visit_import_call(ic),
             Self::Optional(opt) => visitor.visit_optional(opt),
             Self::TaggedTemplate(tt) => visitor.visit_tagged_template(tt),
             Self::Assign(a) => visitor.visit_assign(a),
@@ -312,7 +327,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list(fpl),
-            Self::This | Self::NewTarget => {
+            Self::This | Self::NewTarget | Self::ImportMeta => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
@@ -328,6 +343,7 @@ impl VisitWith for Expression {
             Self::Literal(lit) => visitor.visit_literal_mut(lit),
             Self::ArrayLiteral(arlit) => visitor.visit_array_literal_mut(arlit),
             Self::ObjectLiteral(olit) => visitor.visit_object_literal_mut(olit),
+            Self::RegExpLiteral(regexp) => visitor.visit_reg_exp_literal_mut(regexp),
             Self::Spread(sp) => visitor.visit_spread_mut(sp),
             Self::Function(f) => visitor.visit_function_mut(f),
             Self::ArrowFunction(af) => visitor.visit_arrow_function_mut(af),
@@ -341,6 +357,7 @@ impl VisitWith for Expression {
             Self::New(n) => visitor.visit_new_mut(n),
             Self::Call(c) => visitor.visit_call_mut(c),
             Self::SuperCall(sc) => visitor.visit_super_call_mut(sc),
+            Self::ImportCall(ic) => visitor.visit_import_call_mut(ic),
             Self::Optional(opt) => visitor.visit_optional_mut(opt),
             Self::TaggedTemplate(tt) => visitor.visit_tagged_template_mut(tt),
             Self::Assign(a) => visitor.visit_assign_mut(a),
@@ -353,7 +370,7 @@ impl VisitWith for Expression {
             Self::Yield(y) => visitor.visit_yield_mut(y),
             Self::Parenthesized(e) => visitor.visit_parenthesized_mut(e),
             Self::FormalParameterList(fpl) => visitor.visit_formal_parameter_list_mut(fpl),
-            Self::This | Self::NewTarget => {
+            Self::This | Self::NewTarget | Self::ImportMeta => {
                 // do nothing; can be handled as special case by visitor
                 ControlFlow::Continue(())
             }
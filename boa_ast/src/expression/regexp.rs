@@ -0,0 +1,88 @@
+//! Regular expression literal Expression.
+
+use crate::{
+    try_break,
+    visitor::{VisitWith, Visitor, VisitorMut},
+};
+use boa_interner::{Interner, Sym, ToInternedString};
+use core::ops::ControlFlow;
+
+use super::Expression;
+
+/// A regular expression literal, like `/ab+c/i`.
+///
+/// Evaluating the literal always creates a new `RegExp` object from the `%RegExp%` intrinsic,
+/// regardless of the current value of the global `RegExp` binding.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regular-expression-literals
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RegExpLiteral {
+    pattern: Sym,
+    flags: Sym,
+}
+
+impl RegExpLiteral {
+    /// Creates a new regular expression literal.
+    #[inline]
+    #[must_use]
+    pub const fn new(pattern: Sym, flags: Sym) -> Self {
+        Self { pattern, flags }
+    }
+
+    /// Gets the pattern of the regular expression.
+    #[inline]
+    #[must_use]
+    pub const fn pattern(&self) -> Sym {
+        self.pattern
+    }
+
+    /// Gets the flags of the regular expression.
+    #[inline]
+    #[must_use]
+    pub const fn flags(&self) -> Sym {
+        self.flags
+    }
+}
+
+impl ToInternedString for RegExpLiteral {
+    #[inline]
+    fn to_interned_string(&self, interner: &Interner) -> String {
+        format!(
+            "/{}/{}",
+            interner.resolve_expect(self.pattern),
+            interner.resolve_expect(self.flags)
+        )
+    }
+}
+
+impl From<RegExpLiteral> for Expression {
+    #[inline]
+    fn from(regexp: RegExpLiteral) -> Self {
+        Self::RegExpLiteral(regexp)
+    }
+}
+
+impl VisitWith for RegExpLiteral {
+    fn visit_with<'a, V>(&'a self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: Visitor<'a>,
+    {
+        try_break!(visitor.visit_sym(&self.pattern));
+        visitor.visit_sym(&self.flags)
+    }
+
+    fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
+    where
+        V: VisitorMut<'a>,
+    {
+        try_break!(visitor.visit_sym_mut(&mut self.pattern));
+        visitor.visit_sym_mut(&mut self.flags)
+    }
+}
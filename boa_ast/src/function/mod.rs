@@ -40,7 +40,7 @@ pub use parameters::{FormalParameter, FormalParameterList, FormalParameterListFl
 
 use crate::try_break;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
-use crate::{block_to_string, join_nodes, StatementList};
+use crate::{block_to_string, join_nodes, Span, StatementList};
 use boa_interner::{Interner, ToIndentedString};
 
 use super::expression::{Expression, Identifier};
@@ -58,12 +58,14 @@ use super::Declaration;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Function {
     name: Option<Identifier>,
     parameters: FormalParameterList,
     body: StatementList,
     has_binding_identifier: bool,
+    #[cfg_attr(feature = "arbitrary", arbitrary(default))]
+    span: Option<Span>,
 }
 
 impl Function {
@@ -80,6 +82,7 @@ impl Function {
             parameters,
             body,
             has_binding_identifier: false,
+            span: None,
         }
     }
 
@@ -97,9 +100,18 @@ impl Function {
             parameters,
             body,
             has_binding_identifier,
+            span: None,
         }
     }
 
+    /// Sets the span of the source code that declares this function.
+    #[inline]
+    #[must_use]
+    pub const fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
     /// Gets the name of the function declaration.
     #[inline]
     #[must_use]
@@ -127,6 +139,23 @@ impl Function {
     pub const fn has_binding_identifier(&self) -> bool {
         self.has_binding_identifier
     }
+
+    /// Gets the span of the source code that declares this function, if known.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        self.span
+    }
+}
+
+// The span is not compared, since it's only metadata about the original source code.
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.parameters == other.parameters
+            && self.body == other.body
+            && self.has_binding_identifier == other.has_binding_identifier
+    }
 }
 
 impl ToIndentedString for Function {
@@ -40,6 +40,31 @@ impl ModuleItemList {
         &self.items
     }
 
+    /// Abstract operation [`ModuleRequests`][spec].
+    ///
+    /// Returns the specifiers of the modules requested by the import declarations and re-exports
+    /// of the list, in source order and without duplicates.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-modulerequests
+    #[inline]
+    #[must_use]
+    pub fn requests(&self) -> Vec<Sym> {
+        let mut requests = Vec::new();
+        for item in &*self.items {
+            let specifier = match item {
+                ModuleItem::ImportDeclaration(import) => import.specifier(),
+                ModuleItem::ExportDeclaration(ExportDeclaration::ReExport {
+                    specifier, ..
+                }) => *specifier,
+                _ => continue,
+            };
+            if !requests.contains(&specifier.sym()) {
+                requests.push(specifier.sym());
+            }
+        }
+        requests
+    }
+
     /// Abstract operation [`ExportedNames`][spec].
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-exportednames
@@ -78,8 +103,8 @@ impl ModuleItemList {
                 match node {
                     ExportDeclaration::ReExport { kind, .. } => {
                         match kind {
-                            ReExportKind::Namespaced { name: Some(name) } => self.0.push(*name),
-                            ReExportKind::Namespaced { name: None } => {}
+                            ReExportKind::All => {}
+                            ReExportKind::Namespaced { name } => self.0.push(name.sym()),
                             ReExportKind::Named { names } => {
                                 for specifier in &**names {
                                     try_break!(self.visit_export_specifier(specifier));
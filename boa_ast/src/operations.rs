@@ -9,17 +9,22 @@ use boa_interner::{Interner, Sym};
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
-    declaration::{ExportDeclaration, ImportDeclaration, VarDeclaration},
-    expression::{access::SuperPropertyAccess, Await, Identifier, SuperCall, Yield},
+    declaration::{ExportDeclaration, ImportDeclaration, ModuleSpecifier, VarDeclaration},
+    expression::{
+        access::{PropertyAccess, PropertyAccessField, SuperPropertyAccess},
+        literal::Literal,
+        operator::binary::BinaryOp,
+        Await, Call, Identifier, ImportCall, Optional, OptionalOperationKind, SuperCall, Yield,
+    },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
         Function, Generator, PrivateName,
     },
     property::{MethodDefinition, PropertyDefinition},
-    statement::LabelledItem,
+    statement::{iteration::IterableLoopInitializer, LabelledItem},
     try_break,
     visitor::{NodeRef, VisitWith, Visitor, VisitorMut},
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Span, Statement, StatementList, StatementListItem,
 };
 
 /// Represents all the possible symbols searched for by the [`Contains`][contains] operation.
@@ -567,6 +572,16 @@ impl<'ast> Visitor<'ast> for VarDeclaredNamesVisitor<'_> {
         BoundNamesVisitor(self.0).visit_var_declaration(node)
     }
 
+    fn visit_iterable_loop_initializer(
+        &mut self,
+        node: &'ast IterableLoopInitializer,
+    ) -> ControlFlow<Self::BreakTy> {
+        match node {
+            IterableLoopInitializer::Var(var) => BoundNamesVisitor(self.0).visit_variable(var),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+
     fn visit_labelled_item(&mut self, node: &'ast LabelledItem) -> ControlFlow<Self::BreakTy> {
         match node {
             LabelledItem::Function(_) => ControlFlow::Continue(()),
@@ -1230,3 +1245,211 @@ where
 
     node.visit_with(&mut visitor).is_break()
 }
+
+/// Returns the specifiers of all the modules imported by the given node, in source order.
+///
+/// This includes the specifiers of the import declarations and the string literal arguments
+/// of dynamic `import()` calls. Dynamic imports with a non-literal specifier cannot be
+/// analyzed statically, so they are skipped.
+#[must_use]
+pub fn collect_import_specifiers<N>(node: &N) -> Vec<ModuleSpecifier>
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to collect the imported specifiers.
+    #[derive(Debug)]
+    struct ImportSpecifiersVisitor<'a>(&'a mut Vec<ModuleSpecifier>);
+
+    impl<'ast> Visitor<'ast> for ImportSpecifiersVisitor<'_> {
+        type BreakTy = Infallible;
+
+        fn visit_import_declaration(
+            &mut self,
+            node: &'ast ImportDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            self.0.push(node.specifier());
+            ControlFlow::Continue(())
+        }
+
+        fn visit_import_call(&mut self, node: &'ast ImportCall) -> ControlFlow<Self::BreakTy> {
+            if let Expression::Literal(Literal::String(specifier)) = node.argument() {
+                self.0.push(ModuleSpecifier::new(*specifier));
+            }
+            node.visit_with(self)
+        }
+    }
+
+    let mut specifiers = Vec::new();
+    let _ = node.visit_with(&mut ImportSpecifiersVisitor(&mut specifiers));
+    specifiers
+}
+
+/// Returns the specifiers of all the modules re-exported by the given node, in source order.
+///
+/// These are the specifiers of the `export * from "module"`, `export * as name from "module"`
+/// and `export { name } from "module"` declarations.
+#[must_use]
+pub fn collect_export_specifiers<N>(node: &N) -> Vec<ModuleSpecifier>
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to collect the re-exported specifiers.
+    #[derive(Debug)]
+    struct ExportSpecifiersVisitor<'a>(&'a mut Vec<ModuleSpecifier>);
+
+    impl<'ast> Visitor<'ast> for ExportSpecifiersVisitor<'_> {
+        type BreakTy = Infallible;
+
+        fn visit_export_declaration(
+            &mut self,
+            node: &'ast ExportDeclaration,
+        ) -> ControlFlow<Self::BreakTy> {
+            if let ExportDeclaration::ReExport { specifier, .. } = node {
+                self.0.push(*specifier);
+            }
+            node.visit_with(self)
+        }
+    }
+
+    let mut specifiers = Vec::new();
+    let _ = node.visit_with(&mut ExportSpecifiersVisitor(&mut specifiers));
+    specifiers
+}
+
+/// The kind of an `eval` call site found by [`eval_calls`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvalCallKind {
+    /// A direct `eval` call (`eval(x)` or `(eval)(x)`), which evaluates the code in the scope of
+    /// the caller.
+    Direct,
+    /// An indirect `eval` call (`(0, eval)(x)`, `globalThis.eval(x)` or `eval?.(x)`), which
+    /// evaluates the code in the global scope.
+    Indirect,
+}
+
+/// An `eval` call site found by [`eval_calls`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EvalCallSite<'a> {
+    /// A call expression, like `eval(x)`.
+    Call(&'a Call),
+    /// An optional chain containing the call, like `eval?.(x)`.
+    Optional(&'a Optional),
+}
+
+impl EvalCallSite<'_> {
+    /// Gets the span of the source code from the start of the callee to the opening parenthesis
+    /// of the arguments, if it is known.
+    ///
+    /// Optional chains don't record their span, so this is always `None` for them.
+    #[must_use]
+    pub const fn span(&self) -> Option<Span> {
+        match self {
+            Self::Call(call) => call.span(),
+            Self::Optional(_) => None,
+        }
+    }
+}
+
+/// Returns all the `eval` call sites of the given node in the order of the AST, classified as direct
+/// or indirect calls.
+///
+/// The parser moves function declarations to the start of their statement list, so the calls
+/// inside of them come before the calls of the surrounding statements.
+///
+/// The [`span`][EvalCallSite::span] of the returned calls can be used to report their position in
+/// the source code. Calls through other aliases of `eval` (like `const e = eval; e(x)`) cannot be
+/// detected statically, so they are not included.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-calls-runtime-semantics-evaluation
+#[must_use]
+pub fn eval_calls<N>(node: &N) -> Vec<(EvalCallKind, EvalCallSite<'_>)>
+where
+    N: VisitWith,
+{
+    /// Visitor used by the function to collect the `eval` calls.
+    #[derive(Debug)]
+    struct EvalCallsVisitor<'a, 'ast>(&'a mut Vec<(EvalCallKind, EvalCallSite<'ast>)>);
+
+    impl<'ast> Visitor<'ast> for EvalCallsVisitor<'_, 'ast> {
+        type BreakTy = Infallible;
+
+        fn visit_call(&mut self, node: &'ast Call) -> ControlFlow<Self::BreakTy> {
+            if let Some(kind) = eval_call_kind(node.function()) {
+                self.0.push((kind, EvalCallSite::Call(node)));
+            }
+            node.visit_with(self)
+        }
+
+        fn visit_optional(&mut self, node: &'ast Optional) -> ControlFlow<Self::BreakTy> {
+            // Calls inside of an optional chain are never direct `eval` calls, since the callee
+            // is not a plain `eval` identifier.
+            let eval = node.chain().iter().enumerate().any(|(index, op)| {
+                if !matches!(op.kind(), OptionalOperationKind::Call { .. }) {
+                    return false;
+                }
+                match index
+                    .checked_sub(1)
+                    .map(|previous| node.chain()[previous].kind())
+                {
+                    None => eval_call_kind(node.target()).is_some(),
+                    Some(OptionalOperationKind::SimplePropertyAccess { field }) => {
+                        is_eval_field(field)
+                    }
+                    Some(_) => false,
+                }
+            });
+            if eval {
+                self.0
+                    .push((EvalCallKind::Indirect, EvalCallSite::Optional(node)));
+            }
+            node.visit_with(self)
+        }
+    }
+
+    /// Returns `true` if `field` is the `eval` property.
+    fn is_eval_field(field: &PropertyAccessField) -> bool {
+        match field {
+            PropertyAccessField::Const(field) => *field == Sym::EVAL,
+            PropertyAccessField::Expr(field) => matches!(
+                &**field,
+                Expression::Literal(Literal::String(field)) if *field == Sym::EVAL
+            ),
+        }
+    }
+
+    /// Returns the kind of the `eval` call to `callee`, or `None` if it isn't a call to `eval`.
+    fn eval_call_kind(callee: &Expression) -> Option<EvalCallKind> {
+        match callee.flatten() {
+            Expression::Identifier(ident) if *ident == Sym::EVAL => Some(EvalCallKind::Direct),
+            Expression::Binary(binary) if binary.op() == BinaryOp::Comma => {
+                let eval = matches!(
+                    binary.rhs().flatten(),
+                    Expression::Identifier(ident) if *ident == Sym::EVAL
+                );
+                eval.then_some(EvalCallKind::Indirect)
+            }
+            Expression::PropertyAccess(PropertyAccess::Simple(access)) => {
+                is_eval_field(access.field()).then_some(EvalCallKind::Indirect)
+            }
+            _ => None,
+        }
+    }
+
+    let mut calls = Vec::new();
+    let _ = node.visit_with(&mut EvalCallsVisitor(&mut calls));
+    calls
+}
+
+/// Returns `true` if the given node contains a direct or indirect `eval` call.
+///
+/// See [`eval_calls`] for the `eval` calls that can be detected.
+#[must_use]
+pub fn contains_eval_call<N>(node: &N) -> bool
+where
+    N: VisitWith,
+{
+    !eval_calls(node).is_empty()
+}
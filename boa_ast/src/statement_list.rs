@@ -5,6 +5,7 @@ use crate::{
     statement::Statement,
     try_break,
     visitor::{VisitWith, Visitor, VisitorMut},
+    Position,
 };
 use boa_interner::{Interner, ToIndentedString};
 use core::ops::ControlFlow;
@@ -125,10 +126,12 @@ impl VisitWith for StatementListItem {
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct StatementList {
     statements: Box<[StatementListItem]>,
     strict: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    positions: Option<Box<[Position]>>,
 }
 
 impl StatementList {
@@ -141,9 +144,25 @@ impl StatementList {
         Self {
             statements: statements.into(),
             strict,
+            positions: None,
         }
     }
 
+    /// Sets the start positions in the source code of the statements, in the same order as the
+    /// statements.
+    ///
+    /// The positions are dropped if there isn't exactly one for every statement.
+    #[inline]
+    #[must_use]
+    pub fn with_positions<P>(mut self, positions: P) -> Self
+    where
+        P: Into<Box<[Position]>>,
+    {
+        let positions = positions.into();
+        self.positions = (positions.len() == self.statements.len()).then_some(positions);
+        self
+    }
+
     /// Gets the list of statements.
     #[inline]
     #[must_use]
@@ -157,6 +176,20 @@ impl StatementList {
     pub const fn strict(&self) -> bool {
         self.strict
     }
+
+    /// Gets the start positions in the source code of the statements, if known.
+    #[inline]
+    #[must_use]
+    pub fn positions(&self) -> Option<&[Position]> {
+        self.positions.as_deref()
+    }
+}
+
+// The positions are not compared, since they are only metadata about the original source code.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.statements == other.statements && self.strict == other.strict
+    }
 }
 
 impl From<Box<[StatementListItem]>> for StatementList {
@@ -165,6 +198,7 @@ impl From<Box<[StatementListItem]>> for StatementList {
         Self {
             statements: stm,
             strict: false,
+            positions: None,
         }
     }
 }
@@ -175,6 +209,7 @@ impl From<Vec<StatementListItem>> for StatementList {
         Self {
             statements: stm.into(),
             strict: false,
+            positions: None,
         }
     }
 }
@@ -221,6 +256,7 @@ impl<'a> arbitrary::Arbitrary<'a> for StatementList {
         Ok(Self {
             statements: u.arbitrary()?,
             strict: false, // disable strictness; this is *not* in source data
+            positions: None,
         })
     }
 }
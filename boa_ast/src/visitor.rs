@@ -7,9 +7,9 @@ use std::ops::ControlFlow;
 
 use crate::{
     declaration::{
-        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportDeclaration, ImportKind,
-        ImportSpecifier, LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration,
-        Variable, VariableList,
+        Binding, Declaration, ExportDeclaration, ExportSpecifier, ImportAttribute,
+        ImportDeclaration, ImportKind, ImportSpecifier, LexicalDeclaration, ModuleSpecifier,
+        ReExportKind, VarDeclaration, Variable, VariableList,
     },
     expression::{
         access::{
@@ -21,8 +21,9 @@ use crate::{
             assign::{Assign, AssignTarget},
             Binary, BinaryInPrivate, Conditional, Unary, Update,
         },
-        Await, Call, Expression, Identifier, New, Optional, OptionalOperation,
-        OptionalOperationKind, Parenthesized, Spread, SuperCall, TaggedTemplate, Yield,
+        Await, Call, Expression, Identifier, ImportCall, New, Optional, OptionalOperation,
+        OptionalOperationKind, Parenthesized, RegExpLiteral, Spread, SuperCall, TaggedTemplate,
+        Yield,
     },
     function::{
         ArrowFunction, AsyncArrowFunction, AsyncFunction, AsyncGenerator, Class, ClassElement,
@@ -155,6 +156,7 @@ node_ref! {
     Literal,
     ArrayLiteral,
     ObjectLiteral,
+    RegExpLiteral,
     Spread,
     ArrowFunction,
     AsyncArrowFunction,
@@ -163,6 +165,7 @@ node_ref! {
     New,
     Call,
     SuperCall,
+    ImportCall,
     Optional,
     TaggedTemplate,
     Assign,
@@ -203,6 +206,7 @@ node_ref! {
     ImportKind,
     ImportDeclaration,
     ImportSpecifier,
+    ImportAttribute,
     ReExportKind,
     ExportDeclaration,
     ExportSpecifier
@@ -254,6 +258,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_literal, Literal);
     define_visit!(visit_array_literal, ArrayLiteral);
     define_visit!(visit_object_literal, ObjectLiteral);
+    define_visit!(visit_reg_exp_literal, RegExpLiteral);
     define_visit!(visit_spread, Spread);
     define_visit!(visit_arrow_function, ArrowFunction);
     define_visit!(visit_async_arrow_function, AsyncArrowFunction);
@@ -262,6 +267,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_new, New);
     define_visit!(visit_call, Call);
     define_visit!(visit_super_call, SuperCall);
+    define_visit!(visit_import_call, ImportCall);
     define_visit!(visit_optional, Optional);
     define_visit!(visit_tagged_template, TaggedTemplate);
     define_visit!(visit_assign, Assign);
@@ -302,6 +308,7 @@ pub trait Visitor<'ast>: Sized {
     define_visit!(visit_import_kind, ImportKind);
     define_visit!(visit_import_declaration, ImportDeclaration);
     define_visit!(visit_import_specifier, ImportSpecifier);
+    define_visit!(visit_import_attribute, ImportAttribute);
     define_visit!(visit_re_export_kind, ReExportKind);
     define_visit!(visit_export_declaration, ExportDeclaration);
     define_visit!(visit_export_specifier, ExportSpecifier);
@@ -349,6 +356,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::Literal(n) => self.visit_literal(n),
             NodeRef::ArrayLiteral(n) => self.visit_array_literal(n),
             NodeRef::ObjectLiteral(n) => self.visit_object_literal(n),
+            NodeRef::RegExpLiteral(n) => self.visit_reg_exp_literal(n),
             NodeRef::Spread(n) => self.visit_spread(n),
             NodeRef::ArrowFunction(n) => self.visit_arrow_function(n),
             NodeRef::AsyncArrowFunction(n) => self.visit_async_arrow_function(n),
@@ -357,6 +365,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::New(n) => self.visit_new(n),
             NodeRef::Call(n) => self.visit_call(n),
             NodeRef::SuperCall(n) => self.visit_super_call(n),
+            NodeRef::ImportCall(n) => self.visit_import_call(n),
             NodeRef::Optional(n) => self.visit_optional(n),
             NodeRef::TaggedTemplate(n) => self.visit_tagged_template(n),
             NodeRef::Assign(n) => self.visit_assign(n),
@@ -397,6 +406,7 @@ pub trait Visitor<'ast>: Sized {
             NodeRef::ImportKind(n) => self.visit_import_kind(n),
             NodeRef::ImportDeclaration(n) => self.visit_import_declaration(n),
             NodeRef::ImportSpecifier(n) => self.visit_import_specifier(n),
+            NodeRef::ImportAttribute(n) => self.visit_import_attribute(n),
             NodeRef::ReExportKind(n) => self.visit_re_export_kind(n),
             NodeRef::ExportDeclaration(n) => self.visit_export_declaration(n),
             NodeRef::ExportSpecifier(n) => self.visit_export_specifier(n),
@@ -450,6 +460,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_literal_mut, Literal);
     define_visit_mut!(visit_array_literal_mut, ArrayLiteral);
     define_visit_mut!(visit_object_literal_mut, ObjectLiteral);
+    define_visit_mut!(visit_reg_exp_literal_mut, RegExpLiteral);
     define_visit_mut!(visit_spread_mut, Spread);
     define_visit_mut!(visit_arrow_function_mut, ArrowFunction);
     define_visit_mut!(visit_async_arrow_function_mut, AsyncArrowFunction);
@@ -458,6 +469,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_new_mut, New);
     define_visit_mut!(visit_call_mut, Call);
     define_visit_mut!(visit_super_call_mut, SuperCall);
+    define_visit_mut!(visit_import_call_mut, ImportCall);
     define_visit_mut!(visit_optional_mut, Optional);
     define_visit_mut!(visit_tagged_template_mut, TaggedTemplate);
     define_visit_mut!(visit_assign_mut, Assign);
@@ -498,6 +510,7 @@ pub trait VisitorMut<'ast>: Sized {
     define_visit_mut!(visit_import_kind_mut, ImportKind);
     define_visit_mut!(visit_import_declaration_mut, ImportDeclaration);
     define_visit_mut!(visit_import_specifier_mut, ImportSpecifier);
+    define_visit_mut!(visit_import_attribute_mut, ImportAttribute);
     define_visit_mut!(visit_re_export_kind_mut, ReExportKind);
     define_visit_mut!(visit_export_declaration_mut, ExportDeclaration);
     define_visit_mut!(visit_export_specifier_mut, ExportSpecifier);
@@ -545,6 +558,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::Literal(n) => self.visit_literal_mut(n),
             NodeRefMut::ArrayLiteral(n) => self.visit_array_literal_mut(n),
             NodeRefMut::ObjectLiteral(n) => self.visit_object_literal_mut(n),
+            NodeRefMut::RegExpLiteral(n) => self.visit_reg_exp_literal_mut(n),
             NodeRefMut::Spread(n) => self.visit_spread_mut(n),
             NodeRefMut::ArrowFunction(n) => self.visit_arrow_function_mut(n),
             NodeRefMut::AsyncArrowFunction(n) => self.visit_async_arrow_function_mut(n),
@@ -553,6 +567,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::New(n) => self.visit_new_mut(n),
             NodeRefMut::Call(n) => self.visit_call_mut(n),
             NodeRefMut::SuperCall(n) => self.visit_super_call_mut(n),
+            NodeRefMut::ImportCall(n) => self.visit_import_call_mut(n),
             NodeRefMut::Optional(n) => self.visit_optional_mut(n),
             NodeRefMut::TaggedTemplate(n) => self.visit_tagged_template_mut(n),
             NodeRefMut::Assign(n) => self.visit_assign_mut(n),
@@ -593,6 +608,7 @@ pub trait VisitorMut<'ast>: Sized {
             NodeRefMut::ImportKind(n) => self.visit_import_kind_mut(n),
             NodeRefMut::ImportDeclaration(n) => self.visit_import_declaration_mut(n),
             NodeRefMut::ImportSpecifier(n) => self.visit_import_specifier_mut(n),
+            NodeRefMut::ImportAttribute(n) => self.visit_import_attribute_mut(n),
             NodeRefMut::ReExportKind(n) => self.visit_re_export_kind_mut(n),
             NodeRefMut::ExportDeclaration(n) => self.visit_export_declaration_mut(n),
             NodeRefMut::ExportSpecifier(n) => self.visit_export_specifier_mut(n),
@@ -28,6 +28,21 @@ fn set_constant_folding(
     Ok(JsValue::undefined())
 }
 
+fn get_peephole(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    Ok(context
+        .optimizer_options()
+        .contains(OptimizerOptions::PEEPHOLE)
+        .into())
+}
+
+fn set_peephole(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0).to_boolean();
+    let mut options = context.optimizer_options();
+    options.set(OptimizerOptions::PEEPHOLE, value);
+    context.set_optimizer_options(options);
+    Ok(JsValue::undefined())
+}
+
 fn get_statistics(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
     Ok(context
         .optimizer_options()
@@ -55,6 +70,17 @@ pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
             .length(1)
             .build();
 
+    let get_peephole =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_peephole))
+            .name("get peephole")
+            .length(0)
+            .build();
+    let set_peephole =
+        FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(set_peephole))
+            .name("set peephole")
+            .length(1)
+            .build();
+
     let get_statistics =
         FunctionObjectBuilder::new(context, NativeFunction::from_fn_ptr(get_statistics))
             .name("get statistics")
@@ -72,6 +98,12 @@ pub(super) fn create_object(context: &mut Context<'_>) -> JsObject {
             Some(set_constant_folding),
             Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
         )
+        .accessor(
+            "peephole",
+            Some(get_peephole),
+            Some(set_peephole),
+            Attribute::WRITABLE | Attribute::CONFIGURABLE | Attribute::NON_ENUMERABLE,
+        )
         .accessor(
             "statistics",
             Some(get_statistics),
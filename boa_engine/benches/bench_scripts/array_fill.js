@@ -0,0 +1,9 @@
+(function () {
+  let testArray = new Array(10000);
+  for (let i = 0; i < 10; i++) {
+    testArray.fill(i);
+    testArray.fill("p" + i, 2500, 7500);
+  }
+
+  return testArray;
+})();
@@ -0,0 +1,11 @@
+(function () {
+  let testArray = [];
+  for (let i = 0; i < 10000; i++) {
+    testArray[i] = i;
+  }
+
+  let mapped = testArray.map((value) => value * 2);
+  mapped = mapped.map((value, index) => value + index);
+
+  return mapped;
+})();
@@ -0,0 +1,12 @@
+(function () {
+  let count = 0;
+  for (let i = 0; i < 1000; i++) {
+    const o = { a: i, b: i, c: i, d: i };
+    for (const key in o) {
+      count += o[key];
+    }
+    count += Object.keys(o).length;
+  }
+
+  return count;
+})();
@@ -0,0 +1,14 @@
+(function () {
+  function sum(a, b, c, d, e, f, g, h) {
+    return a + b + c + d + e + f + g + h;
+  }
+
+  let args = [1, 2, 3, 4, 5, 6, 7, 8];
+  let result = 0;
+
+  for (let i = 0; i < 10000; i++) {
+    result += sum.apply(undefined, args);
+  }
+
+  return result;
+})();
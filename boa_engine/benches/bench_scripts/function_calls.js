@@ -0,0 +1,14 @@
+(function () {
+  function sum(a, b, c) {
+    let x = a + b;
+    let y = x + c;
+    return y;
+  }
+
+  let total = 0;
+  for (let i = 0; i < 1000; i++) {
+    total = sum(total, i, 1);
+  }
+
+  return total;
+})();
@@ -0,0 +1,8 @@
+(function () {
+  let keys = [];
+  for (let i = 0; i < 1000; i++) {
+    keys.push("key" + (i % 100));
+  }
+
+  return keys;
+})();
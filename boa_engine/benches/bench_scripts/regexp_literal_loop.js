@@ -0,0 +1,11 @@
+(function () {
+  let matches = 0;
+  for (let i = 0; i < 1000; i++) {
+    const regExp = /hello/i;
+    if (regExp.test("Hello World")) {
+      matches++;
+    }
+  }
+
+  return matches;
+})();
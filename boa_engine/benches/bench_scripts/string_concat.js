@@ -3,4 +3,9 @@
   var b = "world";
 
   var c = a + b;
+
+  var d = "The quick brown fox jumps over the lazy dog, ";
+  var e = "0123456789 ABCDEFGHIJKLMNOPQRSTUVWXYZ.";
+
+  var f = d + e;
 })();
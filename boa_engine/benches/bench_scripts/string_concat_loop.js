@@ -0,0 +1,9 @@
+(function () {
+  var s = "";
+
+  for (let i = 0; i < 10000; i++) {
+    s += "The quick brown fox jumps over the lazy dog. ";
+  }
+
+  s.length;
+})();
@@ -1,4 +1,7 @@
 (function () {
   var a = "hello";
   var b = a;
+
+  var c = "The quick brown fox jumps over the lazy dog, 0123456789.";
+  var d = c;
 })();
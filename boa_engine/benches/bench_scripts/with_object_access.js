@@ -0,0 +1,11 @@
+(function () {
+  var point = { x: 1, y: 2, z: 3 };
+  var total = 0;
+  with (point) {
+    for (var i = 0; i < 1000; i++) {
+      total += x + y + z;
+    }
+  }
+
+  return total;
+})();
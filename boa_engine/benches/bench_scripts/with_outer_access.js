@@ -0,0 +1,10 @@
+(function () {
+  var total = 0;
+  with ({ x: 1 }) {
+    for (var i = 0; i < 1000; i++) {
+      total += x;
+    }
+  }
+
+  return total;
+})();
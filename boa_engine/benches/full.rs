@@ -7,13 +7,29 @@ use boa_engine::{
 use criterion::{criterion_group, criterion_main, Criterion};
 use std::hint::black_box;
 
-#[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
-#[cfg_attr(
-    all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"),
-    global_allocator
-)]
+#[cfg(all(feature = "bench-jemalloc", feature = "bench-mimalloc"))]
+compile_error!("the `bench-jemalloc` and `bench-mimalloc` features cannot be enabled together");
+
+#[cfg(all(
+    feature = "bench-jemalloc",
+    not(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))
+))]
+compile_error!("the `bench-jemalloc` feature is only supported on `x86_64-unknown-linux-gnu`");
+
+#[cfg(all(
+    feature = "bench-jemalloc",
+    not(feature = "bench-mimalloc"),
+    target_arch = "x86_64",
+    target_os = "linux",
+    target_env = "gnu"
+))]
+#[global_allocator]
 static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
+#[cfg(all(feature = "bench-mimalloc", not(feature = "bench-jemalloc")))]
+#[global_allocator]
+static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
+
 fn create_realm(c: &mut Criterion) {
     c.bench_function("Create Realm", move |b| {
         let root_shape = SharedShape::root();
@@ -88,11 +104,16 @@ full_benchmarks!(
     {"RegExp Literal Creation", regexp_literal_creation},
     {"RegExp Creation", regexp_creation},
     {"RegExp Literal", regexp_literal},
+    {"RegExp Literal in a loop", regexp_literal_loop},
     {"RegExp", regexp},
     {"Array access", array_access},
     {"Array creation", array_create},
     {"Array pop", array_pop},
+    {"Array fill", array_fill},
+    {"Array map", array_map},
+    {"Function apply", function_apply},
     {"String concatenation", string_concat},
+    {"String concatenation loop", string_concat_loop},
     {"String comparison", string_compare},
     {"String copy", string_copy},
     {"Number Object Access", number_object_access},
@@ -100,14 +121,47 @@ full_benchmarks!(
     {"String Object Access", string_object_access},
     {"Arithmetic operations", arithmetic_operations},
     {"Clean js", clean_js},
-    {"Mini js", mini_js}
+    {"Mini js", mini_js},
+    {"With outer access", with_outer_access},
+    {"With object access", with_object_access},
+    {"For-in over same shape", for_in_same_shape},
+    {"Function calls", function_calls},
+    {"Integer to string", integer_to_string}
 );
 
+fn bench_integer_cache(c: &mut Criterion) {
+    static SCRIPTS: [(&str, &str); 3] = [
+        ("For loop", include_str!("bench_scripts/for_loop.js")),
+        ("Fibonacci", include_str!("bench_scripts/fibonacci.js")),
+        (
+            "Integer to string",
+            include_str!("bench_scripts/integer_to_string.js"),
+        ),
+    ];
+
+    for (id, code) in SCRIPTS {
+        let mut context = Context::default();
+
+        // Disable optimizations
+        context.set_optimizer_options(OptimizerOptions::empty());
+        context.set_integer_cache_range(-128..=127);
+
+        let statement_list = context
+            .parse_script(Source::from_bytes(code))
+            .expect("parsing failed");
+        let code_block = context.compile_script(&statement_list).unwrap();
+        c.bench_function(&format!("{id} (Execution, integer cache)"), move |b| {
+            b.iter(|| context.execute(black_box(code_block.clone())).unwrap())
+        });
+    }
+}
+
 criterion_group!(
     benches,
     create_realm,
     bench_parser,
     bench_compile,
     bench_execution,
+    bench_integer_cache,
 );
 criterion_main!(benches);
@@ -0,0 +1,507 @@
+//! Boa's implementation of the `Array.fromAsync` static method.
+//!
+//! More information:
+//!  - [Proposal reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+
+use boa_gc::{Finalize, Trace};
+use boa_macros::utf16;
+
+use crate::{
+    builtins::{
+        iterable::{AsyncFromSyncIterator, IteratorHint, IteratorRecord, IteratorResult},
+        promise::{Promise, PromiseCapability},
+    },
+    error::JsNativeError,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsFunction, JsObject},
+    symbol::JsSymbol,
+    Context, JsArgs, JsError, JsResult, JsValue,
+};
+
+use super::Array;
+
+/// The source of the values of an `Array.fromAsync` call.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+
+    /// An array-like object and its length.
+    ArrayLike { object: JsObject, len: u64 },
+}
+
+/// The state of a running `Array.fromAsync` call.
+///
+/// Every `Await` of the algorithm is replaced by a promise reaction that captures this state and
+/// resumes the algorithm on the corresponding continuation.
+#[derive(Debug, Clone, Trace, Finalize)]
+struct FromAsync {
+    capability: PromiseCapability,
+    target: JsObject,
+    source: Source,
+    mapfn: Option<JsObject>,
+    this_arg: JsValue,
+    k: u64,
+}
+
+/// A continuation of `Array.fromAsync`, resumed with the result of an `Await`.
+type Continuation = fn(JsValue, FromAsync, &mut Context<'_>) -> JsResult<()>;
+
+/// The captures of a promise reaction that resumes `Array.fromAsync`.
+#[derive(Clone, Trace, Finalize)]
+struct Reaction {
+    state: FromAsync,
+    #[unsafe_ignore_trace]
+    continuation: Continuation,
+}
+
+impl Array {
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` static method creates a new, shallow-copied `Array` instance from
+    /// an async iterable, iterable, or array-like object, awaiting each of its values.
+    ///
+    /// More information:
+    ///  - [Proposal reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("%Promise% constructor must be a valid constructor");
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C, mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        if let Err(err) = Self::from_async_start(this, args, capability.clone(), context) {
+            FromAsync::reject(&capability, &err, context);
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(capability.promise().clone().into())
+    }
+
+    /// Runs the synchronous part of `fromAsyncClosure`, up to its first `Await`.
+    fn from_async_start(
+        this: &JsValue,
+        args: &[JsValue],
+        capability: PromiseCapability,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        let async_items = args.get_or_undefined(0);
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapfn = match args.get_or_undefined(1) {
+            JsValue::Undefined => None,
+            JsValue::Object(mapfn) if mapfn.is_callable() => Some(mapfn.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message("Array.fromAsync: mapping function is not callable")
+                    .into())
+            }
+        };
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let using_async_iterator = async_items.get_method(JsSymbol::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        // e. Let iteratorRecord be undefined.
+        // f. If usingAsyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? GetIterator(asyncItems, async, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIterator(asyncItems, sync, usingSyncIterator)).
+        let iterator_record = if let Some(method) = using_async_iterator {
+            Some(async_items.get_iterator(context, Some(IteratorHint::Async), Some(method))?)
+        } else if let Some(method) = async_items.get_method(JsSymbol::iterator(), context)? {
+            let sync_iterator_record =
+                async_items.get_iterator(context, Some(IteratorHint::Sync), Some(method))?;
+            Some(AsyncFromSyncIterator::create(sync_iterator_record, context))
+        } else {
+            None
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        if let Some(iterator_record) = iterator_record {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let target = match this.as_constructor() {
+                Some(c) => c.construct(&[], None, context)?,
+                None => Self::array_create(0, None, context)
+                    .expect("creating an empty array with the default prototype must not fail"),
+            };
+
+            // iii. Let k be 0.
+            // iv. Repeat,
+            return FromAsync {
+                capability,
+                target,
+                source: Source::Iterator(iterator_record),
+                mapfn,
+                this_arg,
+                k: 0,
+            }
+            .iterator_step(context);
+        }
+
+        // i. Else,
+        //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+        //     ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = async_items
+            .to_object(context)
+            .expect("should not fail according to spec");
+
+        //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        //     iv. If IsConstructor(C) is true, then
+        //         1. Let A be ? Construct(C, « 𝔽(len) »).
+        //     v. Else,
+        //         1. Let A be ? ArrayCreate(len).
+        let target = match this.as_constructor() {
+            Some(c) => c.construct(&[len.into()], None, context)?,
+            None => Self::array_create(len, None, context)?,
+        };
+
+        //     vi. Let k be 0.
+        //     vii. Repeat, while k < len,
+        FromAsync {
+            capability,
+            target,
+            source: Source::ArrayLike {
+                object: array_like,
+                len,
+            },
+            mapfn,
+            this_arg,
+            k: 0,
+        }
+        .array_like_step(context)
+    }
+}
+
+impl FromAsync {
+    /// Rejects the promise returned by `Array.fromAsync` with `error`.
+    fn reject(capability: &PromiseCapability, error: &JsError, context: &mut Context<'_>) {
+        let error = error.to_opaque(context);
+        capability
+            .reject()
+            .call(&JsValue::undefined(), &[error], context)
+            .expect("cannot fail per spec");
+    }
+
+    /// Creates a promise reaction that resumes the algorithm on `continuation`, rejecting the
+    /// promise returned by `Array.fromAsync` if the continuation throws.
+    fn reaction(self, continuation: Continuation, context: &mut Context<'_>) -> JsFunction {
+        FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, reaction: &Reaction, context| {
+                    let state = reaction.state.clone();
+                    let continuation = reaction.continuation;
+                    let capability = state.capability.clone();
+                    if let Err(err) = continuation(args.get_or_undefined(0).clone(), state, context)
+                    {
+                        Self::reject(&capability, &err, context);
+                    }
+                    Ok(JsValue::undefined())
+                },
+                Reaction {
+                    state: self,
+                    continuation,
+                },
+            ),
+        )
+        .name("")
+        .length(1)
+        .build()
+    }
+
+    /// `Await ( value )`, resuming on `on_fulfilled` or `on_rejected` once `value` settles.
+    fn await_value(
+        self,
+        value: JsValue,
+        on_fulfilled: Continuation,
+        on_rejected: Continuation,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise = match Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        ) {
+            Ok(promise) => promise,
+            Err(err) => return on_rejected(err.to_opaque(context), self, context),
+        };
+
+        // 3-9. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        let on_fulfilled = self.clone().reaction(on_fulfilled, context);
+        let on_rejected = self.reaction(on_rejected, context);
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+
+        Ok(())
+    }
+
+    /// Continuation that rethrows the rejection reason of an `Await`.
+    fn rethrow(error: JsValue, _: Self, _: &mut Context<'_>) -> JsResult<()> {
+        Err(JsError::from_opaque(error))
+    }
+
+    /// Sets the length of the new array and resolves the promise with it.
+    fn finish(self, len: u64, context: &mut Context<'_>) -> JsResult<()> {
+        // Perform ? Set(A, "length", 𝔽(len), true).
+        self.target.set(utf16!("length"), len, true, context)?;
+
+        // Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        self.capability.resolve().call(
+            &JsValue::undefined(),
+            &[self.target.clone().into()],
+            context,
+        )?;
+        Ok(())
+    }
+
+    /// Runs a single iteration of the loop over an async iterator, up to its first `Await`.
+    fn iterator_step(self, context: &mut Context<'_>) -> JsResult<()> {
+        let Source::Iterator(iterator_record) = &self.source else {
+            unreachable!("iterator_step must only be called with an iterator source");
+        };
+
+        // 1. If k ≥ 2^53 - 1, then
+        if self.k >= 9_007_199_254_740_991 {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+            let error = JsNativeError::typ()
+                .with_message("Array.fromAsync: array length exceeds the max safe integer limit")
+                .into();
+            return self.close_iterator(error, context);
+        }
+
+        // 2. Let Pk be ! ToString(𝔽(k)).
+        // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        let next_result = iterator_record.next_method().call(
+            &iterator_record.iterator().clone().into(),
+            &[],
+            context,
+        )?;
+
+        // 4. Set nextResult to ? Await(nextResult).
+        self.await_value(next_result, Self::iterator_next, Self::rethrow, context)
+    }
+
+    /// Resumes the loop over an async iterator with the awaited result of its `next` method.
+    fn iterator_next(next_result: JsValue, state: Self, context: &mut Context<'_>) -> JsResult<()> {
+        // 5. If nextResult is not an Object, throw a TypeError exception.
+        let JsValue::Object(next_result) = next_result else {
+            return Err(JsNativeError::typ()
+                .with_message("Array.fromAsync: iterator result is not an object")
+                .into());
+        };
+        let next_result = IteratorResult::new(next_result);
+
+        // 6. Let done be ? IteratorComplete(nextResult).
+        // 7. If done is true,
+        if next_result.complete(context)? {
+            // a. Perform ? Set(A, "length", 𝔽(k), true).
+            // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            let len = state.k;
+            return state.finish(len, context);
+        }
+
+        // 8. Let nextValue be ? IteratorValue(nextResult).
+        let next_value = next_result.value(context)?;
+
+        // 9. If mapping is true, then
+        if let Some(mapfn) = &state.mapfn {
+            // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+            // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+            let mapped_value =
+                match mapfn.call(&state.this_arg, &[next_value, state.k.into()], context) {
+                    Ok(value) => value,
+                    Err(err) => return state.close_iterator(err, context),
+                };
+
+            // c. Set mappedValue to Await(mappedValue).
+            // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+            return state.await_value(
+                mapped_value,
+                Self::iterator_define,
+                Self::iterator_close_rejected,
+                context,
+            );
+        }
+
+        // 10. Else, let mappedValue be nextValue.
+        Self::iterator_define(next_value, state, context)
+    }
+
+    /// Adds a value produced by an async iterator to the new array and continues the loop.
+    fn iterator_define(
+        mapped_value: JsValue,
+        mut state: Self,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 11. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        if let Err(err) = state
+            .target
+            .create_data_property_or_throw(state.k, mapped_value, context)
+        {
+            return state.close_iterator(err, context);
+        }
+
+        // 13. Set k to k + 1.
+        state.k += 1;
+
+        state.iterator_step(context)
+    }
+
+    /// Continuation that closes the async iterator after a rejected `Await` of a mapped value.
+    fn iterator_close_rejected(
+        error: JsValue,
+        state: Self,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        state.close_iterator(JsError::from_opaque(error), context)
+    }
+
+    /// `AsyncIteratorClose ( iteratorRecord, completion )` for a throw completion.
+    ///
+    /// Calls the `return` method of the iterator and rejects the promise with `error` once its
+    /// result settles, regardless of its outcome.
+    fn close_iterator(self, error: JsError, context: &mut Context<'_>) -> JsResult<()> {
+        let Source::Iterator(iterator_record) = &self.source else {
+            unreachable!("close_iterator must only be called with an iterator source");
+        };
+        let iterator = iterator_record.iterator().clone();
+
+        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 4. If innerResult.[[Type]] is normal, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult.[[Type]] is normal, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 5. If completion.[[Type]] is throw, return ? completion.
+        let Ok(Some(r#return)) = iterator.get_method(utf16!("return"), context) else {
+            return Err(error);
+        };
+        let Ok(inner_result) = r#return.call(&iterator.into(), &[], context) else {
+            return Err(error);
+        };
+        let Ok(promise) = Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            inner_result,
+            context,
+        ) else {
+            return Err(error);
+        };
+
+        let error = error.to_opaque(context);
+        let reject = FunctionObjectBuilder::new(
+            context,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (capability, error): &(PromiseCapability, JsValue), context| {
+                    capability.reject().call(
+                        &JsValue::undefined(),
+                        std::slice::from_ref(error),
+                        context,
+                    )?;
+                    Ok(JsValue::undefined())
+                },
+                (self.capability.clone(), error),
+            ),
+        )
+        .name("")
+        .length(1)
+        .build();
+        Promise::perform_promise_then(&promise, Some(reject.clone()), Some(reject), None, context);
+
+        Ok(())
+    }
+
+    /// Runs a single iteration of the loop over an array-like object, up to its first `Await`.
+    fn array_like_step(self, context: &mut Context<'_>) -> JsResult<()> {
+        let Source::ArrayLike { object, len } = &self.source else {
+            unreachable!("array_like_step must only be called with an array-like source");
+        };
+
+        // vii. Repeat, while k < len,
+        if self.k >= *len {
+            // viii. Perform ? Set(A, "length", 𝔽(len), true).
+            // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            let len = *len;
+            return self.finish(len, context);
+        }
+
+        // 1. Let Pk be ! ToString(𝔽(k)).
+        // 2. Let kValue be ? Get(arrayLike, Pk).
+        let k_value = object.get(self.k, context)?;
+
+        // 3. Set kValue to ? Await(kValue).
+        self.await_value(k_value, Self::array_like_map, Self::rethrow, context)
+    }
+
+    /// Resumes the loop over an array-like object with the awaited element.
+    fn array_like_map(k_value: JsValue, state: Self, context: &mut Context<'_>) -> JsResult<()> {
+        // 4. If mapping is true, then
+        if let Some(mapfn) = &state.mapfn {
+            // a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+            let mapped_value = mapfn.call(&state.this_arg, &[k_value, state.k.into()], context)?;
+
+            // b. Set mappedValue to ? Await(mappedValue).
+            return state.await_value(
+                mapped_value,
+                Self::array_like_define,
+                Self::rethrow,
+                context,
+            );
+        }
+
+        // 5. Else, let mappedValue be kValue.
+        Self::array_like_define(k_value, state, context)
+    }
+
+    /// Adds an element of the array-like object to the new array and continues the loop.
+    fn array_like_define(
+        mapped_value: JsValue,
+        mut state: Self,
+        context: &mut Context<'_>,
+    ) -> JsResult<()> {
+        // 6. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        state
+            .target
+            .create_data_property_or_throw(state.k, mapped_value, context)?;
+
+        // 7. Set k to k + 1.
+        state.k += 1;
+
+        state.array_like_step(context)
+    }
+}
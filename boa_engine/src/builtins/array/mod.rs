@@ -32,6 +32,7 @@ use std::cmp::{max, min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
 pub(crate) use array_iterator::ArrayIterator;
 #[cfg(test)]
 mod tests;
@@ -123,6 +124,7 @@ impl IntrinsicObject for Array {
             .method(Self::copy_within, "copyWithin", 2)
             // Static Methods
             .static_method(Self::from, "from", 1)
+            .static_method(Self::from_async, "fromAsync", 1)
             .static_method(Self::is_array, "isArray", 1)
             .static_method(Self::of, "of", 0)
             .build();
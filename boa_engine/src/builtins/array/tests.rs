@@ -1,7 +1,7 @@
 use super::Array;
 use crate::{
     builtins::{error::ErrorKind, Number},
-    run_test_actions, Context, JsValue, TestAction,
+    js_string, run_test_actions, Context, JsValue, TestAction,
 };
 use indoc::indoc;
 
@@ -403,6 +403,42 @@ fn includes_value() {
     ]);
 }
 
+#[test]
+fn includes_value_same_value_zero() {
+    run_test_actions([
+        TestAction::assert("[NaN].includes(NaN)"),
+        TestAction::assert("[1, 2, NaN].includes(NaN, 2)"),
+        TestAction::assert("[NaN].indexOf(NaN) === -1"),
+        TestAction::assert("[-0].includes(0)"),
+        TestAction::assert("[0].includes(-0)"),
+    ]);
+}
+
+#[test]
+fn includes_value_from_index() {
+    run_test_actions([
+        TestAction::run("var arr = ['a', 'b', 'c'];"),
+        TestAction::assert("!arr.includes('a', 1)"),
+        TestAction::assert("arr.includes('c', -1)"),
+        TestAction::assert("!arr.includes('b', -1)"),
+        TestAction::assert("arr.includes('a', -100)"),
+        TestAction::assert("!arr.includes('a', 3)"),
+        TestAction::assert("!arr.includes('a', Infinity)"),
+        TestAction::assert("arr.includes('a', -Infinity)"),
+    ]);
+}
+
+#[test]
+fn includes_value_sparse() {
+    run_test_actions([
+        TestAction::run("var sparse = [1, , 3];"),
+        TestAction::assert("sparse.includes(undefined)"),
+        TestAction::assert("sparse.indexOf(undefined) === -1"),
+        TestAction::assert("new Array(3).includes(undefined)"),
+        TestAction::assert("!new Array(3).includes(undefined, 3)"),
+    ]);
+}
+
 #[test]
 fn map() {
     run_test_actions([
@@ -948,3 +984,58 @@ fn array_sort() {
             "#}),
     ]);
 }
+
+#[test]
+fn array_from_async() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                let results = {};
+                async function* gen() {
+                    yield 1;
+                    yield Promise.resolve(2);
+                    yield 3;
+                }
+                Array.fromAsync(gen()).then(v => results.asyncIterable = v);
+                Array.fromAsync([Promise.resolve(4), 5]).then(v => results.syncIterable = v);
+                Array.fromAsync({ length: 2, 0: Promise.resolve(6), 1: 7 })
+                    .then(v => results.arrayLike = v);
+                Array.fromAsync([1, 2], async function (x, i) { return x * this.factor + i; }, { factor: 10 })
+                    .then(v => results.mapped = v);
+            "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert("arrayEquals(results.asyncIterable, [1, 2, 3])"),
+        TestAction::assert("arrayEquals(results.syncIterable, [4, 5])"),
+        TestAction::assert("arrayEquals(results.arrayLike, [6, 7])"),
+        TestAction::assert("arrayEquals(results.mapped, [10, 21])"),
+        TestAction::assert("Array.fromAsync.length === 1"),
+    ]);
+}
+
+#[test]
+fn array_from_async_rejections() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let errors = {};
+                let returnCalled = false;
+                const iterable = {
+                    [Symbol.asyncIterator]() {
+                        return {
+                            next() { return Promise.resolve({ value: 1, done: false }); },
+                            return() { returnCalled = true; return Promise.resolve({ done: true }); },
+                        };
+                    },
+                };
+                Array.fromAsync(iterable, () => { throw "mapfn"; }).catch(e => errors.mapfn = e);
+                Array.fromAsync([Promise.reject("element")]).catch(e => errors.element = e);
+                Array.fromAsync([], 1).catch(e => errors.notCallable = e);
+                Array.fromAsync(null).catch(e => errors.nullish = e);
+            "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("errors.mapfn", js_string!("mapfn")),
+        TestAction::assert("returnCalled"),
+        TestAction::assert_eq("errors.element", js_string!("element")),
+        TestAction::assert("errors.notCallable instanceof TypeError"),
+        TestAction::assert("errors.nullish instanceof TypeError"),
+    ]);
+}
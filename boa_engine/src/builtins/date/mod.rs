@@ -208,7 +208,7 @@ impl BuiltInConstructor for Date {
             return Ok(JsValue::new(
                 DateTime::<FixedOffset>::from_utc(
                     context.host_hooks().utc_now(),
-                    context.host_hooks().tz_offset(),
+                    context.tz_offset(),
                 )
                 .format("%a %b %d %Y %H:%M:%S GMT%:z")
                 .to_string(),
@@ -269,13 +269,7 @@ impl BuiltInConstructor for Date {
                 // Separating this into its own function to simplify the logic.
 
                 let dt = Self::construct_date(args, context)?
-                    .and_then(|dt| {
-                        context
-                            .host_hooks()
-                            .tz_offset()
-                            .from_local_datetime(&dt)
-                            .earliest()
-                    })
+                    .and_then(|dt| context.tz_offset().from_local_datetime(&dt).earliest())
                     .map(|dt| dt.naive_utc());
 
                 Self(dt.map(|dt| dt.timestamp_millis()))
@@ -471,11 +465,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return DateFromTime(LocalTime(t)).
@@ -501,11 +491,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return WeekDay(LocalTime(t)).
@@ -535,7 +521,7 @@ impl Date {
         let t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
 
         // 3. Return YearFromTime(LocalTime(t)) - 1900𝔽.
-        let local = context.host_hooks().tz_offset().from_utc_datetime(&t);
+        let local = context.tz_offset().from_utc_datetime(&t);
         Ok(JsValue::from(local.year() - 1900))
     }
 
@@ -557,11 +543,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return YearFromTime(LocalTime(t)).
@@ -586,11 +568,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return HourFromTime(LocalTime(t)).
@@ -615,11 +593,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return msFromTime(LocalTime(t)).
@@ -644,11 +618,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return MinFromTime(LocalTime(t)).
@@ -674,11 +644,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return MonthFromTime(LocalTime(t)).
@@ -703,11 +669,7 @@ impl Date {
         // 2. If t is NaN, return NaN.
         let mut t = some_or_nan!(t.and_then(NaiveDateTime::from_timestamp_millis));
         if LOCAL {
-            t = context
-                .host_hooks()
-                .tz_offset()
-                .from_utc_datetime(&t)
-                .naive_local();
+            t = context.tz_offset().from_utc_datetime(&t).naive_local();
         }
 
         // 3. Return SecFromTime(LocalTime(t))
@@ -754,9 +716,7 @@ impl Date {
         some_or_nan!(this_time_value(this)?);
 
         // 3. Return (t - LocalTime(t)) / msPerMinute.
-        Ok(JsValue::from(
-            -context.host_hooks().tz_offset().local_minus_utc() / 60,
-        ))
+        Ok(JsValue::from(-context.tz_offset().local_minus_utc() / 60))
     }
 
     /// [`Date.prototype.setDate ( date )`][local] and
@@ -790,7 +750,7 @@ impl Date {
                 date: Some(date),
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 7. Set the [[DateValue]] internal slot of this Date object to u.
@@ -820,7 +780,8 @@ impl Date {
         let datetime = match t.0.and_then(NaiveDateTime::from_timestamp_millis) {
             Some(dt) => dt,
             None if LOCAL => {
-                let Some(datetime) = context.host_hooks().tz_offset()
+                let Some(datetime) = context
+                    .tz_offset()
                     .from_local_datetime(&NaiveDateTime::default())
                     .earliest()
                     .as_ref()
@@ -858,7 +819,7 @@ impl Date {
                 date,
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 8. Set the [[DateValue]] internal slot of this Date object to u.
@@ -924,7 +885,7 @@ impl Date {
                 millisecond,
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 13. Set the [[DateValue]] internal slot of this Date object to u.
@@ -965,7 +926,7 @@ impl Date {
                 millisecond: Some(ms),
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 7. Set the [[DateValue]] internal slot of this Date object to u.
@@ -1021,7 +982,7 @@ impl Date {
                 millisecond,
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 11. Set the [[DateValue]] internal slot of this Date object to u.
@@ -1070,7 +1031,7 @@ impl Date {
                 date,
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 9. Set the [[DateValue]] internal slot of this Date object to u.
@@ -1118,7 +1079,7 @@ impl Date {
                 millisecond,
                 ..Default::default()
             },
-            LOCAL.then(|| context.host_hooks().tz_offset()),
+            LOCAL.then(|| context.tz_offset()),
         );
 
         // 9. Set the [[DateValue]] internal slot of this Date object to u.
@@ -1156,7 +1117,8 @@ impl Date {
 
         // 3. If t is NaN, set t to +0𝔽; otherwise, set t to LocalTime(t).
         let Some(datetime) = t.0.and_then(NaiveDateTime::from_timestamp_millis).or_else(|| {
-            context.host_hooks().tz_offset()
+            context
+                .tz_offset()
                 .from_local_datetime(&NaiveDateTime::default())
                 .earliest()
                 .as_ref()
@@ -1189,7 +1151,7 @@ impl Date {
                 year: Some(IntegerOrNan::Integer(year)),
                 ..Default::default()
             },
-            Some(context.host_hooks().tz_offset()),
+            Some(context.tz_offset()),
         );
 
         // 10. Set the [[DateValue]] internal slot of this Date object to TimeClip(date).
@@ -1256,7 +1218,6 @@ impl Date {
         // 4. Let t be LocalTime(tv).
         // 5. Return DateString(t).
         Ok(context
-            .host_hooks()
             .tz_offset()
             .from_utc_datetime(&tv)
             .format("%a %b %d %Y")
@@ -1399,7 +1360,6 @@ impl Date {
             return Ok(js_string!("Invalid Date").into());
         };
         Ok(context
-            .host_hooks()
             .tz_offset()
             .from_utc_datetime(&t)
             .format("%a %b %d %Y %H:%M:%S GMT%z")
@@ -1432,7 +1392,6 @@ impl Date {
         // 4. Let t be LocalTime(tv).
         // 5. Return the string-concatenation of TimeString(t) and TimeZoneString(tv).
         Ok(context
-            .host_hooks()
             .tz_offset()
             .from_utc_datetime(&t)
             .format("%H:%M:%S GMT%z")
@@ -1,5 +1,8 @@
-use crate::{builtins::error::ErrorKind, run_test_actions, TestAction};
-use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+use crate::{
+    builtins::error::ErrorKind, context::ContextBuilder, js_string, run_test_actions,
+    run_test_actions_with, TestAction,
+};
+use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
 use indoc::indoc;
 
 // NOTE: Javascript Uses 0-based months, where chrono uses 1-based months. Many of the assertions look wrong because of
@@ -265,6 +268,46 @@ fn date_proto_get_timezone_offset() {
     ]);
 }
 
+#[test]
+fn date_pinned_timezone() {
+    let context = &mut ContextBuilder::new()
+        .timezone(FixedOffset::east_opt(5 * 3600 + 30 * 60).unwrap())
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run("const date = new Date('2020-07-08T20:00:00Z');"),
+            TestAction::assert_eq("date.getTimezoneOffset()", -330),
+            TestAction::assert_eq("date.getDate()", 9),
+            TestAction::assert_eq("date.getHours()", 1),
+            TestAction::assert_eq("date.getMinutes()", 30),
+            TestAction::assert_eq(
+                "date.toString()",
+                js_string!("Thu Jul 09 2020 01:30:00 GMT+0530"),
+            ),
+            TestAction::assert_eq(
+                "new Date(2020, 6, 9, 1, 30).toISOString()",
+                js_string!("2020-07-08T20:00:00.000Z"),
+            ),
+        ],
+        context,
+    );
+
+    let context = &mut ContextBuilder::new()
+        .timezone(FixedOffset::east_opt(0).unwrap())
+        .build()
+        .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("new Date(0).getTimezoneOffset()", 0),
+            TestAction::assert_eq("new Date(2020, 0, 1).getTime()", 1_577_836_800_000_i64),
+        ],
+        context,
+    );
+}
+
 #[test]
 fn date_proto_get_utc_date_call() {
     run_test_actions([
@@ -124,6 +124,8 @@ impl BuiltInConstructor for AggregateError {
         )
         .expect("should not fail according to spec");
 
+        Error::prepare_stack_trace(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -11,16 +11,21 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error
 
 use crate::{
-    builtins::BuiltInObject,
+    builtins::{Array, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, JsObject, ObjectData, ObjectInitializer,
+    },
     property::Attribute,
     realm::Realm,
     string::utf16,
-    Context, JsArgs, JsResult, JsValue,
+    vm::format_stack_trace,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
+use boa_interner::Sym;
 use boa_profiler::Profiler;
 
 pub(crate) mod aggregate;
@@ -196,6 +201,8 @@ impl BuiltInConstructor for Error {
         // 4. Perform ? InstallErrorCause(O, options).
         Self::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        Self::prepare_stack_trace(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
@@ -222,6 +229,129 @@ impl Error {
         Ok(())
     }
 
+    /// Sets the `stack` property of a new error object to the value returned by the
+    /// `prepareStackTrace` callback, or to a V8-like stack trace if there is no callback.
+    ///
+    /// The callback set with [`Context::set_error_prepare_stack_trace`] takes precedence over
+    /// `Error.prepareStackTrace`. It is called with the error and an array of call site objects,
+    /// starting from the innermost frame. Errors created by the callback itself don't get a
+    /// `stack` property.
+    pub(crate) fn prepare_stack_trace(o: &JsObject, context: &mut Context<'_>) -> JsResult<()> {
+        if context.preparing_stack_trace {
+            return Ok(());
+        }
+
+        let constructor = context.intrinsics().constructors().error().constructor();
+        let callback = if let Some(callback) = context.error_prepare_stack_trace.clone() {
+            JsObject::from(callback)
+        } else if let Some(callback) = constructor
+            .get(utf16!("prepareStackTrace"), context)?
+            .as_callable()
+        {
+            callback.clone()
+        } else {
+            // The header is formatted like `Error.prototype.toString`, but only reads
+            // data properties so that creating an error doesn't run user code.
+            let data_string = |key: &[u16]| {
+                o.get_property(&key.into()).and_then(|property| {
+                    property
+                        .value()
+                        .and_then(JsValue::as_string)
+                        .map(JsString::to_std_string_escaped)
+                })
+            };
+            let name = data_string(utf16!("name")).unwrap_or_else(|| "Error".to_owned());
+            let message = data_string(utf16!("message")).unwrap_or_default();
+            let header = match (name.is_empty(), message.is_empty()) {
+                (_, true) => name,
+                (true, false) => message,
+                (false, false) => format!("{name}: {message}"),
+            };
+            let stack = format_stack_trace(&header, &context.capture_stack_trace());
+            o.create_non_enumerable_data_property_or_throw(utf16!("stack"), stack, context);
+            return Ok(());
+        };
+
+        let call_sites = Self::call_sites(context);
+
+        context.preparing_stack_trace = true;
+        let stack = callback.call(
+            &constructor.into(),
+            &[o.clone().into(), call_sites.into()],
+            context,
+        );
+        context.preparing_stack_trace = false;
+
+        o.create_non_enumerable_data_property_or_throw(utf16!("stack"), stack?, context);
+        Ok(())
+    }
+
+    /// Creates an array with a call site object for each frame of the call stack.
+    fn call_sites(context: &mut Context<'_>) -> JsObject {
+        let names = context
+            .vm
+            .frames
+            .iter()
+            .rev()
+            .map(|frame| match frame.code_block.name {
+                Sym::MAIN | Sym::EMPTY_STRING => None,
+                name => Some(
+                    context
+                        .interner()
+                        .resolve_expect(name)
+                        .into_common::<JsString>(false),
+                ),
+            })
+            .collect::<Vec<_>>();
+
+        let call_sites = names
+            .into_iter()
+            .map(|name| Self::call_site(name, context).into())
+            .collect::<Vec<_>>();
+
+        Array::create_array_from_list(call_sites, context)
+    }
+
+    /// Creates a V8-style call site object for a function called `name`.
+    fn call_site(name: Option<JsString>, context: &mut Context<'_>) -> JsObject {
+        let function_name = name.clone().map_or_else(JsValue::null, JsValue::from);
+        let display_name = name.unwrap_or_else(|| js_string!("<anonymous>"));
+
+        ObjectInitializer::new(context)
+            .function(
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, name, _| Ok(name.clone()),
+                    function_name,
+                ),
+                "getFunctionName",
+                0,
+            )
+            .function(
+                NativeFunction::from_copy_closure(|_, _, _| Ok(JsValue::null())),
+                "getFileName",
+                0,
+            )
+            .function(
+                NativeFunction::from_copy_closure(|_, _, _| Ok(JsValue::null())),
+                "getLineNumber",
+                0,
+            )
+            .function(
+                NativeFunction::from_copy_closure(|_, _, _| Ok(JsValue::null())),
+                "getColumnNumber",
+                0,
+            )
+            .function(
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, name, _| Ok(name.clone().into()),
+                    display_name,
+                ),
+                "toString",
+                0,
+            )
+            .build()
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Error object.
@@ -101,6 +101,8 @@ impl BuiltInConstructor for ReferenceError {
         // 4. Perform ? InstallErrorCause(O, options).
         Error::install_error_cause(&o, args.get_or_undefined(1), context)?;
 
+        Error::prepare_stack_trace(&o, context)?;
+
         // 5. Return O.
         Ok(o.into())
     }
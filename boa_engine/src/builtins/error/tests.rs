@@ -1,4 +1,7 @@
-use crate::{run_test_actions, TestAction};
+use crate::{
+    js_string, native_function::NativeFunction, object::FunctionObjectBuilder, run_test_actions,
+    JsValue, TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -58,3 +61,78 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_prepare_stack_trace() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            Error.prepareStackTrace = (err, sites) =>
+                err.message + " @ " + sites.map(site => site.toString()).join(",");
+            function f() {
+                return new TypeError("oops");
+            }
+        "#}),
+        TestAction::assert_eq("f().stack", "oops @ f,<anonymous>"),
+        TestAction::assert_eq("new Error('x').stack", "x @ <anonymous>"),
+        TestAction::assert("!Object.keys(f()).includes('stack')"),
+        TestAction::assert_eq(
+            "Error.prepareStackTrace = () => new Error('inner').stack; new Error().stack",
+            JsValue::undefined(),
+        ),
+        TestAction::run("delete Error.prepareStackTrace"),
+        TestAction::assert_eq("new Error().stack", "Error\n    at <main> (1:1)"),
+        TestAction::inspect_context(|context| {
+            let callback = FunctionObjectBuilder::new(
+                context,
+                NativeFunction::from_fn_ptr(|_, _, _| Ok(js_string!("from rust").into())),
+            )
+            .build();
+            context.set_error_prepare_stack_trace(Some(callback));
+        }),
+        TestAction::assert_eq("new RangeError().stack", "from rust"),
+        TestAction::inspect_context(|context| context.set_error_prepare_stack_trace(None)),
+        TestAction::assert_eq("new RangeError().stack", "RangeError\n    at <main> (1:1)"),
+    ]);
+}
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function outer() {
+                return inner();
+            }
+            function inner() {
+                return new TypeError("oops");
+            }
+        "#}),
+        TestAction::assert_eq(
+            "outer().stack",
+            "TypeError: oops\n    at inner (5:12)\n    at outer (2:12)\n    at <main> (1:1)",
+        ),
+        TestAction::assert_eq("Error('x').stack", "Error: x\n    at <main> (1:1)"),
+        TestAction::assert("!Object.keys(new Error()).includes('stack')"),
+    ]);
+}
+
+#[test]
+fn native_error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function thrower() {
+                return null.x;
+            }
+            let stack;
+            try {
+                thrower();
+            } catch (e) {
+                stack = e.stack;
+            }
+        "#}),
+        TestAction::assert("stack.startsWith('TypeError: ')"),
+        TestAction::assert_eq(
+            "stack.split('\\n').slice(1).join('|')",
+            "    at thrower (2:5)|    at <main> (6:5)",
+        ),
+    ]);
+}
@@ -9,24 +9,251 @@
 //! [spec]: https://tc39.es/ecma262/#sec-eval-x
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/eval
 
+use std::{hash::BuildHasherDefault, rc::Rc};
+
 use crate::{
-    builtins::BuiltInObject, bytecompiler::ByteCompiler, context::intrinsics::Intrinsics,
-    environments::Environment, error::JsNativeError, object::JsObject, realm::Realm, Context,
-    JsArgs, JsResult, JsString, JsValue,
+    builtins::BuiltInObject,
+    bytecompiler::{ByteCompiler, SourceText},
+    context::intrinsics::Intrinsics,
+    environments::{CompileTimeEnvironment, Environment},
+    error::JsNativeError,
+    object::JsObject,
+    property::PropertyKey,
+    realm::Realm,
+    vm::CodeBlock,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
-use boa_ast::operations::{
-    contains, contains_arguments, top_level_var_declared_names, ContainsSymbol,
+use boa_ast::{
+    expression::Identifier,
+    operations::{contains, contains_arguments, top_level_var_declared_names, ContainsSymbol},
+    Declaration, StatementList, StatementListItem,
 };
-use boa_gc::Gc;
+use boa_gc::{Gc, GcRefCell};
 use boa_interner::Sym;
-use boa_parser::{Parser, Source};
+use boa_parser::{Parser, Source, Warning};
 use boa_profiler::Profiler;
+use indexmap::IndexMap;
+use rustc_hash::{FxHashSet, FxHasher};
 
 use super::{BuiltInBuilder, IntrinsicObject};
 
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Eval;
 
+/// Options used to evaluate a string with [`Context::eval_string`].
+///
+/// The default options perform an indirect, non-strict evaluation, which is the same as calling
+/// the global `eval` function.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EvalOptions {
+    /// If the evaluation is a direct `eval` call, which executes the code in the currently active
+    /// environment instead of the global environment.
+    pub direct: bool,
+
+    /// If the caller of the evaluation is in strict mode.
+    ///
+    /// Only direct evaluations can have a strict caller, so this is ignored if `direct` is `false`.
+    pub strict: bool,
+}
+
+bitflags::bitflags! {
+    /// Syntactic constructs of an `eval` body that are early errors depending on the caller.
+    #[derive(Debug, Clone, Copy, Default)]
+    struct Contains: u8 {
+        const NEW_TARGET = 0b0001;
+        const SUPER_PROPERTY = 0b0010;
+        const SUPER_CALL = 0b0100;
+        const ARGUMENTS = 0b1000;
+    }
+}
+
+/// The properties of a parsed `eval` body that are checked on every call.
+#[derive(Debug, Clone)]
+struct EvalSummary {
+    strict: bool,
+    contains: Contains,
+    var_names: FxHashSet<Identifier>,
+    function_names: FxHashSet<Identifier>,
+}
+
+impl EvalSummary {
+    fn new(body: &StatementList) -> Self {
+        let mut flags = Contains::default();
+        if contains(body, ContainsSymbol::NewTarget) {
+            flags |= Contains::NEW_TARGET;
+        }
+        if contains(body, ContainsSymbol::SuperProperty) {
+            flags |= Contains::SUPER_PROPERTY;
+        }
+        if contains(body, ContainsSymbol::SuperCall) {
+            flags |= Contains::SUPER_CALL;
+        }
+        if contains_arguments(body) {
+            flags |= Contains::ARGUMENTS;
+        }
+
+        Self {
+            strict: body.strict(),
+            contains: flags,
+            var_names: top_level_var_declared_names(body),
+            function_names: body
+                .statements()
+                .iter()
+                .filter_map(|item| match item {
+                    StatementListItem::Declaration(Declaration::Function(f)) => f.name(),
+                    StatementListItem::Declaration(Declaration::Generator(f)) => f.name(),
+                    StatementListItem::Declaration(Declaration::AsyncFunction(f)) => f.name(),
+                    StatementListItem::Declaration(Declaration::AsyncGenerator(f)) => f.name(),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+
+    /// Checks that the top-level function and var declarations can be defined as properties of
+    /// the global object, when the var environment of the code is the global environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-evaldeclarationinstantiation
+    fn check_global_declarations(&self, context: &mut Context<'_>) -> JsResult<()> {
+        let global = context.global_object();
+        let key = |name: Identifier, context: &Context<'_>| -> PropertyKey {
+            context
+                .interner()
+                .resolve_expect(name.sym())
+                .into_common::<JsString>(false)
+                .into()
+        };
+
+        // 10.d.ii.4.a. Let fnDefinable be ? varEnv.CanDeclareGlobalFunction(fn).
+        for name in &self.function_names {
+            let key = key(*name, context);
+            let definable = match global.__get_own_property__(&key, context)? {
+                None => global.is_extensible(context)?,
+                Some(existing) => {
+                    existing.expect_configurable()
+                        || (existing.is_data_descriptor()
+                            && existing.expect_writable()
+                            && existing.expect_enumerable())
+                }
+            };
+            // b. If fnDefinable is false, throw a TypeError exception.
+            if !definable {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot declare global function `{key}`"))
+                    .into());
+            }
+        }
+
+        // 12.b.ii.1.a. Let vnDefinable be ? varEnv.CanDeclareGlobalVar(vn).
+        for name in self.var_names.difference(&self.function_names) {
+            let key = key(*name, context);
+            let definable =
+                global.has_own_property(key.clone(), context)? || global.is_extensible(context)?;
+            // b. If vnDefinable is false, throw a TypeError exception.
+            if !definable {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("cannot declare global variable `{key}`"))
+                    .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The key of a compiled `eval` code in the [`EvalCache`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct EvalCacheKey {
+    source: Box<str>,
+    direct: bool,
+    strict: bool,
+
+    /// The address of the compile time environment the code is compiled in.
+    environment: usize,
+
+    /// The number of runtime environments at the time of the call.
+    depth: usize,
+
+    /// The number of bindings of the compile time environment at the time of the call.
+    bindings: usize,
+}
+
+#[derive(Debug)]
+struct EvalCacheEntry {
+    code_block: Gc<CodeBlock>,
+
+    /// Keeps the compile time environment alive, to ensure its address is not reused while the
+    /// entry is in the cache.
+    _environment: Gc<GcRefCell<CompileTimeEnvironment>>,
+
+    summary: EvalSummary,
+
+    /// The warnings reported while parsing the code, replayed every time the entry is reused.
+    warnings: Box<[Warning]>,
+}
+
+/// A least recently used cache of the code compiled by `eval` calls.
+///
+/// The cache is disabled by default, and can be enabled with
+/// [`Context::set_eval_cache_capacity`].
+#[derive(Debug, Default)]
+pub(crate) struct EvalCache {
+    capacity: usize,
+    entries: IndexMap<EvalCacheKey, EvalCacheEntry, BuildHasherDefault<FxHasher>>,
+
+    /// The number of `eval` calls that reused a cached code block.
+    hits: u64,
+}
+
+impl EvalCache {
+    /// Gets the maximum number of entries of the cache.
+    pub(crate) const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of entries of the cache, evicting the least recently used entries
+    /// that don't fit anymore.
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.entries.shift_remove_index(0);
+        }
+    }
+
+    /// Gets the number of `eval` calls that reused a cached code block.
+    #[cfg(test)]
+    pub(crate) const fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Gets the entry of `key`, marking it as the most recently used one.
+    fn get(&mut self, key: &EvalCacheKey) -> Option<(Gc<CodeBlock>, EvalSummary, Box<[Warning]>)> {
+        let (key, entry) = self.entries.shift_remove_entry(key)?;
+        let result = (
+            entry.code_block.clone(),
+            entry.summary.clone(),
+            entry.warnings.clone(),
+        );
+        self.entries.insert(key, entry);
+        self.hits += 1;
+        Some(result)
+    }
+
+    /// Inserts a new entry, evicting the least recently used one if the cache is full.
+    fn insert(&mut self, key: EvalCacheKey, entry: EvalCacheEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() >= self.capacity {
+            self.entries.shift_remove_index(0);
+        }
+        self.entries.insert(key, entry);
+    }
+}
+
 impl IntrinsicObject for Eval {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
@@ -89,6 +316,13 @@ impl Eval {
             Restore(Vec<Environment>),
         }
 
+        /// The code to evaluate, which is only parsed if it was not found in the eval cache.
+        #[derive(Debug)]
+        enum EvalCode {
+            Parsed(StatementList, Vec<Warning>),
+            Compiled(Gc<CodeBlock>),
+        }
+
         /// Restores the environment after calling `eval` or after throwing an error.
         fn restore_environment(context: &mut Context<'_>, action: EnvStackAction) {
             match action {
@@ -112,6 +346,12 @@ impl Eval {
             return Ok(x.clone());
         };
 
+        if !context.runtime_compilation_enabled() {
+            return Err(JsNativeError::eval()
+                .with_message("eval: runtime compilation is disabled")
+                .into());
+        }
+
         // Because of implementation details the following code differs from the spec.
 
         // 3. Let evalRealm be the current Realm Record.
@@ -120,18 +360,54 @@ impl Eval {
         // 5. Perform ? HostEnsureCanCompileStrings(evalRealm).
         context
             .host_hooks()
-            .ensure_can_compile_strings(context.realm().clone(), context)?;
-
-        // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
-        //     a. Let script be ParseText(StringToCodePoints(x), Script).
-        //     b. If script is a List of errors, throw a SyntaxError exception.
-        //     c. If script Contains ScriptBody is false, return undefined.
-        //     d. Let body be the ScriptBody of script.
-        let mut parser = Parser::new(Source::from_bytes(&x));
-        if strict {
-            parser.set_strict();
-        }
-        let body = parser.parse_eval(direct, context.interner_mut())?;
+            .ensure_can_compile_strings(context.realm().clone(), &x, context)
+            .map_err(JsNativeError::from)?;
+
+        // A direct call is compiled in the current environment, while an indirect call is
+        // compiled in the global environment.
+        let (environment, depth) = if direct {
+            (
+                context.vm.environments.current_compile_environment(),
+                context.vm.environments.len(),
+            )
+        } else {
+            (context.realm().environment().compile_env(), 1)
+        };
+
+        // Reuse the code of a previous call with the same source, flags and environment if the
+        // eval cache is enabled.
+        let cache_key = (context.eval_cache.capacity() > 0).then(|| EvalCacheKey {
+            source: x.as_str().into(),
+            direct,
+            strict,
+            environment: std::ptr::addr_of!(*environment) as usize,
+            depth,
+            bindings: environment.borrow().num_bindings(),
+        });
+        let cached = cache_key
+            .as_ref()
+            .and_then(|key| context.eval_cache.get(key));
+
+        let (body, summary) = if let Some((code_block, summary, warnings)) = cached {
+            context.report_warnings(&warnings);
+            (EvalCode::Compiled(code_block), summary)
+        } else {
+            // 11. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+            //     a. Let script be ParseText(StringToCodePoints(x), Script).
+            //     b. If script is a List of errors, throw a SyntaxError exception.
+            //     c. If script Contains ScriptBody is false, return undefined.
+            //     d. Let body be the ScriptBody of script.
+            let mut parser = Parser::new(Source::from_bytes(&x));
+            if strict {
+                parser.set_strict();
+            }
+            let body = parser.parse_eval(direct, context.interner_mut());
+            let warnings = parser.take_warnings();
+            context.report_warnings(&warnings);
+            let body = body?;
+            let summary = EvalSummary::new(&body);
+            (EvalCode::Parsed(body, warnings), summary)
+        };
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -179,30 +455,33 @@ impl Eval {
             _ => Flags::default(),
         };
 
-        if !flags.contains(Flags::IN_FUNCTION) && contains(&body, ContainsSymbol::NewTarget) {
+        if !flags.contains(Flags::IN_FUNCTION) && summary.contains.contains(Contains::NEW_TARGET) {
             return Err(JsNativeError::syntax()
                 .with_message("invalid `new.target` expression inside eval")
                 .into());
         }
-        if !flags.contains(Flags::IN_METHOD) && contains(&body, ContainsSymbol::SuperProperty) {
+        if !flags.contains(Flags::IN_METHOD) && summary.contains.contains(Contains::SUPER_PROPERTY)
+        {
             return Err(JsNativeError::syntax()
                 .with_message("invalid `super` reference inside eval")
                 .into());
         }
         if !flags.contains(Flags::IN_DERIVED_CONSTRUCTOR)
-            && contains(&body, ContainsSymbol::SuperCall)
+            && summary.contains.contains(Contains::SUPER_CALL)
         {
             return Err(JsNativeError::syntax()
                 .with_message("invalid `super` call inside eval")
                 .into());
         }
-        if flags.contains(Flags::IN_CLASS_FIELD_INITIALIZER) && contains_arguments(&body) {
+        if flags.contains(Flags::IN_CLASS_FIELD_INITIALIZER)
+            && summary.contains.contains(Contains::ARGUMENTS)
+        {
             return Err(JsNativeError::syntax()
                 .with_message("invalid `arguments` reference inside eval")
                 .into());
         }
 
-        strict |= body.strict();
+        strict |= summary.strict;
 
         // Because our environment model does not map directly to the spec, this section looks very different.
         // 12 - 13 are implicit in the call of `Context::compile_with_new_declarative`.
@@ -234,31 +513,63 @@ impl Eval {
         // environment for all eval calls.
         if !strict {
             // Error if any var declaration in the eval code already exists as a let/const declaration in the current running environment.
-            if let Some(name) = context
+            if let Some((name, kind)) = context
                 .vm
                 .environments
-                .has_lex_binding_until_function_environment(&top_level_var_declared_names(&body))
+                .has_lex_binding_until_function_environment(&summary.var_names)
             {
                 restore_environment(context, action);
                 let name = context.interner().resolve_expect(name.sym());
-                let msg = format!("variable declaration {name} in eval function already exists as a lexical variable");
+                let msg = format!("variable declaration {name} in eval function already exists as a lexical variable declared with `{kind}`");
                 return Err(JsNativeError::syntax().with_message(msg).into());
             }
+
+            // Error if a function or var declaration can't be defined on the global object.
+            if context.vm.environments.is_var_environment_global() {
+                if let Err(err) = summary.check_global_declarations(context) {
+                    restore_environment(context, action);
+                    return Err(err);
+                }
+            }
         }
 
         // TODO: check if private identifiers inside `eval` are valid.
 
         // Compile and execute the eval statement list.
-        let code_block = {
-            let mut compiler = ByteCompiler::new(
-                Sym::MAIN,
-                body.strict(),
-                false,
-                context.vm.environments.current_compile_environment(),
-                context,
-            );
-            compiler.compile_statement_list_with_new_declarative(&body, true, strict);
-            Gc::new(compiler.finish())
+        let code_block = match body {
+            EvalCode::Compiled(code_block) => code_block,
+            EvalCode::Parsed(body, warnings) => {
+                let mut compiler = ByteCompiler::new(
+                    Sym::MAIN,
+                    body.strict(),
+                    false,
+                    context.vm.environments.current_compile_environment(),
+                    context,
+                );
+                compiler.source = Some(Rc::new(SourceText::new(x.into())));
+                compiler.compile_statement_list_with_new_declarative(&body, true, strict);
+                let code_block = match compiler.finish() {
+                    Ok(code_block) => Gc::new(code_block),
+                    Err(err) => {
+                        restore_environment(context, action);
+                        return Err(err);
+                    }
+                };
+
+                if let Some(key) = cache_key {
+                    context.eval_cache.insert(
+                        key,
+                        EvalCacheEntry {
+                            code_block: code_block.clone(),
+                            _environment: environment,
+                            summary,
+                            warnings: warnings.into_boxed_slice(),
+                        },
+                    );
+                }
+
+                code_block
+            }
         };
         // Indirect calls don't need extensions, because a non-strict indirect call modifies only
         // the global object.
@@ -19,7 +19,7 @@ use crate::{
     error::JsNativeError,
     js_string,
     native_function::NativeFunction,
-    object::{internal_methods::get_prototype_from_constructor, JsObject, Object, ObjectData},
+    object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
     object::{JsFunction, PrivateElement},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
@@ -314,6 +314,21 @@ impl Function {
         }
     }
 
+    /// Returns a shared reference to the codeblock of the function, or `None` if the function is a
+    /// [`NativeFunction`].
+    ///
+    /// Unlike [`Function::codeblock`], the returned codeblock can be passed to
+    /// [`Context::create_function`] without copying it.
+    pub fn shared_codeblock(&self) -> Option<Gc<CodeBlock>> {
+        match &self.kind {
+            FunctionKind::Native { .. } => None,
+            FunctionKind::Ordinary { code, .. }
+            | FunctionKind::Async { code, .. }
+            | FunctionKind::Generator { code, .. }
+            | FunctionKind::AsyncGenerator { code, .. } => Some(code.clone()),
+        }
+    }
+
     /// Creates a new `Function`.
     pub(crate) fn new(kind: FunctionKind, realm: Realm) -> Self {
         Self { kind, realm }
@@ -535,11 +550,11 @@ impl BuiltInFunctionObject {
         generator: bool,
         context: &mut Context<'_>,
     ) -> JsResult<JsObject> {
-        // 1. Let currentRealm be the current Realm Record.
-        // 2. Perform ? HostEnsureCanCompileStrings(currentRealm).
-        context
-            .host_hooks()
-            .ensure_can_compile_strings(context.realm().clone(), context)?;
+        if !context.runtime_compilation_enabled() {
+            return Err(JsNativeError::eval()
+                .with_message("Function: runtime compilation is disabled")
+                .into());
+        }
 
         // 3. If newTarget is undefined, set newTarget to constructor.
         let new_target = if new_target.is_undefined() {
@@ -583,31 +598,62 @@ impl BuiltInFunctionObject {
             StandardConstructors::function
         };
 
+        let prefix = match (r#async, generator) {
+            (true, true) => "async function*",
+            (true, false) => "async function",
+            (false, true) => "function*",
+            (false, false) => "function",
+        };
+
+        // Convert the arguments to strings, so the host can check the source text before compiling it.
+        let (parameters_text, body_arg) = if let Some((body_arg, args)) = args.split_last() {
+            let mut parameters = Vec::with_capacity(args.len());
+            for arg in args {
+                parameters.push(arg.to_string(context)?);
+            }
+            let parameters_text = String::from_utf16_lossy(&parameters.join(utf16!(",")));
+
+            // 11. Let bodyString be the string-concatenation of 0x000A (LINE FEED), ? ToString(bodyArg), and 0x000A (LINE FEED).
+            let body_arg = body_arg.to_string(context)?.to_std_string_escaped();
+
+            (parameters_text, Some(body_arg))
+        } else {
+            (String::new(), None)
+        };
+
+        // Let sourceText be the string-concatenation of prefix, " anonymous(", P, 0x000A (LINE FEED), ") {", bodyParseString, and "}".
+        let source_text = format!(
+            "{prefix} anonymous({parameters_text}\n) {{\n{}\n}}",
+            body_arg.as_deref().unwrap_or_default()
+        );
+
+        // 1. Let currentRealm be the current Realm Record.
+        // 2. Perform ? HostEnsureCanCompileStrings(currentRealm).
+        context
+            .host_hooks()
+            .ensure_can_compile_strings(context.realm().clone(), &source_text, context)
+            .map_err(JsNativeError::from)?;
+
         // 22. Let proto be ? GetPrototypeFromConstructor(newTarget, fallbackProto).
         let prototype = get_prototype_from_constructor(&new_target, default, context)?;
 
-        if let Some((body_arg, args)) = args.split_last() {
-            let parameters = if args.is_empty() {
+        if let Some(body_arg) = body_arg {
+            let parameters = if args.len() <= 1 {
                 FormalParameterList::default()
             } else {
-                let mut parameters = Vec::with_capacity(args.len());
-                for arg in args {
-                    parameters.push(arg.to_string(context)?);
-                }
-                let parameters = parameters.join(utf16!(","));
-
                 // TODO: make parser generic to u32 iterators
+                let mut parser = Parser::new(Source::from_bytes(&parameters_text));
                 let parameters =
-                    match Parser::new(Source::from_bytes(&String::from_utf16_lossy(&parameters)))
-                        .parse_formal_parameters(context.interner_mut(), generator, r#async)
-                    {
-                        Ok(parameters) => parameters,
-                        Err(e) => {
-                            return Err(JsNativeError::syntax()
-                                .with_message(format!("failed to parse function parameters: {e}"))
-                                .into())
-                        }
-                    };
+                    parser.parse_formal_parameters(context.interner_mut(), generator, r#async);
+                context.report_warnings(&parser.take_warnings());
+                let parameters = match parameters {
+                    Ok(parameters) => parameters,
+                    Err(e) => {
+                        return Err(JsNativeError::syntax()
+                            .with_message(format!("failed to parse function parameters: {e}"))
+                            .into())
+                    }
+                };
 
                 if generator && contains(&parameters, ContainsSymbol::YieldExpression) {
                     return Err(JsNativeError::syntax().with_message(
@@ -632,16 +678,13 @@ impl BuiltInFunctionObject {
                     .into());
             }
 
-            // 11. Let bodyString be the string-concatenation of 0x000A (LINE FEED), ? ToString(bodyArg), and 0x000A (LINE FEED).
-            let body_arg = body_arg.to_string(context)?.to_std_string_escaped();
             let body = b"\n".chain(body_arg.as_bytes()).chain(b"\n".as_slice());
 
             // TODO: make parser generic to u32 iterators
-            let body = match Parser::new(Source::from_reader(body, None)).parse_function_body(
-                context.interner_mut(),
-                generator,
-                r#async,
-            ) {
+            let mut parser = Parser::new(Source::from_reader(body, None));
+            let body = parser.parse_function_body(context.interner_mut(), generator, r#async);
+            context.report_warnings(&parser.take_warnings());
+            let body = match body {
                 Ok(statement_list) => statement_list,
                 Err(e) => {
                     return Err(JsNativeError::syntax()
@@ -715,12 +758,13 @@ impl BuiltInFunctionObject {
                 .name(Sym::ANONYMOUS)
                 .generator(generator)
                 .r#async(r#async)
+                .source_text(Some(source_text.into()))
                 .compile(
                     &parameters,
                     &body,
                     context.realm().environment().compile_env(),
                     context,
-                );
+                )?;
 
             let environments = context.vm.environments.pop_to_global();
 
@@ -750,12 +794,13 @@ impl BuiltInFunctionObject {
             let code = FunctionCompiler::new()
                 .name(Sym::ANONYMOUS)
                 .generator(true)
+                .source_text(Some(source_text.into()))
                 .compile(
                     &FormalParameterList::default(),
                     &StatementList::default(),
                     context.realm().environment().compile_env(),
                     context,
-                );
+                )?;
 
             let environments = context.vm.environments.pop_to_global();
             let function_object = crate::vm::create_generator_function_object(
@@ -769,12 +814,15 @@ impl BuiltInFunctionObject {
 
             Ok(function_object)
         } else {
-            let code = FunctionCompiler::new().name(Sym::ANONYMOUS).compile(
-                &FormalParameterList::default(),
-                &StatementList::default(),
-                context.realm().environment().compile_env(),
-                context,
-            );
+            let code = FunctionCompiler::new()
+                .name(Sym::ANONYMOUS)
+                .source_text(Some(source_text.into()))
+                .compile(
+                    &FormalParameterList::default(),
+                    &StatementList::default(),
+                    context.realm().environment().compile_env(),
+                    context,
+                )?;
 
             let environments = context.vm.environments.pop_to_global();
             let function_object = crate::vm::create_function_object(
@@ -941,44 +989,30 @@ impl BuiltInFunctionObject {
 
     #[allow(clippy::wrong_self_convention)]
     fn to_string(this: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
-        let object = this.as_object().map(JsObject::borrow);
-        let function = object
-            .as_deref()
-            .and_then(Object::as_function)
+        let object = this
+            .as_object()
+            .filter(|object| object.is_callable())
             .ok_or_else(|| JsNativeError::typ().with_message("Not a function"))?;
 
-        let name = {
-            // Is there a case here where if there is no name field on a value
-            // name should default to None? Do all functions have names set?
-            let value = this
-                .as_object()
-                .expect("checked that `this` was an object above")
-                .get(utf16!("name"), &mut *context)?;
-            if value.is_null_or_undefined() {
-                None
-            } else {
-                Some(value.to_string(context)?)
-            }
-        };
+        let source_text = object
+            .borrow()
+            .as_function()
+            .and_then(|function| function.codeblock())
+            .and_then(|code| code.source_text().cloned());
+        if let Some(source_text) = source_text {
+            return Ok(source_text.into());
+        }
 
-        let name = name
-            .filter(|n| !n.is_empty())
-            .unwrap_or_else(|| "anonymous".into());
+        // Callables without source text, like builtins, bound functions or functions of scripts
+        // compiled without retaining their source text, use the syntax of a NativeFunction.
+        let name = object.get(utf16!("name"), context)?;
+        let name = if name.is_null_or_undefined() {
+            js_string!()
+        } else {
+            name.to_string(context)?
+        };
 
-        match function.kind {
-            FunctionKind::Native { .. } | FunctionKind::Ordinary { .. } => {
-                Ok(js_string!(utf16!("[Function: "), &name, utf16!("]")).into())
-            }
-            FunctionKind::Async { .. } => {
-                Ok(js_string!(utf16!("[AsyncFunction: "), &name, utf16!("]")).into())
-            }
-            FunctionKind::Generator { .. } => {
-                Ok(js_string!(utf16!("[GeneratorFunction: "), &name, utf16!("]")).into())
-            }
-            FunctionKind::AsyncGenerator { .. } => {
-                Ok(js_string!(utf16!("[AsyncGeneratorFunction: "), &name, utf16!("]")).into())
-            }
-        }
+        Ok(js_string!(utf16!("function "), &name, utf16!("() { [native code] }")).into())
     }
 
     /// `Function.prototype [ @@hasInstance ] ( V )`
@@ -5,7 +5,7 @@ use crate::{
     native_function::NativeFunction,
     object::{FunctionObjectBuilder, JsObject},
     property::{Attribute, PropertyDescriptor},
-    run_test_actions, JsValue, TestAction,
+    run_test_actions, run_test_actions_with, Context, JsValue, Source, TestAction,
 };
 use indoc::indoc;
 
@@ -127,6 +127,48 @@ fn function_prototype_apply_on_object() {
     ]);
 }
 
+#[test]
+fn function_prototype_apply_array_like() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                function list() {
+                    return Array.prototype.join.call(arguments, ",");
+                }
+                function args() {
+                    return arguments;
+                }
+            "#}),
+        TestAction::assert_eq("list.apply(null, [1, 'a', null])", "1,a,"),
+        TestAction::assert_eq("list.apply(null, args(1, 2, 3))", "1,2,3"),
+        TestAction::assert_eq("list.apply(null, { length: 3, 0: 'x', 2: 'z' })", "x,,z"),
+        TestAction::assert_eq("list.apply(null, new Proxy([4, 5], {}))", "4,5"),
+        // The arguments are a snapshot of the array, even if the callee mutates it.
+        TestAction::assert_eq(
+            indoc! {r#"
+                var packed = [1, 2, 3];
+                (function () {
+                    packed.length = 0;
+                    packed.push("changed");
+                    return list.apply(null, arguments);
+                }).apply(null, packed)
+            "#},
+            "1,2,3",
+        ),
+        // A packed array whose length exceeds its elements reads the holes through the prototype.
+        TestAction::assert_eq(
+            indoc! {r#"
+                Array.prototype[2] = "proto";
+                var short = [1, 2];
+                short.length = 3;
+                var result = list.apply(null, short);
+                delete Array.prototype[2];
+                result
+            "#},
+            "1,2,proto",
+        ),
+    ]);
+}
+
 #[test]
 fn closure_capture_clone() {
     run_test_actions([
@@ -191,3 +233,95 @@ fn function_constructor_early_errors_super() {
         ),
     ]);
 }
+
+#[test]
+fn function_to_string_source_text() {
+    run_test_actions([
+        TestAction::assert_eq(
+            r#"eval("(function f(){return 1})").toString()"#,
+            "function f(){return 1}",
+        ),
+        TestAction::assert_eq(
+            r#"eval("\n  function g( a,b ) {\n  return '\\u0041'; }\n g").toString()"#,
+            "function g( a,b ) {\n  return '\\u0041'; }",
+        ),
+        TestAction::assert_eq(
+            r#"eval("(function() { return function inner() {} })")().toString()"#,
+            "function inner() {}",
+        ),
+        TestAction::assert_eq(
+            "new Function('a', 'b', 'return a + b').toString()",
+            "function anonymous(a,b\n) {\nreturn a + b\n}",
+        ),
+        TestAction::assert_eq("Function().toString()", "function anonymous(\n) {\n\n}"),
+    ]);
+}
+
+#[test]
+fn function_to_string_retained_source_text() {
+    let context = &mut Context::default();
+    context.retain_source_text(true);
+    run_test_actions_with(
+        [
+            TestAction::run("function add(a, b) {\n  return a + b; // sum\n}"),
+            TestAction::assert_eq(
+                "add.toString()",
+                "function add(a, b) {\n  return a + b; // sum\n}",
+            ),
+            TestAction::assert_eq(
+                "(function (x) { return x * 2; }).toString()",
+                "function (x) { return x * 2; }",
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn function_to_string_parsed_script() {
+    let context = &mut Context::default();
+
+    let source = "function add(a, b) { return a + b; }";
+    let script = context.parse_script(Source::from_bytes(source)).unwrap();
+    let code = context
+        .compile_script_with_source_text(&script, source)
+        .unwrap();
+    context.execute(code).unwrap();
+
+    // Scripts compiled without their source text don't retain it.
+    context.retain_source_text(true);
+    let script = context
+        .parse_script(Source::from_bytes("function sub(a, b) { return a - b; }"))
+        .unwrap();
+    let code = context.compile_script(&script).unwrap();
+    context.execute(code).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("add.toString()", "function add(a, b) { return a + b; }"),
+            TestAction::assert_eq("sub.toString()", "function sub() { [native code] }"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn function_to_string_native_code() {
+    run_test_actions([
+        TestAction::run("function add(a, b) { return a + b; }"),
+        TestAction::assert_eq("add.toString()", "function add() { [native code] }"),
+        TestAction::assert_eq(
+            "Array.prototype.push.toString()",
+            "function push() { [native code] }",
+        ),
+        TestAction::assert_eq(
+            "add.bind(null).toString()",
+            "function bound add() { [native code] }",
+        ),
+        TestAction::assert_native_error(
+            "Function.prototype.toString.call({})",
+            ErrorKind::Type,
+            "Not a function",
+        ),
+    ]);
+}
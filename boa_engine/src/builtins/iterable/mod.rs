@@ -12,6 +12,8 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
+use indexmap::IndexMap;
+use std::hash::Hash;
 
 mod async_from_sync_iterator;
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
@@ -567,3 +569,90 @@ pub(crate) fn iterable_to_list(
     // 6. Return values.
     Ok(values)
 }
+
+/// `GroupBy ( items, callbackfn, keyCoercion )`
+///
+/// Groups the values of `items` by the keys returned from `callback`, which are coerced by
+/// `key_coercion`. The groups and their values are kept in insertion order.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+///  [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by<K, F>(
+    items: &JsValue,
+    callback: &JsValue,
+    key_coercion: F,
+    context: &mut Context<'_>,
+) -> JsResult<IndexMap<K, Vec<JsValue>>>
+where
+    K: Hash + Eq,
+    F: Fn(JsValue, &mut Context<'_>) -> JsResult<K>,
+{
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible()?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    let callback = callback.as_callable().ok_or_else(|| {
+        JsNativeError::typ().with_message("GroupBy: callback is not a callable object")
+    })?;
+
+    // 3. Let groups be a new empty List.
+    let mut groups: IndexMap<K, Vec<JsValue>> = IndexMap::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let iterator_record = items.get_iterator(context, Some(IteratorHint::Sync), None)?;
+
+    // 5. Let k be 0.
+    let mut k = 0u64;
+
+    // 6. Repeat,
+    loop {
+        // a. If k ≥ 2^53 - 1, then
+        if k >= 9_007_199_254_740_991 {
+            // i. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = Err(JsNativeError::typ()
+                .with_message("GroupBy: too many elements in the iterable")
+                .into());
+
+            // ii. Return ? IteratorClose(iteratorRecord, error).
+            iterator_record.close(error, context)?;
+            unreachable!("IteratorClose must return the throw completion it was given");
+        }
+
+        // b. Let next be ? IteratorStep(iteratorRecord).
+        // c. If next is false, then
+        //     i. Return groups.
+        let Some(next) = iterator_record.step(context)? else {
+            return Ok(groups);
+        };
+
+        // d. Let value be ? IteratorValue(next).
+        let value = next.value(context)?;
+
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        // g. If keyCoercion is property, then
+        //     i. Set key to Completion(ToPropertyKey(key)).
+        //     ii. IfAbruptCloseIterator(key, iteratorRecord).
+        // h. Else,
+        //     i. Assert: keyCoercion is zero.
+        //     ii. If key is -0𝔽, set key to +0𝔽.
+        let key = callback
+            .call(&JsValue::undefined(), &[value.clone(), k.into()], context)
+            .and_then(|key| key_coercion(key, context));
+        let key = match key {
+            Ok(key) => key,
+            Err(err) => {
+                iterator_record.close(Err(err), context)?;
+                unreachable!("IteratorClose must return the throw completion it was given");
+            }
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        groups.entry(key).or_default().push(value);
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
+}
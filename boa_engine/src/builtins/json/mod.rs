@@ -125,7 +125,7 @@ impl Json {
             );
             compiler.create_script_decls(&statement_list, false);
             compiler.compile_statement_list(&statement_list, true, false);
-            Gc::new(compiler.finish())
+            Gc::new(compiler.finish()?)
         };
         let unfiltered = context.execute(code_block)?;
 
@@ -11,7 +11,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
 
 use crate::{
-    builtins::BuiltInObject,
+    builtins::{iterable::group_by, Array, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     object::{internal_methods::get_prototype_from_constructor, JsObject, ObjectData},
@@ -54,6 +54,7 @@ impl IntrinsicObject for Map {
             .build();
 
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::group_by, "groupBy", 2)
             .static_accessor(
                 JsSymbol::species(),
                 Some(get_species),
@@ -154,6 +155,62 @@ impl BuiltInConstructor for Map {
 }
 
 impl Map {
+    /// `Map.groupBy ( items, callbackfn )`
+    ///
+    /// Groups the elements of an iterable into a new `Map`, using the keys returned by the
+    /// callback compared with `SameValueZero`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    pub(crate) fn group_by(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(
+            items,
+            callback,
+            |key, _| {
+                // If key is -0𝔽, set key to +0𝔽.
+                Ok(match key {
+                    JsValue::Rational(r) if r.is_zero() => JsValue::Rational(0f64),
+                    key => key,
+                })
+            },
+            context,
+        )?;
+
+        // 2. Let map be ! Construct(%Map%).
+        let mut map = OrderedMap::new();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+            // c. Append entry to map.[[MapData]].
+            map.insert(key, elements.into());
+        }
+
+        // 4. Return map.
+        let prototype = context.intrinsics().constructors().map().prototype();
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            ObjectData::map(map),
+        )
+        .into())
+    }
+
     /// `get Map [ @@species ]`
     ///
     /// The `Map [ @@species ]` accessor property returns the Map constructor.
@@ -304,3 +304,27 @@ fn for_of_delete() {
             "#}),
     ]);
 }
+
+#[test]
+fn group_by() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                const key = {};
+                const groups = Map.groupBy([1, -0, 2, 0, 3], n => n === 2 ? key : n === 0 ? n : 1);
+            "#}),
+        TestAction::assert("groups instanceof Map"),
+        TestAction::assert_eq("groups.size", 3),
+        TestAction::assert("arrayEquals(groups.get(1), [1, 3])"),
+        TestAction::assert("arrayEquals(groups.get(0), [-0, 0])"),
+        TestAction::assert("arrayEquals(groups.get(key), [2])"),
+        TestAction::assert("arrayEquals([...groups.keys()], [1, 0, key])"),
+        TestAction::assert("Object.is([...groups.keys()][1], 0)"),
+        TestAction::assert_eq("Map.groupBy.length", 2),
+        TestAction::assert_native_error(
+            "Map.groupBy(null, () => 0)",
+            ErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
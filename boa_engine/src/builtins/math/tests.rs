@@ -1,4 +1,7 @@
-use crate::{run_test_actions, TestAction};
+use crate::{
+    context::{ContextBuilder, HostHooks},
+    run_test_actions, run_test_actions_with, Context, Source, TestAction,
+};
 
 #[test]
 fn abs() {
@@ -317,3 +320,69 @@ fn trunc() {
         TestAction::assert_eq("Math.trunc(0.123)", 0.0),
     ]);
 }
+
+#[test]
+fn random_seeded() {
+    let context = &mut Context::default();
+    context.set_random_seed(7);
+
+    run_test_actions_with(
+        [
+            TestAction::run("const first = Array.from({ length: 1000 }, Math.random);"),
+            TestAction::assert("first.every(n => typeof n === 'number' && n >= 0 && n < 1)"),
+            TestAction::assert("new Set(first).size === first.length"),
+            TestAction::assert(
+                "Math.abs(first.reduce((a, b) => a + b) / first.length - 0.5) < 0.05",
+            ),
+            TestAction::inspect_context(|context| context.set_random_seed(7)),
+            TestAction::run("const second = Array.from({ length: 1000 }, Math.random);"),
+            TestAction::assert("first.every((n, i) => n === second[i])"),
+            TestAction::inspect_context(|context| context.set_random_seed(8)),
+            TestAction::assert("Math.random() !== first[0]"),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn random_seeded_shared_by_realms() {
+    let context = &mut Context::default();
+    let realm = context.create_realm().unwrap();
+
+    context.set_random_seed(3);
+    let first = context.eval_in_realm(&realm, "Math.random()").unwrap();
+    let second = context
+        .eval_script(Source::from_bytes("Math.random()"))
+        .unwrap();
+
+    context.set_random_seed(3);
+    let sequence = context
+        .eval_script(Source::from_bytes("[Math.random(), Math.random()]"))
+        .unwrap();
+    let sequence = sequence.as_object().unwrap();
+    assert_eq!(sequence.get(0, context).unwrap(), first);
+    assert_eq!(sequence.get(1, context).unwrap(), second);
+}
+
+#[test]
+fn random_host_hook() {
+    struct Hooks;
+
+    impl HostHooks for Hooks {
+        fn random_f64(&self) -> f64 {
+            0.25
+        }
+    }
+
+    let hooks: &dyn HostHooks = &Hooks;
+    let context = &mut ContextBuilder::new().host_hooks(hooks).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("Math.random()", 0.25),
+            TestAction::inspect_context(|context| context.set_random_seed(1)),
+            TestAction::assert("Math.random() !== 0.25"),
+        ],
+        context,
+    );
+}
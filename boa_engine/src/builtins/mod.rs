@@ -22,10 +22,12 @@ pub mod number;
 pub mod object;
 pub mod promise;
 pub mod proxy;
+pub mod queue_microtask;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
 pub mod typed_array;
 pub mod uri;
@@ -82,12 +84,14 @@ use crate::{
         iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
+        queue_microtask::QueueMicrotask,
         regexp::RegExpStringIterator,
         set::SetIterator,
         string::StringIterator,
+        structured_clone::StructuredClone,
         typed_array::TypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -250,8 +254,11 @@ impl Realm {
         DecodeUri::init(self);
         DecodeUriComponent::init(self);
         WeakRef::init(self);
+        FinalizationRegistry::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
+        StructuredClone::init(self);
+        QueueMicrotask::init(self);
 
         #[cfg(feature = "annex-b")]
         {
@@ -357,8 +364,11 @@ pub(crate) fn set_default_global_bindings(context: &mut Context<'_>) -> JsResult
     global_binding::<DecodeUri>(context)?;
     global_binding::<DecodeUriComponent>(context)?;
     global_binding::<WeakRef>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
+    global_binding::<StructuredClone>(context)?;
+    global_binding::<QueueMicrotask>(context)?;
 
     #[cfg(feature = "annex-b")]
     {
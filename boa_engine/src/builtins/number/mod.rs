@@ -41,6 +41,41 @@ mod tests;
 
 const BUF_SIZE: usize = 2200;
 
+/// The format of the default string representation of numbers.
+///
+/// See [`Context::set_number_formatting_mode`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NumberFormattingMode {
+    /// The shortest representation that converts back to the same number, as required by the
+    /// specification.
+    #[default]
+    Shortest,
+
+    /// The number rounded to the given count of significant digits, in exponential notation
+    /// (like `1.50e+3`). A count of `0` is treated as `1`.
+    FixedPrecision(u8),
+
+    /// The shortest digits that convert back to the same number, always in exponential notation
+    /// (like `1.5e+3`), which keeps the sign of `-0`.
+    Canonical,
+}
+
+impl NumberFormattingMode {
+    /// Formats `x` according to this mode.
+    pub(crate) fn format(self, x: f64) -> String {
+        if !x.is_finite() {
+            return Number::to_native_string(x);
+        }
+        match self {
+            Self::Shortest => Number::to_native_string(x),
+            Self::FixedPrecision(digits) => {
+                f64_to_exponential_with_precision(x, usize::from(digits.max(1) - 1))
+            }
+            Self::Canonical => f64_to_exponential(x),
+        }
+    }
+}
+
 /// `Number` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Number;
@@ -686,6 +721,10 @@ impl Number {
 
         // 5. If radixNumber = 10, return ! ToString(x).
         if radix_number == 10 {
+            // The format of the default output can be changed by the embedder.
+            if radix.is_undefined() {
+                return Ok(JsValue::new(context.number_formatting_mode().format(x)));
+            }
             return Ok(JsValue::new(Self::to_native_string(x)));
         }
 
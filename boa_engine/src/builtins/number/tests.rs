@@ -1,10 +1,10 @@
 #![allow(clippy::float_cmp)]
 
 use crate::{
-    builtins::{error::ErrorKind, Number},
+    builtins::{error::ErrorKind, number::NumberFormattingMode, Number},
     run_test_actions,
     value::AbstractRelation,
-    TestAction,
+    Context, JsValue, Source, TestAction,
 };
 
 #[test]
@@ -484,3 +484,47 @@ fn issue_2717() {
         TestAction::assert_eq("(0.23046743672210102).toString(36)", "0.8aoosla2phj"),
     ]);
 }
+
+#[test]
+fn number_formatting_mode() {
+    const SOURCE: &str = "[String(0.1 + 0.2), (-0).toString(), String(1e21), (1500).toString(), \
+                          (255).toString(16), `${0.5}`].join()";
+
+    fn render(mode: NumberFormattingMode) -> (String, String) {
+        let context = &mut Context::default();
+        context.set_number_formatting_mode(mode);
+        let result = context
+            .eval_script(Source::from_bytes(SOURCE))
+            .unwrap()
+            .to_string(context)
+            .unwrap()
+            .to_std_string_escaped();
+        let inspected = context.inspect(&JsValue::new(-0.0));
+        (result, inspected)
+    }
+
+    assert_eq!(
+        render(NumberFormattingMode::Shortest),
+        (
+            String::from("0.30000000000000004,0,1e+21,1500,ff,0.5"),
+            String::from("-0")
+        )
+    );
+
+    let canonical = render(NumberFormattingMode::Canonical);
+    assert_eq!(
+        canonical,
+        (
+            String::from("3.0000000000000004e-1,-0e+0,1e+21,1.5e+3,ff,0.5"),
+            String::from("-0e+0")
+        )
+    );
+    for _ in 0..3 {
+        assert_eq!(render(NumberFormattingMode::Canonical), canonical);
+    }
+
+    assert_eq!(
+        render(NumberFormattingMode::FixedPrecision(3)).0,
+        "3.00e-1,-0.00e+0,1.00e+21,1.50e+3,ff,0.5"
+    );
+}
@@ -109,16 +109,22 @@ impl ForInIterator {
         let mut object = iterator.object.to_object(context)?;
         loop {
             if !iterator.object_was_visited {
-                let keys = object.__own_property_keys__(context)?;
-                for k in keys {
-                    match k {
-                        PropertyKey::String(ref k) => {
-                            iterator.remaining_keys.push_back(k.clone());
+                if let Some(keys) = object.cached_enumeration_keys(context) {
+                    iterator
+                        .remaining_keys
+                        .extend(keys.iter().map(|(key, _)| key.clone()));
+                } else {
+                    let keys = object.__own_property_keys__(context)?;
+                    for k in keys {
+                        match k {
+                            PropertyKey::String(ref k) => {
+                                iterator.remaining_keys.push_back(k.clone());
+                            }
+                            PropertyKey::Index(i) => {
+                                iterator.remaining_keys.push_back(i.to_string().into());
+                            }
+                            PropertyKey::Symbol(_) => {}
                         }
-                        PropertyKey::Index(i) => {
-                            iterator.remaining_keys.push_back(i.to_string().into());
-                        }
-                        PropertyKey::Symbol(_) => {}
                     }
                 }
                 iterator.object_was_visited = true;
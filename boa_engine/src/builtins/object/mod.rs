@@ -17,14 +17,14 @@ use std::ops::Deref;
 
 use super::{Array, BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 use crate::{
-    builtins::{map, BuiltInObject},
+    builtins::{iterable::group_by, map, BuiltInObject},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
     native_function::NativeFunction,
     object::{
-        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, IntegrityLevel,
-        JsObject, ObjectData, ObjectKind,
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject,
+        ObjectData, ObjectKind,
     },
     property::{Attribute, PropertyDescriptor, PropertyKey, PropertyNameKind},
     realm::Realm,
@@ -104,6 +104,7 @@ impl IntrinsicObject for Object {
             .static_method(Self::get_own_property_symbols, "getOwnPropertySymbols", 1)
             .static_method(Self::has_own, "hasOwn", 2)
             .static_method(Self::from_entries, "fromEntries", 1)
+            .static_method(Self::group_by, "groupBy", 2)
             .build();
     }
 
@@ -819,7 +820,7 @@ impl Object {
             match o.borrow().kind() {
                 ObjectKind::Arguments(_) => utf16!("Arguments"),
                 _ if o.is_callable() => utf16!("Function"),
-                ObjectKind::Error(_) => utf16!("Error"),
+                ObjectKind::Error(..) => utf16!("Error"),
                 ObjectKind::Boolean(_) => utf16!("Boolean"),
                 ObjectKind::Number(_) => utf16!("Number"),
                 ObjectKind::String(_) => utf16!("String"),
@@ -1063,13 +1064,8 @@ impl Object {
 
         if let Some(o) = o.as_object() {
             // 2. Let status be ? SetIntegrityLevel(O, sealed).
-            let status = o.set_integrity_level(IntegrityLevel::Sealed, context)?;
             // 3. If status is false, throw a TypeError exception.
-            if !status {
-                return Err(JsNativeError::typ()
-                    .with_message("cannot seal object")
-                    .into());
-            }
+            o.seal(context)?;
         }
         // 1. If Type(O) is not Object, return O.
         // 4. Return O.
@@ -1094,8 +1090,7 @@ impl Object {
         // 1. If Type(O) is not Object, return true.
         // 2. Return ? TestIntegrityLevel(O, sealed).
         if let Some(o) = o.as_object() {
-            Ok(o.test_integrity_level(IntegrityLevel::Sealed, context)?
-                .into())
+            Ok(o.is_sealed(context)?.into())
         } else {
             Ok(JsValue::new(true))
         }
@@ -1114,13 +1109,8 @@ impl Object {
 
         if let Some(o) = o.as_object() {
             // 2. Let status be ? SetIntegrityLevel(O, frozen).
-            let status = o.set_integrity_level(IntegrityLevel::Frozen, context)?;
             // 3. If status is false, throw a TypeError exception.
-            if !status {
-                return Err(JsNativeError::typ()
-                    .with_message("cannot freeze object")
-                    .into());
-            }
+            o.freeze(context)?;
         }
         // 1. If Type(O) is not Object, return O.
         // 4. Return O.
@@ -1145,8 +1135,7 @@ impl Object {
         // 1. If Type(O) is not Object, return true.
         // 2. Return ? TestIntegrityLevel(O, frozen).
         if let Some(o) = o.as_object() {
-            Ok(o.test_integrity_level(IntegrityLevel::Frozen, context)?
-                .into())
+            Ok(o.is_frozen(context)?.into())
         } else {
             Ok(JsValue::new(true))
         }
@@ -1169,13 +1158,8 @@ impl Object {
 
         if let Some(o) = o.as_object() {
             // 2. Let status be ? O.[[PreventExtensions]]().
-            let status = o.__prevent_extensions__(context)?;
             // 3. If status is false, throw a TypeError exception.
-            if !status {
-                return Err(JsNativeError::typ()
-                    .with_message("cannot prevent extensions")
-                    .into());
-            }
+            o.prevent_extensions(context)?;
         }
         // 1. If Type(O) is not Object, return O.
         // 4. Return O.
@@ -1308,6 +1292,46 @@ impl Object {
         // 6. Return ? AddEntriesFromIterable(obj, iterable, adder).
         map::add_entries_from_iterable(&obj, iterable, &adder.into(), context)
     }
+
+    /// `Object.groupBy ( items, callbackfn )`
+    ///
+    /// Groups the elements of an iterable into a null-prototype object, using the property keys
+    /// returned by the callback.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub fn group_by(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+        let items = args.get_or_undefined(0);
+        let callback = args.get_or_undefined(1);
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(
+            items,
+            callback,
+            |key, context| key.to_property_key(context),
+            context,
+        )?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let obj = JsObject::with_null_proto();
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let elements = Array::create_array_from_list(elements, context);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            obj.create_data_property_or_throw(key, elements, context)
+                .expect("cannot fail for a newly created extensible object");
+        }
+
+        // 4. Return obj.
+        Ok(obj.into())
+    }
 }
 
 /// The abstract operation `ObjectDefineProperties`
@@ -1,4 +1,7 @@
-use crate::{builtins::error::ErrorKind, run_test_actions, JsValue, TestAction};
+use crate::{
+    builtins::error::ErrorKind, run_test_actions, run_test_actions_with, Context, JsValue,
+    TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -406,3 +409,90 @@ fn object_from_entries() {
         TestAction::assert_eq("map[5]", 4),
     ]);
 }
+
+#[test]
+fn property_enumeration_cache() {
+    let keys_and_for_in = indoc! {r#"
+        function forIn(o) {
+            var keys = [];
+            for (var key in o) {
+                keys.push(key);
+            }
+            return keys.join();
+        }
+        var a = { x: 1, y: 2 };
+        var b = { x: 3, y: 4 };
+        var first = [Object.keys(a).join(), forIn(a), Object.keys(b).join(), forIn(b)];
+        b.z = 5;
+        Object.defineProperty(a, "y", { enumerable: false });
+        var second = [Object.keys(a).join(), forIn(a), Object.keys(b).join(), forIn(b)];
+        delete b.x;
+        a[0] = 6;
+        var third = [Object.keys(a).join(), forIn(a), Object.keys(b).join(), forIn(b)];
+    "#};
+
+    for enabled in [true, false] {
+        let context = &mut Context::default();
+        context.set_property_enumeration_cache(enabled);
+        assert_eq!(context.property_enumeration_cache(), enabled);
+        run_test_actions_with(
+            [
+                TestAction::run(keys_and_for_in),
+                TestAction::assert_eq("first.join(';')", "x,y;x,y;x,y;x,y"),
+                TestAction::assert_eq("second.join(';')", "x;x;x,y,z;x,y,z"),
+                TestAction::assert_eq("third.join(';')", "0,x;0,x;y,z;y,z"),
+            ],
+            context,
+        );
+    }
+}
+
+#[test]
+fn object_group_by() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                const groups = Object.groupBy([1, 2, 3, 4, 5], (n, i) => {
+                    if (n - 1 !== i) throw new Error("wrong index");
+                    return n % 2 === 0 ? "even" : "odd";
+                });
+            "#}),
+        TestAction::assert("Object.getPrototypeOf(groups) === null"),
+        TestAction::assert("arrayEquals(Object.keys(groups), ['odd', 'even'])"),
+        TestAction::assert("arrayEquals(groups.odd, [1, 3, 5])"),
+        TestAction::assert("arrayEquals(groups.even, [2, 4])"),
+        TestAction::assert(indoc! {r#"
+                const sym = Symbol();
+                arrayEquals(Object.groupBy("ab", () => sym)[sym], ["a", "b"])
+            "#}),
+        TestAction::assert_eq("Object.groupBy.length", 2),
+        TestAction::assert_native_error(
+            "Object.groupBy([], 1)",
+            ErrorKind::Type,
+            "GroupBy: callback is not a callable object",
+        ),
+    ]);
+}
+
+#[test]
+fn object_group_by_closes_iterator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                let closed = false;
+                const iterable = {
+                    [Symbol.iterator]() {
+                        return {
+                            next() { return { value: 1, done: false }; },
+                            return() { closed = true; return {}; },
+                        };
+                    },
+                };
+            "#}),
+        TestAction::assert_native_error(
+            "Object.groupBy(iterable, () => { throw new TypeError('callback'); })",
+            ErrorKind::Type,
+            "callback",
+        ),
+        TestAction::assert("closed"),
+    ]);
+}
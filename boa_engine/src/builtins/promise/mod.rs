@@ -1884,16 +1884,16 @@ impl Promise {
 
                 //   e. Perform HostEnqueuePromiseJob(rejectJob.[[Job]], rejectJob.[[Realm]]).
                 context.job_queue().enqueue_promise_job(reject_job, context);
-
-                // 12. Set promise.[[PromiseIsHandled]] to true.
-                promise
-                    .borrow_mut()
-                    .as_promise_mut()
-                    .expect("IsPromise(promise) is false")
-                    .handled = true;
             }
         }
 
+        // 12. Set promise.[[PromiseIsHandled]] to true.
+        promise
+            .borrow_mut()
+            .as_promise_mut()
+            .expect("IsPromise(promise) is false")
+            .handled = true;
+
         // 13. If resultCapability is undefined, then
         //   a. Return undefined.
         // 14. Else,
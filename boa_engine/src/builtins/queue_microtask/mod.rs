@@ -0,0 +1,82 @@
+//! Boa's implementation of the `queueMicrotask()` global function.
+//!
+//! The `queueMicrotask()` function queues a callback to be run as a microtask, in the same job
+//! queue as the reactions of promises.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    job::NativeJob,
+    object::{JsFunction, JsObject},
+    realm::Realm,
+    Context, JsArgs, JsResult, JsValue,
+};
+
+use super::{BuiltInBuilder, BuiltInObject, IntrinsicObject};
+
+/// The `queueMicrotask` function
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QueueMicrotask;
+
+impl IntrinsicObject for QueueMicrotask {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::callable_with_intrinsic::<Self>(realm, queue_microtask)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().queue_microtask().into()
+    }
+}
+
+impl BuiltInObject for QueueMicrotask {
+    const NAME: &'static str = "queueMicrotask";
+}
+
+/// Builtin JavaScript `queueMicrotask ( callback )` function.
+fn queue_microtask(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let callback = args
+        .get_or_undefined(0)
+        .as_object()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .ok_or_else(|| {
+            JsNativeError::typ().with_message("queueMicrotask: callback is not a function")
+        })?;
+
+    let callback = context.host_hooks().make_job_callback(callback, context);
+    let realm = context.realm().clone();
+
+    // Errors thrown by the callback are reported to the host instead of aborting the job queue,
+    // like the errors of the other callbacks of the HTML specification.
+    let job = NativeJob::with_realm(
+        move |context| {
+            let result = context.host_hooks().call_job_callback(
+                callback,
+                &JsValue::undefined(),
+                &[],
+                context,
+            );
+            if let Err(err) = result {
+                context.report_error(&err);
+            }
+            Ok(JsValue::undefined())
+        },
+        realm,
+    );
+    context.job_queue().enqueue_promise_job(job, context);
+
+    Ok(JsValue::undefined())
+}
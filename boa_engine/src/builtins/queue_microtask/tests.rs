@@ -0,0 +1,68 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    builtins::error::ErrorKind, js_string, run_test_actions, run_test_actions_with, Context,
+    JsValue, TestAction,
+};
+use indoc::indoc;
+
+#[test]
+fn ordering() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+                const log = [];
+                queueMicrotask(() => log.push("a"));
+                Promise.resolve().then(() => {
+                    log.push("b");
+                    queueMicrotask(() => log.push("d"));
+                });
+                queueMicrotask(() => log.push("c"));
+                log.push("sync");
+            "#}),
+        TestAction::assert_eq("log.join()", js_string!("sync")),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("log.join()", js_string!("sync,a,b,c,d")),
+    ]);
+}
+
+#[test]
+fn arguments() {
+    run_test_actions([
+        TestAction::assert_eq("queueMicrotask.length", 1),
+        TestAction::assert_eq("queueMicrotask(() => {})", JsValue::undefined()),
+        TestAction::run("let args; queueMicrotask(function () { args = arguments.length; });"),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("args", 0),
+        TestAction::assert_native_error(
+            "queueMicrotask({})",
+            ErrorKind::Type,
+            "queueMicrotask: callback is not a function",
+        ),
+    ]);
+}
+
+#[test]
+fn errors_are_reported() {
+    let context = &mut Context::default();
+
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let reported = errors.clone();
+    context.set_unhandled_error_hook(move |error, context| {
+        reported.borrow_mut().push(error.to_opaque(context));
+    });
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                    let ran = false;
+                    queueMicrotask(() => { throw "oops"; });
+                    queueMicrotask(() => { ran = true; });
+                "#}),
+            TestAction::inspect_context(|ctx| ctx.run_jobs()),
+            TestAction::assert("ran"),
+        ],
+        context,
+    );
+
+    assert_eq!(*errors.borrow(), [JsValue::from(js_string!("oops"))]);
+}
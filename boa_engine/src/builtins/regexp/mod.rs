@@ -23,12 +23,13 @@ use crate::{
     string::{utf16, CodePoint},
     symbol::JsSymbol,
     value::JsValue,
-    Context, JsArgs, JsResult, JsString,
+    Context, JsArgs, JsError, JsResult, JsString,
 };
 use boa_parser::lexer::regex::RegExpFlags;
 use boa_profiler::Profiler;
+use once_cell::unsync::OnceCell;
 use regress::{Flags, Regex};
-use std::str::FromStr;
+use std::{rc::Rc, str::FromStr};
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
@@ -41,12 +42,78 @@ mod tests;
 #[derive(Debug, Clone)]
 pub struct RegExp {
     /// Regex matcher.
-    matcher: Regex,
+    ///
+    /// The matcher is immutable, so it is shared with the other `RegExp` objects created by the
+    /// same regular expression literal.
+    matcher: Rc<Regex>,
     flags: RegExpFlags,
     original_source: JsString,
     original_flags: JsString,
 }
 
+/// A regular expression literal site of a compiled script or function.
+///
+/// Evaluating a regular expression literal creates a new `RegExp` object every time, but its
+/// pattern and flags never change, so the matcher is compiled the first time the literal is
+/// evaluated and shared by all the objects created by that literal. The state of a `RegExp`
+/// object, like its `lastIndex` property, is not part of the matcher.
+#[derive(Debug, Clone)]
+pub(crate) struct RegExpSite {
+    pattern: JsString,
+    flags: JsString,
+    compiled: OnceCell<(RegExpFlags, Rc<Regex>)>,
+}
+
+impl RegExpSite {
+    /// Creates a new regular expression literal site.
+    pub(crate) const fn new(pattern: JsString, flags: JsString) -> Self {
+        Self {
+            pattern,
+            flags,
+            compiled: OnceCell::new(),
+        }
+    }
+
+    /// Gets the pattern of the literal.
+    pub(crate) const fn pattern(&self) -> &JsString {
+        &self.pattern
+    }
+
+    /// Gets the flags of the literal.
+    pub(crate) const fn flags(&self) -> &JsString {
+        &self.flags
+    }
+
+    /// Creates a new `RegExp` object from the literal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regular-expression-literals-runtime-semantics-evaluation
+    pub(crate) fn create(&self, context: &mut Context<'_>) -> JsResult<JsObject> {
+        let (flags, matcher) = self.compiled.get_or_try_init(|| {
+            let flags = RegExpFlags::from_str(&self.flags.to_std_string_escaped())
+                .map_err(|msg| JsNativeError::syntax().with_message(msg))?;
+            let matcher = RegExp::compile_matcher(&self.pattern, flags)?;
+            Ok::<_, JsError>((flags, Rc::new(matcher)))
+        })?;
+
+        // 3. Return ! RegExpCreate(pattern, flags).
+        let constructor = context.intrinsics().constructors().regexp().constructor();
+        let obj = RegExp::alloc(&JsValue::new(constructor), context)?;
+        let regexp = RegExp {
+            matcher: matcher.clone(),
+            flags: *flags,
+            original_source: self.pattern.clone(),
+            original_flags: self.flags.clone(),
+        };
+        *obj.borrow_mut().kind_mut() = ObjectKind::RegExp(Box::new(regexp));
+        obj.set(utf16!("lastIndex"), 0, true, context)?;
+
+        Ok(obj)
+    }
+}
+
 impl IntrinsicObject for RegExp {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(Self::NAME, "init");
@@ -299,18 +366,10 @@ impl RegExp {
         // 18. Let rer be the RegExp Record { [[IgnoreCase]]: i, [[Multiline]]: m, [[DotAll]]: s, [[Unicode]]: u, [[CapturingGroupsCount]]: capturingGroupsCount }.
         // 19. Set obj.[[RegExpRecord]] to rer.
         // 20. Set obj.[[RegExpMatcher]] to CompilePattern of parseResult with argument rer.
-        let matcher =
-            match Regex::from_unicode(p.code_points().map(CodePoint::as_u32), Flags::from(flags)) {
-                Err(error) => {
-                    return Err(JsNativeError::syntax()
-                        .with_message(format!("failed to create matcher: {}", error.text))
-                        .into());
-                }
-                Ok(val) => val,
-            };
+        let matcher = Self::compile_matcher(&p, flags)?;
 
         let regexp = Self {
-            matcher,
+            matcher: Rc::new(matcher),
             flags,
             original_source: p,
             original_flags: f,
@@ -326,6 +385,19 @@ impl RegExp {
         Ok(obj.into())
     }
 
+    /// Compiles the matcher of `pattern` with `flags`.
+    fn compile_matcher(pattern: &JsString, flags: RegExpFlags) -> JsResult<Regex> {
+        Regex::from_unicode(
+            pattern.code_points().map(CodePoint::as_u32),
+            Flags::from(flags),
+        )
+        .map_err(|error| {
+            JsNativeError::syntax()
+                .with_message(format!("failed to create matcher: {}", error.text))
+                .into()
+        })
+    }
+
     /// `22.2.3.2.4 RegExpCreate ( P, F )`
     ///
     /// More information:
@@ -1,5 +1,9 @@
-use crate::{builtins::error::ErrorKind, object::JsObject, run_test_actions, JsValue, TestAction};
+use crate::{
+    builtins::error::ErrorKind, js_string, object::JsObject, run_test_actions, Context, JsValue,
+    Source, TestAction,
+};
 use indoc::indoc;
+use std::rc::Rc;
 
 #[test]
 fn constructors() {
@@ -198,3 +202,110 @@ fn search() {
         TestAction::assert_eq("/d/[Symbol.search](undefined)", 2),
     ]);
 }
+
+#[test]
+fn subclass_symbol_dispatch() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class MyRegExp extends RegExp {
+                [Symbol.replace](string, replacement) {
+                    return `replaced ${string} with ${replacement}`;
+                }
+                [Symbol.match](string) {
+                    return ["matched", string];
+                }
+                [Symbol.split](string) {
+                    return ["split", string];
+                }
+                [Symbol.search](string) {
+                    return 42;
+                }
+            }
+            var re = new MyRegExp("a");
+        "#}),
+        TestAction::assert_eq("'abc'.replace(re, 'x')", "replaced abc with x"),
+        TestAction::assert_eq("'abc'.match(re).join()", "matched,abc"),
+        TestAction::assert_eq("'abc'.split(re).join()", "split,abc"),
+        TestAction::assert_eq("'abc'.search(re)", 42),
+        // Methods not overridden still use the regex engine.
+        TestAction::assert_eq("re.test('abc')", true),
+    ]);
+}
+
+#[test]
+fn literals_share_compiled_pattern() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function make() {
+                return /a/g;
+            }
+            var first = make();
+            var second = make();
+            first.exec("aaa");
+            first.exec("aaa");
+        "#}),
+        TestAction::assert("first !== second"),
+        TestAction::assert_eq("first.lastIndex", 2),
+        TestAction::assert_eq("second.lastIndex", 0),
+        TestAction::assert_eq("second.exec('aaa').index", 0),
+        TestAction::assert_eq("make().lastIndex", 0),
+        TestAction::inspect_context(|ctx| {
+            let matcher = |ctx: &mut Context<'_>, source: &str| {
+                let value = ctx.eval_script(Source::from_bytes(source)).unwrap();
+                let object = value.as_object().unwrap().borrow();
+                object.as_regexp().unwrap().matcher.clone()
+            };
+            let first = matcher(ctx, "first");
+            assert!(Rc::ptr_eq(&first, &matcher(ctx, "second")));
+            assert!(Rc::ptr_eq(&first, &matcher(ctx, "make()")));
+            assert!(!Rc::ptr_eq(&first, &matcher(ctx, "/a/g")));
+        }),
+        TestAction::assert_eq("new RegExp('a', 'g').exec('bab').index", 1),
+        TestAction::assert(indoc! {r#"
+            (() => {
+                try {
+                    new RegExp("(", "g");
+                } catch (e) {
+                    return e instanceof SyntaxError;
+                }
+            })()
+        "#}),
+    ]);
+}
+
+#[test]
+fn compile_and_cache_regexp() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let first = ctx.compile_and_cache_regexp("a+", "g").unwrap();
+        let second = ctx.compile_and_cache_regexp("a+", "g").unwrap();
+        let other = ctx.compile_and_cache_regexp("a+", "gi").unwrap();
+        let matcher = |regexp: &JsObject| {
+            let object = regexp.borrow();
+            object.as_regexp().unwrap().matcher.clone()
+        };
+        assert!(Rc::ptr_eq(&matcher(&first), &matcher(&second)));
+        assert!(!Rc::ptr_eq(&matcher(&first), &matcher(&other)));
+
+        first.exec(js_string!("baa"), ctx).unwrap();
+        assert_eq!(first.get("lastIndex", ctx).unwrap(), JsValue::new(3));
+        assert_eq!(second.get("lastIndex", ctx).unwrap(), JsValue::new(0));
+
+        let error = ctx.compile_and_cache_regexp("(", "g").unwrap_err();
+        assert!(error.as_native().is_some());
+        assert!(ctx.compile_and_cache_regexp("a", "gg").is_err());
+    })]);
+}
+
+#[test]
+fn literals_use_intrinsic_constructor() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var Intrinsic = RegExp;
+            var RegExp = null;
+            var re = /a/g;
+        "#}),
+        TestAction::assert("re instanceof Intrinsic"),
+        TestAction::assert("re.test('a')"),
+        TestAction::assert_eq("re.lastIndex", 1),
+    ]);
+}
@@ -142,7 +142,9 @@ impl IntrinsicObject for String {
             .method(Self::replace_all, "replaceAll", 2)
             .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
             .method(Self::search, "search", 1)
-            .method(Self::at, "at", 1);
+            .method(Self::at, "at", 1)
+            .method(Self::is_well_formed, "isWellFormed", 0)
+            .method(Self::to_well_formed, "toWellFormed", 0);
 
         #[cfg(feature = "annex-b")]
         {
@@ -210,7 +212,11 @@ impl BuiltInConstructor for String {
                 return Ok(sym.descriptive_string().into())
             }
             // b. Let s be ? ToString(value).
-            Some(value) => value.to_string(context)?,
+            // The format of numbers can be changed by the embedder.
+            Some(value) => match value.as_number() {
+                Some(number) => context.number_formatting_mode().format(number).into(),
+                None => value.to_string(context)?,
+            },
             // 1. If value is not present, let s be the empty String.
             None => js_string!(),
         };
@@ -562,6 +568,68 @@ impl String {
         Ok(js_string!(&s[k..=k]).into())
     }
 
+    /// `String.prototype.isWellFormed ( )`
+    ///
+    /// The `isWellFormed()` method returns a boolean indicating whether this string is free of
+    /// lone surrogates.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.iswellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/isWellFormed
+    pub(crate) fn is_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Return IsStringWellFormedUnicode(S).
+        Ok(string.is_well_formed().into())
+    }
+
+    /// `String.prototype.toWellFormed ( )`
+    ///
+    /// The `toWellFormed()` method returns a string where all lone surrogates of this string are
+    /// replaced with the Unicode replacement character U+FFFD.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.towellformed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/toWellFormed
+    pub(crate) fn to_well_formed(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible()?;
+
+        // 2. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Let strLen be the length of S.
+        // 4. Let k be 0.
+        // 5. Let result be the empty String.
+        // 6. Repeat, while k < strLen,
+        //     a. Let cp be CodePointAt(S, k).
+        //     b. If cp.[[IsUnpairedSurrogate]] is true, then
+        //         i. Set result to the string-concatenation of result and 0xFFFD (REPLACEMENT CHARACTER).
+        //     c. Else,
+        //         i. Set result to the string-concatenation of result and UTF16EncodeCodePoint(cp.[[CodePoint]]).
+        //     d. Set k to k + cp.[[CodeUnitCount]].
+        // 7. Return result.
+        Ok(string.to_well_formed().into())
+    }
+
     /// `String.prototype.codePointAt( index )`
     ///
     /// The `codePointAt()` method returns an integer between `0` to `1114111` (`0x10FFFF`) representing the UTF-16 code unit at the given index.
@@ -910,3 +910,26 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[test]
+fn well_formed() {
+    run_test_actions([
+        TestAction::assert("'abc'.isWellFormed()"),
+        TestAction::assert("'a\\uD83D\\uDE00b'.isWellFormed()"),
+        TestAction::assert("!'a\\uD83Db'.isWellFormed()"),
+        TestAction::assert("!'a\\uDE00b'.isWellFormed()"),
+        TestAction::assert("!'\\uDE00\\uD83D'.isWellFormed()"),
+        TestAction::assert_eq("'a\\uD83D\\uDE00b'.toWellFormed()", js_string!("a😀b")),
+        TestAction::assert_eq("'a\\uD83Db'.toWellFormed()", js_string!("a\u{FFFD}b")),
+        TestAction::assert_eq(
+            "'\\uDE00\\uD83D\\uDE00\\uD83D'.toWellFormed()",
+            js_string!("\u{FFFD}😀\u{FFFD}"),
+        ),
+        TestAction::assert_eq("String.prototype.toWellFormed.call(12)", js_string!("12")),
+        TestAction::assert_native_error(
+            "String.prototype.isWellFormed.call(null)",
+            ErrorKind::Type,
+            "cannot convert null or undefined to Object",
+        ),
+    ]);
+}
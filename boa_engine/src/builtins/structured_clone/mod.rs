@@ -0,0 +1,376 @@
+//! Boa's implementation of the `structuredClone()` global function.
+//!
+//! The `structuredClone()` function creates a deep clone of a value using the structured clone
+//! algorithm. Objects referenced more than once in the original value, including cyclic
+//! references, are also shared in the clone.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+#[cfg(test)]
+mod tests;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    builtins::{
+        array_buffer::ArrayBuffer, date::Date, map::ordered_map::OrderedMap,
+        set::ordered_set::OrderedSet, typed_array::integer_indexed_object::IntegerIndexed, Array,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    object::{JsObject, ObjectData},
+    property::{PropertyKey, PropertyNameKind},
+    realm::Realm,
+    Context, JsArgs, JsResult, JsValue,
+};
+
+use super::{BuiltInBuilder, BuiltInObject, IntrinsicObject};
+
+/// The `structuredClone` function
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl IntrinsicObject for StructuredClone {
+    fn init(realm: &Realm) {
+        BuiltInBuilder::callable_with_intrinsic::<Self>(realm, structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().structured_clone().into()
+    }
+}
+
+impl BuiltInObject for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+}
+
+/// Builtin JavaScript `structuredClone ( value )` function.
+fn structured_clone(_: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let mut memory = FxHashMap::default();
+    let mut pending = Vec::new();
+    let clone = clone_value(args.get_or_undefined(0), &mut memory, &mut pending, context)?;
+
+    // The contents of the cloned objects are cloned with an explicit stack instead of recursion,
+    // so deeply nested values can't overflow the native stack. The values are still visited
+    // depth first, which keeps the order in which getters are called.
+    while let Some(mut object) = pending.pop() {
+        let Some(value) = object.next_value(context)? else {
+            continue;
+        };
+        let index = pending.len();
+        pending.push(object);
+        let clone = clone_value(&value, &mut memory, &mut pending, context)?;
+        pending[index].store(clone, context)?;
+    }
+
+    Ok(clone)
+}
+
+/// The cloneable part of an object, extracted before creating its clone.
+enum Serialized {
+    Ordinary,
+    Array,
+    Primitive(JsValue),
+    Date(Date),
+    Map(Vec<(JsValue, JsValue)>),
+    Set(Vec<JsValue>),
+    ArrayBuffer(Vec<u8>),
+    TypedArray(IntegerIndexed),
+}
+
+/// A cloned object whose contents are not cloned yet.
+enum PendingObject {
+    /// An ordinary object or array, which gets the enumerable own string-keyed properties of
+    /// `source`.
+    Properties {
+        source: JsObject,
+        target: JsObject,
+        keys: std::vec::IntoIter<JsValue>,
+        key: Option<PropertyKey>,
+    },
+
+    /// A map, which gets the entries of the original map.
+    Map {
+        target: JsObject,
+        entries: std::vec::IntoIter<(JsValue, JsValue)>,
+        key: Option<JsValue>,
+        value: Option<JsValue>,
+    },
+
+    /// A set, which gets the values of the original set.
+    Set {
+        target: JsObject,
+        values: std::vec::IntoIter<JsValue>,
+    },
+}
+
+impl PendingObject {
+    /// Gets the next original value to clone, or `None` if all the contents were cloned.
+    fn next_value(&mut self, context: &mut Context<'_>) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Properties {
+                source, keys, key, ..
+            } => {
+                for next in keys.by_ref() {
+                    let next = next.to_property_key(context)?;
+
+                    // Getters of previous properties could have deleted this property.
+                    if !source.has_own_property(next.clone(), context)? {
+                        continue;
+                    }
+
+                    let value = source.get(next.clone(), context)?;
+                    *key = Some(next);
+                    return Ok(Some(value));
+                }
+                Ok(None)
+            }
+            Self::Map { entries, value, .. } => {
+                // The key of an entry is cloned first, then its value.
+                if let Some(value) = value.take() {
+                    return Ok(Some(value));
+                }
+                Ok(entries.next().map(|(next_key, next_value)| {
+                    *value = Some(next_value);
+                    next_key
+                }))
+            }
+            Self::Set { values, .. } => Ok(values.next()),
+        }
+    }
+
+    /// Stores the clone of the value returned by the last call to [`Self::next_value`].
+    fn store(&mut self, clone: JsValue, context: &mut Context<'_>) -> JsResult<()> {
+        match self {
+            Self::Properties { target, key, .. } => {
+                let key = key.take().expect("a property must be pending");
+                target.create_data_property_or_throw(key, clone, context)?;
+            }
+            Self::Map {
+                target, key, value, ..
+            } => {
+                if value.is_some() {
+                    *key = Some(clone);
+                } else {
+                    let key = key.take().expect("a map key must be pending");
+                    target
+                        .borrow_mut()
+                        .as_map_mut()
+                        .expect("clone must be a map")
+                        .insert(key, clone);
+                }
+            }
+            Self::Set { target, .. } => {
+                target
+                    .borrow_mut()
+                    .as_set_mut()
+                    .expect("clone must be a set")
+                    .add(clone);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Creates the `TypeError` thrown for values that cannot be cloned, mirroring the
+/// `DataCloneError` of the HTML specification.
+fn data_clone_error(what: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!("DataCloneError: {what} could not be cloned"))
+}
+
+/// Clones `value`, using `memory` to map every object that was already cloned to its clone.
+///
+/// The contents of a new clone of an object, array, map or set are not cloned here. Instead, the
+/// clone is pushed to `pending` to be filled later.
+fn clone_value(
+    value: &JsValue,
+    memory: &mut FxHashMap<JsObject, JsObject>,
+    pending: &mut Vec<PendingObject>,
+    context: &mut Context<'_>,
+) -> JsResult<JsValue> {
+    let object = match value {
+        JsValue::Object(object) => object,
+        JsValue::Symbol(_) => return Err(data_clone_error("Symbol").into()),
+        _ => return Ok(value.clone()),
+    };
+
+    if let Some(clone) = memory.get(object) {
+        return Ok(clone.clone().into());
+    }
+
+    // Extract everything needed from the original object before running any user code, since
+    // getters could mutate the object while it is being cloned.
+    let serialized = {
+        let obj = object.borrow();
+        if obj.is_ordinary() {
+            Serialized::Ordinary
+        } else if obj.is_array() {
+            Serialized::Array
+        } else if let Some(boolean) = obj.as_boolean() {
+            Serialized::Primitive(boolean.into())
+        } else if let Some(number) = obj.as_number() {
+            Serialized::Primitive(number.into())
+        } else if let Some(string) = obj.as_string() {
+            Serialized::Primitive(string.into())
+        } else if let Some(bigint) = obj.as_bigint() {
+            Serialized::Primitive(bigint.clone().into())
+        } else if let Some(date) = obj.as_date() {
+            Serialized::Date(*date)
+        } else if let Some(map) = obj.as_map() {
+            Serialized::Map(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            )
+        } else if let Some(set) = obj.as_set() {
+            Serialized::Set(set.iter().cloned().collect())
+        } else if let Some(buffer) = obj.as_array_buffer() {
+            let data = buffer
+                .array_buffer_data
+                .clone()
+                .ok_or_else(|| data_clone_error("detached ArrayBuffer"))?;
+            Serialized::ArrayBuffer(data)
+        } else if let Some(typed_array) = obj.as_typed_array() {
+            if typed_array.is_detached() {
+                return Err(data_clone_error("TypedArray with a detached buffer").into());
+            }
+            Serialized::TypedArray(typed_array.clone())
+        } else if object.is_callable() {
+            return Err(data_clone_error("function").into());
+        } else {
+            return Err(data_clone_error("object").into());
+        }
+    };
+
+    let clone = match serialized {
+        Serialized::Ordinary => {
+            let clone = JsObject::with_object_proto(context.intrinsics());
+            memory.insert(object.clone(), clone.clone());
+            pending.push(pending_properties(object, &clone, context)?);
+            clone
+        }
+        Serialized::Array => {
+            // The `length` of an array is an ordinary data property, so this can't run user code.
+            let length = object.length_of_array_like(context)?;
+            let clone = Array::array_create(length, None, context)?;
+            memory.insert(object.clone(), clone.clone());
+            pending.push(pending_properties(object, &clone, context)?);
+            clone
+        }
+        Serialized::Primitive(primitive) => {
+            let clone = primitive.to_object(context)?;
+            memory.insert(object.clone(), clone.clone());
+            clone
+        }
+        Serialized::Date(date) => {
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().date().prototype(),
+                ObjectData::date(date),
+            );
+            memory.insert(object.clone(), clone.clone());
+            clone
+        }
+        Serialized::Map(entries) => {
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().map().prototype(),
+                ObjectData::map(OrderedMap::new()),
+            );
+            memory.insert(object.clone(), clone.clone());
+            pending.push(PendingObject::Map {
+                target: clone.clone(),
+                entries: entries.into_iter(),
+                key: None,
+                value: None,
+            });
+            clone
+        }
+        Serialized::Set(values) => {
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context.intrinsics().constructors().set().prototype(),
+                ObjectData::set(OrderedSet::new()),
+            );
+            memory.insert(object.clone(), clone.clone());
+            pending.push(PendingObject::Set {
+                target: clone.clone(),
+                values: values.into_iter(),
+            });
+            clone
+        }
+        Serialized::ArrayBuffer(data) => {
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                context
+                    .intrinsics()
+                    .constructors()
+                    .array_buffer()
+                    .prototype(),
+                ObjectData::array_buffer(ArrayBuffer {
+                    array_buffer_byte_length: data.len() as u64,
+                    array_buffer_data: Some(data),
+                    array_buffer_detach_key: JsValue::undefined(),
+                }),
+            );
+            memory.insert(object.clone(), clone.clone());
+            clone
+        }
+        Serialized::TypedArray(typed_array) => {
+            // The viewed buffer is cloned through `memory`, so typed arrays sharing a buffer keep
+            // sharing the cloned buffer. Buffers have no contents to clone later, so this doesn't
+            // push anything to `pending`.
+            let buffer = typed_array
+                .viewed_array_buffer()
+                .expect("non-detached typed arrays must have a buffer")
+                .clone();
+            let buffer = clone_value(&buffer.into(), memory, pending, context)?
+                .as_object()
+                .expect("the clone of an ArrayBuffer must be an object")
+                .clone();
+
+            let kind = typed_array.typed_array_name();
+            let prototype =
+                kind.standard_constructor()(context.intrinsics().constructors()).prototype();
+            let clone = JsObject::from_proto_and_data_with_shared_shape(
+                context.root_shape(),
+                prototype,
+                ObjectData::integer_indexed(IntegerIndexed::new(
+                    Some(buffer),
+                    kind,
+                    typed_array.byte_offset(),
+                    typed_array.byte_length(),
+                    typed_array.array_length(),
+                )),
+            );
+            memory.insert(object.clone(), clone.clone());
+            clone
+        }
+    };
+
+    Ok(clone.into())
+}
+
+/// Creates the pending clone of the enumerable own string-keyed properties of `source` into
+/// `target`.
+fn pending_properties(
+    source: &JsObject,
+    target: &JsObject,
+    context: &mut Context<'_>,
+) -> JsResult<PendingObject> {
+    let keys = source.enumerable_own_property_names(PropertyNameKind::Key, context)?;
+    Ok(PendingObject::Properties {
+        source: source.clone(),
+        target: target.clone(),
+        keys: keys.into_iter(),
+        key: None,
+    })
+}
@@ -0,0 +1,181 @@
+use crate::{builtins::error::ErrorKind, run_test_actions, TestAction};
+use indoc::indoc;
+
+#[test]
+fn primitives() {
+    run_test_actions([
+        TestAction::assert_eq("structuredClone(1)", 1),
+        TestAction::assert_eq("structuredClone('a')", crate::js_string!("a")),
+        TestAction::assert("structuredClone(undefined) === undefined"),
+        TestAction::assert("structuredClone(null) === null"),
+        TestAction::assert("structuredClone(10n) === 10n"),
+        TestAction::assert_native_error(
+            "structuredClone(Symbol())",
+            ErrorKind::Type,
+            "DataCloneError: Symbol could not be cloned",
+        ),
+    ]);
+}
+
+#[test]
+fn objects_and_arrays() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            class Point { constructor() { this.x = 1; } }
+            const original = {
+                a: 1,
+                nested: { b: [1, , { c: "c" }] },
+                point: new Point(),
+                get computed() { return 42; },
+                [Symbol.iterator]: 1,
+            };
+            Object.defineProperty(original, "hidden", { value: 1 });
+            const clone = structuredClone(original);
+        "#}),
+        TestAction::assert("clone !== original && clone.nested !== original.nested"),
+        TestAction::assert("clone.nested.b !== original.nested.b"),
+        TestAction::assert("Array.isArray(clone.nested.b)"),
+        TestAction::assert_eq("clone.nested.b.length", 3),
+        TestAction::assert("!(1 in clone.nested.b)"),
+        TestAction::assert_eq("clone.nested.b[2].c", crate::js_string!("c")),
+        TestAction::assert("Object.getPrototypeOf(clone.point) === Object.prototype"),
+        TestAction::assert_eq("clone.point.x", 1),
+        TestAction::assert(indoc! {r#"
+            const desc = Object.getOwnPropertyDescriptor(clone, "computed");
+            desc.value === 42 && desc.writable
+        "#}),
+        TestAction::assert("!(Symbol.iterator in clone)"),
+        TestAction::assert("!('hidden' in clone)"),
+    ]);
+}
+
+#[test]
+fn shared_and_cyclic_references() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const shared = { value: 1 };
+            const original = { first: shared, second: shared, list: [shared] };
+            original.self = original;
+            original.list.push(original.list);
+
+            const clone = structuredClone(original);
+        "#}),
+        TestAction::assert("clone.first !== shared"),
+        TestAction::assert("clone.first === clone.second"),
+        TestAction::assert("clone.list[0] === clone.first"),
+        TestAction::assert("clone.self === clone"),
+        TestAction::assert("clone.list[1] === clone.list"),
+    ]);
+}
+
+#[test]
+fn builtin_objects() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const key = { k: 1 };
+            const map = new Map([[key, "value"], ["self", null]]);
+            map.set("self", map);
+            const set = new Set([key, 1]);
+            const date = new Date(1000);
+
+            const clone = structuredClone({ map, set, date, key });
+        "#}),
+        TestAction::assert("clone.map instanceof Map && clone.map !== map"),
+        TestAction::assert("clone.map.get(clone.key) === 'value'"),
+        TestAction::assert("clone.map.get('self') === clone.map"),
+        TestAction::assert("clone.set instanceof Set && clone.set.has(clone.key)"),
+        TestAction::assert("clone.set.has(1) && !clone.set.has(key)"),
+        TestAction::assert("clone.date instanceof Date && clone.date !== date"),
+        TestAction::assert_eq("clone.date.getTime()", 1000),
+        TestAction::assert("structuredClone(new Number(1)) instanceof Number"),
+        TestAction::assert_eq("structuredClone(new String('ab')).length", 2),
+    ]);
+}
+
+#[test]
+fn buffers() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const buffer = new ArrayBuffer(8);
+            const bytes = new Uint8Array(buffer);
+            const words = new Uint16Array(buffer, 2, 2);
+            bytes.set([1, 2, 3, 4, 5, 6, 7, 8]);
+
+            const clone = structuredClone({ buffer, bytes, words });
+        "#}),
+        TestAction::assert("clone.buffer !== buffer && clone.buffer.byteLength === 8"),
+        TestAction::assert("clone.bytes instanceof Uint8Array"),
+        TestAction::assert("clone.words instanceof Uint16Array"),
+        TestAction::assert("clone.bytes.buffer === clone.buffer"),
+        TestAction::assert("clone.words.buffer === clone.buffer"),
+        TestAction::assert_eq("clone.words.byteOffset", 2),
+        TestAction::assert_eq("clone.words.length", 2),
+        TestAction::assert_eq("clone.bytes.join()", crate::js_string!("1,2,3,4,5,6,7,8")),
+        TestAction::run("bytes[0] = 100"),
+        TestAction::assert_eq("clone.bytes[0]", 1),
+    ]);
+}
+
+#[test]
+fn non_cloneable() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "structuredClone({ f() {} })",
+            ErrorKind::Type,
+            "DataCloneError: function could not be cloned",
+        ),
+        TestAction::assert_native_error(
+            "structuredClone(new WeakMap())",
+            ErrorKind::Type,
+            "DataCloneError: object could not be cloned",
+        ),
+        TestAction::assert_native_error(
+            "structuredClone(new Proxy({}, {}))",
+            ErrorKind::Type,
+            "DataCloneError: object could not be cloned",
+        ),
+    ]);
+}
+
+#[test]
+fn deeply_nested() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            let value = null;
+            for (let i = 0; i < 2000; i++) {
+                value = i % 2 ? { i, value } : [i, value];
+            }
+
+            let clone = structuredClone(value);
+            let depth = 0;
+            while (clone !== null) {
+                const i = Array.isArray(clone) ? clone[0] : clone.i;
+                if (i !== 1999 - depth) {
+                    throw new Error(`invalid value ${i} at depth ${depth}`);
+                }
+                clone = Array.isArray(clone) ? clone[1] : clone.value;
+                depth++;
+            }
+        "#}),
+        TestAction::assert_eq("depth", 2000),
+    ]);
+}
+
+#[test]
+fn getters_are_called_depth_first() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            const log = [];
+            structuredClone({
+                get a() {
+                    log.push("a");
+                    return new Map([[{ get b() { log.push("b"); } }, { get c() { log.push("c"); } }]]);
+                },
+                get d() {
+                    log.push("d");
+                },
+            });
+        "#}),
+        TestAction::assert_eq("log.join()", crate::js_string!("a,b,c,d")),
+    ]);
+}
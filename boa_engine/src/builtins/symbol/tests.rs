@@ -1,4 +1,6 @@
-use crate::{run_test_actions, JsValue, TestAction};
+use crate::{
+    js_string, property::Attribute, run_test_actions, JsSymbol, JsValue, Source, TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -31,3 +33,36 @@ fn symbol_access() {
         TestAction::assert_eq("x['Symbol(Hello)']", JsValue::undefined()),
     ]);
 }
+
+#[test]
+fn symbol_description_and_well_known_symbols() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let symbol = context
+                .eval_script(Source::from_bytes("Symbol('Hello')"))
+                .unwrap();
+            let symbol = symbol.as_symbol().unwrap();
+            assert_eq!(symbol.description(), Some(js_string!("Hello")));
+            assert!(!symbol.is_well_known());
+
+            let symbol = JsSymbol::new(None).unwrap();
+            assert_eq!(symbol.description(), None);
+
+            let iterator = JsSymbol::iterator();
+            assert!(iterator.is_well_known());
+            assert_eq!(iterator.description(), Some(js_string!("Symbol.iterator")));
+            context
+                .register_global_property("rustIterator", iterator, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::assert("rustIterator === Symbol.iterator"),
+        TestAction::inspect_context(|_| {
+            let well_known: Vec<_> = JsSymbol::well_known().collect();
+            assert_eq!(well_known.len(), 13);
+            assert!(well_known.iter().all(JsSymbol::is_well_known));
+            assert!(well_known.contains(&JsSymbol::iterator()));
+            assert!(well_known.contains(&JsSymbol::unscopables()));
+            assert!(well_known.contains(&JsSymbol::async_iterator()));
+        }),
+    ]);
+}
@@ -3000,19 +3000,7 @@ impl TypedArray {
         context: &mut Context<'_>,
     ) -> JsResult<JsObject> {
         // 1. Let defaultConstructor be the intrinsic object listed in column one of Table 73 for exemplar.[[TypedArrayName]].
-        let default_constructor = match typed_array_name {
-            TypedArrayKind::Int8 => StandardConstructors::typed_int8_array,
-            TypedArrayKind::Uint8 => StandardConstructors::typed_uint8_array,
-            TypedArrayKind::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
-            TypedArrayKind::Int16 => StandardConstructors::typed_int16_array,
-            TypedArrayKind::Uint16 => StandardConstructors::typed_uint16_array,
-            TypedArrayKind::Int32 => StandardConstructors::typed_int32_array,
-            TypedArrayKind::Uint32 => StandardConstructors::typed_uint32_array,
-            TypedArrayKind::BigInt64 => StandardConstructors::typed_bigint64_array,
-            TypedArrayKind::BigUint64 => StandardConstructors::typed_biguint64_array,
-            TypedArrayKind::Float32 => StandardConstructors::typed_float32_array,
-            TypedArrayKind::Float64 => StandardConstructors::typed_float64_array,
-        };
+        let default_constructor = typed_array_name.standard_constructor();
 
         // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
         let constructor = exemplar.species_constructor(default_constructor, context)?;
@@ -3547,6 +3535,25 @@ impl TypedArrayKind {
     pub(crate) const fn is_big_int_element_type(self) -> bool {
         matches!(self, Self::BigUint64 | Self::BigInt64)
     }
+
+    /// Gets the accessor of the standard constructor of this typed array name.
+    pub(crate) const fn standard_constructor(
+        self,
+    ) -> fn(&StandardConstructors) -> &StandardConstructor {
+        match self {
+            Self::Int8 => StandardConstructors::typed_int8_array,
+            Self::Uint8 => StandardConstructors::typed_uint8_array,
+            Self::Uint8Clamped => StandardConstructors::typed_uint8clamped_array,
+            Self::Int16 => StandardConstructors::typed_int16_array,
+            Self::Uint16 => StandardConstructors::typed_uint16_array,
+            Self::Int32 => StandardConstructors::typed_int32_array,
+            Self::Uint32 => StandardConstructors::typed_uint32_array,
+            Self::BigInt64 => StandardConstructors::typed_bigint64_array,
+            Self::BigUint64 => StandardConstructors::typed_biguint64_array,
+            Self::Float32 => StandardConstructors::typed_float32_array,
+            Self::Float64 => StandardConstructors::typed_float64_array,
+        }
+    }
 }
 
 typed_array!(Int8Array, Int8, "Int8Array", typed_int8_array);
@@ -0,0 +1,382 @@
+use boa_gc::{Finalize, Gc, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    object::{
+        internal_methods::get_prototype_from_constructor, JsObject, ObjectData, VTableObject,
+    },
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    Context, JsArgs, JsNativeError, JsResult, JsValue,
+};
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` lets the user request a cleanup callback to be called after an object
+/// registered in the registry has been collected by the garbage collector. The callbacks are never
+/// called from within the garbage collector; instead, the context enqueues them as jobs the next
+/// time the job queue runs.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct FinalizationRegistry {
+    /// The `[[CleanupCallback]]` internal slot.
+    cleanup_callback: JsObject,
+
+    /// The `[[Cells]]` internal slot.
+    cells: Vec<Cell>,
+}
+
+/// A record of the `[[Cells]]` list of a `FinalizationRegistry`.
+#[derive(Trace, Finalize)]
+struct Cell {
+    /// The `[[WeakRefTarget]]` field.
+    target: WeakGc<VTableObject>,
+
+    /// The `[[HeldValue]]` field.
+    held_value: JsValue,
+
+    /// The `[[UnregisterToken]]` field.
+    unregister_token: Option<WeakGc<VTableObject>>,
+}
+
+impl std::fmt::Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cell")
+            .field("held_value", &self.held_value)
+            .field("has_unregister_token", &self.unregister_token.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(Self::NAME, "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, "register", 2)
+            .method(Self::unregister, "unregister", 1)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: &'static str = "FinalizationRegistry";
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    const LENGTH: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args
+            .get_or_undefined(0)
+            .as_callable()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("FinalizationRegistry: cleanup callback must be callable")
+            })?
+            .clone();
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            get_prototype_from_constructor(
+                new_target,
+                StandardConstructors::finalization_registry,
+                context,
+            )?,
+            ObjectData::finalization_registry(Self {
+                cleanup_callback,
+                cells: Vec::new(),
+            }),
+        );
+
+        context
+            .finalization_registries
+            .borrow_mut()
+            .push(WeakGc::new(registry.inner()));
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Gets the `FinalizationRegistry` data of `this`, throwing a `TypeError` if `this` is not a
+    /// `FinalizationRegistry` object.
+    fn this_registry(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.borrow().as_finalization_registry().is_some())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "FinalizationRegistry.prototype.{method}: expected `this` to be a `FinalizationRegistry` object"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` in the registry, calling the cleanup callback with `heldValue` after
+    /// `target` is collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "register")?;
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let target = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("FinalizationRegistry.prototype.register: target must be an object")
+        })?;
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        let held_value = args.get_or_undefined(1);
+        if held_value
+            .as_object()
+            .map_or(false, |held| JsObject::equals(held, target))
+        {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value must differ",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = match args.get_or_undefined(2) {
+            JsValue::Object(token) => Some(WeakGc::new(token.inner())),
+            // b. Set unregisterToken to empty.
+            JsValue::Undefined => None,
+            // a. If unregisterToken is not undefined, throw a TypeError exception.
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(
+                        "FinalizationRegistry.prototype.register: unregister token must be an object or undefined",
+                    )
+                    .into())
+            }
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .borrow_mut()
+            .as_finalization_registry_mut()
+            .expect("checked above")
+            .cells
+            .push(Cell {
+                target: WeakGc::new(target.inner()),
+                held_value: held_value.clone(),
+                unregister_token,
+            });
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all the cells registered with `unregisterToken`, returning `true` if any cell was
+    /// removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _: &mut Context<'_>,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "unregister")?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let token = args.get_or_undefined(0).as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "FinalizationRegistry.prototype.unregister: unregister token must be an object",
+            )
+        })?;
+
+        // 4. Let removed be false.
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+        //         i. Remove cell from finalizationRegistry.[[Cells]].
+        //         ii. Set removed to true.
+        let mut registry = registry.borrow_mut();
+        let cells = &mut registry
+            .as_finalization_registry_mut()
+            .expect("checked above")
+            .cells;
+        let len = cells.len();
+        cells.retain(|cell| {
+            !cell
+                .unregister_token
+                .as_ref()
+                .and_then(WeakGc::upgrade)
+                .map_or(false, |cell_token| Gc::ptr_eq(&cell_token, token.inner()))
+        });
+
+        // 6. Return removed.
+        Ok((cells.len() != len).into())
+    }
+
+    /// Removes the cells whose targets were collected, returning the cleanup callback and the
+    /// held values of the removed cells.
+    pub(crate) fn take_collected_cells(&mut self) -> (JsObject, Vec<JsValue>) {
+        let mut held_values = Vec::new();
+        self.cells.retain_mut(|cell| {
+            if cell.target.upgrade().is_some() {
+                return true;
+            }
+            held_values.push(std::mem::take(&mut cell.held_value));
+            false
+        });
+        (self.cleanup_callback.clone(), held_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indoc::indoc;
+
+    use crate::{builtins::error::ErrorKind, js_string, run_test_actions, JsValue, TestAction};
+
+    #[test]
+    fn cleanup_after_collection() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var cleaned = [];
+                var registry = new FinalizationRegistry(held => cleaned.push(held));
+                var kept = {};
+                registry.register(kept, "kept");
+                {
+                    let obj = {};
+                    registry.register(obj, "collected");
+                }
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+            }),
+            // The callbacks only run as jobs, never during the collection.
+            TestAction::assert_eq("cleaned.length", 0),
+            TestAction::inspect_context(|context| context.run_jobs()),
+            TestAction::assert_eq("cleaned.join()", js_string!("collected")),
+            TestAction::inspect_context(|context| {
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("cleaned.length", 1),
+        ]);
+    }
+
+    #[test]
+    fn unregister() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var cleaned = [];
+                var registry = new FinalizationRegistry(held => cleaned.push(held));
+                var token = {};
+                {
+                    let obj = {};
+                    registry.register(obj, "first", token);
+                    registry.register({}, "second", token);
+                }
+                var removed = registry.unregister(token);
+            "#}),
+            TestAction::assert("removed"),
+            TestAction::assert("!registry.unregister(token)"),
+            TestAction::assert("!registry.unregister({})"),
+            TestAction::inspect_context(|context| {
+                context.clear_kept_objects();
+                boa_gc::force_collect();
+                context.run_jobs();
+            }),
+            TestAction::assert_eq("cleaned.length", 0),
+        ]);
+    }
+
+    #[test]
+    fn errors() {
+        run_test_actions([
+            TestAction::assert_native_error(
+                "FinalizationRegistry(() => {})",
+                ErrorKind::Type,
+                "FinalizationRegistry: cannot call constructor without `new`",
+            ),
+            TestAction::assert_native_error(
+                "new FinalizationRegistry(1)",
+                ErrorKind::Type,
+                "FinalizationRegistry: cleanup callback must be callable",
+            ),
+            TestAction::run("var registry = new FinalizationRegistry(() => {})"),
+            TestAction::assert_native_error(
+                "registry.register(1, 2)",
+                ErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target must be an object",
+            ),
+            TestAction::assert_native_error(
+                "var o = {}; registry.register(o, o)",
+                ErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target and held value must differ",
+            ),
+            TestAction::assert_native_error(
+                "registry.register({}, 1, 1)",
+                ErrorKind::Type,
+                "FinalizationRegistry.prototype.register: unregister token must be an object or undefined",
+            ),
+            TestAction::assert_eq("registry.register({}, 1)", JsValue::undefined()),
+            TestAction::assert_eq(
+                "Object.prototype.toString.call(registry)",
+                js_string!("[object FinalizationRegistry]"),
+            ),
+        ]);
+    }
+}
@@ -145,7 +145,7 @@ impl WeakRef {
 mod tests {
     use indoc::indoc;
 
-    use crate::{run_test_actions, JsValue, TestAction};
+    use crate::{run_test_actions, JsValue, NativeFunction, TestAction};
 
     #[test]
     fn weak_ref_collected() {
@@ -168,4 +168,33 @@ mod tests {
             TestAction::assert_eq("ptr.deref()", JsValue::undefined()),
         ]);
     }
+
+    #[test]
+    fn weak_ref_kept_alive_for_current_turn() {
+        run_test_actions([
+            TestAction::inspect_context(|context| {
+                context
+                    .register_global_callable(
+                        "collect",
+                        0,
+                        NativeFunction::from_fn_ptr(|_, _, _| {
+                            boa_gc::force_collect();
+                            Ok(JsValue::undefined())
+                        }),
+                    )
+                    .unwrap();
+            }),
+            TestAction::assert(indoc! {r#"
+                var ptr;
+                {
+                    let obj = {a: 5, b: 6};
+                    ptr = new WeakRef(obj);
+                }
+                collect();
+                eval("collect()");
+                ptr.deref().a === 5
+            "#}),
+            TestAction::assert_eq("collect(); ptr.deref()", JsValue::undefined()),
+        ]);
+    }
 }
@@ -26,6 +26,8 @@ impl ByteCompiler<'_, '_> {
             self.current_environment.clone(),
             self.context,
         );
+        compiler.source = self.source.clone();
+        compiler.source_name = self.source_name.clone();
 
         if let Some(class_name) = class.name() {
             if class.has_binding_identifier() {
@@ -107,7 +109,8 @@ impl ByteCompiler<'_, '_> {
         compiler.emit_opcode(Opcode::PushUndefined);
         compiler.emit_opcode(Opcode::Return);
 
-        let code = Gc::new(compiler.finish());
+        let code = compiler.finish().map(Gc::new);
+        let code = self.nested_code_block(code);
         let index = self.functions.len() as u32;
         self.functions.push(code);
         self.emit(Opcode::GetFunction, &[index]);
@@ -273,6 +276,8 @@ impl ByteCompiler<'_, '_> {
                         self.current_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source = self.source.clone();
+                    field_compiler.source_name = self.source_name.clone();
                     field_compiler.push_compile_environment(false);
                     field_compiler.create_immutable_binding(class_name.into(), true);
                     field_compiler.push_compile_environment(true);
@@ -286,9 +291,11 @@ impl ByteCompiler<'_, '_> {
                     field_compiler.num_bindings = env_info.num_bindings;
                     field_compiler.emit_opcode(Opcode::Return);
 
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
+                    let code = field_compiler.finish().map(|mut code| {
+                        code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
+                        Gc::new(code)
+                    });
+                    let code = self.nested_code_block(code);
                     let index = self.functions.len() as u32;
                     self.functions.push(code);
                     self.emit(Opcode::GetFunction, &[index]);
@@ -305,6 +312,8 @@ impl ByteCompiler<'_, '_> {
                         self.current_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source = self.source.clone();
+                    field_compiler.source_name = self.source_name.clone();
                     field_compiler.push_compile_environment(false);
                     field_compiler.create_immutable_binding(class_name.into(), true);
                     field_compiler.push_compile_environment(true);
@@ -318,9 +327,11 @@ impl ByteCompiler<'_, '_> {
                     field_compiler.num_bindings = env_info.num_bindings;
                     field_compiler.emit_opcode(Opcode::Return);
 
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
+                    let code = field_compiler.finish().map(|mut code| {
+                        code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
+                        Gc::new(code)
+                    });
+                    let code = self.nested_code_block(code);
                     let index = self.functions.len() as u32;
                     self.functions.push(code);
                     self.emit(Opcode::GetFunction, &[index]);
@@ -347,6 +358,8 @@ impl ByteCompiler<'_, '_> {
                         self.current_environment.clone(),
                         self.context,
                     );
+                    field_compiler.source = self.source.clone();
+                    field_compiler.source_name = self.source_name.clone();
                     field_compiler.push_compile_environment(false);
                     field_compiler.create_immutable_binding(class_name.into(), true);
                     field_compiler.push_compile_environment(true);
@@ -360,9 +373,11 @@ impl ByteCompiler<'_, '_> {
                     field_compiler.num_bindings = env_info.num_bindings;
                     field_compiler.emit_opcode(Opcode::Return);
 
-                    let mut code = field_compiler.finish();
-                    code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
-                    let code = Gc::new(code);
+                    let code = field_compiler.finish().map(|mut code| {
+                        code.class_field_initializer_name = Some(Sym::EMPTY_STRING);
+                        Gc::new(code)
+                    });
+                    let code = self.nested_code_block(code);
                     let index = self.functions.len() as u32;
                     self.functions.push(code);
                     self.emit(Opcode::GetFunction, &[index]);
@@ -394,6 +409,8 @@ impl ByteCompiler<'_, '_> {
                         self.current_environment.clone(),
                         self.context,
                     );
+                    compiler.source = self.source.clone();
+                    compiler.source_name = self.source_name.clone();
                     compiler.push_compile_environment(false);
                     compiler.create_immutable_binding(class_name.into(), true);
                     compiler.push_compile_environment(true);
@@ -403,7 +420,8 @@ impl ByteCompiler<'_, '_> {
                     compiler.pop_compile_environment();
                     compiler.num_bindings = env_info.num_bindings;
 
-                    let code = Gc::new(compiler.finish());
+                    let code = compiler.finish().map(Gc::new);
+                    let code = self.nested_code_block(code);
                     let index = self.functions.len() as u32;
                     self.functions.push(code);
                     self.emit(Opcode::GetFunction, &[index]);
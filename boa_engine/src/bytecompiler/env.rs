@@ -107,6 +107,13 @@ impl ByteCompiler<'_, '_> {
         }
     }
 
+    /// Create the lexical binding of a class declaration at bytecode compile time.
+    pub(crate) fn create_class_binding(&mut self, name: Identifier) {
+        self.current_environment
+            .borrow_mut()
+            .create_class_binding(name);
+    }
+
     /// Initialize a mutable binding at bytecode compile time and return it's binding locator.
     pub(crate) fn initialize_mutable_binding(
         &self,
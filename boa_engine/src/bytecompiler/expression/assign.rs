@@ -186,7 +186,7 @@ impl ByteCompiler<'_, '_> {
             }
 
             if let Some(early_exit) = early_exit {
-                if pop_count == 0 {
+                if pop_count == 0 && use_expr {
                     self.patch_jump(early_exit);
                 } else {
                     let exit = self.emit_opcode_with_operand(Opcode::Jump);
@@ -195,6 +195,9 @@ impl ByteCompiler<'_, '_> {
                         self.emit_opcode(Opcode::Swap);
                         self.emit_opcode(Opcode::Pop);
                     }
+                    if !use_expr {
+                        self.emit_opcode(Opcode::Pop);
+                    }
                     self.patch_jump(exit);
                 }
             }
@@ -1,39 +1,44 @@
-use boa_ast::expression::operator::{
-    binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
-    Binary, BinaryInPrivate,
+use boa_ast::{
+    expression::{
+        literal::Literal as AstLiteral,
+        operator::{
+            binary::{ArithmeticOp, BinaryOp, BitwiseOp, LogicalOp, RelationalOp},
+            Binary, BinaryInPrivate,
+        },
+    },
+    Expression,
 };
 
-use crate::{bytecompiler::ByteCompiler, vm::Opcode};
+use crate::{
+    bytecompiler::{ByteCompiler, Literal},
+    optimizer::OptimizerOptions,
+    vm::Opcode,
+    JsString, JsValue,
+};
 
 impl ByteCompiler<'_, '_> {
     pub(crate) fn compile_binary(&mut self, binary: &Binary, use_expr: bool) {
-        self.compile_expr(binary.lhs(), true);
-        match binary.op() {
-            BinaryOp::Arithmetic(op) => {
-                self.compile_expr(binary.rhs(), true);
-                match op {
-                    ArithmeticOp::Add => self.emit_opcode(Opcode::Add),
-                    ArithmeticOp::Sub => self.emit_opcode(Opcode::Sub),
-                    ArithmeticOp::Div => self.emit_opcode(Opcode::Div),
-                    ArithmeticOp::Mul => self.emit_opcode(Opcode::Mul),
-                    ArithmeticOp::Exp => self.emit_opcode(Opcode::Pow),
-                    ArithmeticOp::Mod => self.emit_opcode(Opcode::Mod),
-                }
+        if matches!(binary.op(), BinaryOp::Arithmetic(_) | BinaryOp::Bitwise(_))
+            && self
+                .context
+                .optimizer_options()
+                .contains(OptimizerOptions::CONSTANT_FOLDING)
+        {
+            if let Some(value) = self.compile_foldable_binary(binary) {
+                self.emit_push_folded(value);
+            }
 
-                if !use_expr {
-                    self.emit(Opcode::Pop, &[]);
-                }
+            if !use_expr {
+                self.emit(Opcode::Pop, &[]);
             }
-            BinaryOp::Bitwise(op) => {
+            return;
+        }
+
+        self.compile_expr(binary.lhs(), true);
+        match binary.op() {
+            op @ (BinaryOp::Arithmetic(_) | BinaryOp::Bitwise(_)) => {
                 self.compile_expr(binary.rhs(), true);
-                match op {
-                    BitwiseOp::And => self.emit_opcode(Opcode::BitAnd),
-                    BitwiseOp::Or => self.emit_opcode(Opcode::BitOr),
-                    BitwiseOp::Xor => self.emit_opcode(Opcode::BitXor),
-                    BitwiseOp::Shl => self.emit_opcode(Opcode::ShiftLeft),
-                    BitwiseOp::Shr => self.emit_opcode(Opcode::ShiftRight),
-                    BitwiseOp::UShr => self.emit_opcode(Opcode::UnsignedShiftRight),
-                }
+                self.emit_opcode(foldable_opcode(op));
 
                 if !use_expr {
                     self.emit(Opcode::Pop, &[]);
@@ -103,4 +108,120 @@ impl ByteCompiler<'_, '_> {
             self.emit_opcode(Opcode::Pop);
         }
     }
+
+    /// Compiles an arithmetic or bitwise binary expression, or evaluates it at compile time if all
+    /// its operands are number or string literals.
+    ///
+    /// Operands are folded bottom-up while they are compiled, so every node is visited only once.
+    /// Returns the value of the expression without emitting any code if it could be folded.
+    fn compile_foldable_binary(&mut self, binary: &Binary) -> Option<JsValue> {
+        let Some(lhs) = self.compile_foldable_operand(binary.lhs()) else {
+            self.compile_expr(binary.rhs(), true);
+            self.emit_opcode(foldable_opcode(binary.op()));
+            return None;
+        };
+
+        // The left operand has to be pushed before the code of the right one, so push it now and
+        // drop the push again if the right operand turns out to be constant too.
+        let start = self.next_opcode_location() as usize;
+        self.emit_push_folded(lhs.clone());
+        let Some(rhs) = self.compile_foldable_operand(binary.rhs()) else {
+            self.emit_opcode(foldable_opcode(binary.op()));
+            return None;
+        };
+        self.bytecode.truncate(start);
+
+        let context = &mut *self.context;
+
+        // Operations on numbers and strings never call into user code, so evaluating them here
+        // has the exact same result as evaluating them at runtime.
+        let result = match binary.op() {
+            BinaryOp::Arithmetic(op) => match op {
+                ArithmeticOp::Add => lhs.add(&rhs, context),
+                ArithmeticOp::Sub => lhs.sub(&rhs, context),
+                ArithmeticOp::Div => lhs.div(&rhs, context),
+                ArithmeticOp::Mul => lhs.mul(&rhs, context),
+                ArithmeticOp::Exp => lhs.pow(&rhs, context),
+                ArithmeticOp::Mod => lhs.rem(&rhs, context),
+            },
+            BinaryOp::Bitwise(op) => match op {
+                BitwiseOp::And => lhs.bitand(&rhs, context),
+                BitwiseOp::Or => lhs.bitor(&rhs, context),
+                BitwiseOp::Xor => lhs.bitxor(&rhs, context),
+                BitwiseOp::Shl => lhs.shl(&rhs, context),
+                BitwiseOp::Shr => lhs.shr(&rhs, context),
+                BitwiseOp::UShr => lhs.ushr(&rhs, context),
+            },
+            _ => unreachable!("only arithmetic and bitwise expressions can be folded"),
+        };
+
+        // Leave operations that would throw to the runtime.
+        if result.is_err() {
+            self.emit_push_folded(lhs);
+            self.emit_push_folded(rhs);
+            self.emit_opcode(foldable_opcode(binary.op()));
+            return None;
+        }
+        result.ok()
+    }
+
+    /// Compiles an operand of a foldable binary expression, returning its value instead if it is a
+    /// compile time constant.
+    fn compile_foldable_operand(&mut self, expr: &Expression) -> Option<JsValue> {
+        match expr {
+            Expression::Literal(AstLiteral::String(v)) => Some(
+                self.interner()
+                    .resolve_expect(*v)
+                    .into_common::<JsString>(false)
+                    .into(),
+            ),
+            Expression::Literal(AstLiteral::Int(v)) => Some((*v).into()),
+            Expression::Literal(AstLiteral::Num(v)) => Some((*v).into()),
+            Expression::Parenthesized(parenthesized) => {
+                self.compile_foldable_operand(parenthesized.expression())
+            }
+            Expression::Binary(binary)
+                if matches!(binary.op(), BinaryOp::Arithmetic(_) | BinaryOp::Bitwise(_)) =>
+            {
+                self.compile_foldable_binary(binary)
+            }
+            _ => {
+                self.compile_expr(expr, true);
+                None
+            }
+        }
+    }
+
+    /// Pushes a value computed by constant folding.
+    fn emit_push_folded(&mut self, value: JsValue) {
+        match value {
+            JsValue::String(string) => self.emit_push_literal(Literal::String(string)),
+            JsValue::Integer(integer) => self.emit_push_integer(integer),
+            JsValue::Rational(rational) => self.emit_push_rational(rational),
+            _ => unreachable!("folded values must be numbers or strings"),
+        }
+    }
+}
+
+/// Gets the opcode of an arithmetic or bitwise operator.
+const fn foldable_opcode(op: BinaryOp) -> Opcode {
+    match op {
+        BinaryOp::Arithmetic(op) => match op {
+            ArithmeticOp::Add => Opcode::Add,
+            ArithmeticOp::Sub => Opcode::Sub,
+            ArithmeticOp::Div => Opcode::Div,
+            ArithmeticOp::Mul => Opcode::Mul,
+            ArithmeticOp::Exp => Opcode::Pow,
+            ArithmeticOp::Mod => Opcode::Mod,
+        },
+        BinaryOp::Bitwise(op) => match op {
+            BitwiseOp::And => Opcode::BitAnd,
+            BitwiseOp::Or => Opcode::BitOr,
+            BitwiseOp::Xor => Opcode::BitXor,
+            BitwiseOp::Shl => Opcode::ShiftLeft,
+            BitwiseOp::Shr => Opcode::ShiftRight,
+            BitwiseOp::UShr => Opcode::UnsignedShiftRight,
+        },
+        _ => panic!("not an arithmetic or bitwise operator"),
+    }
 }
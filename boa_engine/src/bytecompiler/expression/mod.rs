@@ -6,7 +6,9 @@ mod update;
 
 use super::{Access, Callable, Label, NodeKind};
 use crate::{
+    builtins::regexp::RegExpSite,
     bytecompiler::{ByteCompiler, Literal},
+    js_string,
     vm::Opcode,
 };
 use boa_ast::{
@@ -90,6 +92,23 @@ impl ByteCompiler<'_, '_> {
             Expression::ObjectLiteral(object) => {
                 self.compile_object_literal(object, use_expr);
             }
+            Expression::RegExpLiteral(regexp) => {
+                let pattern = self
+                    .interner()
+                    .resolve_expect(regexp.pattern())
+                    .into_common(false);
+                let flags = self
+                    .interner()
+                    .resolve_expect(regexp.flags())
+                    .into_common(false);
+                let index = self.regexps.len() as u32;
+                self.regexps.push(RegExpSite::new(pattern, flags));
+                self.emit(Opcode::PushRegExp, &[index]);
+
+                if !use_expr {
+                    self.emit_opcode(Opcode::Pop);
+                }
+            }
             Expression::Identifier(name) => {
                 self.access_get(Access::Variable { name: *name }, use_expr);
             }
@@ -99,7 +118,6 @@ impl ByteCompiler<'_, '_> {
             Expression::Conditional(op) => self.compile_conditional(op, use_expr),
             Expression::ArrayLiteral(array) => {
                 self.emit_opcode(Opcode::PushNewArray);
-                self.emit_opcode(Opcode::PopOnReturnAdd);
 
                 for element in array.as_ref() {
                     if let Some(element) = element {
@@ -115,7 +133,6 @@ impl ByteCompiler<'_, '_> {
                     }
                 }
 
-                self.emit_opcode(Opcode::PopOnReturnSub);
                 if !use_expr {
                     self.emit(Opcode::Pop, &[]);
                 }
@@ -323,11 +340,35 @@ impl ByteCompiler<'_, '_> {
                     self.emit_opcode(Opcode::Pop);
                 }
             }
+            Expression::ImportCall(import_call) => {
+                self.compile_expr(import_call.argument(), true);
+                if let Some(options) = import_call.options() {
+                    self.compile_expr(options, true);
+                } else {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
+                self.emit_opcode(Opcode::ImportCall);
+
+                if !use_expr {
+                    self.emit_opcode(Opcode::Pop);
+                }
+            }
             Expression::NewTarget => {
                 if use_expr {
                     self.emit_opcode(Opcode::PushNewTarget);
                 }
             }
+            Expression::ImportMeta => {
+                // TODO: Remove after implementing modules.
+                let msg = self.get_or_insert_literal(Literal::String(js_string!(
+                    "import.meta is unimplemented"
+                )));
+                self.emit(Opcode::ThrowNewTypeError, &[msg]);
+
+                if use_expr {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
+            }
             Expression::Optional(opt) => {
                 self.compile_optional_preserve_this(opt);
 
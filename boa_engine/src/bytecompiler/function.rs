@@ -1,18 +1,19 @@
 use crate::{
     builtins::function::ThisMode,
-    bytecompiler::ByteCompiler,
+    bytecompiler::{ByteCompiler, SourceText},
     environments::CompileTimeEnvironment,
-    vm::{BindingOpcode, CodeBlock, Opcode},
-    Context,
+    vm::{BindingOpcode, CodeBlock, CodeKind, Opcode},
+    Context, JsResult, JsString,
 };
 use boa_ast::{
     declaration::Binding, function::FormalParameterList, operations::bound_names, StatementList,
 };
-use boa_gc::{Gc, GcRefCell};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_interner::Sym;
+use std::{ptr::NonNull, rc::Rc};
 
 /// `FunctionCompiler` is used to compile AST functions to bytecode.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 #[allow(clippy::struct_excessive_bools)]
 pub(crate) struct FunctionCompiler {
     name: Sym,
@@ -22,6 +23,11 @@ pub(crate) struct FunctionCompiler {
     arrow: bool,
     binding_identifier: Option<Sym>,
     class_name: Option<Sym>,
+    source: Option<Rc<SourceText>>,
+    source_text: Option<JsString>,
+    source_name: Option<JsString>,
+    lazy_script: Option<Rc<StatementList>>,
+    completion_value: bool,
 }
 
 impl FunctionCompiler {
@@ -35,6 +41,11 @@ impl FunctionCompiler {
             arrow: false,
             binding_identifier: None,
             class_name: None,
+            source: None,
+            source_text: None,
+            source_name: None,
+            lazy_script: None,
+            completion_value: false,
         }
     }
 
@@ -85,6 +96,71 @@ impl FunctionCompiler {
         self
     }
 
+    /// Set the source code containing the function, used to retain the source text of inner
+    /// functions.
+    pub(crate) fn source(mut self, source: Option<Rc<SourceText>>) -> Self {
+        self.source = source;
+        self
+    }
+
+    /// Set the source text of the function, returned by `Function.prototype.toString`.
+    pub(crate) fn source_text(mut self, source_text: Option<JsString>) -> Self {
+        self.source_text = source_text;
+        self
+    }
+
+    /// Set the name of the source code containing the function, reported in stack traces.
+    pub(crate) fn source_name(mut self, source_name: Option<JsString>) -> Self {
+        self.source_name = source_name;
+        self
+    }
+
+    /// Set the script containing the function if the compilation of its inner functions should
+    /// be deferred until they are first called.
+    pub(crate) fn lazy_script(mut self, lazy_script: Option<Rc<StatementList>>) -> Self {
+        self.lazy_script = lazy_script;
+        self
+    }
+
+    /// Indicate if the function should return the completion value of its body, like a script,
+    /// instead of `undefined` when it doesn't run a `return` statement.
+    pub(crate) const fn completion_value(mut self, completion_value: bool) -> Self {
+        self.completion_value = completion_value;
+        self
+    }
+
+    /// Create a code block for the function that defers the compilation of its statement list and
+    /// parameters until it is first called.
+    ///
+    /// The returned code block only has the attributes required to create function objects. The
+    /// compiled code block is returned by [`CodeBlock::compiled`].
+    pub(crate) fn compile_lazy(
+        mut self,
+        ast: FunctionAst,
+        outer_env: Gc<GcRefCell<CompileTimeEnvironment>>,
+    ) -> Gc<CodeBlock> {
+        let parameters = ast.parameters();
+        self.strict = self.strict || ast.body().strict();
+
+        let mut code = CodeBlock::new(self.name, parameters.length(), self.strict);
+        code.has_binding_identifier = self.binding_identifier.is_some();
+        if self.arrow {
+            code.this_mode = ThisMode::Lexical;
+        }
+        code.kind = self.kind();
+        code.params = parameters.clone();
+        code.source_text = self.source_text.clone();
+        code.source_name = self.source_name.clone();
+        code.lazy = Some(Gc::new(LazyFunction {
+            compiler: self,
+            ast,
+            outer_env,
+            compiled: GcRefCell::new(None),
+        }));
+
+        Gc::new(code)
+    }
+
     /// Compile a function statement list and it's parameters into bytecode.
     pub(crate) fn compile(
         mut self,
@@ -92,14 +168,20 @@ impl FunctionCompiler {
         body: &StatementList,
         outer_env: Gc<GcRefCell<CompileTimeEnvironment>>,
         context: &mut Context<'_>,
-    ) -> Gc<CodeBlock> {
+    ) -> JsResult<Gc<CodeBlock>> {
         self.strict = self.strict || body.strict();
 
         let length = parameters.length();
+        let kind = self.kind();
 
         let mut compiler = ByteCompiler::new(self.name, self.strict, false, outer_env, context);
         compiler.length = length;
+        compiler.source = self.source;
+        compiler.source_text = self.source_text;
+        compiler.source_name = self.source_name;
         compiler.in_async_generator = self.generator && self.r#async;
+        compiler.lazy_script = self.lazy_script;
+        compiler.tail_calls = !self.generator && !self.r#async;
 
         if self.arrow {
             compiler.this_mode = ThisMode::Lexical;
@@ -190,7 +272,7 @@ impl FunctionCompiler {
         }
 
         compiler.create_script_decls(body, false);
-        compiler.compile_statement_list(body, false, false);
+        compiler.compile_statement_list(body, self.completion_value, false);
 
         if let Some(env_label) = env_label {
             let env_info = compiler.pop_compile_environment();
@@ -215,10 +297,135 @@ impl FunctionCompiler {
 
         compiler.params = parameters.clone();
 
-        // TODO These are redundant if a function returns so may need to check if a function returns and adding these if it doesn't
-        compiler.emit(Opcode::PushUndefined, &[]);
-        compiler.emit(Opcode::Return, &[]);
+        // A function that returns its completion value runs until the end of its code, where the
+        // completion value of its frame becomes its result.
+        if !self.completion_value {
+            // TODO These are redundant if a function returns so may need to check if a function returns and adding these if it doesn't
+            compiler.emit(Opcode::PushUndefined, &[]);
+            compiler.emit(Opcode::Return, &[]);
+        }
+
+        let mut code = compiler.finish()?;
+        code.kind = kind;
+        Ok(Gc::new(code))
+    }
+
+    /// Gets the kind of code of the compiled function.
+    const fn kind(&self) -> CodeKind {
+        match (self.generator, self.r#async) {
+            (false, false) => CodeKind::Function,
+            (false, true) => CodeKind::Async,
+            (true, false) => CodeKind::Generator,
+            (true, true) => CodeKind::AsyncGenerator,
+        }
+    }
+}
+
+/// The parameters and body of a function in the AST of a script.
+///
+/// The nodes are borrowed from the script instead of copied, so the functions of a script that
+/// are compiled lazily share its AST, which is kept alive until all of them are dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct FunctionAst {
+    script: Rc<StatementList>,
+    parameters: NonNull<FormalParameterList>,
+    body: NonNull<StatementList>,
+}
+
+impl FunctionAst {
+    /// Creates a handle to the `parameters` and `body` of a function in `script`.
+    ///
+    /// # Safety
+    ///
+    /// `parameters` and `body` must be nodes of the AST owned by `script`.
+    pub(crate) unsafe fn new(
+        script: Rc<StatementList>,
+        parameters: &FormalParameterList,
+        body: &StatementList,
+    ) -> Self {
+        Self {
+            script,
+            parameters: NonNull::from(parameters),
+            body: NonNull::from(body),
+        }
+    }
+
+    /// Gets the script containing the function.
+    pub(crate) fn script(&self) -> Rc<StatementList> {
+        self.script.clone()
+    }
+
+    /// Gets the formal parameters of the function.
+    pub(crate) fn parameters(&self) -> &FormalParameterList {
+        // SAFETY: The parameters are owned by the AST of `script`, which is never mutated behind
+        // the `Rc` and is kept alive by `self`.
+        unsafe { self.parameters.as_ref() }
+    }
+
+    /// Gets the body of the function.
+    pub(crate) fn body(&self) -> &StatementList {
+        // SAFETY: The body is owned by the AST of `script`, which is never mutated behind the
+        // `Rc` and is kept alive by `self`.
+        unsafe { self.body.as_ref() }
+    }
+}
+
+/// A function whose compilation was deferred by [`FunctionCompiler::compile_lazy`].
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct LazyFunction {
+    #[unsafe_ignore_trace]
+    compiler: FunctionCompiler,
+    #[unsafe_ignore_trace]
+    ast: FunctionAst,
+    outer_env: Gc<GcRefCell<CompileTimeEnvironment>>,
+    compiled: GcRefCell<Option<Gc<CodeBlock>>>,
+}
+
+impl LazyFunction {
+    /// Gets the compile time environment the function is compiled in.
+    pub(crate) fn outer_env(&self) -> Gc<GcRefCell<CompileTimeEnvironment>> {
+        self.outer_env.clone()
+    }
+
+    /// Returns `true` if the function has already been compiled.
+    pub(crate) fn is_compiled(&self) -> bool {
+        self.compiled.borrow().is_some()
+    }
+
+    /// Creates a copy of this function that is compiled with the name `name`.
+    pub(crate) fn with_name(&self, name: Sym) -> Self {
+        let mut compiler = self.compiler.clone();
+        compiler.name = name;
+        let code = self
+            .compiled
+            .borrow()
+            .as_ref()
+            .map(|code| Gc::new(code.with_name(name)));
+        Self {
+            compiler,
+            ast: self.ast.clone(),
+            outer_env: self.outer_env.clone(),
+            compiled: GcRefCell::new(code),
+        }
+    }
+
+    /// Compiles the function, or returns the code block of a previous compilation.
+    pub(crate) fn compile(&self, context: &mut Context<'_>) -> JsResult<Gc<CodeBlock>> {
+        if let Some(code) = &*self.compiled.borrow() {
+            return Ok(code.clone());
+        }
 
-        Gc::new(compiler.finish())
+        let code = self
+            .compiler
+            .clone()
+            .lazy_script(Some(self.ast.script()))
+            .compile(
+                self.ast.parameters(),
+                self.ast.body(),
+                self.outer_env.clone(),
+                context,
+            )?;
+        *self.compiled.borrow_mut() = Some(code.clone());
+        Ok(code)
     }
 }
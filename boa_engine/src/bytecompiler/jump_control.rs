@@ -9,7 +9,10 @@
 //! [try spec]: https://tc39.es/ecma262/#sec-try-statement
 //! [labelled spec]: https://tc39.es/ecma262/#sec-labelled-statements
 
-use crate::bytecompiler::{ByteCompiler, Label};
+use crate::{
+    bytecompiler::{ByteCompiler, Label},
+    vm::Opcode,
+};
 use bitflags::bitflags;
 use boa_interner::Sym;
 
@@ -212,6 +215,32 @@ impl ByteCompiler<'_, '_> {
         }
     }
 
+    /// Emits the pops of the values that the `for-in` and `for-of` loops nested in the innermost
+    /// statement with the given label keep on the stack, which are exited by jumping to it.
+    ///
+    /// # Panic
+    ///  - Will panic if there is no statement with the label.
+    pub(crate) fn emit_for_of_in_exits(&mut self, label: Sym) {
+        let mut for_of_in_loops = 0;
+        let mut found = false;
+        for info in self.jump_info.iter().rev() {
+            if info.label() == Some(label) {
+                found = true;
+                break;
+            }
+            if info.for_of_in_loop() {
+                for_of_in_loops += 1;
+            }
+        }
+        assert!(found, "Cannot use the undeclared label");
+
+        for _ in 0..for_of_in_loops {
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::Pop);
+        }
+    }
+
     pub(crate) fn set_jump_control_in_catch(&mut self, value: bool) {
         if !self.jump_info.is_empty() {
             let info = self
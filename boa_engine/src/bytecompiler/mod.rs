@@ -7,14 +7,19 @@ mod expression;
 mod function;
 mod jump_control;
 mod module;
+mod peephole;
+mod source_text;
+mod stack_size;
 mod statement;
 mod utils;
+mod validate;
 
 use crate::{
-    builtins::function::ThisMode,
+    builtins::{function::ThisMode, regexp::RegExpSite},
     environments::{BindingLocator, CompileTimeEnvironment},
-    vm::{BindingOpcode, CodeBlock, Opcode},
-    Context, JsBigInt, JsString, JsValue,
+    optimizer::OptimizerOptions,
+    vm::{BindingOpcode, CodeBlock, CodeKind, InlineCache, Opcode},
+    Context, JsBigInt, JsError, JsNativeError, JsResult, JsString, JsValue,
 };
 use boa_ast::{
     declaration::{Binding, LexicalDeclaration, VarDeclaration},
@@ -29,14 +34,18 @@ use boa_ast::{
     },
     operations::bound_names,
     pattern::Pattern,
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Span, Statement, StatementList, StatementListItem,
 };
 use boa_gc::{Gc, GcRefCell};
 use boa_interner::{Interner, Sym};
 use rustc_hash::FxHashMap;
+use std::rc::Rc;
 
-pub(crate) use function::FunctionCompiler;
+pub(crate) use function::{FunctionAst, FunctionCompiler, LazyFunction};
 pub(crate) use jump_control::JumpControlInfo;
+pub(crate) use source_text::SourceText;
+pub(crate) use stack_size::max_stack_size;
+pub(crate) use validate::{swap_operands_byte_order, validate_bytecode, Tables};
 
 /// Describes how a node has been defined in the source code.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -65,6 +74,7 @@ struct FunctionSpec<'a> {
     parameters: &'a FormalParameterList,
     body: &'a StatementList,
     has_binding_identifier: bool,
+    span: Option<Span>,
 }
 
 impl FunctionSpec<'_> {
@@ -95,6 +105,7 @@ impl<'a> From<&'a Function> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: function.span(),
         }
     }
 }
@@ -107,6 +118,7 @@ impl<'a> From<&'a ArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            span: None,
         }
     }
 }
@@ -119,6 +131,7 @@ impl<'a> From<&'a AsyncArrowFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: false,
+            span: None,
         }
     }
 }
@@ -131,6 +144,7 @@ impl<'a> From<&'a AsyncFunction> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: None,
         }
     }
 }
@@ -143,6 +157,7 @@ impl<'a> From<&'a Generator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: None,
         }
     }
 }
@@ -155,6 +170,7 @@ impl<'a> From<&'a AsyncGenerator> for FunctionSpec<'a> {
             parameters: function.parameters(),
             body: function.body(),
             has_binding_identifier: function.has_binding_identifier(),
+            span: None,
         }
     }
 }
@@ -164,6 +180,8 @@ impl<'a> From<&'a AsyncGenerator> for FunctionSpec<'a> {
 enum Callable<'a> {
     Call(&'a Call),
     New(&'a New),
+    /// A call in tail position that can reuse the current call, see [`ByteCompiler::is_tail_call`].
+    TailCall(&'a Call),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -240,6 +258,9 @@ pub struct ByteCompiler<'ctx, 'host> {
     /// Literals
     pub(crate) literals: Vec<JsValue>,
 
+    /// Regular expression literal sites.
+    pub(crate) regexps: Vec<RegExpSite>,
+
     /// Property field names.
     pub(crate) names: Vec<Identifier>,
 
@@ -279,6 +300,32 @@ pub struct ByteCompiler<'ctx, 'host> {
     /// The number of bindings in the parameters environment.
     pub(crate) parameters_env_bindings: Option<usize>,
 
+    /// The source code that is being compiled, if available.
+    ///
+    /// Used to retain the source text of the compiled functions.
+    pub(crate) source: Option<Rc<SourceText>>,
+
+    /// The source text of this function.
+    pub(crate) source_text: Option<JsString>,
+
+    /// The name of the source code that is being compiled, like the path of a script file.
+    pub(crate) source_name: Option<JsString>,
+
+    /// The source positions of the statements and calls, keyed by the bytecode offset of their
+    /// first instruction.
+    pub(crate) source_positions: Vec<(u32, Position)>,
+
+    /// The script being compiled, if the compilation of the inner functions is deferred until
+    /// they are first called.
+    ///
+    /// All the nodes compiled while this is set must be part of the AST of the script.
+    pub(crate) lazy_script: Option<Rc<StatementList>>,
+
+    /// If calls of the function to itself in `return` statements can be compiled to tail calls.
+    pub(crate) tail_calls: bool,
+
+    /// Maps every string and `BigInt` literal to its index in `literals`, so that identical
+    /// literals share a single entry. Numbers are encoded directly in the bytecode.
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
     private_names_map: FxHashMap<PrivateName, u32>,
@@ -286,6 +333,9 @@ pub struct ByteCompiler<'ctx, 'host> {
     jump_info: Vec<JumpControlInfo>,
     in_async_generator: bool,
     json_parse: bool,
+
+    /// The first error of the compilation of the nested functions, returned by `finish`.
+    nested_error: Option<JsError>,
     // TODO: remove when we separate scripts from the context
     context: &'ctx mut Context<'host>,
 }
@@ -310,6 +360,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             length: 0,
             bytecode: Vec::default(),
             literals: Vec::default(),
+            regexps: Vec::default(),
             names: Vec::default(),
             private_names: Vec::default(),
             bindings: Vec::default(),
@@ -324,12 +375,19 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             class_field_initializer_name: None,
             function_environment_push_location: 0,
             parameters_env_bindings: None,
+            source: None,
+            source_text: None,
+            source_name: None,
+            source_positions: Vec::new(),
+            lazy_script: None,
+            tail_calls: false,
 
             literals_map: FxHashMap::default(),
             names_map: FxHashMap::default(),
             private_names_map: FxHashMap::default(),
             bindings_map: FxHashMap::default(),
             jump_info: Vec::new(),
+            nested_error: None,
             in_async_generator: false,
             json_parse,
             current_environment,
@@ -341,6 +399,12 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         self.context.interner()
     }
 
+    /// Gets the text of the source code matched by `span`, if the source code is available.
+    fn source_text_of(&self, span: Option<Span>) -> Option<JsString> {
+        let text = self.source.as_deref()?.get(span?)?;
+        Some(text.into())
+    }
+
     fn get_or_insert_literal(&mut self, literal: Literal) -> u32 {
         if let Some(index) = self.literals_map.get(&literal) {
             return *index;
@@ -441,6 +505,17 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         self.bytecode.len() as u32
     }
 
+    /// Maps the next instructions to `position` in the source code, until another position is
+    /// recorded.
+    fn record_position(&mut self, position: Position) {
+        let pc = self.next_opcode_location();
+        match self.source_positions.last_mut() {
+            // Nothing was emitted for the previous position.
+            Some((last, last_position)) if *last == pc => *last_position = position,
+            _ => self.source_positions.push((pc, position)),
+        }
+    }
+
     fn emit(&mut self, opcode: Opcode, operands: &[u32]) {
         self.emit_opcode(opcode);
         for operand in operands {
@@ -736,27 +811,21 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         use_expr: bool,
         configurable_globals: bool,
     ) {
-        if use_expr {
-            let expr_index = list
-                .statements()
+        let expr_index = if use_expr {
+            list.statements()
                 .iter()
                 .rev()
-                .skip_while(|item| {
-                    matches!(
-                        item,
-                        &&StatementListItem::Statement(Statement::Empty | Statement::Var(_))
-                            | &&StatementListItem::Declaration(_)
-                    )
-                })
-                .count();
-
-            for (i, item) in list.statements().iter().enumerate() {
-                self.compile_stmt_list_item(item, i + 1 == expr_index, configurable_globals);
-            }
+                .skip_while(|item| has_empty_completion(item))
+                .count()
         } else {
-            for item in list.statements() {
-                self.compile_stmt_list_item(item, false, configurable_globals);
+            0
+        };
+
+        for (i, item) in list.statements().iter().enumerate() {
+            if let Some(positions) = list.positions() {
+                self.record_position(positions[i]);
             }
+            self.compile_stmt_list_item(item, i + 1 == expr_index, configurable_globals);
         }
     }
 
@@ -771,29 +840,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         let push_env = self.emit_opcode_with_two_operands(Opcode::PushDeclarativeEnvironment);
 
         self.create_script_decls(list, true);
-
-        if use_expr {
-            let expr_index = list
-                .statements()
-                .iter()
-                .rev()
-                .skip_while(|item| {
-                    matches!(
-                        item,
-                        &&StatementListItem::Statement(Statement::Empty | Statement::Var(_))
-                            | &&StatementListItem::Declaration(_)
-                    )
-                })
-                .count();
-
-            for (i, item) in list.statements().iter().enumerate() {
-                self.compile_stmt_list_item(item, i + 1 == expr_index, true);
-            }
-        } else {
-            for item in list.statements() {
-                self.compile_stmt_list_item(item, false, true);
-            }
-        }
+        self.compile_statement_list(list, use_expr, true);
 
         let env_info = self.pop_compile_environment();
         self.patch_jump_with_target(push_env.0, env_info.num_bindings as u32);
@@ -1103,6 +1150,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             parameters,
             body,
             has_binding_identifier,
+            span,
             ..
         } = function;
 
@@ -1116,19 +1164,30 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             None
         };
 
-        let code = FunctionCompiler::new()
+        let compiler = FunctionCompiler::new()
             .name(name.map(Identifier::sym))
             .generator(generator)
             .r#async(r#async)
             .strict(self.strict)
             .arrow(arrow)
             .binding_identifier(binding_identifier)
-            .compile(
+            .source(self.source.clone())
+            .source_name(self.source_name.clone())
+            .source_text(self.source_text_of(span))
+            .lazy_script(self.lazy_script.clone());
+        let code = if let Some(script) = self.lazy_script.clone() {
+            // SAFETY: The compiled nodes are part of the AST of the lazy script.
+            let ast = unsafe { FunctionAst::new(script, parameters, body) };
+            compiler.compile_lazy(ast, self.current_environment.clone())
+        } else {
+            let code = compiler.compile(
                 parameters,
                 body,
                 self.current_environment.clone(),
                 self.context,
             );
+            self.nested_code_block(code)
+        };
 
         let index = self.functions.len() as u32;
         self.functions.push(code);
@@ -1181,6 +1240,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             parameters,
             body,
             has_binding_identifier,
+            span,
             ..
         } = function;
 
@@ -1202,12 +1262,16 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             .arrow(arrow)
             .binding_identifier(binding_identifier)
             .class_name(class_name)
+            .source(self.source.clone())
+            .source_name(self.source_name.clone())
+            .source_text(self.source_text_of(span))
             .compile(
                 parameters,
                 body,
                 self.current_environment.clone(),
                 self.context,
             );
+        let code = self.nested_code_block(code);
 
         let index = self.functions.len() as u32;
         self.functions.push(code);
@@ -1242,17 +1306,41 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         }
     }
 
+    /// Returns `true` if `call`, the expression of a `return` statement, can be compiled to a
+    /// tail call.
+    ///
+    /// Only calls of strict functions to themselves by their name, without spread arguments, are
+    /// tail calls, since non-strict functions can observe their callers. A `return` inside of
+    /// `try` statements, loops, switches or labelled statements may need to run more code of the
+    /// function, so it never contains a tail call.
+    fn is_tail_call(&self, call: &Call) -> bool {
+        self.tail_calls
+            && self.strict
+            && self.jump_info.is_empty()
+            && self.function_name != Sym::EVAL
+            && matches!(
+                call.function().flatten(),
+                Expression::Identifier(ident) if *ident == self.function_name
+            )
+            && !call
+                .args()
+                .iter()
+                .any(|arg| matches!(arg, Expression::Spread(_)))
+    }
+
     fn call(&mut self, callable: Callable<'_>, use_expr: bool) {
         #[derive(PartialEq)]
         enum CallKind {
             CallEval,
             Call,
             New,
+            TailCall,
         }
 
         let (call, mut kind) = match callable {
             Callable::Call(call) => (call, CallKind::Call),
             Callable::New(new) => (new.call(), CallKind::New),
+            Callable::TailCall(call) => (call, CallKind::TailCall),
         };
 
         match call.function().flatten() {
@@ -1263,7 +1351,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             Expression::Optional(opt) if kind == CallKind::Call => {
                 self.compile_optional_preserve_this(opt);
             }
-            expr if kind == CallKind::Call => {
+            expr if kind == CallKind::Call || kind == CallKind::TailCall => {
                 if let Expression::Identifier(ident) = expr {
                     if *ident == Sym::EVAL {
                         kind = CallKind::CallEval;
@@ -1300,6 +1388,10 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             }
         }
 
+        if let Some(span) = call.span() {
+            self.record_position(span.start());
+        }
+
         match kind {
             CallKind::CallEval if contains_spread => self.emit_opcode(Opcode::CallEvalSpread),
             CallKind::CallEval => self.emit(Opcode::CallEval, &[call.args().len() as u32]),
@@ -1307,6 +1399,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             CallKind::Call => self.emit(Opcode::Call, &[call.args().len() as u32]),
             CallKind::New if contains_spread => self.emit_opcode(Opcode::NewSpread),
             CallKind::New => self.emit(Opcode::New, &[call.args().len() as u32]),
+            CallKind::TailCall => self.emit(Opcode::TailCall, &[call.args().len() as u32]),
         }
 
         if !use_expr {
@@ -1314,12 +1407,47 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         }
     }
 
+    /// Returns the code block of a nested function, or records the error of its compilation to
+    /// be returned by `finish`, returning an empty code block in its place.
+    fn nested_code_block(&mut self, code: JsResult<Gc<CodeBlock>>) -> Gc<CodeBlock> {
+        code.unwrap_or_else(|err| {
+            self.nested_error.get_or_insert(err);
+            Gc::new(CodeBlock::new(Sym::EMPTY_STRING, 0, self.strict))
+        })
+    }
+
     /// Finish compiling code with the [`ByteCompiler`] and return the generated [`CodeBlock`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the compiled bytecode or the bytecode of a nested function doesn't
+    /// keep the operand stack balanced.
     #[inline]
-    #[must_use]
-    #[allow(clippy::missing_const_for_fn)]
-    pub fn finish(self) -> CodeBlock {
-        CodeBlock {
+    pub fn finish(mut self) -> JsResult<CodeBlock> {
+        if let Some(err) = self.nested_error {
+            return Err(err);
+        }
+
+        if self
+            .context
+            .optimizer_options()
+            .contains(OptimizerOptions::PEEPHOLE)
+        {
+            peephole::optimize(
+                &mut self.bytecode,
+                &mut self.function_environment_push_location,
+                &mut self.source_positions,
+            );
+        }
+
+        let inline_caches = Self::create_inline_caches(&mut self.bytecode, &self.names);
+
+        let max_stack_size =
+            max_stack_size(&self.bytecode, self.params.as_ref().len()).map_err(|err| {
+                JsNativeError::error().with_message(format!("invalid bytecode emitted: {err}"))
+            })?;
+
+        Ok(CodeBlock {
             name: self.function_name,
             has_binding_identifier: self.has_binding_identifier,
             length: self.length,
@@ -1328,20 +1456,58 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             params: self.params,
             bytecode: self.bytecode.into_boxed_slice(),
             literals: self.literals.into_boxed_slice(),
+            regexps: self.regexps.into_boxed_slice(),
             names: self.names.into_boxed_slice(),
             private_names: self.private_names.into_boxed_slice(),
+            inline_caches,
             bindings: self.bindings.into_boxed_slice(),
             num_bindings: self.num_bindings,
             functions: self.functions.into_boxed_slice(),
             arguments_binding: self.arguments_binding,
             compile_environments: self.compile_environments.into_boxed_slice(),
+            kind: CodeKind::Script,
             is_class_constructor: self.is_class_constructor,
             class_field_initializer_name: self.class_field_initializer_name,
             function_environment_push_location: self.function_environment_push_location,
             parameters_env_bindings: self.parameters_env_bindings,
+            source_text: self.source_text,
+            source_name: self.source_name,
+            source_positions: self.source_positions.into_boxed_slice(),
+            max_stack_size,
+            lazy: None,
             #[cfg(feature = "trace")]
             trace: std::cell::Cell::new(false),
+        })
+    }
+
+    /// Gives every `GetPropertyByName` and `SetPropertyByName` instruction its own inline cache,
+    /// replacing the name operand of the instruction with the index of the cache.
+    pub(crate) fn create_inline_caches(
+        bytecode: &mut [u8],
+        names: &[Identifier],
+    ) -> Box<[InlineCache]> {
+        let mut inline_caches = Vec::new();
+        let mut pc = 0;
+        while pc < bytecode.len() {
+            let Ok(len) = stack_size::operands_len(bytecode, pc) else {
+                break;
+            };
+            if matches!(
+                Opcode::try_from(bytecode[pc]),
+                Ok(Opcode::GetPropertyByName | Opcode::SetPropertyByName)
+            ) {
+                let operand = pc + 1..pc + 1 + std::mem::size_of::<u32>();
+                let mut index = [0; std::mem::size_of::<u32>()];
+                index.copy_from_slice(&bytecode[operand.clone()]);
+                let name = names[u32::from_ne_bytes(index) as usize];
+
+                let index = inline_caches.len() as u32;
+                bytecode[operand].copy_from_slice(&index.to_ne_bytes());
+                inline_caches.push(InlineCache::new(name));
+            }
+            pc += 1 + len;
         }
+        inline_caches.into_boxed_slice()
     }
 
     fn compile_declaration_pattern(&mut self, pattern: &Pattern, def: BindingOpcode) {
@@ -1470,7 +1636,7 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
             }
             Declaration::Class(decl) => {
                 let ident = decl.name().expect("class declaration must have a name");
-                self.create_mutable_binding(ident, false, configurable_globals);
+                self.create_class_binding(ident);
                 false
             }
         }
@@ -1524,3 +1690,23 @@ impl<'ctx, 'host> ByteCompiler<'ctx, 'host> {
         self.compile_class(class, expression);
     }
 }
+
+/// Returns `true` if the completion value of `item` is always empty, which means that the
+/// completion value of its statement list is the one of the previous statement.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
+fn has_empty_completion(item: &StatementListItem) -> bool {
+    match item {
+        StatementListItem::Declaration(_)
+        | StatementListItem::Statement(Statement::Empty | Statement::Var(_)) => true,
+        StatementListItem::Statement(Statement::Block(block)) => block
+            .statement_list()
+            .statements()
+            .iter()
+            .all(has_empty_completion),
+        StatementListItem::Statement(_) => false,
+    }
+}
@@ -0,0 +1,251 @@
+//! A peephole optimization pass over compiled bytecode.
+//!
+//! The pass rewrites short instruction sequences emitted by the [`ByteCompiler`] into cheaper
+//! ones, and relocates every address operand to account for the removed bytes:
+//!
+//! - `Jump` to the immediately following instruction is removed.
+//! - `Dup; Pop` is removed.
+//! - A push of a constant followed by `Pop` is removed.
+//! - `LogicalNot; LogicalNot` is folded into `ToBoolean`.
+//!
+//! An instruction is only removed if no address operand points inside it, which keeps the
+//! relocation a strictly increasing mapping of all the referenced addresses.
+
+use super::{stack_size::operands_len, ByteCompiler};
+use crate::vm::Opcode;
+use boa_ast::Position;
+
+/// A decoded instruction of the bytecode.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Instruction {
+    pub(super) start: usize,
+    pub(super) len: usize,
+    pub(super) opcode: Opcode,
+}
+
+impl Instruction {
+    const fn end(self) -> usize {
+        self.start + self.len
+    }
+}
+
+/// Returns the indices of the operands of `opcode` that are addresses in the bytecode.
+const fn address_operands(opcode: Opcode) -> &'static [usize] {
+    match opcode {
+        Opcode::Jump
+        | Opcode::JumpIfTrue
+        | Opcode::JumpIfFalse
+        | Opcode::JumpIfNotUndefined
+        | Opcode::JumpIfNullOrUndefined
+        | Opcode::LogicalAnd
+        | Opcode::LogicalOr
+        | Opcode::Coalesce
+        | Opcode::Case
+        | Opcode::Default
+        | Opcode::CatchStart
+        | Opcode::FinallyStart
+        | Opcode::LabelledStart
+        | Opcode::IteratorUnwrapNextOrJump
+        | Opcode::GeneratorAsyncResumeYield
+        | Opcode::GeneratorNextDelegate => &[0],
+        Opcode::TryStart
+        | Opcode::Break
+        | Opcode::Continue
+        | Opcode::LoopStart
+        | Opcode::LoopContinue
+        | Opcode::AsyncGeneratorNext
+        | Opcode::GeneratorAsyncDelegateNext => &[0, 1],
+        Opcode::GeneratorAsyncDelegateResume => &[0, 1, 2],
+        _ => &[],
+    }
+}
+
+/// Returns `true` if `opcode` only pushes a constant to the stack, without any side effect.
+const fn is_constant_push(opcode: Opcode) -> bool {
+    matches!(
+        opcode,
+        Opcode::PushZero
+            | Opcode::PushOne
+            | Opcode::PushInt8
+            | Opcode::PushInt16
+            | Opcode::PushInt32
+            | Opcode::PushRational
+            | Opcode::PushNaN
+            | Opcode::PushPositiveInfinity
+            | Opcode::PushNegativeInfinity
+            | Opcode::PushNull
+            | Opcode::PushTrue
+            | Opcode::PushFalse
+            | Opcode::PushUndefined
+            | Opcode::PushLiteral
+    )
+}
+
+pub(super) fn read_u32(bytecode: &[u8], offset: usize) -> u32 {
+    let mut buffer = [0; 4];
+    buffer.copy_from_slice(&bytecode[offset..offset + 4]);
+    u32::from_ne_bytes(buffer)
+}
+
+/// Returns the addresses referenced by the operands of `instruction`.
+pub(super) fn addresses(
+    bytecode: &[u8],
+    instruction: Instruction,
+) -> impl Iterator<Item = u32> + '_ {
+    address_operands(instruction.opcode)
+        .iter()
+        .map(move |index| read_u32(bytecode, instruction.start + 1 + index * 4))
+}
+
+/// Splits the bytecode into instructions, or returns `None` if it is malformed.
+pub(super) fn decode(bytecode: &[u8]) -> Option<Vec<Instruction>> {
+    let mut instructions = Vec::new();
+    let mut pc = 0;
+    while pc < bytecode.len() {
+        let opcode = Opcode::try_from(bytecode[pc]).ok()?;
+        let len = 1 + operands_len(bytecode, pc).ok()?;
+        instructions.push(Instruction {
+            start: pc,
+            len,
+            opcode,
+        });
+        pc += len;
+    }
+    Some(instructions)
+}
+
+/// Applies the peephole optimizations to `bytecode` until no more instructions can be rewritten.
+///
+/// `function_environment_push_location` and the offsets of `source_positions` are relocated along
+/// with the address operands.
+pub(crate) fn optimize(
+    bytecode: &mut Vec<u8>,
+    function_environment_push_location: &mut u32,
+    source_positions: &mut [(u32, Position)],
+) {
+    while let Some(optimized) = run_pass(
+        bytecode,
+        function_environment_push_location,
+        source_positions,
+    ) {
+        *bytecode = optimized;
+    }
+}
+
+/// Runs a single pass of the optimizations, returning the rewritten bytecode if anything changed.
+fn run_pass(
+    bytecode: &[u8],
+    function_environment_push_location: &mut u32,
+    source_positions: &mut [(u32, Position)],
+) -> Option<Vec<u8>> {
+    let instructions = decode(bytecode)?;
+
+    // The start of the bytecode is never moved, so it doesn't need to be kept.
+    let mut referenced = Vec::new();
+    if *function_environment_push_location != 0 {
+        referenced.push(*function_environment_push_location);
+    }
+    let mut finally_addresses = Vec::new();
+    for &instruction in &instructions {
+        referenced.extend(addresses(bytecode, instruction));
+        if matches!(instruction.opcode, Opcode::CatchStart) {
+            finally_addresses.extend(addresses(bytecode, instruction));
+        }
+    }
+
+    // `CatchStart` uses the byte preceding its finally address as the exit of the catch block,
+    // so the instruction ending there must be kept as well.
+    let removable = |start: usize, end: usize| {
+        !referenced
+            .iter()
+            .any(|&address| (start..end).contains(&(address as usize)))
+            && !finally_addresses.contains(&(end as u32))
+    };
+
+    let mut removed = Vec::new();
+    let mut replaced = Vec::new();
+    let mut i = 0;
+    while i < instructions.len() {
+        let instruction = instructions[i];
+        let next = instructions.get(i + 1).copied();
+        match (instruction.opcode, next.map(|next| next.opcode)) {
+            (Opcode::Jump, _)
+                if addresses(bytecode, instruction).eq([instruction.end() as u32])
+                    && removable(instruction.start, instruction.end()) =>
+            {
+                removed.push((instruction.start, instruction.end()));
+                i += 1;
+            }
+            (Opcode::Dup, Some(Opcode::Pop))
+                if removable(instruction.start, instruction.end() + 1) =>
+            {
+                removed.push((instruction.start, instruction.end() + 1));
+                i += 2;
+            }
+            (opcode, Some(Opcode::Pop))
+                if is_constant_push(opcode)
+                    && removable(instruction.start, instruction.end() + 1) =>
+            {
+                removed.push((instruction.start, instruction.end() + 1));
+                i += 2;
+            }
+            (Opcode::LogicalNot, Some(Opcode::LogicalNot))
+                if removable(instruction.end(), instruction.end() + 1) =>
+            {
+                replaced.push((instruction.start, Opcode::ToBoolean));
+                removed.push((instruction.end(), instruction.end() + 1));
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+
+    if removed.is_empty() {
+        return None;
+    }
+
+    let relocate = |address: u32| {
+        if address == ByteCompiler::DUMMY_ADDRESS {
+            return address;
+        }
+        let shift: usize = removed
+            .iter()
+            .filter(|(_, end)| *end <= address as usize)
+            .map(|(start, end)| end - start)
+            .sum();
+        address - shift as u32
+    };
+
+    let mut optimized = Vec::with_capacity(bytecode.len());
+    for &instruction in &instructions {
+        if removed
+            .iter()
+            .any(|&(start, end)| (start..end).contains(&instruction.start))
+        {
+            continue;
+        }
+
+        let location = optimized.len();
+        optimized.extend_from_slice(&bytecode[instruction.start..instruction.end()]);
+        if let Some((_, opcode)) = replaced
+            .iter()
+            .find(|(start, _)| *start == instruction.start)
+        {
+            optimized[location] = *opcode as u8;
+        }
+        for (index, address) in address_operands(instruction.opcode)
+            .iter()
+            .zip(addresses(bytecode, instruction))
+        {
+            let offset = location + 1 + index * 4;
+            optimized[offset..offset + 4].copy_from_slice(&relocate(address).to_ne_bytes());
+        }
+    }
+
+    *function_environment_push_location = relocate(*function_environment_push_location);
+    for (pc, _) in source_positions {
+        *pc = relocate(*pc);
+    }
+
+    Some(optimized)
+}
@@ -0,0 +1,83 @@
+//! Looks up the source text of compiled functions.
+//!
+//! The source code is indexed by line once per compilation, so finding the text of a function
+//! only scans the lines its span starts and ends on instead of the whole source.
+
+use boa_ast::{Position, Span};
+use std::rc::Rc;
+
+/// The source code of a script, module or `eval` call, indexed by line.
+#[derive(Debug)]
+pub(crate) struct SourceText {
+    text: Rc<str>,
+
+    /// The byte offset where every line starts, and whether the line only contains ASCII.
+    lines: Box<[(usize, bool)]>,
+}
+
+impl SourceText {
+    /// Indexes the lines of `text`.
+    pub(crate) fn new(text: Rc<str>) -> Self {
+        let mut lines = Vec::new();
+        let mut start = 0;
+        let mut ascii = true;
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, ch)) = chars.next() {
+            // Line terminators must be handled in the same way as the lexer does.
+            let end = match ch {
+                '\r' => match chars.peek() {
+                    Some(&(next, '\n')) => {
+                        chars.next();
+                        next + 1
+                    }
+                    _ => index + 1,
+                },
+                '\n' => index + 1,
+                '\u{2028}' | '\u{2029}' => index + 3,
+                _ => {
+                    ascii &= ch.is_ascii();
+                    continue;
+                }
+            };
+            lines.push((start, ascii));
+            start = end;
+            ascii = true;
+        }
+        lines.push((start, ascii));
+
+        Self {
+            text,
+            lines: lines.into_boxed_slice(),
+        }
+    }
+
+    /// Gets the text matched by `span`, if it lies inside of the source code.
+    pub(crate) fn get(&self, span: Span) -> Option<&str> {
+        let start = self.offset(span.start())?;
+        let end = self.offset(span.end())?;
+        self.text.get(start..end)
+    }
+
+    /// Gets the byte offset of `position`.
+    ///
+    /// A position can point one past the last character of its line.
+    fn offset(&self, position: Position) -> Option<usize> {
+        let line = position.line_number() as usize - 1;
+        let column = position.column_number() as usize - 1;
+        let &(start, ascii) = self.lines.get(line)?;
+        let end = self
+            .lines
+            .get(line + 1)
+            .map_or(self.text.len(), |&(next, _)| next);
+        let text = &self.text[start..end];
+
+        if ascii {
+            return (column <= text.len()).then_some(start + column);
+        }
+        text.char_indices()
+            .map(|(index, _)| index)
+            .chain(std::iter::once(text.len()))
+            .nth(column)
+            .map(|index| start + index)
+    }
+}
@@ -0,0 +1,394 @@
+//! Computes the maximum operand stack depth of compiled bytecode.
+//!
+//! The analysis walks every reachable instruction and tracks the stack height before it, starting
+//! from the height the function is entered with. Every path reaching an instruction must agree on
+//! its height. Abrupt completions that unwind the stack at runtime, like `throw`, or `break` and
+//! `continue` out of a finally block, truncate it to the height recorded when entering the
+//! statement they jump to, which is the height of the paths that reach it statically.
+
+use crate::vm::Opcode;
+use std::fmt;
+
+/// Marker for jump addresses that have not been patched by the compiler.
+const DUMMY_ADDRESS: u32 = u32::MAX;
+
+/// The error returned when the stack depth of malformed bytecode cannot be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StackSizeError {
+    /// The location of the offending instruction.
+    pub(crate) pc: usize,
+
+    /// The reason of the failure.
+    pub(crate) message: &'static str,
+}
+
+impl fmt::Display for StackSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {:06}", self.message, self.pc)
+    }
+}
+
+/// The effect of a single instruction on the operand stack.
+#[derive(Debug, Clone, Copy)]
+struct Effect {
+    /// Size in bytes of the operands of the instruction.
+    operands: usize,
+
+    /// Number of values the instruction pops from the stack.
+    pops: usize,
+
+    /// Number of values pushed when execution continues with the next instruction,
+    /// or `None` if it never does.
+    next: Option<usize>,
+
+    /// Jump targets and the number of values pushed when jumping to them.
+    jumps: [Option<(u32, usize)>; 3],
+}
+
+impl Effect {
+    const fn new(operands: usize, pops: usize, pushes: usize) -> Self {
+        Self {
+            operands,
+            pops,
+            next: Some(pushes),
+            jumps: [None; 3],
+        }
+    }
+
+    const fn exit(operands: usize, pops: usize) -> Self {
+        Self {
+            operands,
+            pops,
+            next: None,
+            jumps: [None; 3],
+        }
+    }
+
+    fn jump(mut self, target: u32, pushes: usize) -> Self {
+        if target != DUMMY_ADDRESS {
+            if let Some(slot) = self.jumps.iter_mut().find(|jump| jump.is_none()) {
+                *slot = Some((target, pushes));
+            }
+        }
+        self
+    }
+}
+
+/// Decodes the stack effect of the instruction at `pc`.
+///
+/// The opcodes are grouped by their kind of operation rather than by their effect, so some arms
+/// are identical.
+#[allow(clippy::match_same_arms)]
+fn effect(bytecode: &[u8], pc: usize) -> Result<Effect, StackSizeError> {
+    let error = |message| StackSizeError { pc, message };
+
+    let opcode = Opcode::try_from(bytecode[pc]).map_err(|_| error("invalid opcode"))?;
+    let u8_at = || {
+        bytecode
+            .get(pc + 1)
+            .map(|byte| usize::from(*byte))
+            .ok_or_else(|| error("truncated operands"))
+    };
+    let u32_at = |index: usize| {
+        let start = pc + 1 + index * 4;
+        let bytes = bytecode
+            .get(start..start + 4)
+            .ok_or_else(|| error("truncated operands"))?;
+        let mut buffer = [0; 4];
+        buffer.copy_from_slice(bytes);
+        Ok(u32::from_ne_bytes(buffer))
+    };
+
+    let effect = match opcode {
+        Opcode::Pop | Opcode::SetCompletionValue => Effect::new(0, 1, 0),
+        Opcode::Dup => Effect::new(0, 1, 2),
+        Opcode::Swap => Effect::new(0, 2, 2),
+        Opcode::RotateLeft | Opcode::RotateRight => {
+            let n = u8_at()?;
+            Effect::new(1, n, n)
+        }
+        Opcode::PushZero
+        | Opcode::PushOne
+        | Opcode::PushNaN
+        | Opcode::PushPositiveInfinity
+        | Opcode::PushNegativeInfinity
+        | Opcode::PushNull
+        | Opcode::PushTrue
+        | Opcode::PushFalse
+        | Opcode::PushUndefined
+        | Opcode::PushEmptyObject
+        | Opcode::PushNewArray
+        | Opcode::This
+        | Opcode::Super
+        | Opcode::SuperCallDerived
+        | Opcode::PushNewTarget => Effect::new(0, 0, 1),
+        Opcode::PushInt8 => Effect::new(1, 0, 1),
+        Opcode::PushInt16 => Effect::new(2, 0, 1),
+        Opcode::PushInt32 => Effect::new(4, 0, 1),
+        Opcode::PushRational => Effect::new(8, 0, 1),
+        Opcode::PushLiteral
+        | Opcode::PushRegExp
+        | Opcode::GetName
+        | Opcode::GetNameOrUndefined
+        | Opcode::DeleteName => Effect::new(4, 0, 1),
+        Opcode::GetArrowFunction
+        | Opcode::GetAsyncArrowFunction
+        | Opcode::GetFunction
+        | Opcode::GetFunctionAsync
+        | Opcode::GetGenerator
+        | Opcode::GetGeneratorAsync => Effect::new(5, 0, 1),
+        Opcode::PushClassPrototype | Opcode::SetHomeObject | Opcode::IteratorClosePush => {
+            Effect::new(0, 2, 2)
+        }
+        Opcode::SetClassPrototype
+        | Opcode::PushValueToArray
+        | Opcode::Add
+        | Opcode::Sub
+        | Opcode::Div
+        | Opcode::Mul
+        | Opcode::Mod
+        | Opcode::Pow
+        | Opcode::ShiftRight
+        | Opcode::ShiftLeft
+        | Opcode::UnsignedShiftRight
+        | Opcode::BitOr
+        | Opcode::BitAnd
+        | Opcode::BitXor
+        | Opcode::In
+        | Opcode::Eq
+        | Opcode::StrictEq
+        | Opcode::NotEq
+        | Opcode::StrictNotEq
+        | Opcode::GreaterThan
+        | Opcode::GreaterThanOrEq
+        | Opcode::LessThan
+        | Opcode::LessThanOrEq
+        | Opcode::InstanceOf
+        | Opcode::GetPropertyByValue
+        | Opcode::DeletePropertyByValue
+        | Opcode::NewSpread => Effect::new(0, 2, 1),
+        Opcode::SetFunctionName => Effect::new(1, 2, 1),
+        Opcode::SetPrivateField => Effect::new(4, 2, 1),
+        Opcode::SetPrototype => Effect::new(0, 2, 0),
+        Opcode::PushElisionToArray
+        | Opcode::BitNot
+        | Opcode::TypeOf
+        | Opcode::Void
+        | Opcode::LogicalNot
+        | Opcode::Pos
+        | Opcode::Neg
+        | Opcode::Inc
+        | Opcode::Dec
+        | Opcode::ToPropertyKey
+        | Opcode::ToBoolean
+        | Opcode::IteratorUnwrapValue
+        | Opcode::RequireObjectCoercible
+        | Opcode::ValueNotNullOrUndefined
+        | Opcode::Yield
+        | Opcode::Await
+        | Opcode::IsObject => Effect::new(0, 1, 1),
+        Opcode::InPrivate
+        | Opcode::GetPropertyByName
+        | Opcode::GetPrivateField
+        | Opcode::DeletePropertyByName => Effect::new(4, 1, 1),
+        Opcode::PushIteratorToArray => Effect::new(0, 3, 1),
+        Opcode::IncPost
+        | Opcode::DecPost
+        | Opcode::CreateForInIterator
+        | Opcode::GetIterator
+        | Opcode::GetAsyncIterator
+        | Opcode::IteratorUnwrapNext => Effect::new(0, 1, 2),
+        Opcode::GetMethod => Effect::new(4, 1, 2),
+        Opcode::GetPropertyByValuePush => Effect::new(0, 2, 2),
+        Opcode::IteratorNext | Opcode::IteratorNextSetDone | Opcode::IteratorToArray => {
+            Effect::new(0, 2, 3)
+        }
+        Opcode::DefInitArg
+        | Opcode::DefInitVar
+        | Opcode::DefInitLet
+        | Opcode::DefInitConst
+        | Opcode::SetName => Effect::new(4, 1, 0),
+        Opcode::DefVar | Opcode::DefLet => Effect::new(4, 0, 0),
+        Opcode::SetPropertyByName => Effect::new(4, 3, 1),
+        Opcode::SetPropertyByValue => Effect::new(0, 3, 1),
+        Opcode::DefineOwnPropertyByName
+        | Opcode::DefineClassStaticMethodByName
+        | Opcode::DefineClassMethodByName
+        | Opcode::SetPropertyGetterByName
+        | Opcode::DefineClassStaticGetterByName
+        | Opcode::DefineClassGetterByName
+        | Opcode::SetPropertySetterByName
+        | Opcode::DefineClassStaticSetterByName
+        | Opcode::DefineClassSetterByName
+        | Opcode::DefinePrivateField
+        | Opcode::SetPrivateMethod
+        | Opcode::SetPrivateSetter
+        | Opcode::SetPrivateGetter
+        | Opcode::PushClassFieldPrivate
+        | Opcode::PushClassPrivateGetter
+        | Opcode::PushClassPrivateSetter
+        | Opcode::PushClassPrivateMethod => Effect::new(4, 2, 0),
+        Opcode::DefineOwnPropertyByValue
+        | Opcode::DefineClassStaticMethodByValue
+        | Opcode::DefineClassMethodByValue
+        | Opcode::SetPropertyGetterByValue
+        | Opcode::DefineClassStaticGetterByValue
+        | Opcode::DefineClassGetterByValue
+        | Opcode::SetPropertySetterByValue
+        | Opcode::DefineClassStaticSetterByValue
+        | Opcode::DefineClassSetterByValue
+        | Opcode::PushClassField => Effect::new(0, 3, 0),
+        Opcode::CopyDataProperties => {
+            let excluded = u32_at(0)? as usize + u32_at(1)? as usize;
+            Effect::new(8, excluded + 2, 1)
+        }
+        Opcode::ConcatToString => Effect::new(4, u32_at(0)? as usize, 1),
+        Opcode::SuperCallPrepare => Effect::new(0, 0, 2),
+        Opcode::SuperCall | Opcode::CallEval | Opcode::Call | Opcode::TailCall => {
+            Effect::new(4, u32_at(0)? as usize + 2, 1)
+        }
+        Opcode::New => Effect::new(4, u32_at(0)? as usize + 1, 1),
+        Opcode::SuperCallSpread | Opcode::CallEvalSpread | Opcode::CallSpread => {
+            Effect::new(0, 3, 1)
+        }
+        Opcode::ImportCall => Effect::new(0, 2, 1),
+        Opcode::TryEnd
+        | Opcode::CatchEnd
+        | Opcode::CatchEnd2
+        | Opcode::FinallyEnd
+        | Opcode::PopEnvironment
+        | Opcode::LoopEnd
+        | Opcode::LabelledEnd
+        | Opcode::IteratorClosePop
+        | Opcode::RestParameterInit
+        | Opcode::RestParameterPop
+        | Opcode::GeneratorNext
+        | Opcode::Nop => Effect::new(0, 0, 0),
+        Opcode::CatchStart | Opcode::FinallyStart | Opcode::LabelledStart => Effect::new(4, 0, 0),
+        Opcode::PushDeclarativeEnvironment
+        | Opcode::PushFunctionEnvironment
+        | Opcode::LoopStart
+        | Opcode::LoopContinue => Effect::new(8, 0, 0),
+        Opcode::PushObjectEnvironment => Effect::new(0, 1, 0),
+        Opcode::Throw
+        | Opcode::Return
+        | Opcode::GeneratorResumeReturn
+        | Opcode::DeleteSuperThrow => Effect::exit(0, 0),
+        Opcode::ThrowNewTypeError => Effect::exit(4, 0),
+        Opcode::Jump => Effect::exit(4, 0).jump(u32_at(0)?, 0),
+        Opcode::JumpIfTrue | Opcode::JumpIfFalse => Effect::new(4, 1, 0).jump(u32_at(0)?, 0),
+        Opcode::JumpIfNotUndefined | Opcode::LogicalAnd | Opcode::LogicalOr | Opcode::Coalesce => {
+            Effect::new(4, 1, 0).jump(u32_at(0)?, 1)
+        }
+        Opcode::JumpIfNullOrUndefined => Effect::new(4, 1, 1).jump(u32_at(0)?, 0),
+        Opcode::Case => Effect::new(4, 2, 1).jump(u32_at(0)?, 0),
+        Opcode::Default => Effect::exit(4, 1).jump(u32_at(0)?, 0),
+        Opcode::IteratorUnwrapNextOrJump => Effect::new(4, 1, 2).jump(u32_at(0)?, 1),
+        // The thrown value is pushed onto the stack when entering the catch block. Without a catch
+        // block the jump goes to the finally block, which carries the value in the frame instead.
+        Opcode::TryStart => {
+            let (catch, finally) = (u32_at(0)?, u32_at(1)?);
+            Effect::new(8, 0, 0).jump(catch, usize::from(catch != finally))
+        }
+        // Jumps to the target through finally blocks continue when they end, from the stack
+        // height of the target statement.
+        Opcode::Break | Opcode::Continue => Effect::exit(8, 0).jump(u32_at(0)?, 0),
+        Opcode::AsyncGeneratorNext => Effect::new(8, 1, 0).jump(u32_at(0)?, 1).jump(u32_at(1)?, 1),
+        Opcode::GeneratorAsyncResumeYield => Effect::new(4, 0, 0).jump(u32_at(0)?, 0),
+        Opcode::GeneratorNextDelegate => Effect::new(4, 3, 3).jump(u32_at(0)?, 1),
+        Opcode::GeneratorAsyncDelegateNext => {
+            Effect::new(8, 3, 4).jump(u32_at(0)?, 4).jump(u32_at(1)?, 3)
+        }
+        Opcode::GeneratorAsyncDelegateResume => Effect::new(12, 2, 0)
+            .jump(u32_at(0)?, 1)
+            .jump(u32_at(1)?, 1)
+            .jump(u32_at(2)?, 1),
+    };
+
+    if pc + 1 + effect.operands > bytecode.len() {
+        return Err(error("truncated operands"));
+    }
+
+    Ok(effect)
+}
+
+/// Returns the size in bytes of the operands of the instruction at `pc`.
+pub(super) fn operands_len(bytecode: &[u8], pc: usize) -> Result<usize, StackSizeError> {
+    effect(bytecode, pc).map(|effect| effect.operands)
+}
+
+/// Computes the maximum operand stack depth reached while executing `bytecode`, when entered with
+/// `initial_height` values on the stack.
+///
+/// # Errors
+///
+/// Returns an error if the bytecode contains invalid opcodes, truncated operands, jumps outside
+/// of the bytecode, instructions that pop more values than the stack holds or instructions that
+/// are reached with different stack heights.
+pub(crate) fn max_stack_size(
+    bytecode: &[u8],
+    initial_height: usize,
+) -> Result<u32, StackSizeError> {
+    let mut heights = vec![None; bytecode.len()];
+    let mut worklist = Vec::new();
+    let mut max = initial_height;
+
+    if !bytecode.is_empty() {
+        heights[0] = Some(initial_height);
+        worklist.push(0);
+    }
+
+    while let Some(pc) = worklist.pop() {
+        let Some(height) = heights[pc] else {
+            continue;
+        };
+
+        let effect = effect(bytecode, pc)?;
+        let base = height.checked_sub(effect.pops).ok_or(StackSizeError {
+            pc,
+            message: "stack underflow",
+        })?;
+
+        let next = effect.next.map(|pushes| (pc + 1 + effect.operands, pushes));
+        let jumps = effect
+            .jumps
+            .into_iter()
+            .flatten()
+            .map(|(target, pushes)| (target as usize, pushes));
+
+        for (target, pushes) in next.into_iter().chain(jumps) {
+            // Running past the end of the bytecode finishes the execution of the frame.
+            if target == bytecode.len() {
+                continue;
+            }
+            if target > bytecode.len() {
+                return Err(StackSizeError {
+                    pc,
+                    message: "jump target out of bounds",
+                });
+            }
+
+            let height = base + pushes;
+            max = max.max(height);
+
+            match heights[target] {
+                Some(known) if known == height => {}
+                Some(_) => {
+                    return Err(StackSizeError {
+                        pc,
+                        message: "stack height mismatch",
+                    });
+                }
+                None => {
+                    heights[target] = Some(height);
+                    worklist.push(target);
+                }
+            }
+        }
+    }
+
+    u32::try_from(max).map_err(|_| StackSizeError {
+        pc: 0,
+        message: "stack size exceeds the format limits",
+    })
+}
@@ -13,13 +13,15 @@ impl ByteCompiler<'_, '_> {
             let in_catch_no_finally = info.in_catch() && !info.has_finally();
             let has_finally_or_is_finally = info.has_finally() || info.in_finally();
 
-            if in_finally {
-                self.emit_opcode(Opcode::PopIfThrown);
-            }
             if in_finally || in_catch_no_finally {
                 self.emit_opcode(Opcode::CatchEnd2);
             }
 
+            // A labelled break that doesn't run a finally block jumps directly to its target.
+            if let Some(node_label) = node.label().filter(|_| !has_finally_or_is_finally) {
+                self.emit_for_of_in_exits(node_label);
+            }
+
             let (break_label, target_jump_label) =
                 self.emit_opcode_with_two_operands(Opcode::Break);
 
@@ -47,6 +49,10 @@ impl ByteCompiler<'_, '_> {
             return;
         }
 
+        if let Some(node_label) = node.label() {
+            self.emit_for_of_in_exits(node_label);
+        }
+
         // Emit the break opcode -> (Label, Label)
         let (break_label, target_label) = self.emit_opcode_with_two_operands(Opcode::Break);
         if node.label().is_some() {
@@ -9,34 +9,14 @@ impl ByteCompiler<'_, '_> {
             let in_finally_or_has_finally = in_finally || info.has_finally();
             let in_catch_no_finally = !info.has_finally() && info.in_catch();
 
-            if in_finally {
-                self.emit_opcode(Opcode::PopIfThrown);
-            }
             if in_finally || in_catch_no_finally {
                 self.emit_opcode(Opcode::CatchEnd2);
             }
             // 1. Handle if node has a label.
             if let Some(node_label) = node.label() {
-                let items = self.jump_info.iter().rev().filter(|info| info.is_loop());
-                let mut emit_for_of_in_exit = 0_u32;
-                let mut loop_info = None;
-                for info in items {
-                    if info.label() == Some(node_label) {
-                        loop_info = Some(info);
-                        break;
-                    }
-
-                    if info.for_of_in_loop() {
-                        emit_for_of_in_exit += 1;
-                    }
-                }
-
-                loop_info.expect("Cannot use the undeclared label");
-
-                for _ in 0..emit_for_of_in_exit {
-                    self.emit_opcode(Opcode::Pop);
-                    self.emit_opcode(Opcode::Pop);
-                    self.emit_opcode(Opcode::Pop);
+                // The loops are exited by the finally block if the continue runs it.
+                if !in_finally_or_has_finally {
+                    self.emit_for_of_in_exits(node_label);
                 }
 
                 let (cont_label, set_label) = self.emit_opcode_with_two_operands(Opcode::Continue);
@@ -86,27 +66,7 @@ impl ByteCompiler<'_, '_> {
                 jump_info.push_try_continue_label(set_label);
             };
         } else if let Some(node_label) = node.label() {
-            let items = self.jump_info.iter().rev().filter(|info| info.is_loop());
-            let mut emit_for_of_in_exit = 0_u32;
-            let mut loop_info = None;
-            for info in items {
-                if info.label() == Some(node_label) {
-                    loop_info = Some(info);
-                    break;
-                }
-
-                if info.for_of_in_loop() {
-                    emit_for_of_in_exit += 1;
-                }
-            }
-
-            loop_info.expect("Cannot use the undeclared label");
-
-            for _ in 0..emit_for_of_in_exit {
-                self.emit_opcode(Opcode::Pop);
-                self.emit_opcode(Opcode::Pop);
-                self.emit_opcode(Opcode::Pop);
-            }
+            self.emit_for_of_in_exits(node_label);
 
             let (cont_label, set_label) = self.emit_opcode_with_two_operands(Opcode::Continue);
             let loops = self
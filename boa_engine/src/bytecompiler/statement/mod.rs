@@ -1,6 +1,9 @@
-use crate::{bytecompiler::ByteCompiler, vm::Opcode};
+use crate::{
+    bytecompiler::{ByteCompiler, Callable},
+    vm::Opcode,
+};
 
-use boa_ast::Statement;
+use boa_ast::{Expression, Statement};
 
 mod block;
 mod r#break;
@@ -49,15 +52,22 @@ impl ByteCompiler<'_, '_> {
                 self.compile_switch(switch, configurable_globals);
             }
             Statement::Return(ret) => {
-                if let Some(expr) = ret.target() {
-                    self.compile_expr(expr, true);
-                } else {
-                    self.emit(Opcode::PushUndefined, &[]);
+                match ret.target() {
+                    Some(Expression::Call(call)) if self.is_tail_call(call) => {
+                        self.call(Callable::TailCall(call), true);
+                    }
+                    Some(expr) => self.compile_expr(expr, true),
+                    None => self.emit(Opcode::PushUndefined, &[]),
                 }
                 self.emit(Opcode::Return, &[]);
             }
             Statement::Try(t) => self.compile_try(t, use_expr, configurable_globals),
-            Statement::Expression(expr) => self.compile_expr(expr, use_expr),
+            Statement::Expression(expr) => {
+                self.compile_expr(expr, use_expr);
+                if use_expr {
+                    self.emit_opcode(Opcode::SetCompletionValue);
+                }
+            }
             Statement::With(with) => self.compile_with(with, configurable_globals),
             Statement::Empty => {}
         }
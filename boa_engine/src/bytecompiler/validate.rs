@@ -0,0 +1,199 @@
+//! Validation of bytecode that was not produced by the [`ByteCompiler`](super::ByteCompiler).
+//!
+//! This is used to execute arbitrary bytecode when fuzzing the VM, and to load serialized code
+//! blocks. The validation rejects the bytecode that would trivially make the VM panic, like
+//! invalid opcodes, truncated operands, addresses that don't point to an instruction or indices
+//! outside of the code block tables, so that the fuzzer can focus on the behaviour of the
+//! instructions themselves.
+
+use super::{
+    max_stack_size,
+    peephole::{addresses, decode, read_u32},
+    stack_size::StackSizeError,
+};
+use crate::{vm::Opcode, JsValue};
+
+/// Marker for addresses that are not set, like the `finally` address of a `try` without one.
+const DUMMY_ADDRESS: u32 = u32::MAX;
+
+/// The code block table indexed by an operand of an instruction.
+#[derive(Debug, Clone, Copy)]
+enum Table {
+    Literals,
+    StringLiterals,
+    RegExps,
+    Names,
+    InlineCaches,
+    Bindings,
+    Functions,
+    PrivateNames,
+    CompileEnvironments,
+}
+
+/// Returns the table indexed by an operand of `opcode` and the index of that operand, if any.
+const fn table(opcode: Opcode) -> Option<(Table, usize)> {
+    match opcode {
+        Opcode::PushLiteral => Some((Table::Literals, 0)),
+        Opcode::PushRegExp => Some((Table::RegExps, 0)),
+        Opcode::ThrowNewTypeError => Some((Table::StringLiterals, 0)),
+        Opcode::GetPropertyByName | Opcode::SetPropertyByName => Some((Table::InlineCaches, 0)),
+        Opcode::GetMethod
+        | Opcode::DeletePropertyByName
+        | Opcode::DefineOwnPropertyByName
+        | Opcode::DefineClassStaticMethodByName
+        | Opcode::DefineClassMethodByName
+        | Opcode::SetPropertyGetterByName
+        | Opcode::DefineClassStaticGetterByName
+        | Opcode::DefineClassGetterByName
+        | Opcode::SetPropertySetterByName
+        | Opcode::DefineClassStaticSetterByName
+        | Opcode::DefineClassSetterByName => Some((Table::Names, 0)),
+        Opcode::GetName
+        | Opcode::GetNameOrUndefined
+        | Opcode::DeleteName
+        | Opcode::DefVar
+        | Opcode::DefLet
+        | Opcode::DefInitArg
+        | Opcode::DefInitVar
+        | Opcode::DefInitLet
+        | Opcode::DefInitConst
+        | Opcode::SetName => Some((Table::Bindings, 0)),
+        Opcode::GetArrowFunction
+        | Opcode::GetAsyncArrowFunction
+        | Opcode::GetFunction
+        | Opcode::GetFunctionAsync
+        | Opcode::GetGenerator
+        | Opcode::GetGeneratorAsync => Some((Table::Functions, 0)),
+        Opcode::InPrivate
+        | Opcode::GetPrivateField
+        | Opcode::SetPrivateField
+        | Opcode::DefinePrivateField
+        | Opcode::SetPrivateMethod
+        | Opcode::SetPrivateSetter
+        | Opcode::SetPrivateGetter
+        | Opcode::PushClassFieldPrivate
+        | Opcode::PushClassPrivateGetter
+        | Opcode::PushClassPrivateSetter
+        | Opcode::PushClassPrivateMethod => Some((Table::PrivateNames, 0)),
+        Opcode::PushDeclarativeEnvironment | Opcode::PushFunctionEnvironment => {
+            Some((Table::CompileEnvironments, 1))
+        }
+        _ => None,
+    }
+}
+
+/// The sizes of the code block tables that are indexed by the operands of the instructions.
+///
+/// Instructions that index a table that is `None` are rejected, since arbitrary data cannot
+/// provide those tables.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tables<'a> {
+    pub(crate) literals: &'a [JsValue],
+    pub(crate) regexps: Option<usize>,
+    pub(crate) names: usize,
+    pub(crate) inline_caches: usize,
+    pub(crate) bindings: Option<usize>,
+    pub(crate) functions: Option<usize>,
+    pub(crate) private_names: Option<usize>,
+    pub(crate) compile_environments: Option<usize>,
+}
+
+/// Validates arbitrary bytecode for a code block with the given `tables`, when entered with
+/// `initial_height` values on the stack, returning its maximum stack size.
+///
+/// Every byte must belong to a valid instruction with complete operands, every address must point
+/// to the start of an instruction or to the end of the bytecode, every index must be inside of its
+/// table, and the stack must never underflow on any path nor reach an instruction with different
+/// heights.
+pub(crate) fn validate_bytecode(
+    bytecode: &[u8],
+    initial_height: usize,
+    tables: &Tables<'_>,
+) -> Result<u32, StackSizeError> {
+    let max_stack_size = max_stack_size(bytecode, initial_height)?;
+
+    let instructions = decode(bytecode).ok_or(StackSizeError {
+        pc: 0,
+        message: "malformed instruction stream",
+    })?;
+    let mut starts = vec![false; bytecode.len() + 1];
+    for instruction in &instructions {
+        starts[instruction.start] = true;
+    }
+    starts[bytecode.len()] = true;
+
+    for instruction in instructions {
+        let error = |message| StackSizeError {
+            pc: instruction.start,
+            message,
+        };
+
+        let valid_addresses = addresses(bytecode, instruction).all(|address| {
+            address == DUMMY_ADDRESS || starts.get(address as usize).copied().unwrap_or(false)
+        });
+        if !valid_addresses {
+            return Err(error("address does not point to an instruction"));
+        }
+
+        let Some((table, operand)) = table(instruction.opcode) else {
+            continue;
+        };
+        let index = read_u32(bytecode, instruction.start + 1 + operand * 4) as usize;
+        let len = match table {
+            Table::Literals | Table::StringLiterals => Some(tables.literals.len()),
+            Table::RegExps => tables.regexps,
+            Table::Names => Some(tables.names),
+            Table::InlineCaches => Some(tables.inline_caches),
+            Table::Bindings => tables.bindings,
+            Table::Functions => tables.functions,
+            Table::PrivateNames => tables.private_names,
+            Table::CompileEnvironments => tables.compile_environments,
+        };
+        let Some(len) = len else {
+            return Err(error("unsupported instruction"));
+        };
+        let valid_index = match table {
+            Table::StringLiterals => tables.literals.get(index).map_or(false, JsValue::is_string),
+            _ => index < len,
+        };
+        if !valid_index {
+            return Err(error("invalid table index"));
+        }
+    }
+
+    Ok(max_stack_size)
+}
+
+/// Converts the multi-byte operands of `bytecode` between the native byte order and little endian,
+/// the byte order of serialized code blocks.
+///
+/// The conversion is its own inverse, and does nothing on little endian targets.
+pub(crate) fn swap_operands_byte_order(bytecode: &mut [u8]) -> Result<(), StackSizeError> {
+    if cfg!(target_endian = "little") {
+        return Ok(());
+    }
+
+    let instructions = decode(bytecode).ok_or(StackSizeError {
+        pc: 0,
+        message: "malformed instruction stream",
+    })?;
+    for instruction in instructions {
+        let operands = &mut bytecode[instruction.start + 1..instruction.start + instruction.len];
+        match instruction.opcode {
+            Opcode::PushInt8
+            | Opcode::RotateLeft
+            | Opcode::RotateRight
+            | Opcode::SetFunctionName => {}
+            Opcode::PushInt16 | Opcode::PushRational => operands.reverse(),
+            // The index of the function is followed by a single byte.
+            Opcode::GetArrowFunction
+            | Opcode::GetAsyncArrowFunction
+            | Opcode::GetFunction
+            | Opcode::GetFunctionAsync
+            | Opcode::GetGenerator
+            | Opcode::GetGeneratorAsync => operands[..4].reverse(),
+            _ => operands.chunks_exact_mut(4).for_each(<[u8]>::reverse),
+        }
+    }
+    Ok(())
+}
@@ -66,11 +66,15 @@
 //! [class-trait]: ./trait.Class.html
 
 use crate::{
+    context::intrinsics::StandardConstructors,
     error::JsNativeError,
     native_function::NativeFunction,
-    object::{ConstructorBuilder, JsFunction, JsObject, NativeObject, ObjectData, PROTOTYPE},
+    object::{
+        internal_methods::get_prototype_from_constructor, ConstructorBuilder, JsFunction, JsObject,
+        NativeObject, ObjectData, PROTOTYPE,
+    },
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    Context, JsResult, JsValue,
+    Context, JsResult, JsString, JsValue,
 };
 
 /// Native class.
@@ -178,6 +182,55 @@ impl<'ctx, 'host> ClassBuilder<'ctx, 'host> {
         Self { builder }
     }
 
+    /// Creates a builder for a class named `name`, which isn't backed by a [`Class`] type.
+    ///
+    /// `constructor` is called with the new instance as `this`, which inherits from the
+    /// `prototype` of `new.target`. Like a JavaScript constructor, it can return an object to
+    /// replace the instance.
+    pub(crate) fn with_native_constructor(
+        context: &'ctx mut Context<'host>,
+        name: &str,
+        length: usize,
+        constructor: NativeFunction,
+    ) -> Self {
+        let function = NativeFunction::from_copy_closure_with_captures(
+            |new_target, args, (constructor, name), context| {
+                if new_target.is_undefined() {
+                    return Err(JsNativeError::typ()
+                        .with_message(format!(
+                            "cannot call constructor of class `{}` without new",
+                            name.to_std_string_escaped()
+                        ))
+                        .into());
+                }
+
+                let prototype = get_prototype_from_constructor(
+                    new_target,
+                    StandardConstructors::object,
+                    context,
+                )?;
+                let this = JsObject::from_proto_and_data_with_shared_shape(
+                    context.root_shape(),
+                    prototype,
+                    ObjectData::ordinary(),
+                );
+
+                let result = constructor.call(&this.clone().into(), args, context)?;
+                if result.is_object() {
+                    Ok(result)
+                } else {
+                    Ok(this.into())
+                }
+            },
+            (constructor, JsString::from(name)),
+        );
+
+        let mut builder = ConstructorBuilder::new(context, function);
+        builder.name(name);
+        builder.length(length);
+        Self { builder }
+    }
+
     pub(crate) fn build(self) -> JsFunction {
         JsFunction::from_object_unchecked(self.builder.build().into())
     }
@@ -3,7 +3,7 @@ use crate::{
     job::JobCallback,
     object::{JsFunction, JsObject},
     realm::Realm,
-    Context, JsResult, JsValue,
+    Context, JsNativeError, JsResult, JsValue,
 };
 use chrono::{FixedOffset, Local, NaiveDateTime, Utc};
 
@@ -21,9 +21,7 @@ use super::intrinsics::Intrinsics;
 ///
 /// ```
 /// use boa_engine::{
-///     context::{Context, ContextBuilder, HostHooks},
-///     JsNativeError,
-///     JsResult,
+///     context::{CompileStringsRejection, Context, ContextBuilder, HostHooks},
 ///     realm::Realm,
 ///     Source
 /// };
@@ -34,9 +32,10 @@ use super::intrinsics::Intrinsics;
 ///     fn ensure_can_compile_strings(
 ///         &self,
 ///         _realm: Realm,
+///         _source: &str,
 ///         context: &mut Context<'_>,
-///     ) -> JsResult<()> {
-///         Err(JsNativeError::typ().with_message("eval calls not available").into())
+///     ) -> Result<(), CompileStringsRejection> {
+///         Err(CompileStringsRejection::new("eval calls not available"))
 ///     }
 /// }
 /// let hooks: &dyn HostHooks = &Hooks; // Can have additional state.
@@ -102,6 +101,12 @@ pub trait HostHooks {
 
     /// [`HostEnsureCanCompileStrings ( calleeRealm )`][spec]
     ///
+    /// `source` is the code that is going to be compiled: the argument of an `eval` call, or the
+    /// source text of the function created by a `Function` constructor.
+    ///
+    /// Returning a [`CompileStringsRejection`] throws a `TypeError` with the message of the
+    /// rejection.
+    ///
     /// # Requirements
     ///
     /// - If the returned Completion Record is a normal completion, it must be a normal completion
@@ -112,8 +117,9 @@ pub trait HostHooks {
     fn ensure_can_compile_strings(
         &self,
         _realm: Realm,
+        _source: &str,
         _context: &mut Context<'_>,
-    ) -> JsResult<()> {
+    ) -> Result<(), CompileStringsRejection> {
         // The default implementation of HostEnsureCanCompileStrings is to return NormalCompletion(unused).
         Ok(())
     }
@@ -187,10 +193,22 @@ pub trait HostHooks {
     /// Defaults to using [`Local::now`] on all targets, which can cause panics if your platform
     /// doesn't support [`SystemTime::now`][time].
     ///
+    /// `Date` uses this offset for all dates, so dates in a different daylight saving time period
+    /// than the current one get the current offset. See [`Context::tz_offset`].
+    ///
     /// [time]: std::time::SystemTime::now
     fn tz_offset(&self) -> FixedOffset {
         *Local::now().offset()
     }
+
+    /// Generates a pseudo-random number in the range `[0, 1)` for `Math.random`.
+    ///
+    /// This is not called if the context was seeded with [`Context::set_random_seed`].
+    ///
+    /// Defaults to using [`rand::random`], which uses the thread-local generator of [`rand`].
+    fn random_f64(&self) -> f64 {
+        rand::random()
+    }
 }
 
 /// Default implementation of [`HostHooks`], which doesn't carry any state.
@@ -198,3 +216,36 @@ pub trait HostHooks {
 pub struct DefaultHooks;
 
 impl HostHooks for DefaultHooks {}
+
+/// The reason why [`HostHooks::ensure_can_compile_strings`] rejected the compilation of a string.
+///
+/// The rejection is thrown to the script as a `TypeError` with the message of the rejection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileStringsRejection {
+    message: Box<str>,
+}
+
+impl CompileStringsRejection {
+    /// Creates a new `CompileStringsRejection` with the provided message.
+    #[must_use]
+    pub fn new<S>(message: S) -> Self
+    where
+        S: Into<Box<str>>,
+    {
+        Self {
+            message: message.into(),
+        }
+    }
+
+    /// Gets the message of the rejection.
+    #[must_use]
+    pub const fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<CompileStringsRejection> for JsNativeError {
+    fn from(rejection: CompileStringsRejection) -> Self {
+        Self::typ().with_message(rejection.message)
+    }
+}
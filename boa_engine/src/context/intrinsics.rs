@@ -143,6 +143,7 @@ pub struct StandardConstructors {
     weak_ref: StandardConstructor,
     weak_map: StandardConstructor,
     weak_set: StandardConstructor,
+    finalization_registry: StandardConstructor,
     #[cfg(feature = "intl")]
     collator: StandardConstructor,
     #[cfg(feature = "intl")]
@@ -214,6 +215,7 @@ impl Default for StandardConstructors {
             weak_ref: StandardConstructor::default(),
             weak_map: StandardConstructor::default(),
             weak_set: StandardConstructor::default(),
+            finalization_registry: StandardConstructor::default(),
             #[cfg(feature = "intl")]
             collator: StandardConstructor::default(),
             #[cfg(feature = "intl")]
@@ -700,6 +702,17 @@ impl StandardConstructors {
         &self.weak_set
     }
 
+    /// Returns the `FinalizationRegistry` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-constructor
+    #[inline]
+    pub const fn finalization_registry(&self) -> &StandardConstructor {
+        &self.finalization_registry
+    }
+
     /// Returns the `Intl.Collator` constructor.
     ///
     /// More information:
@@ -794,6 +807,12 @@ pub struct IntrinsicObjects {
     /// [`%parseInt%`](https://tc39.es/ecma262/#sec-parseint-string-radix)
     parse_int: JsFunction,
 
+    /// [`%structuredClone%`](https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone)
+    structured_clone: JsFunction,
+
+    /// [`%queueMicrotask%`](https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask)
+    queue_microtask: JsFunction,
+
     /// [`%escape%`](https://tc39.es/ecma262/#sec-escape-string)
     #[cfg(feature = "annex-b")]
     escape: JsFunction,
@@ -828,6 +847,8 @@ impl Default for IntrinsicObjects {
             is_nan: JsFunction::empty_intrinsic_function(false),
             parse_float: JsFunction::empty_intrinsic_function(false),
             parse_int: JsFunction::empty_intrinsic_function(false),
+            structured_clone: JsFunction::empty_intrinsic_function(false),
+            queue_microtask: JsFunction::empty_intrinsic_function(false),
             #[cfg(feature = "annex-b")]
             escape: JsFunction::empty_intrinsic_function(false),
             #[cfg(feature = "annex-b")]
@@ -940,6 +961,20 @@ impl IntrinsicObjects {
         self.parse_int.clone()
     }
 
+    /// Gets the [`%structuredClone%`][spec] intrinsic function.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    pub fn structured_clone(&self) -> JsFunction {
+        self.structured_clone.clone()
+    }
+
+    /// Gets the [`%queueMicrotask%`][spec] intrinsic function.
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask
+    pub fn queue_microtask(&self) -> JsFunction {
+        self.queue_microtask.clone()
+    }
+
     /// Gets the [`%escape%`][spec] intrinsic function.
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-escape-string
@@ -6,34 +6,70 @@ pub(crate) mod icu;
 pub mod intrinsics;
 mod maybe_shared;
 
-pub use hooks::{DefaultHooks, HostHooks};
+#[cfg(test)]
+mod tests;
+
+pub use hooks::{CompileStringsRejection, DefaultHooks, HostHooks};
 #[cfg(feature = "intl")]
 pub use icu::{BoaProvider, IcuError};
 use intrinsics::Intrinsics;
 pub use maybe_shared::MaybeShared;
 
+use chrono::FixedOffset;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
 #[cfg(not(feature = "intl"))]
 pub use std::marker::PhantomData;
-use std::{io::Read, rc::Rc};
+use std::{io::Read, ops::RangeInclusive, rc::Rc};
 
+#[cfg(any(feature = "trace", feature = "instcount"))]
+use crate::vm::Opcode;
 use crate::{
-    builtins,
-    bytecompiler::ByteCompiler,
+    builtins::{
+        self,
+        eval::{Eval, EvalCache, EvalOptions},
+        function::{Function, FunctionKind, ThisMode},
+        number::NumberFormattingMode,
+        regexp::RegExpSite,
+    },
+    bytecompiler::{ByteCompiler, FunctionCompiler, SourceText},
     class::{Class, ClassBuilder},
+    environments::{BindingLookupStats, EnvSnapshot, Environment},
     job::{JobQueue, NativeJob, SimpleJobQueue},
+    js_string,
+    module::{IdleModuleLoader, ModuleLoader},
     native_function::NativeFunction,
-    object::{shape::SharedShape, FunctionObjectBuilder, JsObject},
+    object::{
+        builtins::{JsFunction, JsRegExp},
+        shape::SharedShape,
+        FunctionObjectBuilder, JsObject, VTableObject,
+    },
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
-    realm::Realm,
-    vm::{CallFrame, CodeBlock, Vm},
-    JsResult, JsValue, Source,
+    realm::{Realm, RealmId},
+    value::IntegerCache,
+    vm::{
+        create_function_object, create_function_object_fast, create_function_object_from_template,
+        CallFrame, CodeBlock, CodeKind, CompletionRecord, ContextCleanupGuard, DebuggerHook,
+        DeoptEvent, DeoptReason, InterruptHandle, ResumeHandle, StackFrameInfo, Vm,
+    },
+    JsError, JsNativeError, JsResult, JsString, JsSymbol, JsValue, Source,
 };
-use boa_ast::{ModuleItemList, StatementList};
-use boa_gc::Gc;
+use boa_ast::{
+    expression::Identifier,
+    function::FormalParameterList,
+    operations::{top_level_lexically_declared_names, top_level_var_declared_names},
+    ModuleItemList, Statement, StatementList, StatementListItem,
+};
+use boa_gc::{Gc, GcRefCell, WeakGc};
 use boa_interner::{Interner, Sym};
-use boa_parser::{Error as ParseError, Parser};
+use boa_parser::{Error as ParseError, Parser, Warning};
 use boa_profiler::Profiler;
+use rustc_hash::{FxHashMap, FxHashSet};
+#[cfg(feature = "instcount")]
+use std::collections::HashMap;
+#[cfg(feature = "trace")]
+use std::time::Duration;
 
 /// ECMAScript context. It is the primary way to interact with the runtime.
 ///
@@ -77,6 +113,7 @@ use boa_profiler::Profiler;
 ///
 /// assert_eq!(value.as_number(), Some(12.0))
 /// ```
+#[allow(clippy::struct_excessive_bools)]
 pub struct Context<'host> {
     /// realm holds both the global object and the environment
     realm: Realm,
@@ -87,6 +124,12 @@ pub struct Context<'host> {
     /// Execute in strict mode,
     strict: bool,
 
+    /// If `eval` calls and the `Function` constructors can compile code at runtime.
+    runtime_compilation: bool,
+
+    /// If the source text of the functions of scripts and modules is retained.
+    retain_source_text: bool,
+
     /// Number of instructions remaining before a forced exit
     #[cfg(feature = "fuzz")]
     pub(crate) instructions_remaining: usize,
@@ -95,18 +138,77 @@ pub struct Context<'host> {
 
     pub(crate) kept_alive: Vec<JsObject>,
 
+    /// The `FinalizationRegistry` objects created in this context.
+    ///
+    /// The list lives in the garbage collected heap, since the collector only keeps the weak
+    /// references alive if they are reachable from a traced value.
+    pub(crate) finalization_registries: Gc<GcRefCell<Vec<WeakGc<VTableObject>>>>,
+
     /// ICU related utilities
     #[cfg(feature = "intl")]
     icu: icu::Icu<'host>,
 
     host_hooks: MaybeShared<'host, dyn HostHooks>,
 
+    /// The timezone used by `Date`, overriding [`HostHooks::tz_offset`] if set.
+    timezone: Option<FixedOffset>,
+
+    /// The seeded generator used by `Math.random`, overriding [`HostHooks::random_f64`] if set.
+    ///
+    /// This is a single generator shared by all the realms of the context.
+    rng: Option<ChaCha8Rng>,
+
     job_queue: MaybeShared<'host, dyn JobQueue>,
 
+    module_loader: MaybeShared<'host, dyn ModuleLoader>,
+
     optimizer_options: OptimizerOptions,
+    number_formatting_mode: NumberFormattingMode,
+    property_enumeration_cache: bool,
     root_shape: SharedShape,
+
+    /// Cache of the code compiled by `eval` calls.
+    pub(crate) eval_cache: EvalCache,
+
+    /// Cache of the regular expressions compiled by [`Context::compile_and_cache_regexp`].
+    regexp_cache: FxHashMap<(JsString, JsString), Rc<RegExpSite>>,
+
+    /// Cache of the string representations of small integers.
+    pub(crate) integer_cache: IntegerCache,
+
+    unhandled_error_hook: Option<UnhandledErrorHook<'host>>,
+
+    warning_hook: Option<WarningHook<'host>>,
+
+    /// The callback used to compute the `stack` property of new errors.
+    pub(crate) error_prepare_stack_trace: Option<JsFunction>,
+
+    /// If the `prepareStackTrace` callback is currently running.
+    pub(crate) preparing_stack_trace: bool,
+
+    /// The hook consulted before executing every instruction.
+    pub(crate) debugger_hook: Option<Rc<dyn DebuggerHook + 'host>>,
+
+    /// If the debugger hook returned [`StepInto`](crate::vm::DebuggerAction::StepInto) for the
+    /// last instruction.
+    pub(crate) debugger_step: bool,
+
+    /// The handle that resumes the executions paused by the debugger hook.
+    pub(crate) debugger_resume: ResumeHandle,
+
+    /// The hook called with the deoptimizations of the VM.
+    pub(crate) deopt_hook: Option<DeoptHook<'host>>,
 }
 
+/// A hook called with the errors that are not handled by top-level scripts.
+type UnhandledErrorHook<'host> = Rc<dyn Fn(&JsError, &mut Context<'_>) + 'host>;
+
+/// A hook called with the warnings reported while parsing code.
+type WarningHook<'host> = Rc<dyn Fn(&Warning, &mut Context<'_>) + 'host>;
+
+/// A hook called with the deoptimizations of the VM.
+type DeoptHook<'host> = Rc<dyn Fn(&DeoptEvent, &mut Context<'_>) + 'host>;
+
 impl std::fmt::Debug for Context<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut debug = f.debug_struct("Context");
@@ -117,7 +219,25 @@ impl std::fmt::Debug for Context<'_> {
             .field("vm", &self.vm)
             .field("strict", &self.strict)
             .field("promise_job_queue", &"JobQueue")
+            .field("module_loader", &"ModuleLoader")
             .field("hooks", &"HostHooks")
+            .field("timezone", &self.timezone)
+            .field(
+                "unhandled_error_hook",
+                &self
+                    .unhandled_error_hook
+                    .as_ref()
+                    .map(|_| "UnhandledErrorHook"),
+            )
+            .field(
+                "warning_hook",
+                &self.warning_hook.as_ref().map(|_| "WarningHook"),
+            )
+            .field(
+                "debugger_hook",
+                &self.debugger_hook.as_ref().map(|_| "DebuggerHook"),
+            )
+            .field("deopt_hook", &self.deopt_hook.as_ref().map(|_| "DeoptHook"))
             .field("optimizer_options", &self.optimizer_options);
 
         #[cfg(feature = "intl")]
@@ -140,7 +260,7 @@ impl<'host> Context<'host> {
     /// Create a new [`ContextBuilder`] to specify the [`Interner`] and/or
     /// the icu data provider.
     #[must_use]
-    pub fn builder() -> ContextBuilder<'static, 'static, 'static> {
+    pub fn builder() -> ContextBuilder<'static, 'static, 'static, 'static> {
         ContextBuilder::default()
     }
 
@@ -162,11 +282,20 @@ impl<'host> Context<'host> {
     /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
     /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
     #[allow(clippy::unit_arg, clippy::drop_copy)]
-    pub fn eval_script<R: Read>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
+    pub fn eval_script<R: Read>(&mut self, mut src: Source<'_, R>) -> JsResult<JsValue> {
         let main_timer = Profiler::global().start_event("Script evaluation", "Main");
 
-        let script = self.parse_script(src)?;
-        let code_block = self.compile_script(&script)?;
+        let source_name = Self::source_name(&src);
+        let source_text = self.read_source_text(&mut src)?;
+        let script = match &source_text {
+            Some(text) => self.parse_script_with_strict(
+                Source::from_reader(text.as_bytes(), src.path()),
+                self.strict,
+            )?,
+            None => self.parse_script_with_strict(src, self.strict)?,
+        };
+        let code_block =
+            self.compile_script_with_source(&script, source_text, source_name, false)?;
         let result = self.execute(code_block);
 
         // The main_timer needs to be dropped before the Profiler is.
@@ -176,6 +305,184 @@ impl<'host> Context<'host> {
         result
     }
 
+    /// Evaluates the given script `src` and returns its completion value, the value a REPL prints.
+    ///
+    /// Following the ECMAScript completion-value rules, this is the value of the last statement
+    /// that produces one, including statements nested in blocks and `if` statements. Declarations
+    /// and empty statements or blocks don't produce a value, so they keep the previous one, and a
+    /// script without any value-producing statement returns `undefined`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, JsValue, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let value = context
+    ///     .evaluate_returning_last_statement_value(Source::from_bytes("1; 2;"))
+    ///     .unwrap();
+    /// assert_eq!(value, JsValue::new(2));
+    ///
+    /// let value = context
+    ///     .evaluate_returning_last_statement_value(Source::from_bytes("if (true) { 3 } let x = 1;"))
+    ///     .unwrap();
+    /// assert_eq!(value, JsValue::new(3));
+    ///
+    /// let value = context
+    ///     .evaluate_returning_last_statement_value(Source::from_bytes("let y = 1"))
+    ///     .unwrap();
+    /// assert!(value.is_undefined());
+    /// ```
+    ///
+    /// Like [`Context::eval_script`], this won't run any scheduled promise jobs.
+    pub fn evaluate_returning_last_statement_value<R: Read>(
+        &mut self,
+        src: Source<'_, R>,
+    ) -> JsResult<JsValue> {
+        self.eval_script(src)
+    }
+
+    /// Evaluates the given scripts in order, sharing the same global environment, and returns the
+    /// completion value of the last one.
+    ///
+    /// The evaluation stops at the first script that fails to parse, compile or execute, and its
+    /// error is returned without evaluating the remaining scripts. Returns `undefined` if there
+    /// are no scripts.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let value = context
+    ///     .execute_all([
+    ///         Source::from_bytes("var a = 1;"),
+    ///         Source::from_bytes("a + 3"),
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(4.0));
+    /// ```
+    ///
+    /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
+    /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
+    pub fn execute_all<'path, R, I>(&mut self, sources: I) -> JsResult<JsValue>
+    where
+        R: Read,
+        I: IntoIterator<Item = Source<'path, R>>,
+    {
+        let _timer = Profiler::global().start_event("Scripts evaluation", "Main");
+
+        let mut result = JsValue::undefined();
+        for mut src in sources {
+            let source_name = Self::source_name(&src);
+            let source_text = self.read_source_text(&mut src)?;
+            let script = match &source_text {
+                Some(text) => self.parse_script_with_strict(
+                    Source::from_reader(text.as_bytes(), src.path()),
+                    self.strict,
+                )?,
+                None => self.parse_script_with_strict(src, self.strict)?,
+            };
+            let code_block =
+                self.compile_script_with_source(&script, source_text, source_name, false)?;
+            result = self.execute(code_block)?;
+        }
+        Ok(result)
+    }
+
+    /// Evaluates the given script `src` with `this` as the value of the `this` keyword.
+    ///
+    /// The script runs like the body of a function called with `this`, so its declarations are
+    /// local to the script instead of becoming global bindings. As in a function, a non-strict
+    /// script converts `this` to an object, and uses the global object if `this` is `undefined` or
+    /// `null`. Like [`Context::eval_script`], it returns the completion value of the script.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{object::ObjectInitializer, property::Attribute, Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let this = ObjectInitializer::new(&mut context)
+    ///     .property("x", 42, Attribute::all())
+    ///     .build();
+    /// let value = context
+    ///     .evaluate_with_this(Source::from_bytes("this.x"), &this.into())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    ///
+    /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
+    /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
+    pub fn evaluate_with_this<R: Read>(
+        &mut self,
+        src: Source<'_, R>,
+        this: &JsValue,
+    ) -> JsResult<JsValue> {
+        let _timer = Profiler::global().start_event("Script evaluation with this", "Main");
+
+        let statement_list = self.parse_script(src)?;
+        let code = FunctionCompiler::new()
+            .name(Sym::MAIN)
+            .completion_value(true)
+            .compile(
+                &FormalParameterList::default(),
+                &statement_list,
+                self.realm.environment().compile_env(),
+                self,
+            )?;
+
+        // The script is compiled in the global scope, so it must not capture the environments of
+        // a running execution.
+        let environments = self.vm.environments.pop_to_global();
+        let function = create_function_object(code, false, false, None, true, self);
+        self.vm.environments.extend(environments);
+
+        function.call(this, &[], self)
+    }
+
+    /// Evaluates the given source `src` as a single expression, and returns its value.
+    ///
+    /// Unlike [`Context::eval_script`], the source is always parsed as an expression, so
+    /// ambiguous inputs like `{a: 1}` evaluate to an object literal instead of a block. This is
+    /// useful for calculators and REPLs.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let value = context
+    ///     .evaluate_expression(Source::from_bytes("{a: 1}"))
+    ///     .unwrap();
+    ///
+    /// assert!(value.is_object());
+    /// ```
+    ///
+    /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
+    /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
+    pub fn evaluate_expression<R: Read>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
+        let _timer = Profiler::global().start_event("Expression evaluation", "Main");
+
+        let mut parser = Parser::new(src);
+        if self.strict {
+            parser.set_strict();
+        }
+        let result = parser.parse_expression(&mut self.interner);
+        self.report_warnings(&parser.take_warnings());
+        let expression = result?;
+
+        let mut statement_list = StatementList::new(
+            vec![StatementListItem::from(Statement::Expression(expression))],
+            self.strict,
+        );
+        if !self.optimizer_options().is_empty() {
+            self.optimize_statement_list(&mut statement_list);
+        }
+        let code_block = self.compile_script(&statement_list)?;
+        self.execute(code_block)
+    }
+
     // TODO: remove `ignore` after we implement module execution
     /// Evaluates the given module `src` by compiling down to bytecode, then interpreting the
     /// bytecode into a value.
@@ -193,11 +500,17 @@ impl<'host> Context<'host> {
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
     #[allow(clippy::unit_arg, clippy::drop_copy)]
-    pub fn eval_module<R: Read>(&mut self, src: Source<'_, R>) -> JsResult<JsValue> {
+    pub fn eval_module<R: Read>(&mut self, mut src: Source<'_, R>) -> JsResult<JsValue> {
         let main_timer = Profiler::global().start_event("Module evaluation", "Main");
 
-        let module_item_list = self.parse_module(src)?;
-        let code_block = self.compile_module(&module_item_list)?;
+        let source_name = Self::source_name(&src);
+        let source_text = self.read_source_text(&mut src)?;
+        let module_item_list = match &source_text {
+            Some(text) => self.parse_module(Source::from_reader(text.as_bytes(), src.path()))?,
+            None => self.parse_module(src)?,
+        };
+        let code_block =
+            self.compile_module_with_source(&module_item_list, source_text, source_name)?;
         let result = self.execute(code_block);
 
         // The main_timer needs to be dropped before the Profiler is.
@@ -207,6 +520,130 @@ impl<'host> Context<'host> {
         result
     }
 
+    /// Evaluates the given string `src` following the [`PerformEval`][spec] abstract operation.
+    ///
+    /// Unlike [`Context::eval_script`], this runs all the checks done by the `eval` builtin,
+    /// including [`HostHooks::ensure_can_compile_strings`] and the early errors for invalid
+    /// `new.target` and `super` expressions. `options` selects between a direct or indirect
+    /// evaluation, and the strictness of the caller.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{builtins::eval::EvalOptions, Context};
+    /// let mut context = Context::default();
+    ///
+    /// let value = context.eval_string("1 + 3", EvalOptions::default()).unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(4.0));
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub fn eval_string(&mut self, src: &str, options: EvalOptions) -> JsResult<JsValue> {
+        let _timer = Profiler::global().start_event("String evaluation", "Main");
+
+        Eval::perform_eval(
+            &JsValue::from(src),
+            options.direct,
+            options.direct && options.strict,
+            self,
+        )
+    }
+
+    /// Formats `value` for debugging, similarly to the `util.inspect` function of Node.js.
+    ///
+    /// This is the [`inspect`][crate::value::ValueDisplay::inspect] mode of [`JsValue::display`],
+    /// using the [`number_formatting_mode`][Self::number_formatting_mode] of the context.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let value = context
+    ///     .eval_script(Source::from_bytes("({ a: [1, 'two'], b: new Set([3]) })"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.inspect(&value), "{ a: [ 1, 'two' ], b: Set(1) { 3 } }");
+    /// ```
+    #[must_use]
+    pub fn inspect(&self, value: &JsValue) -> String {
+        value
+            .display()
+            .inspect(true)
+            .number_formatting_mode(self.number_formatting_mode)
+            .to_string()
+    }
+
+    /// Evaluates the string `src` as an indirect `eval` call in the global environment of `realm`.
+    ///
+    /// The realm is only entered for the duration of the evaluation, and the previous realm is
+    /// restored afterwards. Errors created during the evaluation belong to `realm`.
+    pub fn eval_in_realm(&mut self, realm: &Realm, src: &str) -> JsResult<JsValue> {
+        let _timer = Profiler::global().start_event("Realm evaluation", "Main");
+
+        self.with_realm(realm, |context| {
+            Eval::perform_eval(&JsValue::from(src), false, false, context)
+        })
+        .map_err(|err| err.inject_realm(realm.clone()))
+    }
+
+    /// Evaluates the source text of a [JSON module][spec], returning its module namespace object.
+    ///
+    /// This is the module record created for imports with the `type: "json"` import attribute:
+    /// the source text is parsed like in `JSON.parse`, and the parsed value becomes the single
+    /// `default` export of the module. Hosts can use this to resolve JSON imports, passing the raw
+    /// text returned by their module loader.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if `json` is not a valid JSON text.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::Context;
+    /// let mut context = Context::default();
+    ///
+    /// let namespace = context
+    ///     .evaluate_json_module(r#"{ "answer": 42 }"#)
+    ///     .unwrap();
+    /// let default = namespace.get("default", &mut context).unwrap();
+    ///
+    /// assert!(default.is_object());
+    /// ```
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/#sec-parse-json-module
+    pub fn evaluate_json_module(&mut self, json: &str) -> JsResult<JsObject> {
+        let _timer = Profiler::global().start_event("JSON module evaluation", "Main");
+
+        // 1. Let jsonParse be realm's intrinsic object named "%JSON.parse%".
+        // 2. Let json be ? Call(jsonParse, undefined, « source »).
+        let value = builtins::json::Json::parse(&JsValue::undefined(), &[json.into()], self)?;
+
+        // 3. Return CreateDefaultExportSyntheticModule(json).
+        let namespace = JsObject::with_null_proto();
+        namespace.define_property_or_throw(
+            "default",
+            PropertyDescriptor::builder()
+                .value(value)
+                .writable(true)
+                .enumerable(true)
+                .configurable(false),
+            self,
+        )?;
+        namespace.define_property_or_throw(
+            JsSymbol::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value(js_string!("Module"))
+                .writable(false)
+                .enumerable(false)
+                .configurable(false),
+            self,
+        )?;
+        namespace.__prevent_extensions__(self)?;
+
+        Ok(namespace)
+    }
+
     /// Applies optimizations to the [`StatementList`] inplace.
     pub fn optimize_statement_list(
         &mut self,
@@ -220,46 +657,192 @@ impl<'host> Context<'host> {
     pub fn parse_script<R: Read>(
         &mut self,
         src: Source<'_, R>,
+    ) -> Result<StatementList, ParseError> {
+        self.parse_script_with_strict(src, self.strict)
+    }
+
+    /// Parses the given source script, with strict mode semantics if `strict` is `true`.
+    fn parse_script_with_strict<R: Read>(
+        &mut self,
+        src: Source<'_, R>,
+        strict: bool,
     ) -> Result<StatementList, ParseError> {
         let _timer = Profiler::global().start_event("Script parsing", "Main");
         let mut parser = Parser::new(src);
-        if self.strict {
+        if strict {
             parser.set_strict();
         }
-        let mut result = parser.parse_script(&mut self.interner)?;
+        let result = parser.parse_script(&mut self.interner);
+        self.report_warnings(&parser.take_warnings());
+        let mut result = result?;
         if !self.optimizer_options().is_empty() {
             self.optimize_statement_list(&mut result);
         }
         Ok(result)
     }
 
-    /// Parse the given source script.
+    /// Parses the given source text with the module goal.
+    ///
+    /// The module is always parsed in strict mode, allowing `import` and `export` declarations
+    /// and top-level `await`. The returned [`ModuleItemList`] can be inspected to analyze the
+    /// imports and exports of the module without evaluating it.
     pub fn parse_module<R: Read>(
         &mut self,
         src: Source<'_, R>,
     ) -> Result<ModuleItemList, ParseError> {
         let _timer = Profiler::global().start_event("Module parsing", "Main");
         let mut parser = Parser::new(src);
-        parser.parse_module(&mut self.interner)
+        let result = parser.parse_module(&mut self.interner);
+        self.report_warnings(&parser.take_warnings());
+        result
     }
 
     /// Compile the script AST into a `CodeBlock` ready to be executed by the VM.
     pub fn compile_script(&mut self, statement_list: &StatementList) -> JsResult<Gc<CodeBlock>> {
+        self.compile_script_with_source(statement_list, None, None, false)
+    }
+
+    /// Compiles the script AST into a `CodeBlock` ready to be executed by the VM, retaining the
+    /// source text of its functions from `source_text`, the text the script was parsed from.
+    ///
+    /// The source text is retained even if [`Context::retain_source_text`] is disabled.
+    pub fn compile_script_with_source_text(
+        &mut self,
+        statement_list: &StatementList,
+        source_text: &str,
+    ) -> JsResult<Gc<CodeBlock>> {
+        self.compile_script_with_source(statement_list, Some(source_text.into()), None, false)
+    }
+
+    /// Compiles the script AST into a `CodeBlock`, retaining the source text of its functions
+    /// from `source_text` if present, and reporting `source_name` in its stack traces.
+    ///
+    /// The script is compiled in strict mode if `strict` is `true`, even without a directive.
+    fn compile_script_with_source(
+        &mut self,
+        statement_list: &StatementList,
+        source_text: Option<Rc<str>>,
+        source_name: Option<JsString>,
+        strict: bool,
+    ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Script compilation", "Main");
+        self.check_host_lexical_redeclarations(statement_list)?;
         let mut compiler = ByteCompiler::new(
             Sym::MAIN,
-            statement_list.strict(),
+            strict || statement_list.strict(),
             false,
             self.realm.environment().compile_env(),
             self,
         );
+        compiler.source = source_text.map(|text| Rc::new(SourceText::new(text)));
+        compiler.source_name = source_name;
         compiler.create_script_decls(statement_list, false);
         compiler.compile_statement_list(statement_list, true, false);
-        Ok(Gc::new(compiler.finish()))
+        Ok(Gc::new(compiler.finish()?))
+    }
+
+    /// Parses the given source script and compiles it into a `CodeBlock` that is always executed
+    /// with strict mode semantics, as if the script started with a `"use strict"` directive.
+    ///
+    /// Unlike prepending the directive to the source text, this cannot be defeated by the source
+    /// itself, and the strict mode early errors, like `with` statements or `delete` of unqualified
+    /// names, are reported as syntax errors.
+    pub fn compile_script_strict<R: Read>(
+        &mut self,
+        mut src: Source<'_, R>,
+    ) -> JsResult<Gc<CodeBlock>> {
+        let source_name = Self::source_name(&src);
+        let source_text = self.read_source_text(&mut src)?;
+        let statement_list = match &source_text {
+            Some(text) => self
+                .parse_script_with_strict(Source::from_reader(text.as_bytes(), src.path()), true)?,
+            None => self.parse_script_with_strict(src, true)?,
+        };
+        self.compile_script_with_source(&statement_list, source_text, source_name, true)
+    }
+
+    /// Gets the name of `src` reported in stack traces, which is its path if it was read from a
+    /// file.
+    fn source_name<R>(src: &Source<'_, R>) -> Option<JsString> {
+        src.path()
+            .map(|path| JsString::from(path.to_string_lossy().as_ref()))
+    }
+
+    /// Reads the whole text of `src` if [`Context::retain_source_text`] is enabled, to retain it
+    /// in the compiled functions.
+    fn read_source_text<R: Read>(&self, src: &mut Source<'_, R>) -> JsResult<Option<Rc<str>>> {
+        if !self.retain_source_text {
+            return Ok(None);
+        }
+        let text = src.read_to_string().map_err(|err| {
+            JsNativeError::error().with_message(format!("could not read the source code: {err}"))
+        })?;
+        Ok(Some(text.into()))
+    }
+
+    /// Parses the given source script and compiles it into a `CodeBlock` ready to be executed by
+    /// the VM, deferring the compilation of every function until it is first called.
+    ///
+    /// The functions of the script are only parsed, so large scripts with many rarely called
+    /// functions start faster. A function that is never called is never compiled, which can be
+    /// checked with [`CodeBlock::is_compiled`].
+    pub fn intern_and_compile_lazy<R: Read>(
+        &mut self,
+        src: Source<'_, R>,
+    ) -> JsResult<Gc<CodeBlock>> {
+        let statement_list = Rc::new(self.parse_script(src)?);
+
+        let _timer = Profiler::global().start_event("Script compilation", "Main");
+        self.check_host_lexical_redeclarations(&statement_list)?;
+        let mut compiler = ByteCompiler::new(
+            Sym::MAIN,
+            statement_list.strict(),
+            false,
+            self.realm.environment().compile_env(),
+            self,
+        );
+        compiler.lazy_script = Some(statement_list.clone());
+        compiler.create_script_decls(&statement_list, false);
+        compiler.compile_statement_list(&statement_list, true, false);
+        Ok(Gc::new(compiler.finish()?))
+    }
+
+    /// Checks that the top-level declarations of a script don't redeclare a global lexical binding
+    /// defined with [`Context::define_global_lexical`].
+    ///
+    /// This is part of the checks of [`GlobalDeclarationInstantiation`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-globaldeclarationinstantiation
+    fn check_host_lexical_redeclarations(&self, statement_list: &StatementList) -> JsResult<()> {
+        let compile_env = self.realm.environment().compile_env();
+        let compile_env = compile_env.borrow();
+        let redeclared = top_level_lexically_declared_names(statement_list)
+            .into_iter()
+            .chain(top_level_var_declared_names(statement_list))
+            .find(|name| compile_env.is_host_binding(*name));
+
+        if let Some(name) = redeclared {
+            let name = self.interner().resolve_expect(name.sym());
+            return Err(JsNativeError::syntax()
+                .with_message(format!("identifier `{name}` has already been declared"))
+                .into());
+        }
+        Ok(())
     }
 
     /// Compile the module AST into a `CodeBlock` ready to be executed by the VM.
     pub fn compile_module(&mut self, statement_list: &ModuleItemList) -> JsResult<Gc<CodeBlock>> {
+        self.compile_module_with_source(statement_list, None, None)
+    }
+
+    /// Compiles the module AST into a `CodeBlock`, retaining the source text of its functions
+    /// from `source_text` if present, and reporting `source_name` in its stack traces.
+    fn compile_module_with_source(
+        &mut self,
+        statement_list: &ModuleItemList,
+        source_text: Option<Rc<str>>,
+        source_name: Option<JsString>,
+    ) -> JsResult<Gc<CodeBlock>> {
         let _timer = Profiler::global().start_event("Module compilation", "Main");
 
         let mut compiler = ByteCompiler::new(
@@ -269,13 +852,18 @@ impl<'host> Context<'host> {
             self.realm.environment().compile_env(),
             self,
         );
+        compiler.source = source_text.map(|text| Rc::new(SourceText::new(text)));
+        compiler.source_name = source_name;
         compiler.create_module_decls(statement_list, false);
         compiler.compile_module_item_list(statement_list, false);
-        Ok(Gc::new(compiler.finish()))
+        Ok(Gc::new(compiler.finish()?))
     }
 
     /// Call the VM with a `CodeBlock` and return the result.
     ///
+    /// For a script, the result is its completion value: the value of the last statement that
+    /// produces one, so trailing declarations and empty statements or blocks don't change it.
+    ///
     /// Since this function receives a `Gc<CodeBlock>`, cloning the code is very cheap, since it's
     /// just a pointer copy. Therefore, if you'd like to execute the same `CodeBlock` multiple
     /// times, there is no need to re-compile it, and you can just call `clone()` on the
@@ -285,47 +873,283 @@ impl<'host> Context<'host> {
     /// Note that this won't run any scheduled promise jobs; you need to call [`Context::run_jobs`]
     /// on the context or [`JobQueue::run_jobs`] on the provided queue to run them.
     pub fn execute(&mut self, code_block: Gc<CodeBlock>) -> JsResult<JsValue> {
+        self.execute_with_completion(code_block).consume()
+    }
+
+    /// Call the VM with a `CodeBlock` and return its [`CompletionRecord`].
+    ///
+    /// This is the lower-level version of [`Context::execute`], which allows telling apart a
+    /// normal completion from a `return` completion of the executed code, like the `return`
+    /// statement of a function body compiled with [`Context::compile_script`].
+    pub fn execute_with_completion(&mut self, code_block: Gc<CodeBlock>) -> CompletionRecord {
         let _timer = Profiler::global().start_event("Execution", "Main");
 
+        // Nested executions, like `eval` calls, also count towards the recursion limit.
+        if let Err(err) = self.vm.check_stack_limits() {
+            return CompletionRecord::Throw(err);
+        }
+        let env_len = self.vm.environments.len();
         self.vm.push_frame(CallFrame::new(code_block));
 
         // TODO: Here should be https://tc39.es/ecma262/#sec-globaldeclarationinstantiation
 
         self.realm().resize_global_env();
         let record = self.run();
-        self.vm.pop_frame();
-        self.clear_kept_objects();
+        let returned = self.vm.pop_frame().map_or(false, |frame| frame.returned);
 
-        record.consume()
+        // A thrown error leaves the environments pushed by the code on the stack.
+        self.vm.environments.truncate(env_len);
+
+        let record = match record {
+            CompletionRecord::Normal(value) if returned => CompletionRecord::Return(value),
+            record => record,
+        };
+
+        // Nested executions (e.g. `eval` calls) are part of the same synchronous run of the
+        // outer code, so the kept objects must stay alive until the outermost execution ends.
+        if self.vm.frames.is_empty() {
+            self.clear_kept_objects();
+
+            // Only report the errors that are not going to be handled by an outer frame.
+            if let CompletionRecord::Throw(err) = &record {
+                self.report_error(err);
+            }
+        }
+
+        record
     }
 
-    /// Register a global property.
+    /// Creates a function object from the compiled code block of a function.
     ///
-    /// It will return an error if the property is already defined.
+    /// Like the evaluation of a function expression, the function captures the environments that
+    /// are active when it's created, which are the global ones outside of a running execution.
+    /// This allows creating many closures of the same code without compiling it again. The code
+    /// blocks of arrow functions create arrow functions.
     ///
-    /// # Example
+    /// The code block must be the code of an ordinary or arrow function, like the code blocks
+    /// returned by [`Function::shared_codeblock`][crate::builtins::function::Function::shared_codeblock].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` for the code blocks of scripts, modules, async functions, generators
+    /// and class members, and for code blocks that were not compiled in the scope of the active
+    /// environments, since the bindings used by a function are resolved when it's compiled.
+    ///
+    /// # Examples
     /// ```
-    /// use boa_engine::{
-    ///     object::ObjectInitializer,
-    ///     property::{Attribute, PropertyDescriptor},
-    ///     Context,
-    /// };
+    /// # use boa_engine::{Context, JsValue, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let add = context
+    ///     .eval_script(Source::from_bytes("(function add(a, b) { return a + b; })"))
+    ///     .unwrap();
+    /// let code = add
+    ///     .as_object()
+    ///     .and_then(|add| add.borrow().as_function()?.shared_codeblock())
+    ///     .unwrap();
+    ///
+    /// let closure = context.create_function(code).unwrap();
+    /// let result = closure
+    ///     .call(&JsValue::undefined(), &[1.into(), 2.into()], &mut context)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(result, JsValue::new(3));
+    /// ```
+    pub fn create_function(&mut self, code: Gc<CodeBlock>) -> JsResult<JsObject> {
+        if code.kind != CodeKind::Function
+            || code.is_class_constructor
+            || code.class_field_initializer_name.is_some()
+        {
+            return Err(JsNativeError::typ()
+                .with_message("only the code of ordinary and arrow functions can be instantiated")
+                .into());
+        }
+
+        let current_env = self.vm.environments.current_compile_environment();
+        if !code
+            .outer_compile_environment()
+            .map_or(false, |env| Gc::ptr_eq(&env, &current_env))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("the function was compiled in another scope")
+                .into());
+        }
+
+        let arrow = code.this_mode == ThisMode::Lexical;
+        Ok(create_function_object_fast(code, false, arrow, false, self))
+    }
+
+    /// Reports an error that cannot be handled by any script to the unhandled error hook.
+    pub(crate) fn report_error(&mut self, err: &JsError) {
+        if let Some(hook) = self.unhandled_error_hook.clone() {
+            hook(err, self);
+        }
+    }
+
+    /// Sets a hook that is called with the error of every top-level execution that completes
+    /// with an uncaught error.
+    ///
+    /// The hook is called by [`Context::execute`], and thus by [`Context::eval_script`] and
+    /// [`Context::eval_module`], before returning the error to the caller. Errors thrown by code
+    /// running inside another execution, like an `eval` call, are not reported, since they can
+    /// still be caught by the outer code. The errors thrown by the callbacks of `queueMicrotask`
+    /// are also reported when the callbacks run.
     ///
+    /// # Example
+    /// ```
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// # use boa_engine::{Context, Source};
     /// let mut context = Context::default();
     ///
-    /// context
-    ///     .register_global_property("myPrimitiveProperty", 10, Attribute::all())
-    ///     .expect("property shouldn't exist");
+    /// let errors = Rc::new(RefCell::new(Vec::new()));
+    /// let reported = errors.clone();
+    /// context.set_unhandled_error_hook(move |error, _| reported.borrow_mut().push(error.clone()));
     ///
-    /// let object = ObjectInitializer::new(&mut context)
-    ///     .property("x", 0, Attribute::all())
-    ///     .property("y", 1, Attribute::all())
-    ///     .build();
-    /// context
-    ///     .register_global_property("myObjectProperty", object, Attribute::all())
-    ///     .expect("property shouldn't exist");
+    /// assert!(context.eval_script(Source::from_bytes("throw 5")).is_err());
+    /// assert_eq!(errors.borrow().len(), 1);
     /// ```
-    pub fn register_global_property<K, V>(
+    pub fn set_unhandled_error_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&JsError, &mut Context<'_>) + 'host,
+    {
+        self.unhandled_error_hook = Some(Rc::new(hook));
+    }
+
+    /// Sets a hook that is called with every warning about the use of error-prone non-strict
+    /// features, like `with` statements or `arguments.callee`.
+    ///
+    /// Warnings are reported while parsing code with [`Context::parse_script`],
+    /// [`Context::parse_module`], `eval` calls and the `Function` constructors, in source order.
+    /// An `eval` call that reuses cached code reports the warnings of that code again. No
+    /// warnings are reported unless a hook is set. See
+    /// [`WarningKind`](boa_parser::warning::WarningKind) for the kinds of warnings.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let warnings = Rc::new(RefCell::new(Vec::new()));
+    /// let reported = warnings.clone();
+    /// context.set_warning_hook(move |warning, _| reported.borrow_mut().push(*warning));
+    ///
+    /// context.eval_script(Source::from_bytes("var x = 010;")).unwrap();
+    /// assert_eq!(warnings.borrow().len(), 1);
+    /// ```
+    pub fn set_warning_hook<F>(&mut self, hook: F)
+    where
+        F: Fn(&Warning, &mut Context<'_>) + 'host,
+    {
+        self.warning_hook = Some(Rc::new(hook));
+    }
+
+    /// Sets the hook that is consulted before executing every instruction, or removes it if
+    /// `hook` is `None`.
+    ///
+    /// See [`DebuggerHook`] for more information.
+    pub fn set_debugger_hook(&mut self, hook: Option<Rc<dyn DebuggerHook + 'host>>) {
+        self.debugger_hook = hook;
+        self.debugger_step = false;
+    }
+
+    /// Gets a handle that resumes the executions of this context paused by the debugger hook,
+    /// which can be used from another thread.
+    ///
+    /// See [`DebuggerAction::Pause`](crate::vm::DebuggerAction::Pause) for more information.
+    #[must_use]
+    pub fn debugger_resume_handle(&self) -> ResumeHandle {
+        self.debugger_resume.clone()
+    }
+
+    /// Sets a hook that is called every time an instruction of the VM is deoptimized, which is
+    /// useful to find the code that doesn't benefit from the optimizations of the VM.
+    ///
+    /// Currently, the deoptimizations are the misses of the inline caches of the property accesses
+    /// by name, when the `inline-caches` feature is enabled. An inline cache that never cached an
+    /// object doesn't report its misses. See [`DeoptEvent`] for the reported information.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    ///
+    /// let deopts = Rc::new(RefCell::new(Vec::new()));
+    /// let reported = deopts.clone();
+    /// context.trace_deopts(move |event, _| reported.borrow_mut().push(event.reason()));
+    ///
+    /// context
+    ///     .eval_script(Source::from_bytes("const f = (o) => o.x; f({ x: 1 }); f({ x: 2 });"))
+    ///     .unwrap();
+    /// assert!(deopts.borrow().is_empty());
+    /// ```
+    pub fn trace_deopts<F>(&mut self, hook: F)
+    where
+        F: Fn(&DeoptEvent, &mut Context<'_>) + 'host,
+    {
+        self.deopt_hook = Some(Rc::new(hook));
+    }
+
+    /// Calls the deoptimization hook with a deoptimization of the instruction at `pc` of the
+    /// current frame, if the hook is set.
+    pub(crate) fn report_deopt(&mut self, pc: u32, reason: DeoptReason) {
+        if let Some(hook) = self.deopt_hook.clone() {
+            let event = DeoptEvent::new(self.vm.frame().code_block.clone(), pc, reason);
+            hook(&event, self);
+        }
+    }
+
+    /// Calls the warning hook with each of the `warnings`, if the hook is set.
+    pub(crate) fn report_warnings(&mut self, warnings: &[Warning]) {
+        if let Some(hook) = self.warning_hook.clone() {
+            for warning in warnings {
+                hook(warning, self);
+            }
+        }
+    }
+
+    /// Sets the callback used to compute the `stack` property of new error objects, similarly to
+    /// `Error.prepareStackTrace` in V8.
+    ///
+    /// The callback is called by the error constructors with the new error and an array of call
+    /// site objects, starting from the innermost frame, and its return value becomes the `stack`
+    /// property of the error. Each call site has the `getFunctionName`, `getFileName`,
+    /// `getLineNumber`, `getColumnNumber` and `toString` methods.
+    ///
+    /// While a callback is set, `Error.prepareStackTrace` is ignored. Passing `None` removes the
+    /// callback.
+    pub fn set_error_prepare_stack_trace(&mut self, callback: Option<JsFunction>) {
+        self.error_prepare_stack_trace = callback;
+    }
+
+    /// Register a global property.
+    ///
+    /// It will return an error if the property is already defined.
+    ///
+    /// # Example
+    /// ```
+    /// use boa_engine::{
+    ///     object::ObjectInitializer,
+    ///     property::{Attribute, PropertyDescriptor},
+    ///     Context,
+    /// };
+    ///
+    /// let mut context = Context::default();
+    ///
+    /// context
+    ///     .register_global_property("myPrimitiveProperty", 10, Attribute::all())
+    ///     .expect("property shouldn't exist");
+    ///
+    /// let object = ObjectInitializer::new(&mut context)
+    ///     .property("x", 0, Attribute::all())
+    ///     .property("y", 1, Attribute::all())
+    ///     .build();
+    /// context
+    ///     .register_global_property("myObjectProperty", object, Attribute::all())
+    ///     .expect("property shouldn't exist");
+    /// ```
+    pub fn register_global_property<K, V>(
         &mut self,
         key: K,
         value: V,
@@ -392,17 +1216,66 @@ impl<'host> Context<'host> {
     ///
     /// The difference to [`Context::register_global_callable`] is, that the function will not be
     /// `constructable`. Usage of the function as a constructor will produce a `TypeError`.
+    ///
+    /// This is the same as [`Context::register_global_function`].
     pub fn register_global_builtin_callable(
         &mut self,
         name: &str,
         length: usize,
         body: NativeFunction,
     ) -> JsResult<()> {
-        let function = FunctionObjectBuilder::new(self, body)
-            .name(name)
-            .length(length)
-            .constructor(false)
-            .build();
+        self.register_global_function(name, length, body)
+    }
+
+    /// Register a global native function.
+    ///
+    /// This is the simplest way to expose a Rust function to scripts. Like every built-in
+    /// function, it will not be `constructable`, and it will be bound to the global object with
+    /// `writable`, `non-enumerable` and `configurable` attributes.
+    ///
+    /// It will return an error if the global property exists and is not configurable.
+    ///
+    /// # Example
+    /// ```
+    /// use boa_engine::{Context, JsValue, NativeFunction, Source};
+    ///
+    /// let mut context = Context::default();
+    ///
+    /// context
+    ///     .register_global_function(
+    ///         "double",
+    ///         1,
+    ///         NativeFunction::from_fn_ptr(|_, args, context| {
+    ///             let x = args.get(0).cloned().unwrap_or_default().to_number(context)?;
+    ///             Ok(JsValue::new(x * 2.0))
+    ///         }),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let value = context.eval_script(Source::from_bytes("double(21)")).unwrap();
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    pub fn register_global_function(
+        &mut self,
+        name: &str,
+        length: usize,
+        body: NativeFunction,
+    ) -> JsResult<()> {
+        let function = Function::new(
+            FunctionKind::Native {
+                function: body,
+                constructor: None,
+            },
+            self.realm().clone(),
+        );
+        let function = create_function_object_from_template(
+            function,
+            JsString::from(name).into(),
+            length.into(),
+            false,
+            true,
+            self,
+        );
 
         self.global_object().define_property_or_throw(
             name,
@@ -416,6 +1289,69 @@ impl<'host> Context<'host> {
         Ok(())
     }
 
+    /// Register a lazily initialized global property.
+    ///
+    /// The property is defined as an accessor on the global object, whose getter calls `init` on
+    /// the first access. The returned value is then cached by replacing the accessor with a
+    /// `writable`, `non-enumerable` and `configurable` data property, meaning `init` is called at
+    /// most once.
+    ///
+    /// It will return an error if the property is already defined.
+    ///
+    /// # Example
+    /// ```
+    /// use boa_engine::{Context, JsValue, NativeFunction, Source};
+    ///
+    /// let mut context = Context::default();
+    ///
+    /// context
+    ///     .define_getter_global(
+    ///         "expensive",
+    ///         NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(42))),
+    ///     )
+    ///     .expect("property shouldn't exist");
+    ///
+    /// let value = context.eval_script(Source::from_bytes("expensive")).unwrap();
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    pub fn define_getter_global(&mut self, name: &str, init: NativeFunction) -> JsResult<()> {
+        let getter = FunctionObjectBuilder::new(
+            self,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (init, name), context| {
+                    let value = init.call(&JsValue::undefined(), &[], context)?;
+
+                    context.global_object().define_property_or_throw(
+                        name.clone(),
+                        PropertyDescriptor::builder()
+                            .value(value.clone())
+                            .writable(true)
+                            .enumerable(false)
+                            .configurable(true),
+                        context,
+                    )?;
+
+                    Ok(value)
+                },
+                (init, JsString::from(name)),
+            ),
+        )
+        .name(format!("get {name}"))
+        .length(0)
+        .constructor(false)
+        .build();
+
+        self.global_object().define_property_or_throw(
+            name,
+            PropertyDescriptor::builder()
+                .get(getter)
+                .enumerable(false)
+                .configurable(true),
+            self,
+        )?;
+        Ok(())
+    }
+
     /// Register a global class of type `T`, where `T` implements `Class`.
     ///
     /// It will return an error if the global property is already defined.
@@ -451,6 +1387,84 @@ impl<'host> Context<'host> {
         Ok(())
     }
 
+    /// Defines a class named `name` in the global object, without a [`Class`] type backing its
+    /// instances.
+    ///
+    /// `constructor` is called for `new name(...)`, with the new instance as `this`; the
+    /// instance inherits from the `prototype` of `new.target`, so the class can also be extended
+    /// from JavaScript. The constructor can return an object to replace the instance, and
+    /// calling the class without `new` throws a `TypeError`. `init` adds the methods, static
+    /// methods and accessors of the class.
+    ///
+    /// Like the builtin constructors, the class is defined as a writable, non-enumerable and
+    /// configurable property of the global object. Returns the constructor of the class.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, JsArgs, JsNativeError, JsValue, NativeFunction, Source};
+    /// let mut context = Context::default();
+    ///
+    /// context
+    ///     .define_class_from_rust(
+    ///         "Counter",
+    ///         1,
+    ///         NativeFunction::from_fn_ptr(|this, args, context| {
+    ///             let start = args.get_or_undefined(0).clone();
+    ///             this.as_object()
+    ///                 .expect("`this` is the new instance")
+    ///                 .set("count", start, true, context)?;
+    ///             Ok(JsValue::undefined())
+    ///         }),
+    ///         |class| {
+    ///             class.method(
+    ///                 "next",
+    ///                 0,
+    ///                 NativeFunction::from_fn_ptr(|this, _, context| {
+    ///                     let Some(this) = this.as_object() else {
+    ///                         return Err(JsNativeError::typ().with_message("not a Counter").into());
+    ///                     };
+    ///                     let count = this.get("count", context)?.to_number(context)? + 1.0;
+    ///                     this.set("count", count, true, context)?;
+    ///                     Ok(count.into())
+    ///                 }),
+    ///             );
+    ///             Ok(())
+    ///         },
+    ///     )
+    ///     .unwrap();
+    ///
+    /// let value = context
+    ///     .eval_script(Source::from_bytes("new Counter(41).next()"))
+    ///     .unwrap();
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    pub fn define_class_from_rust<F>(
+        &mut self,
+        name: &str,
+        length: usize,
+        constructor: NativeFunction,
+        init: F,
+    ) -> JsResult<JsFunction>
+    where
+        F: FnOnce(&mut ClassBuilder<'_, 'host>) -> JsResult<()>,
+    {
+        let mut class_builder =
+            ClassBuilder::with_native_constructor(self, name, length, constructor);
+        init(&mut class_builder)?;
+
+        let class = class_builder.build();
+        let property = PropertyDescriptor::builder()
+            .value(class.clone())
+            .writable(true)
+            .enumerable(false)
+            .configurable(true);
+
+        self.global_object()
+            .define_property_or_throw(name, property, self)?;
+
+        Ok(class)
+    }
+
     /// Gets the string interner.
     #[inline]
     pub const fn interner(&self) -> &Interner {
@@ -481,12 +1495,77 @@ impl<'host> Context<'host> {
         &self.realm
     }
 
+    /// Returns the id of the currently active realm.
+    #[inline]
+    pub fn current_realm_id(&self) -> RealmId {
+        self.realm.id()
+    }
+
     /// Set the value of trace on the context
     #[cfg(feature = "trace")]
     pub fn set_trace(&mut self, trace: bool) {
         self.vm.trace = trace;
     }
 
+    /// Enables or disables recording the cumulative execution time of every opcode.
+    ///
+    /// Enabling the recording resets any previously collected timings.
+    #[cfg(feature = "trace")]
+    pub fn set_opcode_timing(&mut self, enabled: bool) {
+        self.vm.opcode_timings =
+            enabled.then(|| vec![Duration::ZERO; Opcode::COUNT].into_boxed_slice());
+    }
+
+    /// Returns a histogram of the cumulative execution time of every executed opcode, sorted from
+    /// the most to the least expensive.
+    ///
+    /// The time of an opcode includes the time spent in any nested call it performs. Returns an
+    /// empty histogram if the recording is not enabled with [`Context::set_opcode_timing`].
+    #[cfg(feature = "trace")]
+    #[must_use]
+    pub fn opcode_timings(&self) -> Vec<(Opcode, Duration)> {
+        let Some(timings) = &self.vm.opcode_timings else {
+            return Vec::new();
+        };
+
+        let mut histogram = timings
+            .iter()
+            .enumerate()
+            .filter(|(_, duration)| !duration.is_zero())
+            .map(|(opcode, duration)| {
+                let opcode = Opcode::try_from(opcode as u8).expect("opcode index must be valid");
+                (opcode, *duration)
+            })
+            .collect::<Vec<_>>();
+        histogram.sort_by(|(_, a), (_, b)| b.cmp(a));
+        histogram
+    }
+
+    /// Returns the number of times every opcode has been executed since the context was created
+    /// or since the last call to [`Context::reset_opcode_histogram`].
+    ///
+    /// Opcodes that have never been executed are not included in the histogram.
+    #[cfg(feature = "instcount")]
+    #[must_use]
+    pub fn opcode_histogram(&self) -> HashMap<Opcode, u64> {
+        self.vm
+            .opcode_counts
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count != 0)
+            .map(|(opcode, count)| {
+                let opcode = Opcode::try_from(opcode as u8).expect("opcode index must be valid");
+                (opcode, *count)
+            })
+            .collect()
+    }
+
+    /// Resets the execution counters of all opcodes to zero.
+    #[cfg(feature = "instcount")]
+    pub fn reset_opcode_histogram(&mut self) {
+        self.vm.opcode_counts.fill(0);
+    }
+
     /// Get optimizer options.
     pub const fn optimizer_options(&self) -> OptimizerOptions {
         self.optimizer_options
@@ -496,22 +1575,360 @@ impl<'host> Context<'host> {
         self.optimizer_options = optimizer_options;
     }
 
+    /// Sets the format of the default string representation of numbers.
+    ///
+    /// The mode applies to `String(n)`, to `n.toString()` without a radix, and to
+    /// [`Context::inspect`]. Other conversions of numbers to strings, like property keys or string
+    /// concatenation, always follow the specification. The default is
+    /// [`NumberFormattingMode::Shortest`], which is also the format required by the
+    /// specification.
+    pub fn set_number_formatting_mode(&mut self, mode: NumberFormattingMode) {
+        self.number_formatting_mode = mode;
+    }
+
+    /// Gets the format of the default string representation of numbers.
+    #[must_use]
+    pub const fn number_formatting_mode(&self) -> NumberFormattingMode {
+        self.number_formatting_mode
+    }
+
+    /// Enables or disables the property enumeration cache.
+    ///
+    /// When enabled, `for-in` loops and `Object.keys` reuse the list of string keys of ordinary
+    /// objects with the same shape, instead of collecting the keys of every object again. The
+    /// cached list of a shape is discarded when the shape changes. The cache is enabled by
+    /// default.
+    pub fn set_property_enumeration_cache(&mut self, enabled: bool) {
+        self.property_enumeration_cache = enabled;
+    }
+
+    /// Returns `true` if the property enumeration cache is enabled.
+    #[must_use]
+    pub const fn property_enumeration_cache(&self) -> bool {
+        self.property_enumeration_cache
+    }
+
+    /// Sets the maximum number of code blocks compiled by `eval` calls that are kept for reuse.
+    ///
+    /// Repeated `eval` calls with the same source code, strictness and environment reuse the
+    /// cached code block instead of parsing and compiling the code again. The least recently used
+    /// entries are evicted when the cache is full. A capacity of `0`, the default, disables the
+    /// cache and drops all the cached code blocks.
+    pub fn set_eval_cache_capacity(&mut self, capacity: usize) {
+        self.eval_cache.set_capacity(capacity);
+    }
+
+    /// Gets the maximum number of code blocks compiled by `eval` calls that are kept for reuse.
+    #[must_use]
+    pub const fn eval_cache_capacity(&self) -> usize {
+        self.eval_cache.capacity()
+    }
+
+    /// Sets the range of the integers whose string representations are cached.
+    ///
+    /// Converting an integer of the range to a string, like in `"item" + i`, reuses a single
+    /// string shared by all the conversions of the integer, instead of allocating a new string
+    /// every time. Since strings are compared by value, sharing them isn't observable from
+    /// JavaScript. The cache reserves a slot for every integer of the range, and creates the
+    /// string of an integer the first time it is converted. An empty range, the default,
+    /// disables the cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, Source};
+    ///
+    /// let context = &mut Context::default();
+    /// context.set_integer_cache_range(-128..=127);
+    ///
+    /// let source = Source::from_bytes("let s = ''; for (let i = 0; i < 3; i++) { s += i; } s");
+    /// let value = context.eval_script(source).unwrap();
+    /// assert_eq!(value.as_string().unwrap().to_std_string_escaped(), "012");
+    /// ```
+    pub fn set_integer_cache_range(&mut self, range: RangeInclusive<i32>) {
+        self.integer_cache.set_range(range);
+    }
+
+    /// Gets the range of the integers whose string representations are cached.
+    #[must_use]
+    pub fn integer_cache_range(&self) -> RangeInclusive<i32> {
+        self.integer_cache.range()
+    }
+
+    /// Creates a new `RegExp` object from a pattern and flags, compiling the pattern only the first
+    /// time it is used with the same flags in this context.
+    ///
+    /// Like the objects created by a regular expression literal, the objects created from the
+    /// same pattern and flags share the compiled matcher, but each one has its own state, like
+    /// its `lastIndex` property. Only successfully compiled patterns are cached, and the cache
+    /// lives as long as the context.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if the pattern or the flags are invalid.
+    pub fn compile_and_cache_regexp(&mut self, pattern: &str, flags: &str) -> JsResult<JsRegExp> {
+        let key = (JsString::from(pattern), JsString::from(flags));
+        if let Some(site) = self.regexp_cache.get(&key).cloned() {
+            return JsRegExp::from_object(site.create(self)?);
+        }
+
+        let site = Rc::new(RegExpSite::new(key.0.clone(), key.1.clone()));
+        let regexp = site.create(self)?;
+        self.regexp_cache.insert(key, site);
+        JsRegExp::from_object(regexp)
+    }
+
+    /// Sets the maximum number of values that can be stored in the value stack of the VM.
+    ///
+    /// The value stack holds the operands of the instructions being executed, so deeply nested
+    /// expressions or calls with many arguments can grow it. Exceeding the limit throws a
+    /// catchable `RangeError`. This is independent of the call stack limit, and the default is
+    /// no limit.
+    pub fn set_value_stack_limit(&mut self, limit: usize) {
+        self.vm.value_stack_limit = limit;
+    }
+
+    /// Gets the maximum number of values that can be stored in the value stack of the VM.
+    #[must_use]
+    pub const fn value_stack_limit(&self) -> usize {
+        self.vm.value_stack_limit
+    }
+
+    /// Gets a handle that can interrupt the execution of this context from another thread.
+    ///
+    /// All the handles of a context share the same state, so an interruption requested with one
+    /// of them can be reset with another one.
+    #[must_use]
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.vm.interrupt.clone()
+    }
+
+    /// Sets the number of instructions the VM executes between two checks of the
+    /// [`InterruptHandle`] of this context.
+    ///
+    /// Lower values make the execution stop sooner after an interruption is requested, at the
+    /// cost of some performance. An interval of `0` is treated as `1`, and the default is `1000`.
+    pub fn set_interrupt_check_interval(&mut self, interval: u32) {
+        self.vm.interrupt_check_interval = interval.max(1);
+        self.vm.interrupt_countdown = self
+            .vm
+            .interrupt_countdown
+            .min(self.vm.interrupt_check_interval - 1);
+    }
+
+    /// Gets the number of instructions the VM executes between two checks of the
+    /// [`InterruptHandle`] of this context.
+    #[must_use]
+    pub const fn interrupt_check_interval(&self) -> u32 {
+        self.vm.interrupt_check_interval
+    }
+
+    /// Sets the maximum number of instructions the VM can execute from now on, or removes the
+    /// limit with `None`.
+    ///
+    /// Every executed instruction consumes one unit of the budget. When the budget is exhausted,
+    /// the execution unwinds with an error for which [`JsError::is_instruction_limit_exceeded`]
+    /// returns `true`. Scripts cannot catch this error, and `finally` blocks are not run. Every
+    /// later execution fails in the same way until a new limit is set.
+    pub fn set_instruction_limit(&mut self, limit: Option<u64>) {
+        self.vm.instructions_executed = self.instructions_executed();
+        self.vm.instruction_limit = limit;
+        self.vm.instruction_budget = limit.unwrap_or(u64::MAX);
+        self.vm.instructions_remaining = self.vm.instruction_budget;
+    }
+
+    /// Gets the instruction limit set with [`Context::set_instruction_limit`].
+    #[must_use]
+    pub const fn instruction_limit(&self) -> Option<u64> {
+        self.vm.instruction_limit
+    }
+
+    /// Gets the number of instructions executed by the VM since the creation of the context.
+    #[must_use]
+    pub const fn instructions_executed(&self) -> u64 {
+        self.vm.instructions_executed
+            + (self.vm.instruction_budget - self.vm.instructions_remaining)
+    }
+
+    /// Sets the maximum number of nested call frames, which also bounds the number of nested
+    /// environments.
+    ///
+    /// Function calls, constructor calls, `eval` calls and scopes, like `with` statements, that
+    /// exceed the limit throw a catchable `RangeError` instead of overflowing the native stack or
+    /// growing the environment stack without bound. The default depends on the platform.
+    pub fn set_max_call_depth(&mut self, depth: usize) {
+        self.vm.max_call_depth = depth;
+    }
+
+    /// Gets the maximum number of nested call frames.
+    #[must_use]
+    pub const fn max_call_depth(&self) -> usize {
+        self.vm.max_call_depth
+    }
+
+    /// Sets the maximum size of the value stack of the VM at a call or when entering a new scope.
+    ///
+    /// Calls and scopes that exceed the limit throw a catchable `RangeError`. The default is
+    /// `1024`.
+    pub fn set_stack_size_limit(&mut self, limit: usize) {
+        self.vm.stack_size_limit = limit;
+    }
+
+    /// Gets the maximum size of the value stack of the VM at a call or when entering a new scope.
+    #[must_use]
+    pub const fn stack_size_limit(&self) -> usize {
+        self.vm.stack_size_limit
+    }
+
+    /// Sets whether the garbage collector can only run between call frames.
+    ///
+    /// When enabled, allocations never trigger a garbage collection. Instead, pending collections
+    /// run when the VM pushes or pops a call frame, which makes the pauses more predictable for
+    /// latency-sensitive applications. A frame that allocates a lot without calling other
+    /// functions can grow the heap until it returns. The setting only applies while this context
+    /// executes code, so it doesn't affect the other contexts running on the same thread.
+    pub fn set_gc_between_frames(&mut self, value: bool) {
+        self.vm.gc_between_frames = value;
+    }
+
+    /// Returns `true` if the garbage collector can only run between call frames.
+    #[must_use]
+    pub const fn gc_between_frames(&self) -> bool {
+        self.vm.gc_between_frames
+    }
+
     /// Changes the strictness mode of the context.
     pub fn strict(&mut self, strict: bool) {
         self.strict = strict;
     }
 
+    /// Disables the compilation of code at runtime by scripts.
+    ///
+    /// After calling this, every direct or indirect `eval` call with a string argument, and every
+    /// call to the `Function`, `GeneratorFunction`, `AsyncFunction` and `AsyncGeneratorFunction`
+    /// constructors throws an `EvalError` without parsing its arguments. Code compiled from Rust
+    /// with methods like [`Context::parse_script`] or [`Context::compile_script`] is not affected.
+    pub fn disable_runtime_compilation(&mut self) {
+        self.runtime_compilation = false;
+    }
+
+    /// Returns `true` if scripts can compile code at runtime with `eval` or the `Function`
+    /// constructors.
+    ///
+    /// See [`Context::disable_runtime_compilation`].
+    #[must_use]
+    pub const fn runtime_compilation_enabled(&self) -> bool {
+        self.runtime_compilation
+    }
+
+    /// Sets if the source text of the functions of scripts and modules is retained.
+    ///
+    /// Retaining the source text allows `Function.prototype.toString` to return the exact source
+    /// code of the functions defined by scripts evaluated with [`Context::eval_script`],
+    /// [`Context::execute_all`] or [`Context::compile_script_strict`], and modules evaluated with
+    /// [`Context::eval_module`], at the cost of keeping the whole source code of those scripts
+    /// alive. A script parsed with [`Context::parse_script`] retains its source text if compiled
+    /// with [`Context::compile_script_with_source_text`].
+    ///
+    /// It is disabled by default, and functions without source text are converted to strings with
+    /// the `function name() { [native code] }` form.
+    ///
+    /// The source text of functions created by `eval` and the `Function` constructors is always
+    /// retained.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, Source};
+    ///
+    /// let mut context = Context::default();
+    /// context.retain_source_text(true);
+    ///
+    /// let text = context
+    ///     .eval_script(Source::from_bytes("function add(a, b) { return a + b; } add.toString()"))
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     text.as_string().unwrap().to_std_string_escaped(),
+    ///     "function add(a, b) { return a + b; }"
+    /// );
+    /// ```
+    pub fn retain_source_text(&mut self, retain: bool) {
+        self.retain_source_text = retain;
+    }
+
+    /// Returns `true` if the source text of the functions of scripts and modules is retained.
+    ///
+    /// See [`Context::retain_source_text`].
+    #[must_use]
+    pub const fn source_text_retained(&self) -> bool {
+        self.retain_source_text
+    }
+
     /// Enqueues a [`NativeJob`] on the [`JobQueue`].
     pub fn enqueue_job(&mut self, job: NativeJob) {
         self.job_queue().enqueue_promise_job(job, self);
     }
 
     /// Runs all the jobs in the job queue.
+    ///
+    /// This also enqueues the cleanup callbacks of the `FinalizationRegistry` objects whose
+    /// registered targets have been collected.
     pub fn run_jobs(&mut self) {
+        self.enqueue_finalization_registry_cleanups();
         self.job_queue().run_jobs(self);
         self.clear_kept_objects();
     }
 
+    /// Runs the next job in the job queue, returning `true` if a job was run.
+    ///
+    /// This is useful to integrate the engine with an external event loop, since it gives the host
+    /// control over when each promise reaction runs. Note that the [`JobQueue`] needs to support
+    /// running jobs individually; see [`JobQueue::run_next_job`].
+    pub fn run_jobs_once(&mut self) -> bool {
+        self.enqueue_finalization_registry_cleanups();
+        let ran = self.job_queue().run_next_job(self);
+        self.clear_kept_objects();
+        ran
+    }
+
+    /// Returns `true` if the job queue has jobs waiting to be run.
+    #[must_use]
+    pub fn has_pending_jobs(&self) -> bool {
+        self.job_queue().has_pending_jobs()
+    }
+
+    /// Abstract operation [`HostEnqueueFinalizationRegistryCleanupJob`][spec], for every
+    /// `FinalizationRegistry` with registered targets that were collected.
+    ///
+    /// The cleanup callbacks are enqueued as jobs instead of being called right away, so no
+    /// ECMAScript code ever runs during a garbage collection.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    fn enqueue_finalization_registry_cleanups(&mut self) {
+        let mut cleanups = Vec::new();
+        self.finalization_registries
+            .borrow_mut()
+            .retain(|registry| {
+                let Some(registry) = registry.upgrade() else {
+                    return false;
+                };
+                let registry = JsObject::from(registry);
+                let (callback, held_values) = registry
+                    .borrow_mut()
+                    .as_finalization_registry_mut()
+                    .expect("must be a `FinalizationRegistry`")
+                    .take_collected_cells();
+                cleanups.extend(held_values.into_iter().map(|held| (callback.clone(), held)));
+                true
+            });
+
+        for (callback, held_value) in cleanups {
+            self.enqueue_job(NativeJob::new(move |context| {
+                callback.call(&JsValue::undefined(), &[held_value], context)
+            }));
+        }
+    }
+
     /// Abstract operation [`ClearKeptObjects`][clear].
     ///
     /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
@@ -529,6 +1946,278 @@ impl<'host> Context<'host> {
         self.vm.frames.iter().rev()
     }
 
+    /// Captures the JavaScript call stack of the context, from the innermost frame to the
+    /// outermost one.
+    ///
+    /// This is useful to report where a native function was called from, and the result can be
+    /// attached to an error with [`JsNativeError::with_stack_trace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{Context, JsValue, NativeFunction, Source};
+    /// let mut context = Context::default();
+    ///
+    /// context
+    ///     .register_global_builtin_callable(
+    ///         "trace",
+    ///         0,
+    ///         NativeFunction::from_fn_ptr(|_, _, context| {
+    ///             let trace = context.capture_stack_trace();
+    ///             assert_eq!(trace.len(), 2);
+    ///             assert_eq!(trace[0].to_string(), "inner (2:5)");
+    ///             assert_eq!(trace[1].to_string(), "<main> (4:1)");
+    ///             Ok(JsValue::undefined())
+    ///         }),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// context
+    ///     .eval_script(Source::from_bytes("function inner() {\n    trace();\n}\ninner();"))
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn capture_stack_trace(&self) -> Vec<StackFrameInfo> {
+        self.vm
+            .frames
+            .iter()
+            .rev()
+            .map(|frame| {
+                let code = &frame.code_block;
+                let name = if code.name == Sym::EMPTY_STRING {
+                    js_string!("<anonymous>")
+                } else {
+                    self.interner().resolve_expect(code.name).into_common(false)
+                };
+                StackFrameInfo::new(
+                    name,
+                    code.source_name.clone(),
+                    code.source_position(frame.pc as u32),
+                )
+            })
+            .collect()
+    }
+
+    /// Gets the variables that are visible in the current scope, with their current values.
+    ///
+    /// The environments are walked from the innermost to the outermost one, so a variable hides
+    /// the variables with the same name in outer scopes. Variables that are not initialized yet,
+    /// like a `let` in its temporal dead zone, are skipped. The objects of `with` statements
+    /// contribute their enumerable own data properties, without calling getters or proxy traps.
+    /// Top-level `var` declarations are properties of the global object, and are not included.
+    ///
+    /// This doesn't modify any environment.
+    #[must_use]
+    pub fn scope_variables(&self) -> Vec<(JsString, JsValue)> {
+        let mut seen = FxHashSet::default();
+        let mut variables = Vec::new();
+
+        for environment in self.vm.environments.environments().iter().rev() {
+            match environment {
+                Environment::Declarative(environment) => {
+                    let values = environment.bindings().borrow();
+                    for (name, binding) in environment.compile_env().borrow().bindings() {
+                        let name: JsString = self
+                            .interner()
+                            .resolve_expect(name.sym())
+                            .into_common(false);
+                        // Uninitialized bindings still shadow the outer bindings.
+                        if !seen.insert(name.clone()) {
+                            continue;
+                        }
+                        if let Some(Some(value)) = values.get(binding.index) {
+                            variables.push((name, value.clone()));
+                        }
+                    }
+                }
+                Environment::Object(environment) => {
+                    let object = environment.object().borrow();
+                    for key in object.shape().keys() {
+                        let PropertyKey::String(name) = &key else {
+                            continue;
+                        };
+                        let Some(property) = object.properties().get(&key) else {
+                            continue;
+                        };
+                        if property.enumerable() != Some(true) || !seen.insert(name.clone()) {
+                            continue;
+                        }
+                        if let Some(value) = property.value() {
+                            variables.push((name.clone(), value.clone()));
+                        }
+                    }
+                }
+            }
+        }
+
+        variables
+    }
+
+    /// Defines a top-level lexical binding in the current realm, like a `let` declaration if
+    /// `mutable` is `true` or a `const` declaration otherwise, initialized with `value`.
+    ///
+    /// Unlike the properties of [`Context::global_object`], scripts cannot redeclare the binding:
+    /// compiling a script with a top-level declaration of `name` fails with a `SyntaxError`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyntaxError` if a global lexical binding named `name` already exists.
+    pub fn define_global_lexical<V>(&mut self, name: &str, value: V, mutable: bool) -> JsResult<()>
+    where
+        V: Into<JsValue>,
+    {
+        let identifier = Identifier::new(self.interner.get_or_intern(name));
+        let environment = self.realm.environment().clone();
+        let compile_env = environment.compile_env();
+
+        let index = {
+            let mut compile_env = compile_env.borrow_mut();
+            if compile_env.has_lex_binding(identifier) {
+                return Err(JsNativeError::syntax()
+                    .with_message(format!("identifier `{name}` has already been declared"))
+                    .into());
+            }
+            // New bindings are appended to the bindings of the environment.
+            let index = compile_env.num_bindings();
+            if mutable {
+                compile_env.create_mutable_binding(identifier, false);
+            } else {
+                compile_env.create_immutable_binding(identifier, true);
+            }
+            compile_env.set_host_binding(identifier);
+            index
+        };
+
+        self.realm.resize_global_env();
+        environment.bindings().borrow_mut()[index] = Some(value.into());
+        Ok(())
+    }
+
+    /// Gets the top-level lexical declarations of the current realm, like `let`, `const` and
+    /// `class` declarations, with their current values.
+    ///
+    /// These bindings live in the global declarative environment instead of the global object, so
+    /// they are not properties of [`Context::global_object`]. The bindings are returned in
+    /// declaration order, and the bindings that are not initialized yet, like a `let` in its
+    /// temporal dead zone, are skipped.
+    #[must_use]
+    pub fn global_bindings(&self) -> Vec<(JsString, JsValue)> {
+        let environment = self.realm.environment();
+        let values = environment.bindings().borrow();
+        environment
+            .compile_env()
+            .borrow()
+            .bindings()
+            .into_iter()
+            .filter_map(|(name, binding)| {
+                let value = values.get(binding.index)?.clone()?;
+                let name = self
+                    .interner()
+                    .resolve_expect(name.sym())
+                    .into_common(false);
+                Some((name, value))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the current environment is poisoned.
+    ///
+    /// An environment is poisoned when a direct `eval` call in it, or in an inner environment of
+    /// the same function, may have declared new `var` bindings. Bindings looked up in a poisoned
+    /// environment take a slower path, see [`Context::binding_lookup_stats`].
+    #[must_use]
+    pub fn current_environment_poisoned(&self) -> bool {
+        self.vm.environments.is_current_poisoned()
+    }
+
+    /// Gets the number of runtime binding lookups that took the fast and the slow path since the
+    /// creation of the context, or since the last call to
+    /// [`Context::reset_binding_lookup_stats`].
+    #[must_use]
+    pub const fn binding_lookup_stats(&self) -> BindingLookupStats {
+        self.vm.binding_lookups
+    }
+
+    /// Resets the counters returned by [`Context::binding_lookup_stats`].
+    pub fn reset_binding_lookup_stats(&mut self) {
+        self.vm.binding_lookups = BindingLookupStats::default();
+    }
+
+    /// Takes a snapshot of the current environment stack.
+    ///
+    /// The snapshot can be restored later with [`Context::restore_environments`], for example to
+    /// suspend a cooperative task while running another one on the same context.
+    #[must_use]
+    pub fn environment_snapshot(&self) -> EnvSnapshot {
+        self.vm.environments.snapshot()
+    }
+
+    /// Replaces the current environment stack with a snapshot taken by
+    /// [`Context::environment_snapshot`].
+    ///
+    /// Bindings are looked up exactly as they were when the snapshot was taken.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the snapshot was taken in another realm than the current one, or if
+    /// code is running on the context, like when this is called from a native function, since the
+    /// running frames expect the environments they pushed to still be there.
+    pub fn restore_environments(&mut self, snapshot: &EnvSnapshot) -> JsResult<()> {
+        if !self.vm.frames.is_empty() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot restore the environments while code is running")
+                .into());
+        }
+        if !snapshot.belongs_to(&self.realm) {
+            return Err(JsNativeError::typ()
+                .with_message("the environment snapshot was taken in another realm")
+                .into());
+        }
+        self.vm.environments.restore(snapshot);
+        Ok(())
+    }
+
+    /// Creates a new [`Realm`] with its own intrinsics and global object, initialized with the
+    /// default global bindings.
+    ///
+    /// The new realm is not entered; use [`Context::enter_realm`] or [`Context::eval_in_realm`] to
+    /// run code in it.
+    pub fn create_realm(&mut self) -> JsResult<Realm> {
+        let realm = Realm::create(&*self.host_hooks, &self.root_shape);
+        self.with_realm(&realm, builtins::set_default_global_bindings)?;
+        Ok(realm)
+    }
+
+    /// Runs `f` with `realm` as the currently active realm.
+    ///
+    /// The previously active realm is restored when `f` returns, and also if it panics.
+    ///
+    /// # Examples
+    /// ```
+    /// # use boa_engine::{Context, Source};
+    /// let mut context = Context::default();
+    /// let realm = context.create_realm().unwrap();
+    ///
+    /// context
+    ///     .with_realm(&realm, |context| {
+    ///         context.eval_script(Source::from_bytes("var secondary = true;"))
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let value = context
+    ///     .eval_script(Source::from_bytes("typeof secondary"))
+    ///     .unwrap();
+    /// assert_eq!(value.as_string().unwrap().to_std_string_escaped(), "undefined");
+    /// ```
+    pub fn with_realm<R>(&mut self, realm: &Realm, f: impl FnOnce(&mut Self) -> R) -> R {
+        let active_function = self.vm.active_function.clone();
+        f(&mut ContextCleanupGuard::new(
+            self,
+            realm.clone(),
+            active_function,
+        ))
+    }
+
     /// Replaces the currently active realm with `realm`, and returns the old realm.
     pub fn enter_realm(&mut self, realm: Realm) -> Realm {
         self.vm
@@ -546,10 +2235,68 @@ impl<'host> Context<'host> {
         self.host_hooks.clone()
     }
 
+    /// Gets the timezone used by `Date`, as a fixed offset from UTC.
+    ///
+    /// This is the timezone set by [`ContextBuilder::timezone`] or, if none was set,
+    /// the one returned by [`HostHooks::tz_offset`].
+    ///
+    /// The same offset is used for every date, whatever its time value, so daylight saving time
+    /// transitions are not applied. Dates on the other side of a transition from the current
+    /// date get the current offset instead of the one of their time zone at that date.
+    pub fn tz_offset(&self) -> FixedOffset {
+        self.timezone.unwrap_or_else(|| self.host_hooks.tz_offset())
+    }
+
+    /// Seeds the pseudo-random number generator used by `Math.random`.
+    ///
+    /// After calling this, `Math.random` produces the same sequence of values for the same seed,
+    /// instead of calling [`HostHooks::random_f64`]. Reseeding is allowed at any point, even while
+    /// a script is running, and takes effect on the next call to `Math.random`.
+    ///
+    /// The sequence only depends on the seed, and is stable across platforms and versions of the
+    /// `rand` crate. All the realms of the context draw from the same generator, so the values a
+    /// realm gets depend on the calls to `Math.random` made by the other realms before it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{Context, Source};
+    ///
+    /// let mut context = Context::default();
+    ///
+    /// context.set_random_seed(42);
+    /// let first = context
+    ///     .eval_script(Source::from_bytes("Math.random()"))
+    ///     .unwrap();
+    ///
+    /// context.set_random_seed(42);
+    /// let second = context
+    ///     .eval_script(Source::from_bytes("Math.random()"))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn set_random_seed(&mut self, seed: u64) {
+        self.rng = Some(ChaCha8Rng::seed_from_u64(seed));
+    }
+
+    /// Generates a pseudo-random number in the range `[0, 1)` for `Math.random`.
+    pub(crate) fn random_f64(&mut self) -> f64 {
+        match &mut self.rng {
+            Some(rng) => rng.gen(),
+            None => self.host_hooks.random_f64(),
+        }
+    }
+
     /// Gets the job queue.
     pub fn job_queue(&self) -> MaybeShared<'host, dyn JobQueue> {
         self.job_queue.clone()
     }
+
+    /// Gets the module loader.
+    pub fn module_loader(&self) -> MaybeShared<'host, dyn ModuleLoader> {
+        self.module_loader.clone()
+    }
 }
 
 // ==== Private API ====
@@ -581,10 +2328,12 @@ impl<'host> Context<'host> {
     doc = "The required data in a valid provider is specified in [`BoaProvider`]"
 )]
 #[derive(Default)]
-pub struct ContextBuilder<'icu, 'hooks, 'queue> {
+pub struct ContextBuilder<'icu, 'hooks, 'queue, 'module> {
     interner: Option<Interner>,
     host_hooks: Option<MaybeShared<'hooks, dyn HostHooks>>,
+    timezone: Option<FixedOffset>,
     job_queue: Option<MaybeShared<'queue, dyn JobQueue>>,
+    module_loader: Option<MaybeShared<'module, dyn ModuleLoader>>,
     #[cfg(feature = "intl")]
     icu: Option<icu::Icu<'icu>>,
     #[cfg(not(feature = "intl"))]
@@ -593,17 +2342,24 @@ pub struct ContextBuilder<'icu, 'hooks, 'queue> {
     instructions_remaining: usize,
 }
 
-impl std::fmt::Debug for ContextBuilder<'_, '_, '_> {
+impl std::fmt::Debug for ContextBuilder<'_, '_, '_, '_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         #[derive(Clone, Copy, Debug)]
         struct JobQueue;
         #[derive(Clone, Copy, Debug)]
         struct HostHooks;
+        #[derive(Clone, Copy, Debug)]
+        struct ModuleLoader;
         let mut out = f.debug_struct("ContextBuilder");
 
         out.field("interner", &self.interner)
             .field("host_hooks", &self.host_hooks.as_ref().map(|_| HostHooks))
-            .field("job_queue", &self.job_queue.as_ref().map(|_| JobQueue));
+            .field("timezone", &self.timezone)
+            .field("job_queue", &self.job_queue.as_ref().map(|_| JobQueue))
+            .field(
+                "module_loader",
+                &self.module_loader.as_ref().map(|_| ModuleLoader),
+            );
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -615,7 +2371,7 @@ impl std::fmt::Debug for ContextBuilder<'_, '_, '_> {
     }
 }
 
-impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
+impl<'icu, 'hooks, 'queue, 'module> ContextBuilder<'icu, 'hooks, 'queue, 'module> {
     /// Creates a new [`ContextBuilder`] with a default empty [`Interner`]
     /// and a default `BoaProvider` if the `intl` feature is enabled.
     #[must_use]
@@ -651,7 +2407,7 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
     pub fn icu_provider(
         self,
         provider: BoaProvider<'_>,
-    ) -> Result<ContextBuilder<'_, 'hooks, 'queue>, IcuError> {
+    ) -> Result<ContextBuilder<'_, 'hooks, 'queue, 'module>, IcuError> {
         Ok(ContextBuilder {
             icu: Some(icu::Icu::new(provider)?),
             ..self
@@ -665,7 +2421,7 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
     pub fn host_hooks<'new_hooks, H>(
         self,
         host_hooks: H,
-    ) -> ContextBuilder<'icu, 'new_hooks, 'queue>
+    ) -> ContextBuilder<'icu, 'new_hooks, 'queue, 'module>
     where
         H: Into<MaybeShared<'new_hooks, dyn HostHooks>>,
     {
@@ -675,9 +2431,44 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
         }
     }
 
+    /// Sets the timezone used by `Date` for the context, as a fixed offset from UTC.
+    ///
+    /// This takes precedence over [`HostHooks::tz_offset`], which uses the timezone of the
+    /// system by default. Pinning the timezone makes the local time methods of `Date`
+    /// deterministic across machines.
+    ///
+    /// Only fixed offsets are supported, so IANA time zones with daylight saving time can't be
+    /// represented: every date uses `timezone`, even the dates where the time zone it stands for
+    /// has a different offset. Use UTC or a zone without daylight saving time for exact results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use boa_engine::{context::ContextBuilder, Source};
+    /// use chrono::FixedOffset;
+    ///
+    /// let context = &mut ContextBuilder::new()
+    ///     .timezone(FixedOffset::east_opt(0).unwrap())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let offset = context
+    ///     .eval_script(Source::from_bytes("new Date().getTimezoneOffset()"))
+    ///     .unwrap();
+    /// assert_eq!(offset.as_number(), Some(0.0));
+    /// ```
+    #[must_use]
+    pub const fn timezone(mut self, timezone: FixedOffset) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
     /// Initializes the [`JobQueue`] for the context.
     #[must_use]
-    pub fn job_queue<'new_queue, Q>(self, job_queue: Q) -> ContextBuilder<'icu, 'hooks, 'new_queue>
+    pub fn job_queue<'new_queue, Q>(
+        self,
+        job_queue: Q,
+    ) -> ContextBuilder<'icu, 'hooks, 'new_queue, 'module>
     where
         Q: Into<MaybeShared<'new_queue, dyn JobQueue>>,
     {
@@ -687,6 +2478,24 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
         }
     }
 
+    /// Initializes the [`ModuleLoader`] for the context.
+    ///
+    /// If no loader is provided, the context uses an [`IdleModuleLoader`], which doesn't load any
+    /// module.
+    #[must_use]
+    pub fn module_loader<'new_module, M>(
+        self,
+        module_loader: M,
+    ) -> ContextBuilder<'icu, 'hooks, 'queue, 'new_module>
+    where
+        M: Into<MaybeShared<'new_module, dyn ModuleLoader>>,
+    {
+        ContextBuilder {
+            module_loader: Some(module_loader.into()),
+            ..self
+        }
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -704,6 +2513,7 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
         'icu: 'host,
         'hooks: 'host,
         'queue: 'host,
+        'module: 'host,
     {
         let root_shape = SharedShape::root();
 
@@ -719,6 +2529,8 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
             interner: self.interner.unwrap_or_default(),
             vm,
             strict: false,
+            runtime_compilation: true,
+            retain_source_text: false,
             #[cfg(feature = "intl")]
             icu: self.icu.unwrap_or_else(|| {
                 let buffer: &dyn icu_provider::BufferProvider = boa_icu_provider::buffer();
@@ -728,13 +2540,33 @@ impl<'icu, 'hooks, 'queue> ContextBuilder<'icu, 'hooks, 'queue> {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Gc::default(),
             host_hooks,
+            timezone: self.timezone,
+            rng: None,
             job_queue: self.job_queue.unwrap_or_else(|| {
                 let queue: Rc<dyn JobQueue> = Rc::new(SimpleJobQueue::new());
                 queue.into()
             }),
+            module_loader: self.module_loader.unwrap_or_else(|| {
+                let loader: Rc<dyn ModuleLoader> = Rc::new(IdleModuleLoader);
+                loader.into()
+            }),
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
+            number_formatting_mode: NumberFormattingMode::Shortest,
+            property_enumeration_cache: true,
             root_shape,
+            eval_cache: EvalCache::default(),
+            regexp_cache: FxHashMap::default(),
+            integer_cache: IntegerCache::default(),
+            unhandled_error_hook: None,
+            warning_hook: None,
+            error_prepare_stack_trace: None,
+            preparing_stack_trace: false,
+            debugger_hook: None,
+            debugger_step: false,
+            debugger_resume: ResumeHandle::default(),
+            deopt_hook: None,
         };
 
         builtins::set_default_global_bindings(&mut context)?;
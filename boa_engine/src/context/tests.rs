@@ -0,0 +1,1199 @@
+use std::{cell::RefCell, rc::Rc};
+
+use boa_ast::ModuleItem;
+use boa_gc::{Finalize, Trace};
+use boa_parser::{warning::WarningKind, Parser};
+use indoc::indoc;
+
+use crate::{
+    builtins::{error::ErrorKind, eval::EvalOptions, promise::OperationType},
+    context::{CompileStringsRejection, ContextBuilder, HostHooks},
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    run_test_actions, run_test_actions_with,
+    vm::CompletionRecord,
+    Context, JsArgs, JsNativeErrorKind, JsValue, NativeFunction, Source, TestAction,
+};
+
+#[test]
+fn define_getter_global_runs_once() {
+    run_test_actions([
+        TestAction::run("var calls = 0;"),
+        TestAction::inspect_context(|context| {
+            context
+                .define_getter_global(
+                    "lazy",
+                    NativeFunction::from_fn_ptr(|_, _, context| {
+                        let global = context.global_object();
+                        let calls = global.get("calls", context)?.to_number(context)?;
+                        global.set("calls", calls + 1.0, true, context)?;
+                        Ok(JsValue::new(42))
+                    }),
+                )
+                .unwrap();
+        }),
+        TestAction::assert_eq("calls", 0),
+        TestAction::assert_eq("lazy + lazy + lazy", 126),
+        TestAction::assert_eq("calls", 1),
+        TestAction::assert(indoc! {r#"
+            let desc = Object.getOwnPropertyDescriptor(globalThis, "lazy");
+            desc.value === 42 &&
+                desc.get === undefined &&
+                desc.writable &&
+                !desc.enumerable &&
+                desc.configurable
+        "#}),
+    ]);
+}
+
+#[test]
+fn current_realm_id() {
+    let context = &mut Context::default();
+
+    let first = context.current_realm_id();
+    let first_fn = context
+        .eval_script(Source::from_bytes("(function() {})"))
+        .unwrap();
+
+    let realm = Realm::create(&*context.host_hooks(), &context.root_shape());
+    let old_realm = context.enter_realm(realm);
+    let second = context.current_realm_id();
+    let second_fn = context
+        .eval_script(Source::from_bytes("(function() {})"))
+        .unwrap();
+
+    assert_ne!(first, second);
+    assert_eq!(old_realm.id(), first);
+    assert_eq!(
+        first_fn
+            .as_object()
+            .unwrap()
+            .function_realm_id(context)
+            .unwrap(),
+        first
+    );
+    assert_eq!(
+        second_fn
+            .as_object()
+            .unwrap()
+            .function_realm_id(context)
+            .unwrap(),
+        second
+    );
+
+    context.enter_realm(old_realm);
+    assert_eq!(context.current_realm_id(), first);
+}
+
+#[test]
+fn eval_string() {
+    let context = &mut Context::default();
+
+    let indirect = EvalOptions::default();
+    let direct_strict = EvalOptions {
+        direct: true,
+        strict: true,
+    };
+
+    assert_eq!(
+        context.eval_string("1 + 3", indirect).unwrap(),
+        JsValue::new(4)
+    );
+
+    // Non-strict evaluations can declare new global variables.
+    context.eval_string("var a = 5;", indirect).unwrap();
+    assert_eq!(context.eval_string("a", indirect).unwrap(), JsValue::new(5));
+
+    // Strict evaluations have their own variable environment.
+    context.eval_string("var b = 5;", direct_strict).unwrap();
+    assert_eq!(
+        context.eval_string("typeof b", indirect).unwrap(),
+        JsValue::from("undefined")
+    );
+
+    // Early errors are reported, and failed evaluations don't leave dangling environments.
+    let environments = context.vm.environments.len();
+    for options in [indirect, direct_strict] {
+        for src in [
+            "new.target",
+            "super.a",
+            "super()",
+            "let c; var c;",
+            "throw 1",
+        ] {
+            assert!(context.eval_string(src, options).is_err());
+            assert_eq!(context.vm.environments.len(), environments);
+        }
+    }
+}
+
+#[test]
+fn eval_cache() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            assert_eq!(context.eval_cache_capacity(), 0);
+            context.set_eval_cache_capacity(2);
+            assert_eq!(context.eval_cache_capacity(), 2);
+        }),
+        TestAction::run(indoc! {r#"
+            var x = 1;
+            function outer(x) {
+                return eval("x");
+            }
+            function other() {
+                let x = "other";
+                return eval("x");
+            }
+        "#}),
+        TestAction::assert_eq("eval('x') + eval('x')", 2),
+        TestAction::inspect_context(|context| assert_eq!(context.eval_cache.hits(), 1)),
+        TestAction::assert_eq("outer(5) + outer(6)", 11),
+        TestAction::inspect_context(|context| assert_eq!(context.eval_cache.hits(), 2)),
+        TestAction::assert_eq("other() + other()", "otherother"),
+        TestAction::inspect_context(|context| assert_eq!(context.eval_cache.hits(), 3)),
+        TestAction::assert_eq("(0, eval)('x') + outer(3)", 4),
+        TestAction::assert_eq("eval('x = x + 1; x'); eval('x = x + 1; x')", 3),
+        TestAction::assert_native_error(
+            "function f() { let y; eval('var y'); } f()",
+            ErrorKind::Syntax,
+            "variable declaration y in eval function already exists as a lexical variable declared with `let`",
+        ),
+        TestAction::assert_native_error(
+            "eval('new.target')",
+            ErrorKind::Syntax,
+            "invalid `new.target` expression inside eval",
+        ),
+        TestAction::assert_eq(
+            "function g() { return eval('new.target'); } new g() === g && new g() === g",
+            true,
+        ),
+        TestAction::inspect_context(|context| {
+            context.set_eval_cache_capacity(0);
+            assert_eq!(context.eval_cache_capacity(), 0);
+        }),
+        TestAction::assert_eq("outer(7)", 7),
+    ]);
+}
+
+#[test]
+fn compile_script_strict() {
+    let context = &mut Context::default();
+
+    let code = context
+        .compile_script_strict(Source::from_bytes("(function() { return this; })()"))
+        .unwrap();
+    assert!(code.is_strict());
+    assert_eq!(context.execute(code).unwrap(), JsValue::undefined());
+
+    let code = context
+        .compile_script_strict(Source::from_bytes("undeclared = 1"))
+        .unwrap();
+    let err = context.execute(code).unwrap_err();
+    assert!(matches!(
+        err.try_native(context).unwrap().kind,
+        JsNativeErrorKind::Reference
+    ));
+
+    // Strict mode early errors are rejected even without the directive.
+    for src in ["with ({}) {}", "var a; delete a;"] {
+        let result = context.compile_script_strict(Source::from_bytes(src));
+        assert!(result.is_err(), "`{src}` must be a syntax error");
+    }
+
+    // The same sources are valid sloppy mode scripts.
+    let script = context
+        .parse_script(Source::from_bytes("with ({}) {}"))
+        .unwrap();
+    assert!(!context.compile_script(&script).unwrap().is_strict());
+}
+
+#[test]
+fn create_function() {
+    let context = &mut Context::default();
+
+    let template = context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            var counter = 0;
+            [
+                function () { return ++counter; },
+                () => this,
+                function* () {},
+                async function () {},
+                (function () { let x; return function () { return x; }; })(),
+            ];
+        "#}))
+        .unwrap();
+    let code = |index: u32, context: &mut Context<'_>| {
+        let function = template.as_object().unwrap().get(index, context).unwrap();
+        function
+            .as_object()
+            .and_then(|function| function.borrow().as_function()?.shared_codeblock())
+            .unwrap()
+    };
+
+    let function = code(0, context);
+    let first = context.create_function(function.clone()).unwrap();
+    let second = context.create_function(function).unwrap();
+    assert!(!JsObject::equals(&first, &second));
+    assert!(first.is_constructor());
+    assert_ne!(
+        first.get(js_string!("prototype"), context).unwrap(),
+        second.get(js_string!("prototype"), context).unwrap()
+    );
+
+    // Both closures capture the global environment.
+    for function in [&first, &second, &first] {
+        function.call(&JsValue::undefined(), &[], context).unwrap();
+    }
+    assert_eq!(
+        context.eval_script(Source::from_bytes("counter")).unwrap(),
+        JsValue::new(3)
+    );
+
+    let arrow = code(1, context);
+    let arrow = context.create_function(arrow).unwrap();
+    assert!(!arrow.is_constructor());
+    assert_eq!(
+        arrow.call(&JsValue::new(1), &[], context).unwrap(),
+        context.global_object().into()
+    );
+
+    // Generators, async functions and functions compiled in another scope are rejected.
+    for index in 2..5 {
+        let code = code(index, context);
+        assert!(context.create_function(code).is_err());
+    }
+
+    // So are scripts.
+    let script = context.parse_script(Source::from_bytes("counter")).unwrap();
+    let script = context.compile_script(&script).unwrap();
+    assert!(context.create_function(script).is_err());
+}
+
+#[test]
+fn parse_module() {
+    let context = &mut Context::default();
+
+    let module = context
+        .parse_module(Source::from_bytes(indoc! {r#"
+            import { a } from "./a.js";
+            import * as b from "./b.js";
+            export { c } from "./c.js";
+            export const d = await a;
+        "#}))
+        .unwrap();
+
+    let imports = module
+        .items()
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ImportDeclaration(import) => Some(import.specifier().sym()),
+            _ => None,
+        })
+        .map(|sym| context.interner().resolve_expect(sym).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(imports, ["./a.js", "./b.js"]);
+
+    let requests = module
+        .requests()
+        .into_iter()
+        .map(|sym| context.interner().resolve_expect(sym).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(requests, ["./a.js", "./b.js", "./c.js"]);
+
+    // Modules are always strict.
+    for src in ["with ({}) {}", "var a; delete a;"] {
+        let result = context.parse_module(Source::from_bytes(src));
+        assert!(
+            result.is_err(),
+            "`{src}` must be a syntax error in a module"
+        );
+    }
+
+    // Import and export declarations are still rejected in the script goal.
+    for src in ["import { a } from \"./a.js\";", "export const a = 1;"] {
+        let result = context.parse_script(Source::from_bytes(src));
+        assert!(
+            result.is_err(),
+            "`{src}` must be a syntax error in a script"
+        );
+    }
+}
+
+#[test]
+fn execute_with_completion() {
+    let context = &mut Context::default();
+
+    let script = context
+        .parse_script(Source::from_bytes("let a = 1; a + 1"))
+        .unwrap();
+    let code = context.compile_script(&script).unwrap();
+    assert!(matches!(
+        context.execute_with_completion(code),
+        CompletionRecord::Normal(value) if value == JsValue::from(2)
+    ));
+
+    let script = context.parse_script(Source::from_bytes("throw 3")).unwrap();
+    let code = context.compile_script(&script).unwrap();
+    assert!(matches!(
+        context.execute_with_completion(code),
+        CompletionRecord::Throw(err) if err.as_opaque() == Some(&JsValue::from(3))
+    ));
+
+    // A function body can complete with a `return` completion at the top level.
+    let body = Parser::new(Source::from_bytes("if (true) { return 4; } 5"))
+        .parse_function_body(context.interner_mut(), false, false)
+        .unwrap();
+    let code = context.compile_script(&body).unwrap();
+    assert!(matches!(
+        context.execute_with_completion(code.clone()),
+        CompletionRecord::Return(value) if value == JsValue::from(4)
+    ));
+    assert_eq!(context.execute(code).unwrap(), JsValue::from(4));
+}
+
+#[test]
+fn eval_var_lexical_conflict() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "{ const c = 1; eval('var c'); }",
+            ErrorKind::Syntax,
+            "variable declaration c in eval function already exists as a lexical variable declared with `const`",
+        ),
+        TestAction::assert_native_error(
+            "(function() { class C {} eval('var C'); })()",
+            ErrorKind::Syntax,
+            "variable declaration C in eval function already exists as a lexical variable declared with `class`",
+        ),
+        TestAction::assert_eq(
+            "(function() { { let l = 1; } eval('var l = 2'); return l; })()",
+            2,
+        ),
+    ]);
+}
+
+#[test]
+fn unhandled_error_hook() {
+    let context = &mut Context::default();
+
+    let errors = Rc::new(RefCell::new(Vec::new()));
+    let reported = errors.clone();
+    context.set_unhandled_error_hook(move |error, context| {
+        let error = error.to_opaque(context);
+        reported.borrow_mut().push(error);
+    });
+
+    // Errors caught by the script or by an outer frame are not reported.
+    context
+        .eval_script(Source::from_bytes(
+            "try { throw 1 } catch {}; try { eval('throw 2') } catch {}",
+        ))
+        .unwrap();
+    assert!(errors.borrow().is_empty());
+
+    let result = context.eval_script(Source::from_bytes("throw new TypeError('uncaught')"));
+    assert!(result.is_err());
+
+    let errors = errors.borrow();
+    assert_eq!(errors.len(), 1);
+    let message = errors[0]
+        .as_object()
+        .unwrap()
+        .get("message", context)
+        .unwrap();
+    assert_eq!(message, JsValue::from("uncaught"));
+}
+
+#[test]
+fn ensure_can_compile_strings_rejection() {
+    struct Hooks;
+
+    impl HostHooks for Hooks {
+        fn ensure_can_compile_strings(
+            &self,
+            _realm: Realm,
+            source: &str,
+            _context: &mut Context<'_>,
+        ) -> Result<(), CompileStringsRejection> {
+            if source == "1 + 1" {
+                Ok(())
+            } else {
+                Err(CompileStringsRejection::new("eval is disabled by CSP"))
+            }
+        }
+    }
+
+    let hooks: &dyn HostHooks = &Hooks;
+    let context = &mut ContextBuilder::new().host_hooks(hooks).build().unwrap();
+
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("eval('1 + 1')"))
+            .unwrap(),
+        JsValue::new(2)
+    );
+
+    let error = context
+        .eval_script(Source::from_bytes("eval('2 + 2')"))
+        .unwrap_err();
+    assert_eq!(error.to_string(), "TypeError: eval is disabled by CSP");
+
+    let error = context
+        .eval_script(Source::from_bytes("new Function('return 1')"))
+        .unwrap_err();
+    assert_eq!(error.to_string(), "TypeError: eval is disabled by CSP");
+}
+
+#[test]
+fn promise_rejection_tracker() {
+    #[derive(Default)]
+    struct Hooks {
+        operations: RefCell<Vec<(JsObject, OperationType)>>,
+    }
+
+    impl HostHooks for Hooks {
+        fn promise_rejection_tracker(
+            &self,
+            promise: &JsObject,
+            operation: OperationType,
+            _context: &mut Context<'_>,
+        ) {
+            self.operations
+                .borrow_mut()
+                .push((promise.clone(), operation));
+        }
+    }
+
+    let hooks = Hooks::default();
+    let host_hooks: &dyn HostHooks = &hooks;
+    let context = &mut ContextBuilder::new()
+        .host_hooks(host_hooks)
+        .build()
+        .unwrap();
+
+    let rejected = context
+        .eval_script(Source::from_bytes("var p = Promise.reject(1); p"))
+        .unwrap();
+    let rejected = rejected.as_object().unwrap();
+    assert_eq!(
+        *hooks.operations.borrow(),
+        [(rejected.clone(), OperationType::Reject)]
+    );
+
+    context
+        .eval_script(Source::from_bytes("p.catch(() => {})"))
+        .unwrap();
+    context.run_jobs();
+    assert_eq!(
+        *hooks.operations.borrow(),
+        [
+            (rejected.clone(), OperationType::Reject),
+            (rejected.clone(), OperationType::Handle)
+        ]
+    );
+
+    // Rejecting a promise that already has a handler is not tracked.
+    hooks.operations.borrow_mut().clear();
+    context
+        .eval_script(Source::from_bytes(
+            "new Promise((_, reject) => { Promise.resolve().then(() => reject(2)) }).catch(() => {})",
+        ))
+        .unwrap();
+    context.run_jobs();
+    assert!(hooks.operations.borrow().is_empty());
+}
+
+#[test]
+fn disable_runtime_compilation() {
+    run_test_actions([
+        TestAction::assert_eq("eval('1 + 1')", 2),
+        TestAction::inspect_context(|context| {
+            assert!(context.runtime_compilation_enabled());
+            context.disable_runtime_compilation();
+            assert!(!context.runtime_compilation_enabled());
+        }),
+        TestAction::assert_native_error(
+            "eval('1 + 1')",
+            ErrorKind::Eval,
+            "eval: runtime compilation is disabled",
+        ),
+        TestAction::assert_native_error(
+            "(0, eval)('(')",
+            ErrorKind::Eval,
+            "eval: runtime compilation is disabled",
+        ),
+        TestAction::assert_native_error(
+            "new Function('return 1')",
+            ErrorKind::Eval,
+            "Function: runtime compilation is disabled",
+        ),
+        TestAction::assert_native_error(
+            "Object.getPrototypeOf(function*(){}).constructor('yield 1')",
+            ErrorKind::Eval,
+            "Function: runtime compilation is disabled",
+        ),
+        TestAction::assert_eq("eval(5)", 5),
+        TestAction::assert_eq("1 + 1", 2),
+    ]);
+}
+
+#[test]
+fn eval_string_syntax_error_position() {
+    let context = &mut Context::default();
+
+    let error = context
+        .eval_string("let a = 5;\nlet b = ;", EvalOptions::default())
+        .unwrap_err();
+    let error = error.as_native().unwrap();
+    assert!(matches!(error.kind, crate::JsNativeErrorKind::Syntax));
+
+    let position = error.position().unwrap();
+    assert_eq!(position.line(), 1);
+    assert_eq!(position.column(), 8);
+
+    // The position survives a round trip through an error object.
+    let opaque = crate::JsError::from_opaque(error.to_opaque(context).into());
+    let native = opaque.try_native(context).unwrap();
+    assert_eq!(native.position(), Some(position));
+
+    // Errors thrown while executing the code have no position.
+    let error = context
+        .eval_string("null.a", EvalOptions::default())
+        .unwrap_err();
+    assert!(error
+        .as_native()
+        .and_then(crate::JsNativeError::position)
+        .is_none());
+}
+
+#[test]
+fn inspect() {
+    let context = &mut Context::default();
+    let mut inspect = |source: &str| {
+        let value = context.eval_script(Source::from_bytes(source)).unwrap();
+        context.inspect(&value)
+    };
+
+    assert_eq!(
+        inspect("[{ a: 1, b: 'x' }, { 'c-d': [1, , , 2] }]"),
+        "[ { a: 1, b: 'x' }, { 'c-d': [ 1, <2 empty items>, 2 ] } ]"
+    );
+    assert_eq!(
+        inspect("new Map([['a', 1], [{}, new Set()]])"),
+        "Map(2) { 'a' => 1, {} => Set(0) {} }"
+    );
+    assert_eq!(
+        inspect("let o = { name: 'o' }; o.self = o; o.list = [o]; o"),
+        "{ name: 'o', self: [Circular], list: [ [Circular] ] }"
+    );
+    assert_eq!(
+        inspect("class Point { constructor() { this.x = 1 } }; new Point()"),
+        "Point { x: 1 }"
+    );
+    assert_eq!(
+        inspect("[function f() {}, new TypeError('oops'), Object.create(null)]"),
+        "[ [Function: f], [TypeError: oops], [Object: null prototype] {} ]"
+    );
+    assert_eq!(
+        inspect("let a = []; a.length = 4294967295; a[3] = 'x'; a"),
+        "[ <3 empty items>, 'x', <4294967291 empty items> ]"
+    );
+    assert_eq!(
+        inspect("Array.from({ length: 150 }, (_, i) => i)"),
+        format!(
+            "[ {}, ... 50 more items ]",
+            (0..100)
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    );
+}
+
+#[test]
+fn eval_in_realm() {
+    let context = &mut Context::default();
+    let main_realm = context.current_realm_id();
+
+    let realm = Realm::create(&*context.host_hooks(), &context.root_shape());
+    context.eval_in_realm(&realm, "var answer = 42;").unwrap();
+
+    assert_eq!(
+        context.eval_in_realm(&realm, "answer").unwrap(),
+        JsValue::new(42)
+    );
+    assert_eq!(context.current_realm_id(), main_realm);
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("typeof answer"))
+            .unwrap(),
+        JsValue::from("undefined")
+    );
+
+    let error = context.eval_in_realm(&realm, "(").unwrap_err();
+    let error = error.to_opaque(context);
+    let prototype = error.as_object().unwrap().prototype().unwrap();
+    assert!(JsObject::equals(
+        &prototype,
+        &realm.intrinsics().constructors().syntax_error().prototype()
+    ));
+    assert_eq!(context.current_realm_id(), main_realm);
+}
+
+#[test]
+fn create_realm() {
+    let context = &mut Context::default();
+    let main_realm = context.current_realm_id();
+
+    let realm = context.create_realm().unwrap();
+    assert_eq!(context.current_realm_id(), main_realm);
+    assert_ne!(realm.id(), main_realm);
+
+    // The new realm has its own set of global bindings.
+    assert_eq!(
+        context
+            .eval_in_realm(&realm, "globalThis.Array = 1; [typeof eval, Array]")
+            .map(|value| context.inspect(&value))
+            .unwrap(),
+        "[ 'function', 1 ]"
+    );
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("typeof Array"))
+            .unwrap(),
+        JsValue::from("function")
+    );
+}
+
+#[test]
+fn evaluate_json_module() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            let namespace = context
+                .evaluate_json_module(r#"{ "someKey": "value", "list": [1, 2, 3] }"#)
+                .unwrap();
+            context
+                .register_global_property("json", namespace, Attribute::all())
+                .unwrap();
+        }),
+        TestAction::assert_eq("json.default.someKey", "value"),
+        TestAction::assert_eq("json.default.list.length", 3),
+        TestAction::assert_eq("Object.keys(json).join()", "default"),
+        TestAction::assert_eq("Object.prototype.toString.call(json)", "[object Module]"),
+        TestAction::assert("!Object.isExtensible(json)"),
+        TestAction::inspect_context(|context| {
+            let error = context
+                .evaluate_json_module("{ someKey: 1 }")
+                .expect_err("invalid JSON should not evaluate")
+                .try_native(context)
+                .unwrap();
+            assert!(matches!(error.kind, crate::JsNativeErrorKind::Syntax));
+        }),
+    ]);
+}
+
+#[test]
+fn gc_between_frames() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_callable(
+                    "gcCount",
+                    0,
+                    NativeFunction::from_fn_ptr(|_, _, _| {
+                        Ok(JsValue::new(i32::try_from(boa_gc::collections()).unwrap()))
+                    }),
+                )
+                .unwrap();
+            context.set_gc_between_frames(true);
+            assert!(context.gc_between_frames());
+        }),
+        TestAction::run(indoc! {r#"
+            function allocate() {
+                const before = gcCount();
+                const objects = [];
+                for (let i = 0; i < 10000; i++) {
+                    objects.push({ i });
+                }
+                return gcCount() - before;
+            }
+            var start = gcCount();
+            var during = allocate();
+        "#}),
+        TestAction::assert_eq("during", 0),
+        TestAction::assert("gcCount() > start"),
+        TestAction::inspect_context(|context| context.set_gc_between_frames(false)),
+    ]);
+}
+
+#[test]
+fn gc_between_frames_is_per_context() {
+    let safe_points_only =
+        || NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(boa_gc::safe_points_only())));
+    let first = &mut Context::default();
+    let second = &mut Context::default();
+    for context in [&mut *first, &mut *second] {
+        context
+            .register_global_callable("safePointsOnly", 0, safe_points_only())
+            .unwrap();
+    }
+    first.set_gc_between_frames(true);
+
+    let check = |context: &mut Context<'_>| {
+        context
+            .eval_script(Source::from_bytes("safePointsOnly()"))
+            .unwrap()
+    };
+    assert_eq!(check(first), JsValue::new(true));
+    assert_eq!(check(second), JsValue::new(false));
+    assert_eq!(check(first), JsValue::new(true));
+    assert!(!boa_gc::safe_points_only());
+}
+
+#[test]
+fn register_global_function() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_function(
+                    "add",
+                    2,
+                    NativeFunction::from_fn_ptr(|_, args, context| {
+                        let x = args.get_or_undefined(0).to_number(context)?;
+                        let y = args.get_or_undefined(1).to_number(context)?;
+                        Ok(JsValue::new(x + y))
+                    }),
+                )
+                .unwrap();
+        }),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_string!("add")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert(indoc! {r#"
+            const desc = Object.getOwnPropertyDescriptor(globalThis, "add");
+            desc.writable && !desc.enumerable && desc.configurable
+        "#}),
+        TestAction::assert_native_error("new add(1, 2)", ErrorKind::Type, "not a constructor"),
+    ]);
+}
+
+#[test]
+fn warning_hook() {
+    let context = &mut Context::default();
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let reported = warnings.clone();
+    context.set_warning_hook(move |warning, _| reported.borrow_mut().push(*warning));
+
+    let result = context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            function f() {
+                with ({ x: 1 }) {
+                    return arguments.callee === f && x === 1;
+                }
+            }
+            f()
+        "#}))
+        .unwrap();
+    assert_eq!(result, JsValue::new(true));
+
+    let warnings = warnings.borrow();
+    let kinds = warnings
+        .iter()
+        .map(boa_parser::Warning::kind)
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, [WarningKind::With, WarningKind::ArgumentsCallee]);
+    assert_eq!(warnings[0].span().start().line_number(), 2);
+    assert_eq!(warnings[1].span().start().line_number(), 3);
+}
+
+#[test]
+fn warning_hook_dynamic_code() {
+    let context = &mut Context::default();
+    context.set_eval_cache_capacity(4);
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let reported = warnings.clone();
+    context.set_warning_hook(move |warning, _| reported.borrow_mut().push(warning.kind()));
+
+    context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            for (let i = 0; i < 2; i++) {
+                (0, eval)("with ({}) {}");
+            }
+            new Function("a", "return arguments.callee");
+        "#}))
+        .unwrap();
+
+    assert_eq!(context.eval_cache.hits(), 1);
+    assert_eq!(
+        *warnings.borrow(),
+        [
+            WarningKind::With,
+            WarningKind::With,
+            WarningKind::ArgumentsCallee
+        ]
+    );
+}
+
+#[test]
+fn evaluate_expression() {
+    let context = &mut Context::default();
+
+    let object = context
+        .evaluate_expression(Source::from_bytes("{a:1}"))
+        .unwrap();
+    let object = object.as_object().unwrap();
+    assert_eq!(
+        object.get(js_string!("a"), context).unwrap(),
+        JsValue::new(1)
+    );
+
+    let value = context
+        .evaluate_expression(Source::from_bytes("1+1"))
+        .unwrap();
+    assert_eq!(value, JsValue::new(2));
+
+    let value = context
+        .evaluate_expression(Source::from_bytes("[1, 2].length;\n"))
+        .unwrap();
+    assert_eq!(value, JsValue::new(2));
+
+    // The same input is a block with a labelled statement when evaluated as a script.
+    let value = context.eval_script(Source::from_bytes("{a:1}")).unwrap();
+    assert_eq!(value, JsValue::new(1));
+
+    let error = context
+        .evaluate_expression(Source::from_bytes("let a = 1"))
+        .unwrap_err();
+    assert!(matches!(
+        error.as_native().unwrap().kind,
+        crate::JsNativeErrorKind::Syntax
+    ));
+}
+
+#[test]
+fn execute_all() {
+    let context = &mut Context::default();
+
+    let value = context
+        .execute_all([
+            Source::from_bytes("var counter = 1; let step = 2;"),
+            Source::from_bytes("function next() { return counter += step; }"),
+            Source::from_bytes("next(); next()"),
+        ])
+        .unwrap();
+    assert_eq!(value, JsValue::new(5));
+
+    let value = context
+        .execute_all(std::iter::empty::<Source<'_, &[u8]>>())
+        .unwrap();
+    assert!(value.is_undefined());
+
+    // The scripts after the first error are not evaluated.
+    let error = context
+        .execute_all([
+            Source::from_bytes("counter = 10;"),
+            Source::from_bytes("throw new RangeError('stop');"),
+            Source::from_bytes("counter = 20;"),
+        ])
+        .unwrap_err();
+    assert_eq!(
+        error.to_opaque(context).display().to_string(),
+        "RangeError: stop"
+    );
+    assert_eq!(
+        context.eval_script(Source::from_bytes("counter")).unwrap(),
+        JsValue::new(10)
+    );
+}
+
+#[test]
+fn with_realm() {
+    let context = &mut Context::default();
+    let main_realm = context.current_realm_id();
+    let realm = context.create_realm().unwrap();
+
+    let value = context.with_realm(&realm, |context| {
+        assert_eq!(context.current_realm_id(), realm.id());
+        context
+            .eval_script(Source::from_bytes("var secondary = 'secondary'; secondary"))
+            .unwrap()
+    });
+    assert_eq!(value, JsValue::from("secondary"));
+    assert_eq!(context.current_realm_id(), main_realm);
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("typeof secondary"))
+            .unwrap(),
+        JsValue::from("undefined")
+    );
+
+    // The original realm is also restored after a panic in the closure.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        context.with_realm(&realm, |_| panic!("closure panicked"));
+    }));
+    assert!(result.is_err());
+    assert_eq!(context.current_realm_id(), main_realm);
+    assert_eq!(
+        context
+            .with_realm(&realm, |context| context
+                .eval_script(Source::from_bytes("secondary")))
+            .unwrap(),
+        JsValue::from("secondary")
+    );
+}
+
+#[test]
+fn scope_variables() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            assert!(context
+                .scope_variables()
+                .iter()
+                .all(|(name, _)| name != &js_string!("outer")));
+            context
+                .register_global_callable(
+                    "scope",
+                    0,
+                    NativeFunction::from_fn_ptr(|_, _, context| {
+                        let object = JsObject::with_object_proto(context.intrinsics());
+                        for (name, value) in context.scope_variables() {
+                            object.create_data_property_or_throw(name, value, context)?;
+                        }
+                        Ok(object.into())
+                    }),
+                )
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            let outer = 1;
+            let shadowed = "outer";
+            function f(param) {
+                let shadowed = "inner";
+                const target = { fromWith: true, get getter() { throw new Error(); } };
+                with (target) {
+                    {
+                        var result = scope();
+                        let tdz = 1;
+                    }
+                }
+                return result;
+            }
+            var variables = f(42);
+        "#}),
+        TestAction::assert_eq("variables.shadowed", "inner"),
+        TestAction::assert_eq("variables.param", 42),
+        TestAction::assert_eq("variables.outer", 1),
+        TestAction::assert_eq("variables.fromWith", true),
+        TestAction::assert("!('tdz' in variables)"),
+        TestAction::assert("!('getter' in variables)"),
+        TestAction::assert_eq(
+            indoc! {r#"
+                const keys = Object.keys(variables);
+                keys.indexOf("fromWith") < keys.indexOf("param") &&
+                    keys.indexOf("param") < keys.indexOf("outer")
+            "#},
+            true,
+        ),
+        TestAction::inspect_context(|context| {
+            let variables = context.scope_variables();
+            assert!(variables.contains(&(js_string!("outer"), JsValue::new(1))));
+        }),
+    ]);
+}
+
+#[test]
+fn global_bindings() {
+    let context = &mut Context::default();
+    assert!(context.global_bindings().is_empty());
+
+    context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            let a = 1;
+            const b = "b";
+            var notLexical = true;
+            function notLexicalEither() {}
+            { let inner = 2; }
+        "#}))
+        .unwrap();
+    context
+        .eval_script(Source::from_bytes("class C {}; let c = 3;"))
+        .unwrap();
+
+    let names = context
+        .global_bindings()
+        .into_iter()
+        .map(|(name, _)| name.to_std_string_escaped())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a", "b", "C", "c"]);
+    assert!(context
+        .global_bindings()
+        .contains(&(js_string!("b"), JsValue::from("b"))));
+
+    // Bindings in their temporal dead zone are skipped.
+    let result = context.eval_script(Source::from_bytes("let d = (() => { throw 1; })();"));
+    assert!(result.is_err());
+    assert!(context
+        .global_bindings()
+        .iter()
+        .all(|(name, _)| name != &js_string!("d")));
+}
+
+#[test]
+fn define_global_lexical() {
+    let context = &mut Context::default();
+    context.define_global_lexical("constant", 1, false).unwrap();
+    context
+        .define_global_lexical("variable", js_string!("a"), true)
+        .unwrap();
+    assert!(context.define_global_lexical("constant", 2, true).is_err());
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("constant", 1),
+            TestAction::assert("!('constant' in globalThis)"),
+            TestAction::assert_native_error(
+                "constant = 2",
+                ErrorKind::Type,
+                "cannot mutate an immutable binding 'constant'",
+            ),
+            TestAction::run("variable += 'b'"),
+            TestAction::assert_eq("variable", "ab"),
+            TestAction::assert_native_error(
+                "let constant = 3;",
+                ErrorKind::Syntax,
+                "identifier `constant` has already been declared",
+            ),
+            TestAction::assert_native_error(
+                "var variable;",
+                ErrorKind::Syntax,
+                "identifier `variable` has already been declared",
+            ),
+            TestAction::assert_eq("{ let constant = 4; constant }", 4),
+            TestAction::assert_native_error(
+                "(0, eval)('var constant;')",
+                ErrorKind::Syntax,
+                "variable declaration constant in eval function already exists as a lexical variable declared with `const`",
+            ),
+            TestAction::assert_native_error(
+                "(0, eval)('for (var variable of [1]) {}')",
+                ErrorKind::Syntax,
+                "variable declaration variable in eval function already exists as a lexical variable declared with `let`",
+            ),
+            TestAction::assert_eq("variable", "ab"),
+        ],
+        context,
+    );
+
+    assert!(context
+        .global_bindings()
+        .contains(&(js_string!("variable"), JsValue::from("ab"))));
+}
+
+#[test]
+fn evaluate_with_this() {
+    let context = &mut Context::default();
+
+    let this = JsObject::with_object_proto(context.intrinsics());
+    this.set(js_string!("x"), 5, false, context).unwrap();
+
+    let value = context
+        .evaluate_with_this(Source::from_bytes("var y = this.x; y"), &this.into())
+        .unwrap();
+    assert_eq!(value, JsValue::new(5));
+
+    // Declarations of the script don't leak into the global scope.
+    let leaked = context.eval_script(Source::from_bytes("typeof y")).unwrap();
+    assert_eq!(leaked, JsValue::new(js_string!("undefined")));
+}
+
+#[test]
+fn define_class_from_rust() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .define_class_from_rust(
+                    "Point",
+                    2,
+                    NativeFunction::from_fn_ptr(|this, args, context| {
+                        let this = this.as_object().expect("`this` must be the new instance");
+                        this.set("x", args.get_or_undefined(0).clone(), true, context)?;
+                        this.set("y", args.get_or_undefined(1).clone(), true, context)?;
+                        Ok(JsValue::undefined())
+                    }),
+                    |class| {
+                        class.method(
+                            "lengthSquared",
+                            0,
+                            NativeFunction::from_fn_ptr(|this, _, context| {
+                                let this = this.to_object(context)?;
+                                let x = this.get("x", context)?.to_number(context)?;
+                                let y = this.get("y", context)?.to_number(context)?;
+                                Ok(x.mul_add(x, y * y).into())
+                            }),
+                        );
+                        Ok(())
+                    },
+                )
+                .unwrap();
+        }),
+        TestAction::assert_eq("new Point(3, 4).lengthSquared()", 25),
+        TestAction::assert("new Point(1, 2) instanceof Point"),
+        TestAction::assert("Point.name === 'Point' && Point.length === 2"),
+        TestAction::assert("!Object.keys(globalThis).includes('Point')"),
+        TestAction::assert(indoc! {r#"
+            class Point3 extends Point {
+                constructor(x, y, z) {
+                    super(x, y);
+                    this.z = z;
+                }
+            }
+            const p = new Point3(1, 2, 3);
+            p instanceof Point3 && p.lengthSquared() === 5 && p.z === 3
+        "#}),
+        TestAction::assert_native_error(
+            "Point(1, 2)",
+            ErrorKind::Type,
+            "cannot call constructor of class `Point` without new",
+        ),
+    ]);
+}
+
+#[test]
+fn host_data() {
+    #[derive(Debug, Trace, Finalize)]
+    struct Counter(u32);
+
+    let context = &mut Context::default();
+    context.realm().set_host_data(Counter(0));
+    context
+        .register_global_callable(
+            "increment",
+            0,
+            NativeFunction::from_fn_ptr(|_, _, context| {
+                let mut counter = context
+                    .realm()
+                    .host_data_mut::<Counter>()
+                    .expect("the realm must have a counter");
+                counter.0 += 1;
+                Ok(counter.0.into())
+            }),
+        )
+        .unwrap();
+
+    let value = context
+        .eval_script(Source::from_bytes("increment(); increment(); increment()"))
+        .unwrap();
+    assert_eq!(value, JsValue::new(3));
+    assert_eq!(context.realm().host_data::<Counter>().unwrap().0, 3);
+    assert!(context.realm().host_data::<String>().is_none());
+
+    // Objects have their own host data, which doesn't change their behaviour.
+    let object = JsObject::with_object_proto(context.intrinsics());
+    assert!(object.host_data::<Counter>().is_none());
+    object.set_host_data(Counter(7));
+    object.host_data_mut::<Counter>().unwrap().0 += 1;
+    assert_eq!(object.host_data::<Counter>().unwrap().0, 8);
+    assert!(!object.is_native_object());
+}
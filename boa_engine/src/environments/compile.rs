@@ -2,17 +2,53 @@ use crate::environments::runtime::BindingLocator;
 use boa_ast::expression::Identifier;
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::fmt;
 
 /// A compile time binding represents a binding at bytecode compile time in a [`CompileTimeEnvironment`].
 ///
 /// It contains the binding index and a flag to indicate if this is a mutable binding or not.
-#[derive(Debug)]
-struct CompileTimeBinding {
-    index: usize,
-    mutable: bool,
-    lex: bool,
-    strict: bool,
+#[derive(Debug, Clone, Copy)]
+#[allow(clippy::struct_excessive_bools)]
+pub(crate) struct CompileTimeBinding {
+    pub(crate) index: usize,
+    pub(crate) mutable: bool,
+    pub(crate) lex: bool,
+    pub(crate) strict: bool,
+    pub(crate) class: bool,
+}
+
+impl CompileTimeBinding {
+    /// Gets the kind of declaration of this binding, if it is a lexical binding.
+    pub(crate) const fn lexical_kind(&self) -> Option<LexicalKind> {
+        if !self.lex {
+            None
+        } else if self.class {
+            Some(LexicalKind::Class)
+        } else if self.mutable {
+            Some(LexicalKind::Let)
+        } else {
+            Some(LexicalKind::Const)
+        }
+    }
+}
+
+/// The kind of declaration of a lexical binding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LexicalKind {
+    Let,
+    Const,
+    Class,
+}
+
+impl fmt::Display for LexicalKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Let => "let",
+            Self::Const => "const",
+            Self::Class => "class",
+        })
+    }
 }
 
 /// A compile time environment maps bound identifiers to their binding positions.
@@ -25,6 +61,9 @@ pub(crate) struct CompileTimeEnvironment {
     #[unsafe_ignore_trace]
     bindings: FxHashMap<Identifier, CompileTimeBinding>,
     function_scope: bool,
+    /// The bindings defined by the host instead of a declaration in the code.
+    #[unsafe_ignore_trace]
+    host_bindings: FxHashSet<Identifier>,
 }
 
 impl CompileTimeEnvironment {
@@ -35,6 +74,7 @@ impl CompileTimeEnvironment {
             environment_index: 0,
             bindings: FxHashMap::default(),
             function_scope: true,
+            host_bindings: FxHashSet::default(),
         }
     }
 
@@ -46,14 +86,30 @@ impl CompileTimeEnvironment {
             environment_index: index,
             bindings: FxHashMap::default(),
             function_scope,
+            host_bindings: FxHashSet::default(),
         }
     }
 
     /// Check if environment has a lexical binding with the given name.
     pub(crate) fn has_lex_binding(&self, name: Identifier) -> bool {
+        self.lex_binding_kind(name).is_some()
+    }
+
+    /// Gets the kind of declaration of the lexical binding with the given name, if it exists.
+    pub(crate) fn lex_binding_kind(&self, name: Identifier) -> Option<LexicalKind> {
         self.bindings
             .get(&name)
-            .map_or(false, |binding| binding.lex)
+            .and_then(CompileTimeBinding::lexical_kind)
+    }
+
+    /// Check if the binding with the given name was defined by the host.
+    pub(crate) fn is_host_binding(&self, name: Identifier) -> bool {
+        self.host_bindings.contains(&name)
+    }
+
+    /// Marks the binding with the given name as defined by the host.
+    pub(crate) fn set_host_binding(&mut self, name: Identifier) {
+        self.host_bindings.insert(name);
     }
 
     /// Returns the number of bindings in this environment.
@@ -114,6 +170,7 @@ impl CompileTimeEnvironment {
                             mutable: true,
                             lex: !function_scope,
                             strict: false,
+                            class: false,
                         },
                     );
                 }
@@ -135,6 +192,7 @@ impl CompileTimeEnvironment {
                         mutable: true,
                         lex: !function_scope,
                         strict: false,
+                        class: false,
                     },
                 );
             }
@@ -152,10 +210,19 @@ impl CompileTimeEnvironment {
                 mutable: false,
                 lex: true,
                 strict,
+                class: false,
             },
         );
     }
 
+    /// Create the mutable lexical binding of a class declaration.
+    pub(crate) fn create_class_binding(&mut self, name: Identifier) {
+        self.create_mutable_binding(name, false);
+        if let Some(binding) = self.bindings.get_mut(&name) {
+            binding.class = true;
+        }
+    }
+
     /// Return the binding locator for a mutable binding with the given binding name and scope.
     pub(crate) fn initialize_mutable_binding(
         &self,
@@ -218,6 +285,29 @@ impl CompileTimeEnvironment {
         self.environment_index
     }
 
+    /// Gets all bindings of this environment, sorted by their binding index.
+    pub(crate) fn bindings(&self) -> Vec<(Identifier, CompileTimeBinding)> {
+        let mut bindings: Vec<_> = self
+            .bindings
+            .iter()
+            .map(|(name, binding)| (*name, *binding))
+            .collect();
+        bindings.sort_unstable_by_key(|(_, binding)| binding.index);
+        bindings
+    }
+
+    /// Gets the binding with the given name, if it exists in this environment.
+    pub(crate) fn binding(&self, name: Identifier) -> Option<CompileTimeBinding> {
+        self.bindings.get(&name).copied()
+    }
+
+    /// Inserts a binding with a fixed binding index.
+    ///
+    /// This is used to restore environments from a serialized [`CodeBlock`](crate::vm::CodeBlock).
+    pub(crate) fn insert_binding(&mut self, name: Identifier, binding: CompileTimeBinding) {
+        self.bindings.insert(name, binding);
+    }
+
     /// Gets the indices of all `var` bindings in this environment.
     pub(crate) fn var_binding_indices(&self) -> Vec<usize> {
         self.bindings
@@ -27,8 +27,10 @@
 mod compile;
 mod runtime;
 
+pub use runtime::{BindingLookupStats, EnvSnapshot};
+
 pub(crate) use {
-    compile::CompileTimeEnvironment,
+    compile::{CompileTimeBinding, CompileTimeEnvironment, LexicalKind},
     runtime::{
         BindingLocator, DeclarativeEnvironment, DeclarativeEnvironmentStack, Environment,
         EnvironmentSlots,
@@ -1,6 +1,10 @@
 use crate::{
-    environments::CompileTimeEnvironment, error::JsNativeError, object::JsObject, Context,
-    JsResult, JsString, JsSymbol, JsValue,
+    environments::{CompileTimeEnvironment, LexicalKind},
+    error::JsNativeError,
+    object::{shape::Shape, JsObject},
+    property::PropertyKey,
+    realm::Realm,
+    Context, JsResult, JsString, JsSymbol, JsValue,
 };
 use boa_ast::expression::Identifier;
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
@@ -35,6 +39,11 @@ pub(crate) struct DeclarativeEnvironment {
     poisoned: Cell<bool>,
     #[unsafe_ignore_trace]
     with: Cell<bool>,
+    /// `true` if the environment may be referenced from outside of the environment stack that
+    /// created it, for example by a closure. Only the bindings of environments that didn't escape
+    /// can be reused after the environment is popped.
+    #[unsafe_ignore_trace]
+    escaped: Cell<bool>,
     slots: Option<EnvironmentSlots>,
 }
 
@@ -46,6 +55,7 @@ impl DeclarativeEnvironment {
             compile: Gc::new(GcRefCell::new(CompileTimeEnvironment::new_global())),
             poisoned: Cell::new(false),
             with: Cell::new(false),
+            escaped: Cell::new(true),
             slots: Some(EnvironmentSlots::Global),
         }
     }
@@ -240,20 +250,235 @@ impl DeclarativeEnvironment {
     }
 }
 
+/// The maximum number of binding buffers kept for reuse by a [`DeclarativeEnvironmentStack`].
+const BINDINGS_POOL_CAPACITY: usize = 64;
+
 /// The environment stack holds all environments at runtime.
 ///
 /// Environments themselves are garbage collected,
 /// because they must be preserved for function calls.
-#[derive(Clone, Debug, Trace, Finalize)]
+///
+/// To avoid allocating the bindings of every function call, the binding buffers of truncated
+/// environments that didn't escape the stack are cleared and kept in a pool, to be reused by the
+/// next pushed environments.
+#[derive(Debug, Trace, Finalize)]
 pub(crate) struct DeclarativeEnvironmentStack {
     stack: Vec<Environment>,
+    // SAFETY: The pooled buffers are always empty, so they don't contain anything to trace.
+    #[unsafe_ignore_trace]
+    pool: Vec<Vec<Option<JsValue>>>,
+}
+
+impl Clone for DeclarativeEnvironmentStack {
+    fn clone(&self) -> Self {
+        self.mark_escaped();
+        Self {
+            stack: self.stack.clone(),
+            pool: Vec::new(),
+        }
+    }
+}
+
+/// A frozen copy of a [`DeclarativeEnvironmentStack`].
+///
+/// A snapshot holds the environments of the stack, not the values of their bindings, so
+/// assignments done after taking the snapshot are visible after restoring it. The `poisoned` and
+/// `with` flags of the declarative environments are copied, and restoring the snapshot also
+/// restores them.
+///
+/// A snapshot can only be restored into the realm it was taken in, since it contains the global
+/// environment of that realm.
+#[derive(Clone, Debug, Trace, Finalize)]
+pub struct EnvSnapshot {
+    stack: Vec<Environment>,
+    /// The `poisoned` and `with` flags of each environment, `None` for object environments.
+    #[unsafe_ignore_trace]
+    flags: Vec<Option<(bool, bool)>>,
+}
+
+impl EnvSnapshot {
+    /// Checks if the snapshot was taken in `realm`.
+    pub(crate) fn belongs_to(&self, realm: &Realm) -> bool {
+        matches!(
+            self.stack.first(),
+            Some(Environment::Declarative(env)) if Gc::ptr_eq(env, realm.environment())
+        )
+    }
+}
+
+/// Counters of the bindings looked up at runtime by a [`Context`].
+///
+/// Bindings that may be shadowed by a `with` statement or by a direct `eval` call are looked up
+/// at runtime. The lookup returns immediately unless the current environment is inside a `with`
+/// statement or is poisoned, meaning that an `eval` call may have added bindings to it. In that
+/// case the environments have to be searched, which is slower.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BindingLookupStats {
+    /// The number of lookups that returned immediately.
+    pub fast: u64,
+
+    /// The number of lookups that searched the environments.
+    pub slow: u64,
 }
 
 /// A runtime environment.
 #[derive(Clone, Debug, Trace, Finalize)]
 pub(crate) enum Environment {
     Declarative(Gc<DeclarativeEnvironment>),
-    Object(JsObject),
+    Object(Gc<ObjectEnvironment>),
+}
+
+/// An object environment, created by a `with` statement.
+///
+/// Finding a binding in an object environment checks if the binding object or one of its
+/// prototypes has the property, which is slow. To speed up the repeated lookups of outer bindings
+/// inside a `with` block, the environment remembers the names that are not properties of the
+/// object, as long as the object and its prototypes are ordinary objects whose shapes don't
+/// change. In the same way, it remembers if none of them has a `@@unscopables` property, which
+/// skips getting it for every binding found in the object.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct ObjectEnvironment {
+    object: JsObject,
+    misses: GcRefCell<BindingMisses>,
+
+    /// The shapes of the object and its prototypes when none of them had a `@@unscopables`
+    /// property, or empty if that is unknown.
+    no_unscopables: GcRefCell<Vec<ShapeStamp>>,
+}
+
+/// The names that are known to not be properties of the object of an [`ObjectEnvironment`].
+#[derive(Debug, Default, Trace, Finalize)]
+struct BindingMisses {
+    /// The shapes of the object and its prototypes when the names were cached.
+    shapes: Vec<ShapeStamp>,
+    #[unsafe_ignore_trace]
+    names: FxHashSet<Identifier>,
+}
+
+/// The layout of an object at some point in time.
+#[derive(Debug, Trace, Finalize)]
+struct ShapeStamp {
+    shape: Shape,
+    version: u32,
+}
+
+impl ObjectEnvironment {
+    /// Creates a new object environment for `object`.
+    pub(crate) fn new(object: JsObject) -> Self {
+        Self {
+            object,
+            misses: GcRefCell::default(),
+            no_unscopables: GcRefCell::default(),
+        }
+    }
+
+    /// Gets the binding object of the environment.
+    pub(crate) const fn object(&self) -> &JsObject {
+        &self.object
+    }
+
+    /// Returns `true` if `name` is known to not be a property of the binding object.
+    fn is_known_miss(&self, name: Identifier) -> bool {
+        let misses = self.misses.borrow();
+        misses.names.contains(&name) && misses.is_valid_for(&self.object)
+    }
+
+    /// Remembers that `name` is not a property of the binding object.
+    ///
+    /// Nothing is remembered if the object or one of its prototypes is not an ordinary object.
+    fn insert_miss(&self, name: Identifier) {
+        let mut misses = self.misses.borrow_mut();
+        if !misses.is_valid_for(&self.object) {
+            let Some(shapes) = ShapeStamp::chain(&self.object) else {
+                return;
+            };
+            *misses = BindingMisses {
+                shapes,
+                names: FxHashSet::default(),
+            };
+        }
+        misses.names.insert(name);
+    }
+
+    /// Returns `true` if the binding object and its prototypes are known to not have a
+    /// `@@unscopables` property.
+    fn is_known_without_unscopables(&self) -> bool {
+        ShapeStamp::matches(&self.no_unscopables.borrow(), &self.object)
+    }
+
+    /// Remembers that the binding object and its prototypes don't have a `@@unscopables`
+    /// property, if that is the case.
+    ///
+    /// Nothing is remembered if the object or one of its prototypes is not an ordinary object.
+    fn insert_without_unscopables(&self) {
+        let Some(shapes) = ShapeStamp::chain(&self.object) else {
+            return;
+        };
+        let key = PropertyKey::from(JsSymbol::unscopables());
+        if shapes
+            .iter()
+            .all(|stamp| stamp.shape.lookup(&key).is_none())
+        {
+            *self.no_unscopables.borrow_mut() = shapes;
+        }
+    }
+}
+
+impl BindingMisses {
+    /// Returns `true` if the object and its prototypes still have the cached shapes.
+    fn is_valid_for(&self, object: &JsObject) -> bool {
+        ShapeStamp::matches(&self.shapes, object)
+    }
+}
+
+impl ShapeStamp {
+    /// Returns `true` if `object` and its prototypes have the shapes of `stamps`.
+    ///
+    /// The prototype of an object is part of its shape, so comparing the shapes of the prototype
+    /// chain of `object` also checks that the prototypes are the same objects.
+    fn matches(stamps: &[Self], object: &JsObject) -> bool {
+        if stamps.is_empty() {
+            return false;
+        }
+
+        let mut current = Some(object.clone());
+        for stamp in stamps {
+            let Some(object) = current else {
+                return false;
+            };
+            let object = object.borrow();
+            let shape = &object.properties().shape;
+            if shape.to_addr_usize() != stamp.shape.to_addr_usize()
+                || shape.version() != stamp.version
+            {
+                return false;
+            }
+            current = object.prototype();
+        }
+
+        current.is_none()
+    }
+
+    /// Gets the shapes of `object` and its prototypes, or `None` if one of them is not an
+    /// ordinary object.
+    fn chain(object: &JsObject) -> Option<Vec<Self>> {
+        let mut stamps = Vec::new();
+        let mut current = Some(object.clone());
+        while let Some(object) = current {
+            if !object.has_ordinary_internal_methods() {
+                return None;
+            }
+
+            let object = object.borrow();
+            let shape = object.properties().shape.clone();
+            stamps.push(Self {
+                version: shape.version(),
+                shape,
+            });
+            current = object.prototype();
+        }
+        Some(stamps)
+    }
 }
 
 impl Environment {
@@ -278,6 +503,7 @@ impl DeclarativeEnvironmentStack {
     pub(crate) fn new(global: Gc<DeclarativeEnvironment>) -> Self {
         Self {
             stack: vec![Environment::Declarative(global)],
+            pool: Vec::new(),
         }
     }
 
@@ -309,14 +535,15 @@ impl DeclarativeEnvironmentStack {
         }
     }
 
-    /// Check if any of the provided binding names are defined as lexical bindings.
+    /// Check if any of the provided binding names are defined as lexical bindings, returning the
+    /// first one found with its kind of declaration.
     ///
     /// Start at the current environment.
     /// Stop at the next outer function environment.
     pub(crate) fn has_lex_binding_until_function_environment(
         &self,
         names: &FxHashSet<Identifier>,
-    ) -> Option<Identifier> {
+    ) -> Option<(Identifier, LexicalKind)> {
         for env in self
             .stack
             .iter()
@@ -325,8 +552,8 @@ impl DeclarativeEnvironmentStack {
         {
             let compile = env.compile.borrow();
             for name in names {
-                if compile.has_lex_binding(*name) {
-                    return Some(*name);
+                if let Some(kind) = compile.lex_binding_kind(*name) {
+                    return Some((*name, kind));
                 }
             }
             if compile.is_function() {
@@ -336,6 +563,54 @@ impl DeclarativeEnvironmentStack {
         None
     }
 
+    /// Returns `true` if the var environment of the current environment is the global
+    /// environment, meaning there is no function environment between them.
+    pub(crate) fn is_var_environment_global(&self) -> bool {
+        self.stack
+            .iter()
+            .filter_map(Environment::as_declarative)
+            .skip(1)
+            .all(|env| !env.compile.borrow().is_function())
+    }
+
+    /// Gets all environments of the stack, from the outermost to the innermost one.
+    pub(crate) fn environments(&self) -> &[Environment] {
+        &self.stack
+    }
+
+    /// Takes a snapshot of the current environments, that can be restored later with
+    /// [`DeclarativeEnvironmentStack::restore`].
+    pub(crate) fn snapshot(&self) -> EnvSnapshot {
+        let flags = self
+            .stack
+            .iter()
+            .map(|env| match env {
+                Environment::Declarative(env) => Some((env.poisoned.get(), env.with.get())),
+                Environment::Object(_) => None,
+            })
+            .collect();
+
+        self.mark_escaped();
+        EnvSnapshot {
+            stack: self.stack.clone(),
+            flags,
+        }
+    }
+
+    /// Replaces the current environments with the environments of `snapshot`, restoring their
+    /// `poisoned` and `with` flags.
+    ///
+    /// The snapshot must have been taken in the same realm.
+    pub(crate) fn restore(&mut self, snapshot: &EnvSnapshot) {
+        for (env, flags) in snapshot.stack.iter().zip(&snapshot.flags) {
+            if let (Environment::Declarative(env), Some((poisoned, with))) = (env, flags) {
+                env.poisoned.set(*poisoned);
+                env.with.set(*with);
+            }
+        }
+        self.stack.clone_from(&snapshot.stack);
+    }
+
     /// Pop all current environments except the global environment.
     pub(crate) fn pop_to_global(&mut self) -> Vec<Environment> {
         self.stack.split_off(1)
@@ -347,8 +622,48 @@ impl DeclarativeEnvironmentStack {
     }
 
     /// Truncate current environments to the given number.
+    ///
+    /// The binding buffers of the removed environments are kept for reuse if the environments
+    /// didn't escape the stack.
     pub(crate) fn truncate(&mut self, len: usize) {
-        self.stack.truncate(len);
+        while self.stack.len() > len {
+            if let Some(Environment::Declarative(env)) = self.stack.pop().as_ref() {
+                self.recycle_bindings(env);
+            }
+        }
+    }
+
+    /// Exchanges the pool of reusable binding buffers with the pool of `other`.
+    ///
+    /// This is used to share the pool with the environment stack of a called function.
+    pub(crate) fn swap_bindings_pool(&mut self, other: &mut Self) {
+        std::mem::swap(&mut self.pool, &mut other.pool);
+    }
+
+    /// Marks all environments of the stack as escaped, since they are about to be referenced from
+    /// outside of the stack.
+    fn mark_escaped(&self) {
+        for env in self.stack.iter().filter_map(Environment::as_declarative) {
+            env.escaped.set(true);
+        }
+    }
+
+    /// Creates the bindings of a new environment, reusing a pooled buffer if there is one.
+    fn new_bindings(&mut self, num_bindings: usize) -> Vec<Option<JsValue>> {
+        let mut bindings = self.pool.pop().unwrap_or_default();
+        bindings.resize(num_bindings, None);
+        bindings
+    }
+
+    /// Moves the binding buffer of a removed environment to the pool, if the environment is not
+    /// referenced from anywhere else.
+    fn recycle_bindings(&mut self, env: &DeclarativeEnvironment) {
+        if env.escaped.get() || self.pool.len() >= BINDINGS_POOL_CAPACITY {
+            return;
+        }
+        let mut bindings = std::mem::take(&mut *env.bindings.borrow_mut());
+        bindings.clear();
+        self.pool.push(bindings);
     }
 
     /// Extend the current environment stack with the given environments.
@@ -393,7 +708,8 @@ impl DeclarativeEnvironmentStack {
     /// Push a new object environment on the environments stack and return it's index.
     pub(crate) fn push_object(&mut self, object: JsObject) -> usize {
         let index = self.stack.len();
-        self.stack.push(Environment::Object(object));
+        self.stack
+            .push(Environment::Object(Gc::new(ObjectEnvironment::new(object))));
         index
     }
 
@@ -426,13 +742,15 @@ impl DeclarativeEnvironmentStack {
         };
 
         let index = self.stack.len();
+        let bindings = self.new_bindings(num_bindings);
 
         self.stack
             .push(Environment::Declarative(Gc::new(DeclarativeEnvironment {
-                bindings: GcRefCell::new(vec![None; num_bindings]),
+                bindings: GcRefCell::new(bindings),
                 compile: compile_environment,
                 poisoned: Cell::new(poisoned),
                 with: Cell::new(with),
+                escaped: Cell::new(false),
                 slots: None,
             })));
 
@@ -481,7 +799,7 @@ impl DeclarativeEnvironmentStack {
 
         let this = this.unwrap_or(JsValue::Null);
 
-        let mut bindings = vec![None; num_bindings];
+        let mut bindings = self.new_bindings(num_bindings);
         for index in compile_environment.borrow().var_binding_indices() {
             bindings[index] = Some(JsValue::Undefined);
         }
@@ -492,6 +810,7 @@ impl DeclarativeEnvironmentStack {
                 compile: compile_environment,
                 poisoned: Cell::new(poisoned),
                 with: Cell::new(with),
+                escaped: Cell::new(false),
                 slots: Some(EnvironmentSlots::Function(GcRefCell::new(FunctionSlots {
                     this,
                     this_binding_status,
@@ -539,7 +858,7 @@ impl DeclarativeEnvironmentStack {
             )
         };
 
-        let mut bindings = vec![None; num_bindings];
+        let mut bindings = self.new_bindings(num_bindings);
         for index in compile_environment.borrow().var_binding_indices() {
             bindings[index] = Some(JsValue::Undefined);
         }
@@ -550,6 +869,7 @@ impl DeclarativeEnvironmentStack {
                 compile: compile_environment,
                 poisoned: Cell::new(poisoned),
                 with: Cell::new(with),
+                escaped: Cell::new(false),
                 slots,
             })));
     }
@@ -570,10 +890,14 @@ impl DeclarativeEnvironmentStack {
     /// Panics if no environment exists on the stack.
     #[track_caller]
     pub(crate) fn current(&mut self) -> Environment {
-        self.stack
+        let env = self
+            .stack
             .last()
-            .expect("global environment must always exist")
-            .clone()
+            .expect("global environment must always exist");
+        if let Environment::Declarative(env) = env {
+            env.escaped.set(true);
+        }
+        env.clone()
     }
 
     /// Get the compile environment for the current runtime environment.
@@ -591,6 +915,17 @@ impl DeclarativeEnvironmentStack {
             .clone()
     }
 
+    /// Returns `true` if the innermost declarative environment is poisoned.
+    pub(crate) fn is_current_poisoned(&self) -> bool {
+        self.stack
+            .iter()
+            .rev()
+            .find_map(Environment::as_declarative)
+            .expect("global environment must always exist")
+            .poisoned
+            .get()
+    }
+
     /// Mark that there may be added bindings from the current environment to the next function
     /// environment.
     pub(crate) fn poison_until_last_function(&mut self) {
@@ -691,7 +1026,7 @@ impl BindingLocator {
     }
 
     /// Creates a binding locator that indicates that the binding is on the global object.
-    pub(in crate::environments) const fn global(name: Identifier) -> Self {
+    pub(crate) const fn global(name: Identifier) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -704,7 +1039,7 @@ impl BindingLocator {
 
     /// Creates a binding locator that indicates that it was attempted to mutate an immutable binding.
     /// At runtime this should always produce a type error.
-    pub(in crate::environments) const fn mutate_immutable(name: Identifier) -> Self {
+    pub(crate) const fn mutate_immutable(name: Identifier) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -716,7 +1051,7 @@ impl BindingLocator {
     }
 
     /// Creates a binding locator that indicates that any action is silently ignored.
-    pub(in crate::environments) const fn silent(name: Identifier) -> Self {
+    pub(crate) const fn silent(name: Identifier) -> Self {
         Self {
             name,
             environment_index: 0,
@@ -752,6 +1087,11 @@ impl BindingLocator {
         self.silent
     }
 
+    /// Returns if the binding access is an attempt to mutate an immutable binding.
+    pub(crate) const fn is_mutate_immutable(&self) -> bool {
+        self.mutate_immutable
+    }
+
     /// Helper method to throws an error if the binding access is illegal.
     pub(crate) fn throw_mutate_immutable(
         &self,
@@ -779,12 +1119,19 @@ impl Context<'_> {
     /// are completely removed of runtime checks because the specification guarantees that runtime
     /// semantics cannot add or remove lexical bindings.
     pub(crate) fn find_runtime_binding(&mut self, locator: &mut BindingLocator) -> JsResult<()> {
-        let current = self.vm.environments.current();
-        if let Some(env) = current.as_declarative() {
+        let current = self
+            .vm
+            .environments
+            .stack
+            .last()
+            .and_then(Environment::as_declarative);
+        if let Some(env) = current {
             if !env.with.get() && !env.poisoned.get() {
+                self.vm.binding_lookups.fast += 1;
                 return Ok(());
             }
         }
+        self.vm.binding_lookups.slow += 1;
 
         for env_index in (locator.environment_index..self.vm.environments.stack.len()).rev() {
             match self.environment_expect(env_index) {
@@ -803,23 +1150,34 @@ impl Context<'_> {
                         break;
                     }
                 }
-                Environment::Object(o) => {
-                    let o = o.clone();
+                Environment::Object(env) => {
+                    if env.is_known_miss(locator.name) {
+                        continue;
+                    }
+                    let env = env.clone();
+                    let o = env.object().clone();
                     let key: JsString = self
                         .interner()
                         .resolve_expect(locator.name.sym())
                         .into_common(false);
                     if o.has_property(key.clone(), self)? {
-                        if let Some(unscopables) = o.get(JsSymbol::unscopables(), self)?.as_object()
-                        {
-                            if unscopables.get(key.clone(), self)?.to_boolean() {
-                                continue;
+                        // The unscopables object can change at any time, so only its absence is
+                        // cached, until the object or its prototypes change.
+                        if !env.is_known_without_unscopables() {
+                            let unscopables = o.get(JsSymbol::unscopables(), self)?;
+                            if let Some(unscopables) = unscopables.as_object() {
+                                if unscopables.get(key.clone(), self)?.to_boolean() {
+                                    continue;
+                                }
+                            } else {
+                                env.insert_without_unscopables();
                             }
                         }
                         locator.environment_index = env_index;
                         locator.global = false;
                         break;
                     }
+                    env.insert_miss(locator.name);
                 }
             }
         }
@@ -871,8 +1229,8 @@ impl Context<'_> {
                 Environment::Declarative(env) => {
                     Ok(env.bindings.borrow()[locator.binding_index].clone())
                 }
-                Environment::Object(obj) => {
-                    let obj = obj.clone();
+                Environment::Object(env) => {
+                    let obj = env.object().clone();
                     let key: JsString = self
                         .interner()
                         .resolve_expect(locator.name.sym())
@@ -907,8 +1265,8 @@ impl Context<'_> {
                 Environment::Declarative(decl) => {
                     decl.bindings.borrow_mut()[locator.binding_index] = Some(value);
                 }
-                Environment::Object(obj) => {
-                    let obj = obj.clone();
+                Environment::Object(env) => {
+                    let obj = env.object().clone();
                     let key: JsString = self
                         .interner()
                         .resolve_expect(locator.name.sym())
@@ -939,8 +1297,8 @@ impl Context<'_> {
         } else {
             match self.environment_expect(locator.environment_index) {
                 Environment::Declarative(_) => Ok(false),
-                Environment::Object(obj) => {
-                    let obj = obj.clone();
+                Environment::Object(env) => {
+                    let obj = env.object().clone();
                     let key: JsString = self
                         .interner()
                         .resolve_expect(locator.name.sym())
@@ -1,4 +1,12 @@
-use crate::{builtins::error::ErrorKind, run_test_actions, TestAction};
+use crate::{
+    builtins::error::ErrorKind,
+    environments::{BindingLocator, BindingLookupStats},
+    js_string,
+    native_function::NativeFunction,
+    object::JsObject,
+    run_test_actions, Context, Source, TestAction,
+};
+use boa_ast::expression::Identifier;
 use indoc::indoc;
 
 #[test]
@@ -77,3 +85,208 @@ fn set_outer_let_in_block_scope() {
             bar == "foo";
         "#})]);
 }
+
+#[test]
+fn with_outer_binding_lookup_follows_object_changes() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var x = "outer";
+            var obj = {};
+            var seen = [];
+            with (obj) {
+                for (var i = 0; i < 3; i++) {
+                    seen.push(x);
+                }
+                obj.x = "own";
+                seen.push(x);
+                delete obj.x;
+                seen.push(x);
+                Object.prototype.x = "proto";
+                seen.push(x);
+                delete Object.prototype.x;
+                seen.push(x);
+            }
+        "#}),
+        TestAction::assert_eq("seen.join()", "outer,outer,outer,own,outer,proto,outer"),
+    ]);
+}
+
+#[test]
+fn with_outer_binding_lookup_respects_unscopables() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var x = "outer";
+            var obj = {};
+            var seen = [];
+            with (obj) {
+                seen.push(x);
+                obj.x = "own";
+                obj[Symbol.unscopables] = { x: true };
+                seen.push(x);
+                obj[Symbol.unscopables].x = false;
+                seen.push(x);
+            }
+        "#}),
+        TestAction::assert_eq("seen.join()", "outer,outer,own"),
+    ]);
+}
+
+#[test]
+fn with_cached_missing_unscopables_is_invalidated() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var x = "outer";
+            var y = "outer";
+            var proto = {};
+            var obj = Object.create(proto);
+            obj.x = "own";
+            obj.y = "own";
+            var seen = [];
+            with (obj) {
+                seen.push(x, y);
+                obj[Symbol.unscopables] = { x: true };
+                seen.push(x, y);
+                delete obj[Symbol.unscopables];
+                seen.push(x, y);
+                proto[Symbol.unscopables] = { y: true };
+                seen.push(x, y);
+            }
+        "#}),
+        TestAction::assert_eq("seen.join()", "own,own,outer,own,own,own,own,outer"),
+    ]);
+}
+
+#[test]
+fn environment_snapshot_restore() {
+    let mut context = Context::default();
+    context
+        .eval_script(Source::from_bytes("var x = 'global';"))
+        .unwrap();
+    let name = Identifier::new(context.interner_mut().get_or_intern("x"));
+    let lookup = |context: &mut Context<'_>| {
+        let mut locator = BindingLocator::global(name);
+        context.find_runtime_binding(&mut locator).unwrap();
+        context.get_binding(locator).unwrap().unwrap()
+    };
+
+    let global = context.environment_snapshot();
+    assert_eq!(lookup(&mut context), "global".into());
+
+    context.vm.environments.poison_until_last_function();
+    let poisoned = context.environment_snapshot();
+
+    let object = JsObject::with_object_proto(context.intrinsics());
+    object
+        .set(js_string!("x"), "with", false, &mut context)
+        .unwrap();
+    context.vm.environments.push_object(object);
+    let with = context.environment_snapshot();
+    assert_eq!(lookup(&mut context), "with".into());
+
+    // Restoring the snapshots also restores the `poisoned` flag, which decides if the
+    // environments have to be searched.
+    context.restore_environments(&global).unwrap();
+    context.reset_binding_lookup_stats();
+    assert_eq!(lookup(&mut context), "global".into());
+    assert_eq!(context.binding_lookup_stats().slow, 0);
+
+    context.restore_environments(&poisoned).unwrap();
+    assert_eq!(lookup(&mut context), "global".into());
+    assert_eq!(context.binding_lookup_stats().slow, 1);
+
+    context.restore_environments(&with).unwrap();
+    assert_eq!(lookup(&mut context), "with".into());
+
+    // Snapshots can't be restored into another realm.
+    let realm = context.create_realm().unwrap();
+    context.enter_realm(realm);
+    assert!(context.restore_environments(&global).is_err());
+}
+
+#[test]
+fn reused_bindings_are_not_shared() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function counter(start) {
+                let count = start;
+                return () => count++;
+            }
+            function local(value) {
+                let x = value;
+                return x;
+            }
+            function mapped(a) {
+                return arguments;
+            }
+            function* generator(value) {
+                let x = value;
+                yield x;
+                yield x;
+            }
+
+            var first = counter(10);
+            var args = mapped("arg");
+            var gen = generator("gen");
+            gen.next();
+            for (let i = 0; i < 100; i++) {
+                local(i);
+                counter(i)();
+            }
+            var second = counter(20);
+        "#}),
+        TestAction::assert_eq("first()", 10),
+        TestAction::assert_eq("second()", 20),
+        TestAction::assert_eq("first()", 11),
+        TestAction::assert_eq("args[0]", "arg"),
+        TestAction::assert_eq("gen.next().value", "gen"),
+        TestAction::assert_eq("local(5)", 5),
+    ]);
+}
+
+#[test]
+fn poisoned_environment_diagnostics() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            assert!(!context.current_environment_poisoned());
+            context
+                .register_global_callable(
+                    "isPoisoned",
+                    0,
+                    NativeFunction::from_fn_ptr(|_, _, context| {
+                        Ok(context.current_environment_poisoned().into())
+                    }),
+                )
+                .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            function clean() {
+                var x = 1;
+                return [isPoisoned(), x];
+            }
+            function poisoned() {
+                eval("");
+                var x = 1;
+                return [isPoisoned(), x];
+            }
+        "#}),
+        TestAction::assert("!clean()[0]"),
+        TestAction::assert("poisoned()[0]"),
+        TestAction::inspect_context(|context| {
+            context.reset_binding_lookup_stats();
+            assert_eq!(
+                context.binding_lookup_stats(),
+                BindingLookupStats::default()
+            );
+
+            context.eval_script(Source::from_bytes("clean()")).unwrap();
+            let stats = context.binding_lookup_stats();
+            assert!(stats.fast > 0);
+            assert_eq!(stats.slow, 0);
+
+            context
+                .eval_script(Source::from_bytes("poisoned()"))
+                .unwrap();
+            assert!(context.binding_lookup_stats().slow > 0);
+        }),
+    ]);
+}
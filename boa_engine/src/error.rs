@@ -7,8 +7,10 @@ use crate::{
     property::PropertyDescriptor,
     realm::Realm,
     string::utf16,
+    vm::{format_stack_trace, StackFrameInfo},
     Context, JsString, JsValue,
 };
+use boa_ast::Position;
 use boa_gc::{Finalize, Trace};
 use thiserror::Error;
 
@@ -221,10 +223,13 @@ impl JsError {
                 let obj = val
                     .as_object()
                     .ok_or_else(|| TryNativeError::NotAnErrorObject(val.clone()))?;
-                let error = obj
-                    .borrow()
-                    .as_error()
-                    .ok_or_else(|| TryNativeError::NotAnErrorObject(val.clone()))?;
+                let (error, position) = {
+                    let obj = obj.borrow();
+                    let error = obj
+                        .as_error()
+                        .ok_or_else(|| TryNativeError::NotAnErrorObject(val.clone()))?;
+                    (error, obj.error_position())
+                };
 
                 let try_get_property = |key, context: &mut Context<'_>| {
                     obj.has_property(key, context)
@@ -307,6 +312,8 @@ impl JsError {
                     message,
                     cause: cause.map(|v| Box::new(Self::from_opaque(v))),
                     realm: Some(realm),
+                    position,
+                    stack_trace: None,
                 })
             }
         }
@@ -358,6 +365,60 @@ impl JsError {
         }
     }
 
+    /// Returns `true` if the error was thrown because the execution was interrupted with an
+    /// [`InterruptHandle`](crate::vm::InterruptHandle).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsError, JsNativeError};
+    /// let error: JsError = JsNativeError::interrupted().into();
+    ///
+    /// assert!(error.is_interrupted());
+    ///
+    /// let error: JsError = JsNativeError::error().into();
+    ///
+    /// assert!(!error.is_interrupted());
+    /// ```
+    #[must_use]
+    pub const fn is_interrupted(&self) -> bool {
+        matches!(
+            &self.inner,
+            Repr::Native(JsNativeError {
+                kind: JsNativeErrorKind::Interrupted,
+                ..
+            })
+        )
+    }
+
+    /// Returns `true` if the error was thrown because the execution exceeded the limit set with
+    /// [`Context::set_instruction_limit`](crate::Context::set_instruction_limit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsError, JsNativeError};
+    /// let error: JsError = JsNativeError::instruction_limit_exceeded().into();
+    ///
+    /// assert!(error.is_instruction_limit_exceeded());
+    /// ```
+    #[must_use]
+    pub const fn is_instruction_limit_exceeded(&self) -> bool {
+        matches!(
+            &self.inner,
+            Repr::Native(JsNativeError {
+                kind: JsNativeErrorKind::InstructionLimitExceeded,
+                ..
+            })
+        )
+    }
+
+    /// Returns `true` if the error cannot be caught by scripts, and must bubble up to the caller
+    /// of the VM.
+    pub(crate) const fn is_uncatchable(&self) -> bool {
+        self.is_interrupted() || self.is_instruction_limit_exceeded()
+    }
+
     /// Injects a realm on the `realm` field of a native error.
     ///
     /// This is a no-op if the error is not native or if the `realm` field of the error is already
@@ -371,6 +432,21 @@ impl JsError {
         }
         self
     }
+
+    /// Injects the current stack trace of the `context` on the `stack_trace` field of a native
+    /// error.
+    ///
+    /// This is a no-op if the error is not native or if the `stack_trace` field of the error is
+    /// already set, so the trace of the frame that threw the error is kept while it unwinds.
+    pub(crate) fn inject_stack_trace(mut self, context: &Context<'_>) -> JsError {
+        match &mut self.inner {
+            Repr::Native(err) if err.stack_trace.is_none() => {
+                err.stack_trace = Some(context.capture_stack_trace().into_boxed_slice());
+            }
+            _ => {}
+        }
+        self
+    }
 }
 
 impl From<boa_parser::Error> for JsError {
@@ -426,6 +502,10 @@ pub struct JsNativeError {
     #[source]
     cause: Option<Box<JsError>>,
     realm: Option<Realm>,
+    #[unsafe_ignore_trace]
+    position: Option<ErrorPosition>,
+    #[unsafe_ignore_trace]
+    stack_trace: Option<Box<[StackFrameInfo]>>,
 }
 
 impl JsNativeError {
@@ -436,6 +516,8 @@ impl JsNativeError {
             message,
             cause,
             realm: None,
+            position: None,
+            stack_trace: None,
         }
     }
 
@@ -567,6 +649,49 @@ impl JsNativeError {
         Self::new(JsNativeErrorKind::Uri, Box::default(), None)
     }
 
+    /// Creates a new `JsNativeError` that indicates that the execution was interrupted with an
+    /// [`InterruptHandle`](crate::vm::InterruptHandle).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::interrupted();
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Interrupted));
+    /// ```
+    #[must_use]
+    pub fn interrupted() -> Self {
+        Self::new(
+            JsNativeErrorKind::Interrupted,
+            "execution was interrupted".into(),
+            None,
+        )
+    }
+
+    /// Creates a new `JsNativeError` that indicates that the execution exceeded the limit set with
+    /// [`Context::set_instruction_limit`](crate::Context::set_instruction_limit).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::instruction_limit_exceeded();
+    ///
+    /// assert!(matches!(
+    ///     error.kind,
+    ///     JsNativeErrorKind::InstructionLimitExceeded
+    /// ));
+    /// ```
+    #[must_use]
+    pub fn instruction_limit_exceeded() -> Self {
+        Self::new(
+            JsNativeErrorKind::InstructionLimitExceeded,
+            "instruction limit exceeded".into(),
+            None,
+        )
+    }
+
     /// Creates a new `JsNativeError` that indicates that the context hit its execution limit. This
     /// is only used in a fuzzing context.
     #[cfg(feature = "fuzz")]
@@ -638,6 +763,34 @@ impl JsNativeError {
         &self.message
     }
 
+    /// Gets the position in the source code where this error occurred, if any.
+    ///
+    /// This is only available for syntax errors thrown while parsing code, and it's relative to the
+    /// start of the parsed source. For example, the position of an error thrown by an `eval` call
+    /// points into the evaluated string, regardless of where the `eval` call appeared.
+    ///
+    /// The position is kept when the error is converted to an opaque error object with
+    /// [`JsNativeError::to_opaque`] and back with [`JsError::try_native`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{builtins::eval::EvalOptions, Context};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = context
+    ///     .eval_string("let a = 5;\nlet b = ;", EvalOptions::default())
+    ///     .unwrap_err();
+    /// let position = error.as_native().unwrap().position().unwrap();
+    ///
+    /// assert_eq!(position.line(), 1);
+    /// assert_eq!(position.column(), 8);
+    /// ```
+    #[must_use]
+    pub const fn position(&self) -> Option<ErrorPosition> {
+        self.position
+    }
+
     /// Gets the `cause` of this error.
     ///
     /// This is equivalent to the [`NativeError.prototype.cause`][spec]
@@ -659,6 +812,36 @@ impl JsNativeError {
         self.cause.as_deref()
     }
 
+    /// Sets the JavaScript stack trace of this error, usually captured with
+    /// [`Context::capture_stack_trace`].
+    ///
+    /// The stack trace populates the `stack` property of the error object returned by
+    /// [`JsNativeError::to_opaque`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, JsNativeError};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = JsNativeError::typ()
+    ///     .with_message("invalid type!")
+    ///     .with_stack_trace(context.capture_stack_trace());
+    ///
+    /// assert!(error.stack_trace().unwrap().is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_stack_trace(mut self, stack_trace: Vec<StackFrameInfo>) -> Self {
+        self.stack_trace = Some(stack_trace.into_boxed_slice());
+        self
+    }
+
+    /// Gets the JavaScript stack trace of this error, if any.
+    #[must_use]
+    pub fn stack_trace(&self) -> Option<&[StackFrameInfo]> {
+        self.stack_trace.as_deref()
+    }
+
     /// Converts this native error to its opaque representation as a [`JsObject`].
     ///
     /// # Examples
@@ -679,6 +862,8 @@ impl JsNativeError {
             message,
             cause,
             realm,
+            position,
+            stack_trace,
         } = self;
         let constructors = realm.as_ref().map_or_else(
             || context.intrinsics().constructors(),
@@ -701,6 +886,11 @@ impl JsNativeError {
             }
             JsNativeErrorKind::Type => (constructors.type_error().prototype(), ErrorKind::Type),
             JsNativeErrorKind::Uri => (constructors.uri_error().prototype(), ErrorKind::Uri),
+            // These errors cannot be caught by scripts, but native code like promise jobs can
+            // still observe them.
+            JsNativeErrorKind::Interrupted | JsNativeErrorKind::InstructionLimitExceeded => {
+                (constructors.error().prototype(), ErrorKind::Error)
+            }
             #[cfg(feature = "fuzz")]
             JsNativeErrorKind::NoInstructionsRemain => {
                 unreachable!(
@@ -712,11 +902,24 @@ impl JsNativeError {
         let o = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            ObjectData::error(tag),
+            ObjectData::error_with_position(tag, *position),
         );
 
         o.create_non_enumerable_data_property_or_throw(utf16!("message"), &**message, context);
 
+        if let Some(stack_trace) = stack_trace {
+            let header = if message.is_empty() {
+                kind.to_string()
+            } else {
+                format!("{kind}: {message}")
+            };
+            o.create_non_enumerable_data_property_or_throw(
+                utf16!("stack"),
+                format_stack_trace(&header, stack_trace),
+                context,
+            );
+        }
+
         if let Some(cause) = cause {
             o.create_non_enumerable_data_property_or_throw(
                 utf16!("cause"),
@@ -754,7 +957,40 @@ impl JsNativeError {
 
 impl From<boa_parser::Error> for JsNativeError {
     fn from(err: boa_parser::Error) -> Self {
-        Self::syntax().with_message(err.to_string())
+        let mut error = Self::syntax().with_message(err.to_string());
+        error.position = err.position().map(ErrorPosition::from);
+        error
+    }
+}
+
+/// A zero-based position in the source code where a [`JsNativeError`] occurred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ErrorPosition {
+    line: u32,
+    column: u32,
+}
+
+impl ErrorPosition {
+    /// Gets the zero-based line number of the position.
+    #[must_use]
+    pub const fn line(self) -> u32 {
+        self.line
+    }
+
+    /// Gets the zero-based column number of the position, in characters from the start of the
+    /// line.
+    #[must_use]
+    pub const fn column(self) -> u32 {
+        self.column
+    }
+}
+
+impl From<Position> for ErrorPosition {
+    fn from(position: Position) -> Self {
+        Self {
+            line: position.line_number() - 1,
+            column: position.column_number() - 1,
+        }
     }
 }
 
@@ -845,6 +1081,14 @@ pub enum JsNativeErrorKind {
     /// [e_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
     /// [d_uri]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
     Uri,
+    /// Error thrown when the execution is interrupted with an
+    /// [`InterruptHandle`](crate::vm::InterruptHandle). Not a valid JS error variant, and it
+    /// cannot be caught by a `try` statement.
+    Interrupted,
+    /// Error thrown when the execution exceeds the limit set with
+    /// [`Context::set_instruction_limit`](crate::Context::set_instruction_limit). Not a valid JS
+    /// error variant, and it cannot be caught by a `try` statement.
+    InstructionLimitExceeded,
     /// Error thrown when no instructions remain. Only used in a fuzzing context; not a valid JS
     /// error variant.
     #[cfg(feature = "fuzz")]
@@ -878,6 +1122,8 @@ impl std::fmt::Display for JsNativeErrorKind {
             Self::Syntax => "SyntaxError",
             Self::Type => "TypeError",
             Self::Uri => "UriError",
+            Self::Interrupted => "Interrupted",
+            Self::InstructionLimitExceeded => "InstructionLimitExceeded",
             #[cfg(feature = "fuzz")]
             Self::NoInstructionsRemain => "NoInstructionsRemain",
         }
@@ -187,6 +187,23 @@ pub trait JobQueue {
     /// it should only run one iteration of the queue.
     fn run_jobs(&self, context: &mut Context<'_>);
 
+    /// Runs the next job in the queue, returning `true` if a job was run.
+    ///
+    /// This allows hosts to interleave the execution of jobs with their own event loop. The
+    /// default implementation doesn't support running jobs individually, so it does nothing and
+    /// returns `false`.
+    fn run_next_job(&self, context: &mut Context<'_>) -> bool {
+        let _ = context;
+        false
+    }
+
+    /// Returns `true` if there are jobs waiting to be run in the queue.
+    ///
+    /// The default implementation always returns `false`.
+    fn has_pending_jobs(&self) -> bool {
+        false
+    }
+
     /// Enqueues a new [`Future`] job on the job queue.
     ///
     /// On completion, `future` returns a new [`NativeJob`] that needs to be enqueued into the
@@ -253,16 +270,30 @@ impl JobQueue for SimpleJobQueue {
     }
 
     fn run_jobs(&self, context: &mut Context<'_>) {
+        while self.run_next_job(context) {}
+    }
+
+    fn run_next_job(&self, context: &mut Context<'_>) -> bool {
         // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
         // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
-                return;
-            };
-            next_job = self.0.borrow_mut().pop_front();
+        let next_job = self.0.borrow_mut().pop_front();
+        let Some(job) = next_job else {
+            return false;
+        };
+        let result = job.call(context);
+
+        // Every job is a separate synchronous run of ECMAScript code, which must release the
+        // targets of the `WeakRef`s dereferenced during the job.
+        context.clear_kept_objects();
+
+        if result.is_err() {
+            self.0.borrow_mut().clear();
         }
+        true
+    }
+
+    fn has_pending_jobs(&self) -> bool {
+        !self.0.borrow().is_empty()
     }
 
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context<'_>) {
@@ -0,0 +1,240 @@
+//! The graph of the modules requested by an entry module.
+
+use super::{ModuleLoader, ModuleSpecifierKey};
+use crate::{Context, JsResult};
+use boa_ast::ModuleItemList;
+use rustc_hash::FxHashMap;
+
+/// A module of a [`ModuleGraph`].
+#[derive(Debug)]
+pub struct ModuleNode {
+    key: ModuleSpecifierKey,
+    items: ModuleItemList,
+    dependencies: Vec<usize>,
+}
+
+impl ModuleNode {
+    /// Gets the key of the module.
+    #[must_use]
+    pub const fn key(&self) -> &ModuleSpecifierKey {
+        &self.key
+    }
+
+    /// Gets the parsed items of the module.
+    #[must_use]
+    pub const fn items(&self) -> &ModuleItemList {
+        &self.items
+    }
+
+    /// Gets the indices in [`ModuleGraph::modules`] of the modules requested by this module, in
+    /// source order.
+    #[must_use]
+    pub fn dependencies(&self) -> &[usize] {
+        &self.dependencies
+    }
+}
+
+/// The graph of the modules requested, directly or indirectly, by an entry module.
+///
+/// The graph is built by [`ModuleGraph::build`], which loads every module once with the
+/// [`ModuleLoader`] of the context. Modules can import each other in cycles; the graph finds the
+/// cycles and computes the order in which the modules must be evaluated, following the depth-first
+/// traversal of [`InnerModuleEvaluation`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+#[derive(Debug)]
+pub struct ModuleGraph {
+    modules: Vec<ModuleNode>,
+    evaluation_order: Vec<usize>,
+    cycles: Vec<Box<[usize]>>,
+}
+
+impl ModuleGraph {
+    /// Builds the graph of the modules requested by the entry module `specifier`.
+    ///
+    /// The entry module is resolved with no referrer, and every module is loaded and parsed once,
+    /// even if it is requested multiple times.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a module cannot be resolved, loaded or parsed.
+    pub fn build(specifier: &str, context: &mut Context<'_>) -> JsResult<Self> {
+        let loader = context.module_loader();
+
+        let mut graph = Self {
+            modules: Vec::new(),
+            evaluation_order: Vec::new(),
+            cycles: Vec::new(),
+        };
+        let mut indices = FxHashMap::default();
+
+        let entry = loader.resolve(None, specifier)?;
+        let mut pending = vec![graph.load(entry, &*loader, &mut indices, context)?];
+
+        while let Some(index) = pending.pop() {
+            let referrer = graph.modules[index].key.clone();
+            for request in graph.modules[index].items.requests() {
+                let specifier = context.interner().resolve_expect(request).to_string();
+                let key = loader.resolve(Some(&referrer), &specifier)?;
+                let dependency = if let Some(dependency) = indices.get(&key) {
+                    *dependency
+                } else {
+                    let dependency = graph.load(key, &*loader, &mut indices, context)?;
+                    pending.push(dependency);
+                    dependency
+                };
+
+                let dependencies = &mut graph.modules[index].dependencies;
+                if !dependencies.contains(&dependency) {
+                    dependencies.push(dependency);
+                }
+            }
+        }
+
+        graph.link();
+        Ok(graph)
+    }
+
+    /// Loads and parses the module `key`, adding it to the graph and returning its index.
+    fn load(
+        &mut self,
+        key: ModuleSpecifierKey,
+        loader: &dyn ModuleLoader,
+        indices: &mut FxHashMap<ModuleSpecifierKey, usize>,
+        context: &mut Context<'_>,
+    ) -> JsResult<usize> {
+        let source = loader.load(&key)?;
+        let items = context.parse_module(source)?;
+
+        let index = self.modules.len();
+        indices.insert(key.clone(), index);
+        self.modules.push(ModuleNode {
+            key,
+            items,
+            dependencies: Vec::new(),
+        });
+        Ok(index)
+    }
+
+    /// Computes the evaluation order and the cycles of the graph.
+    ///
+    /// This is Tarjan's strongly connected components algorithm, which is also what
+    /// [`InnerModuleEvaluation`][spec] uses with the `[[DFSIndex]]` and `[[DFSAncestorIndex]]`
+    /// fields of the module records.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-innermoduleevaluation
+    fn link(&mut self) {
+        let mut state = LinkState {
+            next_dfs_index: 0,
+            dfs_indices: vec![None; self.modules.len()],
+            dfs_ancestor_indices: vec![0; self.modules.len()],
+            stack: Vec::new(),
+            on_stack: vec![false; self.modules.len()],
+        };
+
+        // The traversal keeps its own stack of the modules being visited, with the position of
+        // their next dependency, so long chains of imports cannot overflow the native stack.
+        state.visit(0);
+        let mut visiting = vec![(0, 0)];
+        while let Some((index, next)) = visiting.last_mut() {
+            let index = *index;
+            let dependency = self.modules[index].dependencies.get(*next).copied();
+            *next += 1;
+
+            let Some(dependency) = dependency else {
+                visiting.pop();
+                self.finish_module(index, &mut state);
+                if let Some(&(parent, _)) = visiting.last() {
+                    state.dfs_ancestor_indices[parent] =
+                        state.dfs_ancestor_indices[parent].min(state.dfs_ancestor_indices[index]);
+                }
+                continue;
+            };
+
+            match state.dfs_indices[dependency] {
+                None => {
+                    state.visit(dependency);
+                    visiting.push((dependency, 0));
+                }
+                Some(dependency_index) if state.on_stack[dependency] => {
+                    state.dfs_ancestor_indices[index] =
+                        state.dfs_ancestor_indices[index].min(dependency_index);
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    /// Finishes the visit of the module at `index`, after all its dependencies were visited.
+    fn finish_module(&mut self, index: usize, state: &mut LinkState) {
+        self.evaluation_order.push(index);
+
+        let dfs_index = state.dfs_indices[index].expect("the module must have been visited");
+        if state.dfs_ancestor_indices[index] == dfs_index {
+            let start = state
+                .stack
+                .iter()
+                .rposition(|module| *module == index)
+                .expect("the module must be on the stack");
+            let component = state.stack.split_off(start);
+            for module in &component {
+                state.on_stack[*module] = false;
+            }
+
+            let self_dependency = self.modules[index].dependencies.contains(&index);
+            if component.len() > 1 || self_dependency {
+                self.cycles.push(component.into_boxed_slice());
+            }
+        }
+    }
+
+    /// Gets the modules of the graph. The entry module is always the first module.
+    #[must_use]
+    pub fn modules(&self) -> &[ModuleNode] {
+        &self.modules
+    }
+
+    /// Gets the module identified by `key`, if it is part of the graph.
+    #[must_use]
+    pub fn get(&self, key: &ModuleSpecifierKey) -> Option<&ModuleNode> {
+        self.modules.iter().find(|module| module.key == *key)
+    }
+
+    /// Gets the indices in [`ModuleGraph::modules`] of the modules in the order they must be
+    /// evaluated, which is after all their dependencies, unless they are part of a cycle.
+    #[must_use]
+    pub fn evaluation_order(&self) -> &[usize] {
+        &self.evaluation_order
+    }
+
+    /// Gets the cycles of the graph, as lists of indices in [`ModuleGraph::modules`] in the order
+    /// the modules of each cycle were first visited.
+    ///
+    /// A module that imports itself is a cycle of a single module.
+    #[must_use]
+    pub fn cycles(&self) -> &[Box<[usize]>] {
+        &self.cycles
+    }
+}
+
+/// The state of the depth-first traversal of [`ModuleGraph::link`].
+#[derive(Debug)]
+struct LinkState {
+    next_dfs_index: usize,
+    dfs_indices: Vec<Option<usize>>,
+    dfs_ancestor_indices: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+}
+
+impl LinkState {
+    /// Starts the visit of the module at `index`.
+    fn visit(&mut self, index: usize) {
+        let dfs_index = self.next_dfs_index;
+        self.next_dfs_index += 1;
+        self.dfs_indices[index] = Some(dfs_index);
+        self.dfs_ancestor_indices[index] = dfs_index;
+        self.stack.push(index);
+        self.on_stack[index] = true;
+    }
+}
@@ -0,0 +1,156 @@
+//! Boa's implementation of the ECMAScript module loading.
+//!
+//! The host decides how modules are found and fetched by implementing the [`ModuleLoader`] trait
+//! and passing it to [`ContextBuilder::module_loader`]. The engine uses the loader to resolve the
+//! specifiers of the import declarations and re-exports of a module, and to load the source of
+//! each module of the resulting [`ModuleGraph`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+//! [`ContextBuilder::module_loader`]: crate::context::ContextBuilder::module_loader
+
+mod graph;
+
+#[cfg(test)]
+mod tests;
+
+pub use graph::{ModuleGraph, ModuleNode};
+
+use crate::{JsNativeError, JsResult, Source};
+use std::{fmt, io::Read, rc::Rc};
+
+/// The source of a module returned by [`ModuleLoader::load`].
+pub type ModuleSource = Source<'static, Box<dyn Read>>;
+
+/// The key that uniquely identifies a module, returned by [`ModuleLoader::resolve`].
+///
+/// Two specifiers that resolve to the same key refer to the same module, which is only loaded
+/// once per [`ModuleGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleSpecifierKey(Rc<str>);
+
+impl ModuleSpecifierKey {
+    /// Creates a new module key.
+    #[must_use]
+    pub fn new(key: &str) -> Self {
+        Self(key.into())
+    }
+
+    /// Gets the module key as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ModuleSpecifierKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A host-defined loader of modules.
+///
+/// This is the main API that allows hosts to customize how modules are found and fetched, similar
+/// to the [`HostLoadImportedModule`][spec] host hook.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+pub trait ModuleLoader {
+    /// Resolves the module `specifier` requested by the module `referrer` into the key of the
+    /// requested module.
+    ///
+    /// `referrer` is `None` for the entry module of a [`ModuleGraph`].
+    fn resolve(
+        &self,
+        referrer: Option<&ModuleSpecifierKey>,
+        specifier: &str,
+    ) -> JsResult<ModuleSpecifierKey>;
+
+    /// Loads the source of the module identified by `key`.
+    fn load(&self, key: &ModuleSpecifierKey) -> JsResult<ModuleSource>;
+}
+
+/// A module loader that doesn't load any module.
+///
+/// This is the loader used by a [`Context`](crate::Context) if no loader is provided to its
+/// builder. Specifiers resolve to themselves, and loading any module throws a `TypeError`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IdleModuleLoader;
+
+impl ModuleLoader for IdleModuleLoader {
+    fn resolve(
+        &self,
+        _referrer: Option<&ModuleSpecifierKey>,
+        specifier: &str,
+    ) -> JsResult<ModuleSpecifierKey> {
+        Ok(ModuleSpecifierKey::new(specifier))
+    }
+
+    fn load(&self, key: &ModuleSpecifierKey) -> JsResult<ModuleSource> {
+        Err(JsNativeError::typ()
+            .with_message(format!(
+                "cannot load module `{key}`: module loading is disabled"
+            ))
+            .into())
+    }
+}
+
+/// A module loader that loads modules from the filesystem.
+///
+/// Relative specifiers (starting with `./` or `../`) are resolved relative to the directory of the
+/// referrer, and any other specifier is resolved relative to the root directory of the loader.
+/// The keys of the modules are their canonical paths.
+///
+/// This loader is only available if the `fs-module-loader` feature is enabled.
+#[cfg(feature = "fs-module-loader")]
+#[derive(Debug, Clone)]
+pub struct FsModuleLoader {
+    root: std::path::PathBuf,
+}
+
+#[cfg(feature = "fs-module-loader")]
+impl FsModuleLoader {
+    /// Creates a new filesystem module loader that resolves non-relative specifiers from `root`.
+    pub fn new<P: Into<std::path::PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+#[cfg(feature = "fs-module-loader")]
+impl ModuleLoader for FsModuleLoader {
+    fn resolve(
+        &self,
+        referrer: Option<&ModuleSpecifierKey>,
+        specifier: &str,
+    ) -> JsResult<ModuleSpecifierKey> {
+        use std::path::Path;
+
+        let relative = specifier.starts_with("./") || specifier.starts_with("../");
+        let base = match referrer {
+            Some(referrer) if relative => Path::new(referrer.as_str())
+                .parent()
+                .unwrap_or(self.root.as_path()),
+            _ => self.root.as_path(),
+        };
+        let path = base.join(specifier).canonicalize().map_err(|err| {
+            JsNativeError::typ()
+                .with_message(format!("could not resolve module `{specifier}`: {err}"))
+        })?;
+        let key = path.to_str().ok_or_else(|| {
+            JsNativeError::typ().with_message(format!(
+                "the path of module `{specifier}` is not valid UTF-8"
+            ))
+        })?;
+        Ok(ModuleSpecifierKey::new(key))
+    }
+
+    fn load(&self, key: &ModuleSpecifierKey) -> JsResult<ModuleSource> {
+        let file = std::fs::File::open(key.as_str()).map_err(|err| {
+            JsNativeError::typ().with_message(format!("could not load module `{key}`: {err}"))
+        })?;
+        let reader: Box<dyn Read> = Box::new(std::io::BufReader::new(file));
+        Ok(Source::from_reader(reader, None))
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::Read;
+
+use rustc_hash::FxHashMap;
+
+use super::{ModuleGraph, ModuleLoader, ModuleSource, ModuleSpecifierKey};
+use crate::{context::ContextBuilder, Context, JsNativeError, JsResult, Source};
+
+/// A loader of in-memory modules, whose keys are their specifiers.
+#[derive(Debug)]
+struct MemoryModuleLoader(FxHashMap<&'static str, &'static str>);
+
+impl MemoryModuleLoader {
+    fn new<const N: usize>(modules: [(&'static str, &'static str); N]) -> Self {
+        Self(modules.into_iter().collect())
+    }
+}
+
+impl ModuleLoader for MemoryModuleLoader {
+    fn resolve(
+        &self,
+        _referrer: Option<&ModuleSpecifierKey>,
+        specifier: &str,
+    ) -> JsResult<ModuleSpecifierKey> {
+        Ok(ModuleSpecifierKey::new(specifier))
+    }
+
+    fn load(&self, key: &ModuleSpecifierKey) -> JsResult<ModuleSource> {
+        let source = self.0.get(key.as_str()).ok_or_else(|| {
+            JsNativeError::typ().with_message(format!("module `{key}` not found"))
+        })?;
+        let reader: Box<dyn Read> = Box::new(source.as_bytes());
+        Ok(Source::from_reader(reader, None))
+    }
+}
+
+/// Gets the keys of the modules at `indices`.
+fn keys<'a>(graph: &'a ModuleGraph, indices: &[usize]) -> Vec<&'a str> {
+    indices
+        .iter()
+        .map(|index| graph.modules()[*index].key().as_str())
+        .collect()
+}
+
+#[test]
+fn module_graph_acyclic() {
+    let loader: &dyn ModuleLoader = &MemoryModuleLoader::new([
+        (
+            "main",
+            r#"import { x } from "x"; import "y"; export * from "x";"#,
+        ),
+        ("x", r#"import { z } from "z"; export const x = z;"#),
+        ("y", r#"export { z as y } from "z";"#),
+        ("z", "export const z = 1;"),
+    ]);
+    let context = &mut ContextBuilder::new().module_loader(loader).build().unwrap();
+
+    let graph = ModuleGraph::build("main", context).unwrap();
+    assert_eq!(graph.modules().len(), 4);
+    assert_eq!(graph.modules()[0].key().as_str(), "main");
+
+    let main = graph.get(&ModuleSpecifierKey::new("main")).unwrap();
+    assert_eq!(keys(&graph, main.dependencies()), ["x", "y"]);
+    assert_eq!(
+        keys(&graph, graph.evaluation_order()),
+        ["z", "x", "y", "main"]
+    );
+    assert!(graph.cycles().is_empty());
+}
+
+#[test]
+fn module_graph_cycles() {
+    let loader: &dyn ModuleLoader = &MemoryModuleLoader::new([
+        ("main", r#"import "a"; import "c";"#),
+        ("a", r#"import "b"; export const a = 1;"#),
+        ("b", r#"import { a } from "a"; export const b = a;"#),
+        ("c", r#"import * as c from "c"; export const d = 1;"#),
+    ]);
+    let context = &mut ContextBuilder::new().module_loader(loader).build().unwrap();
+
+    let graph = ModuleGraph::build("main", context).unwrap();
+    assert_eq!(graph.modules().len(), 4);
+    assert_eq!(
+        keys(&graph, graph.evaluation_order()),
+        ["b", "a", "c", "main"]
+    );
+
+    let cycles = graph
+        .cycles()
+        .iter()
+        .map(|cycle| keys(&graph, cycle))
+        .collect::<Vec<_>>();
+    assert_eq!(cycles, [vec!["a", "b"], vec!["c"]]);
+}
+
+#[test]
+fn module_graph_errors() {
+    let loader: &dyn ModuleLoader = &MemoryModuleLoader::new([
+        ("main", r#"import "missing";"#),
+        ("invalid", "export export;"),
+    ]);
+    let context = &mut ContextBuilder::new().module_loader(loader).build().unwrap();
+
+    assert!(ModuleGraph::build("main", context).is_err());
+    assert!(ModuleGraph::build("invalid", context).is_err());
+
+    // The default loader doesn't load any module.
+    let context = &mut Context::default();
+    assert!(ModuleGraph::build("main", context).is_err());
+}
+
+#[test]
+fn dynamic_import_rejects() {
+    let loader: &dyn ModuleLoader = &MemoryModuleLoader::new([("invalid", "export export;")]);
+    let context = &mut ContextBuilder::new().module_loader(loader).build().unwrap();
+
+    let result = context
+        .eval_script(Source::from_bytes(
+            r#"
+            var errors = [];
+            var promises = [
+                import("missing"),
+                import("invalid"),
+                import({ toString() { throw new RangeError("specifier"); } }),
+                import("invalid", 1),
+            ];
+            for (const promise of promises) {
+                promise.catch((e) => errors.push(e.constructor.name));
+            }
+            promises.every((promise) => promise instanceof Promise)
+            "#,
+        ))
+        .unwrap();
+    assert!(result.to_boolean());
+
+    context.run_jobs();
+    let errors = context
+        .eval_script(Source::from_bytes("errors.join()"))
+        .unwrap();
+    assert_eq!(
+        errors.to_string(context).unwrap().to_std_string_escaped(),
+        "TypeError,SyntaxError,RangeError,TypeError"
+    );
+}
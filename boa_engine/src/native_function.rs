@@ -179,6 +179,20 @@ impl NativeFunction {
     pub fn from_async_fn<Fut>(f: fn(&JsValue, &[JsValue], &mut Context<'_>) -> Fut) -> Self
     where
         Fut: Future<Output = JsResult<JsValue>> + 'static,
+    {
+        Self::from_async_copy_closure(f)
+    }
+
+    /// Creates a `NativeFunction` from a `Copy` closure returning a [`Future`].
+    ///
+    /// This is the closure counterpart of [`NativeFunction::from_async_fn`]. The closure only has
+    /// access to the [`Context`] before the returned future starts, meaning every value needed by
+    /// the future must be extracted beforehand. The result of the future is handed back to the
+    /// engine as a job, which settles the returned `Promise` the next time the job queue runs.
+    pub fn from_async_copy_closure<F, Fut>(f: F) -> Self
+    where
+        F: Fn(&JsValue, &[JsValue], &mut Context<'_>) -> Fut + Copy + 'static,
+        Fut: Future<Output = JsResult<JsValue>> + 'static,
     {
         Self::from_copy_closure(move |this, args, context| {
             let (promise, resolvers) = JsPromise::new_pending(context);
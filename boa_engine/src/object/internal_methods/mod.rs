@@ -24,6 +24,7 @@ pub(super) mod proxy;
 pub(super) mod string;
 
 pub(crate) use array::ARRAY_EXOTIC_INTERNAL_METHODS;
+pub(crate) use function::{CONSTRUCTOR_INTERNAL_METHODS, FUNCTION_INTERNAL_METHODS};
 
 impl JsObject {
     /// Internal method `[[GetPrototypeOf]]`
@@ -3,9 +3,12 @@
 //! The `JsObject` is a garbage collected Object.
 
 use super::{
-    internal_methods::{InternalObjectMethods, ARRAY_EXOTIC_INTERNAL_METHODS},
+    internal_methods::{
+        InternalObjectMethods, ARRAY_EXOTIC_INTERNAL_METHODS, CONSTRUCTOR_INTERNAL_METHODS,
+        FUNCTION_INTERNAL_METHODS, ORDINARY_INTERNAL_METHODS,
+    },
     shape::{shared_shape::SharedShape, Shape},
-    JsPrototype, NativeObject, Object, PropertyMap,
+    HostDefined, JsPrototype, NativeObject, Object, PropertyMap,
 };
 use crate::{
     context::intrinsics::Intrinsics,
@@ -113,6 +116,7 @@ impl JsObject {
                     properties: PropertyMap::from_prototype_unique_shape(prototype.into()),
                     extensible: true,
                     private_elements: ThinVec::new(),
+                    host_defined: HostDefined::default(),
                 }),
                 vtable: data.internal_methods,
             }),
@@ -141,6 +145,7 @@ impl JsObject {
                     ),
                     extensible: true,
                     private_elements: ThinVec::new(),
+                    host_defined: HostDefined::default(),
                 }),
                 vtable: data.internal_methods,
             }),
@@ -336,6 +341,69 @@ impl JsObject {
         }
     }
 
+    /// Attaches `data` to the `[[HostDefined]]` field of the object, replacing any data that was
+    /// previously attached.
+    ///
+    /// Unlike the data of a native object, the host data can be attached to any object, and
+    /// doesn't change its behaviour.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    #[track_caller]
+    pub fn set_host_data<T>(&self, data: T)
+    where
+        T: NativeObject,
+    {
+        self.borrow_mut().host_defined.set(data);
+    }
+
+    /// Gets a reference to the data attached to the object with [`JsObject::set_host_data`], if
+    /// it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[track_caller]
+    pub fn host_data<T>(&self) -> Option<Ref<'_, T>>
+    where
+        T: NativeObject,
+    {
+        let object = self.borrow();
+        if object.host_defined.is::<T>() {
+            Some(Ref::map(object, |x| {
+                x.host_defined
+                    .downcast_ref::<T>()
+                    .expect("downcasting reference failed")
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the data attached to the object with
+    /// [`JsObject::set_host_data`], if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    #[track_caller]
+    pub fn host_data_mut<T>(&self) -> Option<RefMut<'_, Object, T>>
+    where
+        T: NativeObject,
+    {
+        let object = self.borrow_mut();
+        if object.host_defined.is::<T>() {
+            Some(RefMut::map(object, |x| {
+                x.host_defined
+                    .downcast_mut::<T>()
+                    .expect("downcasting mutable reference failed")
+            }))
+        } else {
+            None
+        }
+    }
+
     /// Get the prototype of the object.
     ///
     /// # Panics
@@ -374,6 +442,21 @@ impl JsObject {
         std::ptr::eq(self.vtable(), &ARRAY_EXOTIC_INTERNAL_METHODS)
     }
 
+    /// Checks if the internal methods of the object are the ones of ordinary objects, functions or
+    /// arrays, so its own keys are only described by its shape and indexed properties.
+    #[inline]
+    pub(crate) fn has_ordinary_internal_methods(&self) -> bool {
+        let methods = self.vtable();
+        [
+            &ORDINARY_INTERNAL_METHODS,
+            &FUNCTION_INTERNAL_METHODS,
+            &CONSTRUCTOR_INTERNAL_METHODS,
+            &ARRAY_EXOTIC_INTERNAL_METHODS,
+        ]
+        .into_iter()
+        .any(|ordinary| std::ptr::eq(methods, ordinary))
+    }
+
     /// Checks if it's a `DataView` object.
     ///
     /// # Panics
@@ -52,8 +52,10 @@ use crate::{
         set::SetIterator,
         string::StringIterator,
         typed_array::{integer_indexed_object::IntegerIndexed, TypedArrayKind},
+        weak::FinalizationRegistry,
         DataView, Date, Promise, RegExp,
     },
+    error::ErrorPosition,
     js_string,
     native_function::NativeFunction,
     property::{Attribute, PropertyDescriptor, PropertyKey},
@@ -138,6 +140,8 @@ pub struct Object {
     pub(crate) extensible: bool,
     /// The `[[PrivateElements]]` internal slot.
     private_elements: ThinVec<(PrivateName, PrivateElement)>,
+    /// The data attached to the object by the host.
+    host_defined: HostDefined,
 }
 
 impl Default for Object {
@@ -147,6 +151,7 @@ impl Default for Object {
             properties: PropertyMap::default(),
             extensible: true,
             private_elements: ThinVec::new(),
+            host_defined: HostDefined::default(),
         }
     }
 }
@@ -158,9 +163,57 @@ unsafe impl Trace for Object {
         for (_, element) in &this.private_elements {
             mark(element);
         }
+        mark(&this.host_defined);
     });
 }
 
+/// A `[[HostDefined]]` field, holding arbitrary Rust data that an embedder attached to an object
+/// or a [`Realm`][crate::realm::Realm].
+///
+/// The trait object is boxed twice to keep the field as small as a thin pointer, since every
+/// object has one but few objects ever get host data.
+#[derive(Default, Trace, Finalize)]
+#[allow(clippy::box_collection, clippy::redundant_allocation)]
+pub struct HostDefined(Option<Box<Box<dyn NativeObject>>>);
+
+sa::assert_eq_size!(HostDefined, *const ());
+
+impl HostDefined {
+    /// Replaces the data of the field with `data`.
+    pub(crate) fn set<T: NativeObject>(&mut self, data: T) {
+        self.0 = Some(Box::new(Box::new(data)));
+    }
+
+    /// Checks if the field holds data of type `T`.
+    pub(crate) fn is<T: NativeObject>(&self) -> bool {
+        self.0
+            .as_deref()
+            .map_or(false, |data| data.deref().as_any().is::<T>())
+    }
+
+    /// Downcasts a reference to the data of the field, if it is of type `T`.
+    pub(crate) fn downcast_ref<T: NativeObject>(&self) -> Option<&T> {
+        self.0.as_deref()?.deref().as_any().downcast_ref::<T>()
+    }
+
+    /// Downcasts a mutable reference to the data of the field, if it is of type `T`.
+    pub(crate) fn downcast_mut<T: NativeObject>(&mut self) -> Option<&mut T> {
+        self.0
+            .as_deref_mut()?
+            .deref_mut()
+            .as_mut_any()
+            .downcast_mut::<T>()
+    }
+}
+
+impl Debug for HostDefined {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("HostDefined")
+            .field(&self.0.as_ref().map(|_| "dyn NativeObject"))
+            .finish()
+    }
+}
+
 /// The representation of private object elements.
 #[derive(Clone, Debug, Trace, Finalize)]
 pub enum PrivateElement {
@@ -271,8 +324,8 @@ pub enum ObjectKind {
     /// The `Symbol` object kind.
     Symbol(JsSymbol),
 
-    /// The `Error` object kind.
-    Error(ErrorKind),
+    /// The `Error` object kind, with the source position of syntax errors thrown by the parser.
+    Error(ErrorKind, Option<ErrorPosition>),
 
     /// The ordinary object kind.
     Ordinary,
@@ -307,6 +360,9 @@ pub enum ObjectKind {
     /// The `WeakSet` object kind.
     WeakSet(boa_gc::WeakMap<VTableObject, ()>),
 
+    /// The `FinalizationRegistry` object kind.
+    FinalizationRegistry(FinalizationRegistry),
+
     /// The `Intl.Collator` object kind.
     #[cfg(feature = "intl")]
     Collator(Box<Collator>),
@@ -362,6 +418,7 @@ unsafe impl Trace for ObjectKind {
             Self::WeakRef(wr) => mark(wr),
             Self::WeakMap(wm) => mark(wm),
             Self::WeakSet(ws) => mark(ws),
+            Self::FinalizationRegistry(fr) => mark(fr),
             #[cfg(feature = "intl")]
             Self::DateTimeFormat(f) => mark(f),
             #[cfg(feature = "intl")]
@@ -378,7 +435,7 @@ unsafe impl Trace for ObjectKind {
             | Self::String(_)
             | Self::Date(_)
             | Self::Array
-            | Self::Error(_)
+            | Self::Error(..)
             | Self::Ordinary
             | Self::Global
             | Self::Number(_)
@@ -616,8 +673,13 @@ impl ObjectData {
 
     /// Create the `Error` object data
     pub(crate) fn error(error: ErrorKind) -> Self {
+        Self::error_with_position(error, None)
+    }
+
+    /// Create the `Error` object data of an error thrown at `position` of the source code.
+    pub(crate) fn error_with_position(error: ErrorKind, position: Option<ErrorPosition>) -> Self {
         Self {
-            kind: ObjectKind::Error(error),
+            kind: ObjectKind::Error(error, position),
             internal_methods: &ORDINARY_INTERNAL_METHODS,
         }
     }
@@ -692,6 +754,15 @@ impl ObjectData {
         }
     }
 
+    /// Create the `FinalizationRegistry` object data
+    #[must_use]
+    pub fn finalization_registry(finalization_registry: FinalizationRegistry) -> Self {
+        Self {
+            kind: ObjectKind::FinalizationRegistry(finalization_registry),
+            internal_methods: &ORDINARY_INTERNAL_METHODS,
+        }
+    }
+
     /// Create the `NativeObject` object data
     #[must_use]
     pub fn native_object<T: NativeObject>(native_object: T) -> Self {
@@ -803,7 +874,7 @@ impl Debug for ObjectKind {
             Self::String(_) => "String",
             Self::StringIterator(_) => "StringIterator",
             Self::Symbol(_) => "Symbol",
-            Self::Error(_) => "Error",
+            Self::Error(..) => "Error",
             Self::Ordinary => "Ordinary",
             Self::Proxy(_) => "Proxy",
             Self::Boolean(_) => "Boolean",
@@ -819,6 +890,7 @@ impl Debug for ObjectKind {
             Self::WeakRef(_) => "WeakRef",
             Self::WeakMap(_) => "WeakMap",
             Self::WeakSet(_) => "WeakSet",
+            Self::FinalizationRegistry(_) => "FinalizationRegistry",
             #[cfg(feature = "intl")]
             Self::Collator(_) => "Collator",
             #[cfg(feature = "intl")]
@@ -1176,14 +1248,23 @@ impl Object {
     /// Checks if the object is a `Error` object.
     #[inline]
     pub const fn is_error(&self) -> bool {
-        matches!(self.kind, ObjectKind::Error(_))
+        matches!(self.kind, ObjectKind::Error(..))
     }
 
     /// Gets the error data if the object is a `Error`.
     #[inline]
     pub const fn as_error(&self) -> Option<ErrorKind> {
         match self.kind {
-            ObjectKind::Error(e) => Some(e),
+            ObjectKind::Error(e, _) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Gets the source position of the error if the object is a `Error` thrown by the parser.
+    #[inline]
+    pub const fn error_position(&self) -> Option<ErrorPosition> {
+        match self.kind {
+            ObjectKind::Error(_, position) => position,
             _ => None,
         }
     }
@@ -1486,6 +1567,24 @@ impl Object {
         }
     }
 
+    /// Gets the `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub const fn as_finalization_registry(&self) -> Option<&FinalizationRegistry> {
+        match self.kind {
+            ObjectKind::FinalizationRegistry(ref registry) => Some(registry),
+            _ => None,
+        }
+    }
+
+    /// Gets the mutable `FinalizationRegistry` data if the object is a `FinalizationRegistry`.
+    #[inline]
+    pub fn as_finalization_registry_mut(&mut self) -> Option<&mut FinalizationRegistry> {
+        match self.kind {
+            ObjectKind::FinalizationRegistry(ref mut registry) => Some(registry),
+            _ => None,
+        }
+    }
+
     /// Gets the prototype instance of this object.
     #[inline]
     pub fn prototype(&self) -> JsPrototype {
@@ -2031,12 +2130,14 @@ impl<'ctx, 'host> ConstructorBuilder<'ctx, 'host> {
                 properties: PropertyMap::default(),
                 extensible: true,
                 private_elements: ThinVec::new(),
+                host_defined: HostDefined::default(),
             },
             prototype: Object {
                 kind: ObjectKind::Ordinary,
                 properties: PropertyMap::default(),
                 extensible: true,
                 private_elements: ThinVec::new(),
+                host_defined: HostDefined::default(),
             },
             length: 0,
             name: js_string!(),
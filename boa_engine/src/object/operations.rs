@@ -2,9 +2,9 @@ use crate::{
     builtins::{function::ClassFieldDefinition, Array},
     context::intrinsics::{StandardConstructor, StandardConstructors},
     error::JsNativeError,
-    object::{JsObject, PrivateElement, PROTOTYPE},
+    object::{shape::EnumerationKeys, JsObject, PrivateElement, PROTOTYPE},
     property::{PropertyDescriptor, PropertyDescriptorBuilder, PropertyKey, PropertyNameKind},
-    realm::Realm,
+    realm::{Realm, RealmId},
     string::utf16,
     value::Type,
     Context, JsResult, JsSymbol, JsValue,
@@ -473,6 +473,88 @@ impl JsObject {
         Ok(true)
     }
 
+    /// Prevents new properties from being added to the object, like `Object.preventExtensions`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the object refuses to become non-extensible.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+    pub fn prevent_extensions(&self, context: &mut Context<'_>) -> JsResult<()> {
+        if !self.__prevent_extensions__(context)? {
+            return Err(JsNativeError::typ()
+                .with_message("cannot prevent extensions")
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Makes the object [`sealed`][IntegrityLevel::Sealed], like `Object.seal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the object cannot be sealed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    pub fn seal(&self, context: &mut Context<'_>) -> JsResult<()> {
+        if !self.set_integrity_level(IntegrityLevel::Sealed, context)? {
+            return Err(JsNativeError::typ()
+                .with_message("cannot seal object")
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Makes the object [`frozen`][IntegrityLevel::Frozen], like `Object.freeze`.
+    ///
+    /// Writes to the properties of a frozen object throw a `TypeError` in strict mode code, and
+    /// are silently ignored in non-strict code.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the object cannot be frozen.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    pub fn freeze(&self, context: &mut Context<'_>) -> JsResult<()> {
+        if !self.set_integrity_level(IntegrityLevel::Frozen, context)? {
+            return Err(JsNativeError::typ()
+                .with_message("cannot freeze object")
+                .into());
+        }
+        Ok(())
+    }
+
+    /// Checks if the object is [`sealed`][IntegrityLevel::Sealed], like `Object.isSealed`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    #[inline]
+    pub fn is_sealed(&self, context: &mut Context<'_>) -> JsResult<bool> {
+        self.test_integrity_level(IntegrityLevel::Sealed, context)
+    }
+
+    /// Checks if the object is [`frozen`][IntegrityLevel::Frozen], like `Object.isFrozen`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    #[inline]
+    pub fn is_frozen(&self, context: &mut Context<'_>) -> JsResult<bool> {
+        self.test_integrity_level(IntegrityLevel::Frozen, context)
+    }
+
     /// Abstract operation [`LengthOfArrayLike ( obj )`][spec].
     ///
     /// Returns the value of the "length" property of an array-like object.
@@ -548,6 +630,16 @@ impl JsObject {
         kind: PropertyNameKind,
         context: &mut Context<'_>,
     ) -> JsResult<Vec<JsValue>> {
+        if matches!(kind, PropertyNameKind::Key) {
+            if let Some(keys) = self.cached_enumeration_keys(context) {
+                return Ok(keys
+                    .iter()
+                    .filter(|(_, enumerable)| *enumerable)
+                    .map(|(key, _)| key.clone().into())
+                    .collect());
+            }
+        }
+
         // 1. Assert: Type(O) is Object.
         // 2. Let ownKeys be ? O.[[OwnPropertyKeys]]().
         let own_keys = self.__own_property_keys__(context)?;
@@ -599,6 +691,28 @@ impl JsObject {
         Ok(properties)
     }
 
+    /// Gets the string keys of the object in the order of `[[OwnPropertyKeys]]`, paired with their
+    /// enumerability, from the property enumeration cache of its shape.
+    ///
+    /// Returns `None` if the cache is disabled, or if the keys of the object don't depend only on
+    /// its shape, which is the case for exotic objects and objects with indexed properties.
+    pub(crate) fn cached_enumeration_keys(&self, context: &Context<'_>) -> Option<EnumerationKeys> {
+        if !context.property_enumeration_cache() {
+            return None;
+        }
+
+        if !self.has_ordinary_internal_methods() {
+            return None;
+        }
+
+        let object = self.borrow();
+        if object.properties().index_property_keys().len() != 0 {
+            return None;
+        }
+
+        Some(object.properties().shape.enumeration_keys())
+    }
+
     /// Abstract operation `GetMethod ( V, P )`
     ///
     /// Retrieves the value of a specific property, when the value of the property is expected to be a function.
@@ -684,6 +798,16 @@ impl JsObject {
         Ok(context.realm().clone())
     }
 
+    /// Returns the id of the realm associated with this object, as given by the
+    /// [`GetFunctionRealm`][spec] abstract operation.
+    ///
+    /// For objects that are not functions, this is the id of the currently active realm.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getfunctionrealm
+    pub fn function_realm_id(&self, context: &mut Context<'_>) -> JsResult<RealmId> {
+        self.get_function_realm(context).map(|realm| realm.id())
+    }
+
     // todo: CopyDataProperties
 
     /// Abstract operation `PrivateElementFind ( O, P )`
@@ -1103,6 +1227,26 @@ impl JsValue {
         // 3. Let len be ? LengthOfArrayLike(obj).
         let len = obj.length_of_array_like(context)?;
 
+        // Fast path: the elements of a packed array are plain data properties, so they can be
+        // read in place from its dense storage without any observable `Get` calls. The list is
+        // still a snapshot, since the callee of `apply` can mutate the array while it runs.
+        if obj.is_array() {
+            let obj = obj.borrow();
+            if let Some(elements) = obj
+                .properties()
+                .dense_indexed_properties()
+                .filter(|elements| elements.len() as u64 == len)
+            {
+                if !elements
+                    .iter()
+                    .all(|element| types.contains(&element.get_type()))
+                {
+                    return Err(JsNativeError::typ().with_message("bad type").into());
+                }
+                return Ok(elements.to_vec());
+            }
+        }
+
         // 4. Let list be a new empty List.
         let mut list = Vec::with_capacity(len as usize);
 
@@ -8,11 +8,11 @@ pub(crate) mod unique_shape;
 pub use shared_shape::SharedShape;
 pub(crate) use unique_shape::UniqueShape;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, rc::Rc};
 
 use boa_gc::{Finalize, Trace};
 
-use crate::property::PropertyKey;
+use crate::{property::PropertyKey, JsString};
 
 use self::{shared_shape::TransitionKey, slot::Slot};
 
@@ -49,6 +49,9 @@ pub(crate) struct ChangeTransition<T> {
     pub(crate) action: ChangeTransitionAction,
 }
 
+/// The string keys of a shape in property enumeration order, paired with their enumerability.
+pub(crate) type EnumerationKeys = Rc<[(JsString, bool)]>;
+
 /// The internal representation of [`Shape`].
 #[derive(Debug, Trace, Finalize, Clone)]
 enum Inner {
@@ -108,6 +111,13 @@ impl Shape {
         None
     }
 
+    pub(crate) const fn as_shared(&self) -> Option<&SharedShape> {
+        if let Inner::Shared(shape) = &self.inner {
+            return Some(shape);
+        }
+        None
+    }
+
     /// Create an insert property transitions returning the new transitioned [`Shape`].
     ///
     /// NOTE: This assumes that there is no property with the given key!
@@ -206,6 +216,28 @@ impl Shape {
         }
     }
 
+    /// Gets the string keys of the [`Shape`] in property enumeration order, paired with their
+    /// enumerability.
+    ///
+    /// The keys are cached in the shape until it changes.
+    pub(crate) fn enumeration_keys(&self) -> EnumerationKeys {
+        match &self.inner {
+            Inner::Shared(shape) => shape.enumeration_keys(),
+            Inner::Unique(shape) => shape.enumeration_keys(),
+        }
+    }
+
+    /// Gets the number of times the [`Shape`] was modified in place.
+    ///
+    /// Only unique shapes are modified in place, so together with the address of the shape this
+    /// identifies the layout of an object. Always `0` for shared shapes.
+    pub(crate) fn version(&self) -> u32 {
+        match &self.inner {
+            Inner::Shared(_) => 0,
+            Inner::Unique(shape) => shape.version(),
+        }
+    }
+
     /// Return location in memory of the [`Shape`].
     #[inline]
     pub fn to_addr_usize(&self) -> usize {
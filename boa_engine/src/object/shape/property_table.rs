@@ -5,6 +5,7 @@ use rustc_hash::FxHashMap;
 use crate::{
     object::shape::slot::{Slot, SlotAttributes},
     property::PropertyKey,
+    JsString,
 };
 
 /// The internal representation of [`PropertyTable`].
@@ -40,6 +41,23 @@ impl PropertyTableInner {
             .collect()
     }
 
+    /// Returns the string keys of the first `n` properties, in insertion order, paired with
+    /// their enumerability.
+    pub(crate) fn enumeration_keys_n(&self, n: u32) -> Vec<(JsString, bool)> {
+        self.keys
+            .iter()
+            .take(n as usize)
+            .filter_map(|(key, slot)| {
+                let key = match key {
+                    PropertyKey::String(key) => key.clone(),
+                    PropertyKey::Index(index) => index.to_string().into(),
+                    PropertyKey::Symbol(_) => return None,
+                };
+                Some((key, slot.attributes.contains(SlotAttributes::ENUMERABLE)))
+            })
+            .collect()
+    }
+
     /// Returns a new table with `n` cloned properties.
     pub(crate) fn clone_count(&self, n: u32) -> Self {
         let n = n as usize;
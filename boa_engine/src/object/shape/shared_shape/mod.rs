@@ -1,7 +1,7 @@
 mod forward_transition;
 pub(crate) mod template;
 
-use std::{collections::hash_map::RandomState, hash::Hash};
+use std::{cell::RefCell, collections::hash_map::RandomState, hash::Hash};
 
 use bitflags::bitflags;
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
@@ -13,7 +13,7 @@ use self::forward_transition::ForwardTransition;
 
 use super::{
     property_table::PropertyTable, slot::SlotAttributes, ChangeTransition, ChangeTransitionAction,
-    Slot, UniqueShape,
+    EnumerationKeys, Slot, UniqueShape,
 };
 
 /// Represent a [`SharedShape`] property transition.
@@ -108,6 +108,10 @@ struct Inner {
 
     /// Flags about the shape.
     flags: ShapeFlags,
+
+    /// The cached enumeration keys of the shape, see [`SharedShape::enumeration_keys`].
+    #[unsafe_ignore_trace]
+    enumeration_keys: RefCell<Option<EnumerationKeys>>,
 }
 
 /// Represents a shared object shape.
@@ -182,6 +186,7 @@ impl SharedShape {
             property_table: PropertyTable::default(),
             previous: None,
             flags: ShapeFlags::default(),
+            enumeration_keys: RefCell::default(),
             transition_count: 0,
         })
     }
@@ -201,6 +206,7 @@ impl SharedShape {
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::prototype_transition_from(self.flags()),
+            enumeration_keys: RefCell::default(),
         };
         let new_shape = Self::new(new_inner_shape);
 
@@ -232,6 +238,7 @@ impl SharedShape {
             previous: Some(self.clone()),
             transition_count: self.transition_count() + 1,
             flags: ShapeFlags::insert_property_transition_from(self.flags()),
+            enumeration_keys: RefCell::default(),
         };
         let new_shape = Self::new(new_inner_shape);
 
@@ -280,6 +287,7 @@ impl SharedShape {
                 previous: Some(self.clone()),
                 transition_count: self.transition_count() + 1,
                 flags: ShapeFlags::configure_property_transition_from(self.flags()),
+                enumeration_keys: RefCell::default(),
             };
             let shape = Self::new(inner_shape);
 
@@ -450,6 +458,22 @@ impl SharedShape {
         property_table.keys_cloned_n(self.property_count())
     }
 
+    /// Gets the string keys of the [`SharedShape`] paired with their enumerability.
+    ///
+    /// Shared shapes never change, so the keys are computed only once.
+    pub(crate) fn enumeration_keys(&self) -> EnumerationKeys {
+        self.inner
+            .enumeration_keys
+            .borrow_mut()
+            .get_or_insert_with(|| {
+                let property_table = self.property_table().inner().borrow();
+                property_table
+                    .enumeration_keys_n(self.property_count())
+                    .into()
+            })
+            .clone()
+    }
+
     /// Returns a new [`UniqueShape`] with the properties of the [`SharedShape`].
     pub(crate) fn to_unique(&self) -> UniqueShape {
         UniqueShape::new(
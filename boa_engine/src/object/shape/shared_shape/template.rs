@@ -4,7 +4,7 @@ use thin_vec::ThinVec;
 use crate::{
     object::{
         shape::{slot::SlotAttributes, Shape},
-        JsObject, Object, ObjectData, PropertyMap,
+        HostDefined, JsObject, Object, ObjectData, PropertyMap,
     },
     property::{Attribute, PropertyKey},
     JsValue,
@@ -110,6 +110,7 @@ impl ObjectTemplate {
             extensible: true,
             properties: PropertyMap::new(Shape::shared(self.shape.clone()), ThinVec::default()),
             private_elements: ThinVec::new(),
+            host_defined: HostDefined::default(),
         };
 
         object.properties.storage = storage;
@@ -132,6 +133,7 @@ impl ObjectTemplate {
             extensible: true,
             properties: PropertyMap::new(Shape::shared(self.shape.clone()), elements),
             private_elements: ThinVec::new(),
+            host_defined: HostDefined::default(),
         };
 
         object.properties.storage = storage;
@@ -1,4 +1,7 @@
-use std::{cell::RefCell, fmt::Debug};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+};
 
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 
@@ -6,7 +9,7 @@ use crate::property::PropertyKey;
 
 use super::{
     property_table::PropertyTableInner, shared_shape::TransitionKey, ChangeTransition,
-    ChangeTransitionAction, JsPrototype, Shape, Slot,
+    ChangeTransitionAction, EnumerationKeys, JsPrototype, Shape, Slot,
 };
 
 /// The internal representation of [`UniqueShape`].
@@ -20,6 +23,14 @@ struct Inner {
 
     /// The prototype of the shape.
     prototype: GcRefCell<JsPrototype>,
+
+    /// The number of times the shape was modified in place.
+    #[unsafe_ignore_trace]
+    version: Cell<u32>,
+
+    /// The cached enumeration keys of the shape, with the version they were computed for.
+    #[unsafe_ignore_trace]
+    enumeration_keys: RefCell<Option<(u32, EnumerationKeys)>>,
 }
 
 /// Represents a [`Shape`] that is not shared with any other object.
@@ -40,6 +51,8 @@ impl UniqueShape {
             inner: Gc::new(Inner {
                 property_table: RefCell::new(property_table),
                 prototype: GcRefCell::new(prototype),
+                version: Cell::new(0),
+                enumeration_keys: RefCell::default(),
             }),
         }
     }
@@ -51,6 +64,21 @@ impl UniqueShape {
     ) {
         *self.inner.property_table.borrow_mut() = property_table;
         *self.inner.prototype.borrow_mut() = prototype;
+        self.bump_version();
+    }
+
+    /// Gets the number of times the shape was modified in place.
+    ///
+    /// Modifications that create a new [`UniqueShape`] don't change the version.
+    pub(crate) fn version(&self) -> u32 {
+        self.inner.version.get()
+    }
+
+    /// Records a modification that doesn't create a new [`UniqueShape`].
+    fn bump_version(&self) {
+        self.inner
+            .version
+            .set(self.inner.version.get().wrapping_add(1));
     }
 
     /// Get the prototype of the [`UniqueShape`].
@@ -67,6 +95,7 @@ impl UniqueShape {
     pub(crate) fn insert_property_transition(&self, key: TransitionKey) -> Self {
         let mut property_table = self.property_table().borrow_mut();
         property_table.insert(key.property_key, key.attributes);
+        self.bump_version();
         self.clone()
     }
 
@@ -148,6 +177,7 @@ impl UniqueShape {
             slot.attributes = key.attributes;
             property_table.keys[index].1.attributes = key.attributes;
             // TODO: invalidate the pointer.
+            self.bump_version();
             return ChangeTransition {
                 shape: Shape::unique(self.clone()),
                 action: ChangeTransitionAction::Nothing,
@@ -233,6 +263,25 @@ impl UniqueShape {
         self.property_table().borrow().keys()
     }
 
+    /// Gets the string keys of the [`UniqueShape`] paired with their enumerability.
+    ///
+    /// The keys are cached until the shape is modified in place.
+    pub(crate) fn enumeration_keys(&self) -> EnumerationKeys {
+        let version = self.version();
+        let mut cache = self.inner.enumeration_keys.borrow_mut();
+        match &*cache {
+            Some((cached_version, keys)) if *cached_version == version => keys.clone(),
+            _ => {
+                let property_table = self.property_table().borrow();
+                let keys: EnumerationKeys = property_table
+                    .enumeration_keys_n(property_table.keys.len() as u32)
+                    .into();
+                *cache = Some((version, keys.clone()));
+                keys
+            }
+        }
+    }
+
     /// Return location in memory of the [`UniqueShape`].
     pub(crate) fn to_addr_usize(&self) -> usize {
         let ptr: *const _ = self.inner.as_ref();
@@ -1,4 +1,7 @@
-use crate::{builtins::error::ErrorKind, run_test_actions, TestAction};
+use crate::{
+    builtins::error::ErrorKind, js_string, object::ObjectInitializer, property::Attribute,
+    run_test_actions, JsValue, TestAction,
+};
 use indoc::indoc;
 
 #[test]
@@ -38,3 +41,62 @@ fn object_properties_return_order() {
         ),
     ]);
 }
+
+#[test]
+fn integrity_level_helpers() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            let sealed = ObjectInitializer::new(ctx)
+                .property(js_string!("x"), 1, Attribute::all())
+                .build();
+            let frozen = ObjectInitializer::new(ctx)
+                .property(js_string!("x"), 1, Attribute::all())
+                .build();
+            let locked = ObjectInitializer::new(ctx).build();
+
+            assert!(!sealed.is_sealed(ctx).unwrap());
+            sealed.seal(ctx).unwrap();
+            assert!(sealed.is_sealed(ctx).unwrap());
+            assert!(!sealed.is_frozen(ctx).unwrap());
+
+            frozen.freeze(ctx).unwrap();
+            assert!(frozen.is_frozen(ctx).unwrap());
+            assert!(frozen.is_sealed(ctx).unwrap());
+
+            locked.prevent_extensions(ctx).unwrap();
+            assert!(!locked.is_extensible(ctx).unwrap());
+
+            let global = ctx.global_object();
+            for (name, object) in [("sealed", sealed), ("frozen", frozen), ("locked", locked)] {
+                global.set(js_string!(name), object, true, ctx).unwrap();
+            }
+        }),
+        TestAction::assert_eq("sealed.x = 2; sealed.x", 2),
+        TestAction::assert_eq("sealed.y = 2; sealed.y", JsValue::undefined()),
+        TestAction::assert_eq("delete sealed.x", false),
+        TestAction::assert_eq("frozen.x = 2; frozen.x", 1),
+        TestAction::assert_eq("frozen.y = 2; frozen.y", JsValue::undefined()),
+        TestAction::assert_eq("locked.y = 2; locked.y", JsValue::undefined()),
+        TestAction::assert("Object.isFrozen(frozen) && Object.isSealed(sealed)"),
+        TestAction::assert_native_error(
+            "'use strict'; frozen.x = 2;",
+            ErrorKind::Type,
+            "cannot set non-writable property: x",
+        ),
+        TestAction::assert_native_error(
+            "'use strict'; frozen.y = 2;",
+            ErrorKind::Type,
+            "cannot set non-writable property: y",
+        ),
+        TestAction::assert_native_error(
+            "'use strict'; sealed.y = 2;",
+            ErrorKind::Type,
+            "cannot set non-writable property: y",
+        ),
+        TestAction::assert_native_error(
+            "'use strict'; delete frozen.x;",
+            ErrorKind::Type,
+            "Cannot delete property",
+        ),
+    ]);
+}
@@ -19,8 +19,11 @@ bitflags! {
         /// Apply contant folding optimization.
         const CONSTANT_FOLDING = 0b0000_0010;
 
+        /// Apply peephole optimizations to the compiled bytecode.
+        const PEEPHOLE = 0b0000_0100;
+
         /// Apply all optimizations.
-        const OPTIMIZE_ALL = Self::CONSTANT_FOLDING.bits();
+        const OPTIMIZE_ALL = Self::CONSTANT_FOLDING.bits() | Self::PEEPHOLE.bits();
     }
 }
 
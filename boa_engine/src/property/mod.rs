@@ -17,7 +17,11 @@
 
 mod attribute;
 
+#[cfg(test)]
+mod tests;
+
 use crate::{js_string, object::shape::slot::SlotAttributes, JsString, JsSymbol, JsValue};
+use bitflags::bitflags;
 use boa_gc::{Finalize, Trace};
 use std::{fmt, iter::FusedIterator};
 
@@ -339,6 +343,42 @@ impl PropertyDescriptor {
         }
     }
 
+    /// Compares the fields of this descriptor with the fields of `other`, and returns the fields
+    /// that differ.
+    ///
+    /// A field that is present in only one of the descriptors is different. Values, getters and
+    /// setters are compared with `SameValue`.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> DescriptorDiff {
+        fn same_value(a: Option<&JsValue>, b: Option<&JsValue>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => JsValue::same_value(a, b),
+                (a, b) => a.is_none() && b.is_none(),
+            }
+        }
+
+        let mut diff = DescriptorDiff::empty();
+        diff.set(
+            DescriptorDiff::VALUE,
+            !same_value(self.value(), other.value()),
+        );
+        diff.set(
+            DescriptorDiff::WRITABLE,
+            self.writable() != other.writable(),
+        );
+        diff.set(DescriptorDiff::GET, !same_value(self.get(), other.get()));
+        diff.set(DescriptorDiff::SET, !same_value(self.set(), other.set()));
+        diff.set(
+            DescriptorDiff::ENUMERABLE,
+            self.enumerable() != other.enumerable(),
+        );
+        diff.set(
+            DescriptorDiff::CONFIGURABLE,
+            self.configurable() != other.configurable(),
+        );
+        diff
+    }
+
     pub(crate) fn to_slot_attributes(&self) -> SlotAttributes {
         let mut attributes = SlotAttributes::empty();
         attributes.set(SlotAttributes::CONFIGURABLE, self.expect_configurable());
@@ -353,6 +393,31 @@ impl PropertyDescriptor {
     }
 }
 
+bitflags! {
+    /// The fields that differ between two property descriptors, as returned by
+    /// [`PropertyDescriptor::diff`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct DescriptorDiff: u8 {
+        /// The `[[Value]]` fields differ.
+        const VALUE = 0b0000_0001;
+
+        /// The `[[Writable]]` fields differ.
+        const WRITABLE = 0b0000_0010;
+
+        /// The `[[Enumerable]]` fields differ.
+        const ENUMERABLE = 0b0000_0100;
+
+        /// The `[[Configurable]]` fields differ.
+        const CONFIGURABLE = 0b0000_1000;
+
+        /// The `[[Get]]` fields differ.
+        const GET = 0b0001_0000;
+
+        /// The `[[Set]]` fields differ.
+        const SET = 0b0010_0000;
+    }
+}
+
 /// A builder for [`PropertyDescriptor`].
 #[derive(Default, Debug, Clone)]
 pub struct PropertyDescriptorBuilder {
@@ -0,0 +1,56 @@
+use super::{DescriptorDiff, PropertyDescriptor};
+use crate::JsValue;
+
+#[test]
+fn diff_data_and_accessor_descriptors() {
+    let data = PropertyDescriptor::builder()
+        .value(1)
+        .writable(true)
+        .enumerable(true)
+        .configurable(true)
+        .build();
+    let accessor = PropertyDescriptor::builder()
+        .get(JsValue::undefined())
+        .set(JsValue::undefined())
+        .enumerable(true)
+        .configurable(true)
+        .build();
+
+    assert_eq!(
+        data.diff(&accessor),
+        DescriptorDiff::VALUE
+            | DescriptorDiff::WRITABLE
+            | DescriptorDiff::GET
+            | DescriptorDiff::SET
+    );
+    assert_eq!(accessor.diff(&data), data.diff(&accessor));
+    assert!(data.diff(&data).is_empty());
+    assert!(accessor.diff(&accessor).is_empty());
+}
+
+#[test]
+fn diff_data_descriptors() {
+    let writable = PropertyDescriptor::builder()
+        .value(f64::NAN)
+        .writable(true)
+        .enumerable(false)
+        .configurable(true)
+        .build();
+    let read_only = PropertyDescriptor::builder()
+        .value(f64::NAN)
+        .writable(false)
+        .enumerable(false)
+        .configurable(true)
+        .build();
+    assert_eq!(writable.diff(&read_only), DescriptorDiff::WRITABLE);
+
+    let positive_zero = PropertyDescriptor::builder().value(0.0).build();
+    let negative_zero = PropertyDescriptor::builder().value(-0.0).build();
+    assert_eq!(positive_zero.diff(&negative_zero), DescriptorDiff::VALUE);
+
+    let generic = PropertyDescriptor::builder().enumerable(true).build();
+    assert_eq!(
+        positive_zero.diff(&generic),
+        DescriptorDiff::VALUE | DescriptorDiff::ENUMERABLE
+    );
+}
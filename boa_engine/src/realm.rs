@@ -9,10 +9,32 @@
 use crate::{
     context::{intrinsics::Intrinsics, HostHooks},
     environments::DeclarativeEnvironment,
-    object::{shape::shared_shape::SharedShape, JsObject},
+    object::{shape::shared_shape::SharedShape, HostDefined, JsObject, NativeObject},
 };
-use boa_gc::{Finalize, Gc, Trace};
+use boa_gc::{Finalize, Gc, GcRef, GcRefCell, GcRefMut, Trace};
 use boa_profiler::Profiler;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A unique identifier of a [`Realm`].
+///
+/// Every realm gets a distinct id when it is created, which can be used to tag objects or errors
+/// with the realm they originate from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RealmId(usize);
+
+impl RealmId {
+    /// Generates a new, unique `RealmId`.
+    fn new() -> Self {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        Self(NEXT_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+impl std::fmt::Display for RealmId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "realm#{}", self.0)
+    }
+}
 
 /// Representation of a Realm.
 ///
@@ -30,10 +52,13 @@ impl PartialEq for Realm {
 
 #[derive(Debug, Trace, Finalize)]
 struct Inner {
+    #[unsafe_ignore_trace]
+    id: RealmId,
     intrinsics: Intrinsics,
     environment: Gc<DeclarativeEnvironment>,
     global_object: JsObject,
     global_this: JsObject,
+    host_defined: GcRefCell<HostDefined>,
 }
 
 impl Realm {
@@ -50,10 +75,12 @@ impl Realm {
 
         let realm = Self {
             inner: Gc::new(Inner {
+                id: RealmId::new(),
                 intrinsics,
                 environment: Gc::new(DeclarativeEnvironment::new_global()),
                 global_object,
                 global_this,
+                host_defined: GcRefCell::default(),
             }),
         };
 
@@ -62,11 +89,66 @@ impl Realm {
         realm
     }
 
+    /// Gets the unique id of this `Realm`.
+    #[must_use]
+    pub fn id(&self) -> RealmId {
+        self.inner.id
+    }
+
     /// Gets the intrinsics of this `Realm`.
     pub fn intrinsics(&self) -> &Intrinsics {
         &self.inner.intrinsics
     }
 
+    /// Attaches `data` to the `[[HostDefined]]` field of this `Realm`, replacing any data that
+    /// was previously attached.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host data of the realm is currently borrowed.
+    #[track_caller]
+    pub fn set_host_data<T: NativeObject>(&self, data: T) {
+        self.inner.host_defined.borrow_mut().set(data);
+    }
+
+    /// Gets a reference to the data attached to this `Realm` with [`Realm::set_host_data`], if it
+    /// is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host data of the realm is currently mutably borrowed.
+    #[track_caller]
+    pub fn host_data<T: NativeObject>(&self) -> Option<GcRef<'_, T>> {
+        let host_defined = self.inner.host_defined.borrow();
+        if host_defined.is::<T>() {
+            Some(GcRef::map(host_defined, |data| {
+                data.downcast_ref::<T>()
+                    .expect("downcasting reference failed")
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Gets a mutable reference to the data attached to this `Realm` with
+    /// [`Realm::set_host_data`], if it is of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the host data of the realm is currently borrowed.
+    #[track_caller]
+    pub fn host_data_mut<T: NativeObject>(&self) -> Option<GcRefMut<'_, HostDefined, T>> {
+        let host_defined = self.inner.host_defined.borrow_mut();
+        if host_defined.is::<T>() {
+            Some(GcRefMut::map(host_defined, |data| {
+                data.downcast_mut::<T>()
+                    .expect("downcasting mutable reference failed")
+            }))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn environment(&self) -> &Gc<DeclarativeEnvironment> {
         &self.inner.environment
     }
@@ -30,11 +30,12 @@ use crate::{
 };
 use boa_gc::{empty_trace, Finalize, Trace};
 pub use boa_macros::utf16;
+use once_cell::unsync::OnceCell;
 
 use std::{
     alloc::{alloc, dealloc, Layout},
     borrow::Borrow,
-    cell::Cell,
+    cell::{Cell, RefCell},
     convert::Infallible,
     hash::{Hash, Hasher},
     iter::Peekable,
@@ -189,6 +190,87 @@ struct RawJsString {
     data: [u16; 0],
 }
 
+/// A lazily flattened string.
+///
+/// Concatenating through [`JsString::concat_rope`] only allocates one of these nodes, deferring
+/// the copy of the string data until the contents are first accessed. Long strings created with
+/// [`JsString::from_latin1`] are leaves of one byte per code unit, which are only widened to
+/// UTF-16 when their contents are first accessed.
+///
+/// Rope strings point to the header of their node with the flag of their pointer set, so they
+/// share the length and reference count of flat strings but never store data inline.
+#[repr(C)]
+struct Rope {
+    /// The length and reference count of the rope string.
+    header: RawJsString,
+
+    /// The contents of the rope, released once the rope is flattened.
+    parts: RefCell<Option<RopeParts>>,
+
+    /// The flattened contents of the rope.
+    flat: OnceCell<Box<[u16]>>,
+}
+
+/// The contents of a [`Rope`] that hasn't been flattened yet.
+enum RopeParts {
+    /// The two halves of a concatenation.
+    Concat(JsString, JsString),
+
+    /// Latin-1 encoded bytes, each of them being the UTF-16 code unit with the same value.
+    Latin1(Box<[u8]>),
+}
+
+/// Strings shorter than this are always stored flat, since copying them is cheaper than
+/// allocating a rope node.
+const ROPE_MIN_LEN: usize = 256;
+
+impl Rope {
+    /// Gets the contents of the rope, flattening it if needed.
+    fn flatten(&self) -> &[u16] {
+        self.flat.get_or_init(|| {
+            let len = self.header.len;
+            let mut data = Vec::with_capacity(len);
+            let mut stack = Vec::new();
+
+            // The parts are no longer needed after flattening, so they can be released here.
+            match self.parts.borrow_mut().take() {
+                Some(RopeParts::Concat(left, right)) => {
+                    stack.push(right);
+                    stack.push(left);
+                }
+                Some(RopeParts::Latin1(bytes)) => data.extend(bytes.iter().map(|&b| u16::from(b))),
+                None => {}
+            }
+
+            // Walk the tree iteratively, since ropes built in loops can get arbitrarily deep.
+            while let Some(string) = stack.pop() {
+                match string.rope() {
+                    Some(rope) if rope.flat.get().is_none() => {
+                        match rope
+                            .parts
+                            .borrow()
+                            .as_ref()
+                            .expect("unflattened ropes must have their parts")
+                        {
+                            RopeParts::Concat(left, right) => {
+                                stack.push(right.clone());
+                                stack.push(left.clone());
+                            }
+                            RopeParts::Latin1(bytes) => {
+                                data.extend(bytes.iter().map(|&b| u16::from(b)));
+                            }
+                        }
+                    }
+                    _ => data.extend_from_slice(&string),
+                }
+            }
+
+            debug_assert_eq!(data.len(), len);
+            data.into_boxed_slice()
+        })
+    }
+}
+
 const DATA_OFFSET: usize = std::mem::size_of::<RawJsString>();
 
 /// A UTF-16–encoded, reference counted, immutable string.
@@ -273,16 +355,176 @@ impl JsString {
         StaticJsStrings::get_string(&string[..]).unwrap_or(string)
     }
 
+    /// Creates a new [`JsString`] from the concatenation of `x` and `y`, deferring the copy of
+    /// their contents.
+    ///
+    /// Long strings are concatenated into a rope, which makes every concatenation step O(1) and
+    /// only copies the string data when the contents of the result are first accessed. Short
+    /// strings are concatenated eagerly, like [`JsString::concat`].
+    ///
+    /// The returned string behaves exactly like any other [`JsString`].
+    #[must_use]
+    pub fn concat_rope(x: &Self, y: &Self) -> Self {
+        if x.is_empty() {
+            return y.clone();
+        }
+        if y.is_empty() {
+            return x.clone();
+        }
+
+        let Some(len) = x.len().checked_add(y.len()) else {
+            alloc_overflow()
+        };
+        if len < ROPE_MIN_LEN {
+            return Self::concat(x, y);
+        }
+
+        Self::from_rope_parts(len, RopeParts::Concat(x.clone(), y.clone()))
+    }
+
+    /// Creates a new rope [`JsString`] of length `len` from its unflattened `parts`.
+    fn from_rope_parts(len: usize, parts: RopeParts) -> Self {
+        let rope = Box::new(Rope {
+            header: RawJsString {
+                len,
+                refcount: Cell::new(1),
+                data: [0; 0],
+            },
+            parts: RefCell::new(Some(parts)),
+            flat: OnceCell::new(),
+        });
+
+        Self {
+            // The header is the first field of the `#[repr(C)]` node, so the pointer to the node
+            // is also a valid pointer to the header.
+            ptr: Tagged::from_non_null_flagged(NonNull::from(Box::leak(rope)).cast()),
+        }
+    }
+
+    /// Returns the number of UTF-16 code units of the string.
+    ///
+    /// Unlike the length of the dereferenced slice, this doesn't need to flatten rope strings.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self.ptr.unwrap() {
+            // SAFETY: The reference count of `JsString` guarantees that `h` is always valid.
+            UnwrappedTagged::Ptr(h) => unsafe { h.as_ref().len },
+            UnwrappedTagged::Tag(index) => {
+                // SAFETY: all static strings are valid indices on `STATIC_JS_STRINGS`, so `get` should always
+                // return `Some`.
+                unsafe { StaticJsStrings::get(index).unwrap_unchecked() }.len()
+            }
+        }
+    }
+
+    /// Returns `true` if the string has a length of zero.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Gets the number of `JsString`s which point to this allocation.
+    fn refcount(&self) -> Option<usize> {
+        match self.ptr.unwrap() {
+            UnwrappedTagged::Ptr(inner) => {
+                // SAFETY: The reference count of `JsString` guarantees that `inner` is always valid.
+                let inner = unsafe { inner.as_ref() };
+                Some(inner.refcount.get())
+            }
+            UnwrappedTagged::Tag(_inner) => None,
+        }
+    }
+
+    /// Gets the rope node of this string, if this is a rope string.
+    fn rope(&self) -> Option<&Rope> {
+        match self.ptr.unwrap() {
+            // SAFETY: Flagged pointers always point to the header of a rope node, and the
+            // reference count of `JsString` guarantees that the node is valid for the lifetime of
+            // `self`.
+            UnwrappedTagged::Ptr(h) if self.ptr.is_flagged() => unsafe {
+                Some(h.cast::<Rope>().as_ref())
+            },
+            _ => None,
+        }
+    }
+
+    /// Creates a new [`JsString`] from a slice of Latin-1 (ISO-8859-1) encoded bytes.
+    ///
+    /// Every Latin-1 byte maps directly to the UTF-16 code unit with the same value. Long strings
+    /// keep a compact copy of the bytes, using one byte per code unit, and are only widened to
+    /// UTF-16 when their contents are first accessed. Converting them back with
+    /// [`JsString::to_std_string`] doesn't widen them. Short strings are widened eagerly, since
+    /// the compact representation needs an extra allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::JsString;
+    /// # use boa_engine::string::utf16;
+    /// let s = JsString::from_latin1(b"caf\xE9");
+    /// assert_eq!(&s, utf16!("café"));
+    /// ```
+    #[must_use]
+    pub fn from_latin1(bytes: &[u8]) -> Self {
+        if bytes.len() < ROPE_MIN_LEN {
+            return Self::from_latin1_flat(bytes);
+        }
+
+        Self::from_rope_parts(bytes.len(), RopeParts::Latin1(bytes.into()))
+    }
+
+    /// Creates a new flat [`JsString`] from a slice of Latin-1 encoded bytes, widening them
+    /// directly into the string allocation.
+    fn from_latin1_flat(bytes: &[u8]) -> Self {
+        let count = bytes.len();
+        let ptr = Self::allocate_inner(count);
+
+        let string = {
+            // SAFETY: `allocate_inner` guarantees that `ptr` is a valid pointer.
+            let data: *mut u16 = unsafe { addr_of_mut!((*ptr.as_ptr()).data).cast() };
+            for (i, &byte) in bytes.iter().enumerate() {
+                // SAFETY:
+                // `allocate_inner` allocates space for `count` properly aligned `u16`s, and
+                // `i < count`, so every write stays in-bounds.
+                unsafe {
+                    data.add(i).write(u16::from(byte));
+                }
+            }
+            Self {
+                // Safety: `allocate_inner` guarantees `ptr` is a valid heap pointer.
+                ptr: Tagged::from_non_null(ptr),
+            }
+        };
+
+        StaticJsStrings::get_string(&string[..]).unwrap_or(string)
+    }
+
+    /// Decodes the bytes of an unflattened Latin-1 string into a [`String`], without widening them.
+    fn latin1_to_std_string(&self) -> Option<String> {
+        match self.rope()?.parts.borrow().as_ref() {
+            Some(RopeParts::Latin1(bytes)) => Some(bytes.iter().map(|&b| char::from(b)).collect()),
+            _ => None,
+        }
+    }
+
     /// Decodes a [`JsString`] into a [`String`], replacing invalid data with its escaped representation
     /// in 4 digit hexadecimal.
     #[must_use]
     pub fn to_std_string_escaped(&self) -> String {
+        if let Some(string) = self.latin1_to_std_string() {
+            return string;
+        }
         self.to_string_escaped()
     }
 
     /// Decodes a [`JsString`] into a [`String`], returning
     /// [`FromUtf16Error`][std::string::FromUtf16Error] if it contains any invalid data.
     pub fn to_std_string(&self) -> Result<String, std::string::FromUtf16Error> {
+        if let Some(string) = self.latin1_to_std_string() {
+            return Ok(string);
+        }
         String::from_utf16(self)
     }
 
@@ -362,6 +604,44 @@ impl JsString {
         })
     }
 
+    /// Checks if the [`JsString`] contains no unpaired surrogate code units.
+    #[must_use]
+    pub fn is_well_formed(&self) -> bool {
+        let mut iter = self.iter().copied();
+        while let Some(code_unit) = iter.next() {
+            match code_unit {
+                0xD800..=0xDBFF => {
+                    if !matches!(iter.next(), Some(0xDC00..=0xDFFF)) {
+                        return false;
+                    }
+                }
+                0xDC00..=0xDFFF => return false,
+                _ => {}
+            }
+        }
+        true
+    }
+
+    /// Returns a copy of the [`JsString`] with every unpaired surrogate code unit replaced by
+    /// U+FFFD (REPLACEMENT CHARACTER).
+    #[must_use]
+    pub fn to_well_formed(&self) -> Self {
+        if self.is_well_formed() {
+            return self.clone();
+        }
+
+        let mut buf = [0; 2];
+        let mut text = Vec::with_capacity(self.len());
+        for code_point in self.code_points() {
+            match code_point {
+                CodePoint::Unicode(c) => text.extend_from_slice(c.encode_utf16(&mut buf)),
+                CodePoint::UnpairedSurrogate(_) => text.push(0xFFFD),
+            }
+        }
+
+        Self::from(text)
+    }
+
     /// Abstract operation `StringIndexOf ( string, searchValue, fromIndex )`
     ///
     /// Note: Instead of returning an isize with `-1` as the "not found" value, we make use of the
@@ -666,6 +946,27 @@ impl Drop for JsString {
                 return;
             }
 
+            if self.ptr.is_flagged() {
+                // SAFETY: Flagged pointers point to a rope node leaked from a `Box` on
+                // `concat_rope`, and this is the last reference to it.
+                let rope = unsafe { Box::from_raw(raw.cast::<Rope>().as_ptr()) };
+
+                // Release the parts of the rope iteratively, detaching the parts of every uniquely
+                // owned subrope before it is dropped, so that deep ropes don't overflow the stack.
+                let mut pending: Vec<_> = rope.parts.into_inner().into_iter().collect();
+                while let Some(parts) = pending.pop() {
+                    let RopeParts::Concat(left, right) = parts else {
+                        continue;
+                    };
+                    for string in [left, right] {
+                        if let (Some(subrope), Some(1)) = (string.rope(), string.refcount()) {
+                            pending.extend(subrope.parts.borrow_mut().take());
+                        }
+                    }
+                }
+                return;
+            }
+
             // SAFETY:
             // All the checks for the validity of the layout have already been made on `alloc_inner`,
             // so we can skip the unwrap.
@@ -706,6 +1007,10 @@ impl Deref for JsString {
     fn deref(&self) -> &Self::Target {
         match self.ptr.unwrap() {
             UnwrappedTagged::Ptr(h) => {
+                if let Some(rope) = self.rope() {
+                    return rope.flatten();
+                }
+
                 // SAFETY:
                 // - The `RawJsString` type has all the necessary information to reconstruct a valid
                 //   slice (length and starting pointer).
@@ -746,6 +1051,10 @@ impl From<Vec<u16>> for JsString {
 impl From<&str> for JsString {
     #[inline]
     fn from(s: &str) -> Self {
+        if s.is_ascii() {
+            return Self::from_latin1_flat(s.as_bytes());
+        }
+
         let s = s.encode_utf16().collect::<Vec<_>>();
 
         Self::from(&s[..])
@@ -912,24 +1221,8 @@ impl ToStringEscaped for [u16] {
 }
 #[cfg(test)]
 mod tests {
-    use crate::tagged::UnwrappedTagged;
-
     use super::utf16;
-    use super::JsString;
-
-    impl JsString {
-        /// Gets the number of `JsString`s which point to this allocation.
-        fn refcount(&self) -> Option<usize> {
-            match self.ptr.unwrap() {
-                UnwrappedTagged::Ptr(inner) => {
-                    // SAFETY: The reference count of `JsString` guarantees that `inner` is always valid.
-                    let inner = unsafe { inner.as_ref() };
-                    Some(inner.refcount.get())
-                }
-                UnwrappedTagged::Tag(_inner) => None,
-            }
-        }
-    }
+    use super::{JsString, RawJsString, ROPE_MIN_LEN};
 
     #[test]
     fn empty() {
@@ -1052,4 +1345,127 @@ mod tests {
         assert_eq!(&xyzw, utf16!("hello, world!"));
         assert_eq!(xyzw.refcount(), Some(1));
     }
+
+    #[test]
+    fn concat_rope() {
+        let short = js_string!("hello");
+        let flat = JsString::concat_rope(&short, &short);
+        assert!(flat.rope().is_none());
+        assert_eq!(&flat, utf16!("hellohello"));
+
+        let empty = js_string!();
+        assert_eq!(
+            JsString::concat_rope(&empty, &short).ptr.addr(),
+            short.ptr.addr()
+        );
+        assert_eq!(
+            JsString::concat_rope(&short, &empty).ptr.addr(),
+            short.ptr.addr()
+        );
+
+        let chunk = JsString::from("a".repeat(200));
+        let rope = JsString::concat_rope(&chunk, &chunk);
+        let nested = JsString::concat_rope(&rope, &rope);
+        assert!(nested.rope().is_some());
+        assert!(nested.ptr.is_flagged());
+        assert_eq!(nested.len(), 800);
+        assert!(nested.rope().and_then(|r| r.flat.get()).is_none());
+
+        let expected = "a".repeat(800).encode_utf16().collect::<Vec<_>>();
+        assert_eq!(nested[..], expected[..]);
+        assert_eq!(nested, JsString::from("a".repeat(800)));
+        assert!(rope.rope().and_then(|r| r.flat.get()).is_none());
+        assert_eq!(rope.refcount(), Some(1));
+        assert_eq!(chunk.refcount(), Some(3));
+    }
+
+    #[test]
+    fn rope_header_size() {
+        // Ropes are encoded in the pointer, so flat strings don't pay for them.
+        assert_eq!(
+            std::mem::size_of::<RawJsString>(),
+            2 * std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn deep_rope() {
+        const DEPTH: usize = 100_000;
+
+        let base = JsString::from("x".repeat(ROPE_MIN_LEN));
+        let chunk = js_string!("x");
+
+        // Flattening must not recurse on deep ropes.
+        let mut string = base.clone();
+        for _ in 0..DEPTH {
+            string = JsString::concat_rope(&string, &chunk);
+        }
+        assert_eq!(string.len(), ROPE_MIN_LEN + DEPTH);
+        assert!(string.iter().all(|&c| c == u16::from(b'x')));
+        drop(string);
+
+        // Neither should dropping them without flattening first.
+        let mut string = base.clone();
+        for _ in 0..DEPTH {
+            string = JsString::concat_rope(&string, &chunk);
+        }
+        drop(string);
+        assert_eq!(base.refcount(), Some(1));
+    }
+
+    #[test]
+    fn from_latin1() {
+        let empty = JsString::from_latin1(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.refcount(), None);
+
+        let ascii = JsString::from_latin1(b"hello, world");
+        assert_eq!(&ascii, utf16!("hello, world"));
+        assert_eq!(ascii.refcount(), Some(1));
+        assert_eq!(ascii, JsString::from("hello, world"));
+
+        let latin1 = JsString::from_latin1(&[0x63, 0x61, 0x66, 0xE9, 0xFF]);
+        assert_eq!(&latin1, &[0x63u16, 0x61, 0x66, 0xE9, 0xFF]);
+        assert_eq!(latin1.to_std_string_escaped(), "caf\u{e9}\u{ff}");
+
+        // Static strings are still deduplicated.
+        let length = JsString::from_latin1(b"length");
+        assert_eq!(length.refcount(), None);
+
+        let bytes = [b'a', 0xE9].repeat(ROPE_MIN_LEN);
+        let expected = "a\u{e9}".repeat(ROPE_MIN_LEN);
+        let compact = JsString::from_latin1(&bytes);
+        assert!(compact.ptr.is_flagged());
+        assert_eq!(compact.len(), bytes.len());
+        assert_eq!(compact.to_std_string().unwrap(), expected);
+        assert!(compact.rope().and_then(|r| r.flat.get()).is_none());
+
+        // Flattening a concatenation reads the leaves without flattening them.
+        let nested = JsString::concat_rope(&compact, &compact);
+        assert_eq!(nested, JsString::from(expected.repeat(2)));
+        assert!(compact.rope().and_then(|r| r.flat.get()).is_none());
+
+        assert_eq!(compact, JsString::from(expected));
+        assert!(compact.rope().and_then(|r| r.flat.get()).is_some());
+    }
+
+    #[test]
+    fn well_formed() {
+        let pair = JsString::from(&[0x61, 0xD83D, 0xDE00, 0x62][..]);
+        assert!(pair.is_well_formed());
+        assert_eq!(pair.to_well_formed(), pair);
+
+        let lone_high = JsString::from(&[0x61, 0xD83D, 0x62][..]);
+        assert!(!lone_high.is_well_formed());
+        assert_eq!(&lone_high.to_well_formed(), &[0x61u16, 0xFFFD, 0x62]);
+
+        let lone_low = JsString::from(&[0xDE00, 0xD83D, 0xDE00, 0xD83D][..]);
+        assert!(!lone_low.is_well_formed());
+        assert_eq!(
+            &lone_low.to_well_formed(),
+            &[0xFFFDu16, 0xD83D, 0xDE00, 0xFFFD]
+        );
+
+        assert!(js_string!().is_well_formed());
+    }
 }
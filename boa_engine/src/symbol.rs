@@ -137,7 +137,7 @@ unsafe impl Trace for JsSymbol {
 macro_rules! well_known_symbols {
     ( $( $(#[$attr:meta])* ($name:ident, $variant:path) ),+$(,)? ) => {
         $(
-            $(#[$attr])* pub(crate) const fn $name() -> JsSymbol {
+            $(#[$attr])* #[must_use] pub const fn $name() -> JsSymbol {
                 JsSymbol {
                     repr: Tagged::from_tag($variant.tag()),
                 }
@@ -215,6 +215,22 @@ impl JsSymbol {
         )
     }
 
+    /// Returns `true` if the symbol is one of the well-known symbols, like `Symbol.iterator`.
+    #[inline]
+    #[must_use]
+    pub fn is_well_known(&self) -> bool {
+        matches!(self.repr.unwrap(), UnwrappedTagged::Tag(_))
+    }
+
+    /// Gets all the well-known symbols, like `Symbol.iterator` or `Symbol.toPrimitive`.
+    #[must_use]
+    pub fn well_known() -> impl ExactSizeIterator<Item = Self> {
+        // The variants of `WellKnown` are numbered consecutively from zero.
+        (0..=u8::from(WellKnown::Unscopables)).map(|tag| Self {
+            repr: Tagged::from_tag(usize::from(tag)),
+        })
+    }
+
     well_known_symbols! {
         /// Gets the static `JsSymbol` for `"Symbol.asyncIterator"`.
         (async_iterator, WellKnown::AsyncIterator),
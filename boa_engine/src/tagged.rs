@@ -15,7 +15,8 @@ use std::ptr::NonNull;
 ///
 /// If the least significant bit (LSB) of the internal [`NonNull`] is set (1), then the pointer
 /// address represents a tag where the remaining bits store the tag. Otherwise, the whole pointer
-/// represents the pointer itself.
+/// represents the pointer itself, except for the second least significant bit, which can be used
+/// as a flag on pointers with a minimum alignment of 4-bytes.
 ///
 /// It uses [`NonNull`], which guarantees that [`Tagged`] can use the "null pointer optimization"
 /// to optimize the size of [`Option<Tagged>`].
@@ -78,11 +79,26 @@ impl<T> Tagged<T> {
         Self(ptr)
     }
 
-    /// Unwraps the `Tagged` pointer.
+    /// Creates a new `Tagged` pointer from a `NonNull` pointer, with its flag set.
+    ///
+    /// # Requirements
+    ///
+    /// - `T` must have an alignment of at least 4.
+    pub(crate) fn from_non_null_flagged(ptr: NonNull<T>) -> Tagged<T> {
+        debug_assert!(std::mem::align_of::<T>() >= 4);
+        let ptr = ptr.as_ptr().map_addr(|addr| addr | 2);
+        // SAFETY: `ptr` is never null, since its second bit is always set.
+        unsafe { Self(NonNull::new_unchecked(ptr)) }
+    }
+
+    /// Unwraps the `Tagged` pointer, clearing the flag of the pointer.
     pub(crate) fn unwrap(self) -> UnwrappedTagged<T> {
         let addr = self.0.as_ptr().addr();
         if addr & 1 == 0 {
-            UnwrappedTagged::Ptr(self.0)
+            let ptr = self.0.as_ptr().map_addr(|addr| addr & !2);
+            // SAFETY: Flagged pointers are valid non-null pointers with an alignment of at least
+            // 4, so `ptr` is never null.
+            UnwrappedTagged::Ptr(unsafe { NonNull::new_unchecked(ptr) })
         } else {
             UnwrappedTagged::Tag(addr >> 1)
         }
@@ -99,6 +115,11 @@ impl<T> Tagged<T> {
     pub(crate) fn is_tagged(self) -> bool {
         self.0.as_ptr().addr() & 1 > 0
     }
+
+    /// Returns `true` if `self` is a pointer with its flag set.
+    pub(crate) fn is_flagged(self) -> bool {
+        self.0.as_ptr().addr() & 3 == 2
+    }
 }
 
 /// The unwrapped value of a [`Tagged`] pointer.
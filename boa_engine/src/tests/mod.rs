@@ -7,7 +7,7 @@ mod operators;
 mod promise;
 mod spread;
 
-use crate::{builtins::error::ErrorKind, run_test_actions, JsValue, TestAction};
+use crate::{builtins::error::ErrorKind, js_string, run_test_actions, JsValue, Source, TestAction};
 
 #[test]
 fn length_correct_value_on_string_literal() {
@@ -363,6 +363,65 @@ fn result_of_empty_block() {
     run_test_actions([TestAction::assert_eq("{}", JsValue::undefined())]);
 }
 
+#[test]
+fn script_completion_value() {
+    run_test_actions([
+        TestAction::assert_eq("1; 2;", 2),
+        TestAction::assert_eq("if (true) { 3 }", 3),
+        TestAction::assert_eq("if (false) { 3 } else { 4 }", 4),
+        TestAction::assert_eq("5; if (false) { 3 }", JsValue::undefined()),
+        TestAction::assert_eq("let x = 1", JsValue::undefined()),
+        TestAction::assert_eq("6; let y = 1;", 6),
+        TestAction::assert_eq("7; var z = 1; function f() {}", 7),
+        TestAction::assert_eq("8; {}", 8),
+        TestAction::assert_eq("9; { let w = 1; ; }", 9),
+        TestAction::assert_eq("10; { 11; let v = 1; }", 11),
+    ]);
+}
+
+#[test]
+fn eval_global_declarations() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "(0, eval)('function NaN() {}')",
+            ErrorKind::Type,
+            "cannot declare global function `NaN`",
+        ),
+        TestAction::run("(0, eval)('var NaN; function parseFloat() {}')"),
+        TestAction::assert_eq("parseFloat('1')", JsValue::undefined()),
+        TestAction::run("Object.preventExtensions(globalThis)"),
+        TestAction::assert_native_error(
+            "(0, eval)('var x = 1')",
+            ErrorKind::Type,
+            "cannot declare global variable `x`",
+        ),
+        TestAction::assert_native_error(
+            "eval('function f() {}')",
+            ErrorKind::Type,
+            "cannot declare global function `f`",
+        ),
+        TestAction::assert_eq("typeof x", js_string!("undefined")),
+        TestAction::assert_eq(
+            "(function () { eval('var local = 1'); return local; })()",
+            1,
+        ),
+    ]);
+}
+
+#[test]
+fn evaluate_returning_last_statement_value() {
+    run_test_actions([TestAction::inspect_context(|ctx| {
+        let mut eval = |source: &str| {
+            ctx.evaluate_returning_last_statement_value(Source::from_bytes(source))
+                .unwrap()
+        };
+        assert_eq!(eval("1; 2;"), JsValue::new(2));
+        assert_eq!(eval("if (true) { 3 }"), JsValue::new(3));
+        assert_eq!(eval("let x = 1"), JsValue::undefined());
+        assert_eq!(eval("4; { let y = 1; }"), JsValue::new(4));
+    })]);
+}
+
 #[test]
 fn undefined_constant() {
     run_test_actions([TestAction::assert_eq("undefined", JsValue::undefined())]);
@@ -206,6 +206,31 @@ fn typeofs() {
     ]);
 }
 
+#[test]
+fn typeof_identifier() {
+    run_test_actions([
+        TestAction::assert_eq("typeof undeclared", "undefined"),
+        TestAction::assert_eq("typeof (undeclared)", "undefined"),
+        TestAction::assert_eq("(function() { return typeof undeclared; })()", "undefined"),
+        TestAction::assert_eq("{ let declared = 1; typeof declared }", "number"),
+        TestAction::assert_native_error(
+            "{ typeof tdz; let tdz = 1; }",
+            ErrorKind::Reference,
+            "tdz is not initialized",
+        ),
+        TestAction::assert_native_error(
+            "(function() { typeof tdz; const tdz = 1; })()",
+            ErrorKind::Reference,
+            "tdz is not initialized",
+        ),
+        TestAction::assert_native_error(
+            "typeof TopLevel; class TopLevel {}",
+            ErrorKind::Reference,
+            "TopLevel is not initialized",
+        ),
+    ]);
+}
+
 #[test]
 fn unary_post() {
     run_test_actions([
@@ -331,7 +356,7 @@ fn assignment_to_non_assignable_ctd() {
             TestAction::assert_native_error(
                 src,
                 ErrorKind::Syntax,
-                "Invalid left-hand side in assignment at position: 1:13",
+                "Invalid left-hand side in assignment at position: 1:12",
             )
         }),
     );
@@ -359,7 +384,7 @@ fn multicharacter_assignment_to_non_assignable_ctd() {
                 TestAction::assert_native_error(
                     src,
                     ErrorKind::Syntax,
-                    "Invalid left-hand side in assignment at position: 1:13",
+                    "Invalid left-hand side in assignment at position: 1:12",
                 )
             }),
     );
@@ -394,7 +419,7 @@ fn multicharacter_bitwise_assignment_to_non_assignable_ctd() {
             TestAction::assert_native_error(
                 src,
                 ErrorKind::Syntax,
-                "Invalid left-hand side in assignment at position: 1:13",
+                "Invalid left-hand side in assignment at position: 1:12",
             )
         }),
     );
@@ -1,6 +1,6 @@
 use indoc::indoc;
 
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, JsArgs, JsNativeError, JsValue, NativeFunction, TestAction};
 
 #[test]
 #[allow(clippy::redundant_closure_for_method_calls)]
@@ -38,3 +38,70 @@ fn issue_2658() {
         TestAction::assert_eq("result2.value", 5),
     ]);
 }
+
+#[test]
+fn async_native_function() {
+    run_test_actions([
+        TestAction::inspect_context(|ctx| {
+            let offset = 10;
+            ctx.register_global_function(
+                "asyncAdd",
+                1,
+                NativeFunction::from_async_copy_closure(move |_, args, ctx| {
+                    let value = args.get_or_undefined(0).to_i32(ctx);
+                    async move {
+                        let value = value?;
+                        std::future::ready(()).await;
+                        if value < 0 {
+                            return Err(JsNativeError::range()
+                                .with_message("negative value")
+                                .into());
+                        }
+                        Ok(JsValue::new(value + offset))
+                    }
+                }),
+            )
+            .unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            let result;
+            let error;
+            (async () => {
+                result = await asyncAdd(32);
+                try {
+                    await asyncAdd(-1);
+                } catch (e) {
+                    error = e;
+                }
+            })();
+        "#}),
+        TestAction::assert_eq("result", JsValue::undefined()),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert_eq("result", 42),
+        TestAction::assert("error instanceof RangeError"),
+        TestAction::assert_eq("error.message", crate::js_string!("negative value")),
+    ]);
+}
+
+#[test]
+fn run_jobs_once() {
+    run_test_actions([
+        TestAction::assert_context(|ctx| !ctx.has_pending_jobs() && !ctx.run_jobs_once()),
+        TestAction::run(indoc! {r#"
+            var order = [];
+            Promise.resolve().then(() => {
+                order.push(1);
+                Promise.resolve().then(() => order.push(3));
+            });
+            Promise.resolve().then(() => order.push(2));
+        "#}),
+        TestAction::assert_context(|ctx| ctx.has_pending_jobs()),
+        TestAction::assert_context(|ctx| ctx.run_jobs_once()),
+        TestAction::assert_eq("order.join()", crate::js_string!("1")),
+        TestAction::assert_context(|ctx| ctx.run_jobs_once()),
+        TestAction::assert_eq("order.join()", crate::js_string!("1,2")),
+        TestAction::assert_context(|ctx| ctx.run_jobs_once()),
+        TestAction::assert_eq("order.join()", crate::js_string!("1,2,3")),
+        TestAction::assert_context(|ctx| !ctx.has_pending_jobs() && !ctx.run_jobs_once()),
+    ]);
+}
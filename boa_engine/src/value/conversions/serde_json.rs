@@ -13,6 +13,9 @@ use serde_json::{Map, Value};
 impl JsValue {
     /// Converts a [`serde_json::Value`] to a `JsValue`.
     ///
+    /// JSON numbers are converted to integers if they fit an `i32`, and to `f64` otherwise, which
+    /// loses precision for integers larger than 2<sup>53</sup>.
+    ///
     /// # Example
     ///
     /// ```
@@ -81,6 +84,9 @@ impl JsValue {
 
     /// Converts the `JsValue` to a [`serde_json::Value`].
     ///
+    /// `undefined` is converted like in `JSON.stringify`: properties with an `undefined` value are
+    /// skipped, while `undefined` array elements and an `undefined` value are converted to `null`.
+    ///
     /// # Example
     ///
     /// ```
@@ -106,67 +112,110 @@ impl JsValue {
     /// # assert_eq!(json, back_to_json);
     /// ```
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Panics if the `JsValue` is `Undefined`.
+    /// Returns a `TypeError` if the value is or contains a `BigInt` or a `Symbol`, or if it
+    /// contains a cyclic object.
     pub fn to_json(&self, context: &mut Context<'_>) -> JsResult<Value> {
+        let mut ancestors = Vec::new();
+        Ok(self
+            .to_json_inner(&mut ancestors, context)?
+            .unwrap_or(Value::Null))
+    }
+
+    /// Converts the `JsValue` to a [`serde_json::Value`], or `None` if it is `undefined`.
+    ///
+    /// `ancestors` are the objects being converted that contain this value, used to detect cycles.
+    fn to_json_inner(
+        &self,
+        ancestors: &mut Vec<JsObject>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Option<Value>> {
         match self {
-            Self::Null => Ok(Value::Null),
-            Self::Undefined => todo!("undefined to JSON"),
-            &Self::Boolean(b) => Ok(b.into()),
-            Self::String(string) => Ok(string.to_std_string_escaped().into()),
-            &Self::Rational(rat) => Ok(rat.into()),
-            &Self::Integer(int) => Ok(int.into()),
+            Self::Null => Ok(Some(Value::Null)),
+            Self::Undefined => Ok(None),
+            &Self::Boolean(b) => Ok(Some(b.into())),
+            Self::String(string) => Ok(Some(string.to_std_string_escaped().into())),
+            &Self::Rational(rat) => Ok(Some(rat.into())),
+            &Self::Integer(int) => Ok(Some(int.into())),
             Self::BigInt(_bigint) => Err(JsNativeError::typ()
                 .with_message("cannot convert bigint to JSON")
                 .into()),
             Self::Object(obj) => {
-                if obj.is_array() {
-                    let len = obj.length_of_array_like(context)?;
-                    let mut arr = Vec::with_capacity(len as usize);
-
-                    let obj = obj.borrow();
+                if ancestors
+                    .iter()
+                    .any(|ancestor| JsObject::equals(ancestor, obj))
+                {
+                    return Err(JsNativeError::typ()
+                        .with_message("cannot convert cyclic object to JSON")
+                        .into());
+                }
 
-                    for k in 0..len as u32 {
-                        let val = obj.properties().get(&k.into()).map_or(Self::Null, |desc| {
-                            desc.value().cloned().unwrap_or(Self::Null)
-                        });
-                        arr.push(val.to_json(context)?);
-                    }
+                ancestors.push(obj.clone());
+                let result = Self::object_to_json(obj, ancestors, context);
+                ancestors.pop();
+                result.map(Some)
+            }
+            Self::Symbol(_sym) => Err(JsNativeError::typ()
+                .with_message("cannot convert Symbol to JSON")
+                .into()),
+        }
+    }
 
-                    Ok(Value::Array(arr))
-                } else {
-                    let mut map = Map::new();
-                    for property_key in obj.borrow().properties().shape.keys() {
-                        let key = match &property_key {
-                            PropertyKey::String(string) => string.to_std_string_escaped(),
-                            PropertyKey::Index(i) => i.to_string(),
-                            PropertyKey::Symbol(_sym) => {
-                                return Err(JsNativeError::typ()
-                                    .with_message("cannot convert Symbol to JSON")
-                                    .into())
-                            }
-                        };
+    /// Converts the object `obj` to a [`serde_json::Value`].
+    fn object_to_json(
+        obj: &JsObject,
+        ancestors: &mut Vec<JsObject>,
+        context: &mut Context<'_>,
+    ) -> JsResult<Value> {
+        if obj.is_array() {
+            let len = obj.length_of_array_like(context)?;
+            let mut arr = Vec::with_capacity(len as usize);
 
-                        let value = match obj
-                            .borrow()
-                            .properties()
-                            .get(&property_key)
-                            .and_then(|x| x.value().cloned())
-                        {
-                            Some(val) => val.to_json(context)?,
-                            None => Value::Null,
-                        };
+            for k in 0..len as u32 {
+                let val = obj
+                    .borrow()
+                    .properties()
+                    .get(&k.into())
+                    .and_then(|desc| desc.value().cloned())
+                    .unwrap_or(Self::Null);
+                arr.push(
+                    val.to_json_inner(ancestors, context)?
+                        .unwrap_or(Value::Null),
+                );
+            }
 
-                        map.insert(key, value);
+            Ok(Value::Array(arr))
+        } else {
+            let mut map = Map::new();
+            let keys = obj.borrow().properties().shape.keys();
+            for property_key in keys {
+                let key = match &property_key {
+                    PropertyKey::String(string) => string.to_std_string_escaped(),
+                    PropertyKey::Index(i) => i.to_string(),
+                    PropertyKey::Symbol(_sym) => {
+                        return Err(JsNativeError::typ()
+                            .with_message("cannot convert Symbol to JSON")
+                            .into())
                     }
+                };
+
+                let value = obj
+                    .borrow()
+                    .properties()
+                    .get(&property_key)
+                    .and_then(|x| x.value().cloned());
+                let value = match value {
+                    Some(val) => val.to_json_inner(ancestors, context)?,
+                    None => Some(Value::Null),
+                };
 
-                    Ok(Value::Object(map))
+                if let Some(value) = value {
+                    map.insert(key, value);
                 }
             }
-            Self::Symbol(_sym) => Err(JsNativeError::typ()
-                .with_message("cannot convert Symbol to JSON")
-                .into()),
+
+            Ok(Value::Object(map))
         }
     }
 }
@@ -230,6 +279,37 @@ mod tests {
         })]);
     }
 
+    #[test]
+    fn undefined_to_json() {
+        run_test_actions([
+            TestAction::assert_with_op("undefined", |v, ctx| {
+                v.to_json(ctx).unwrap() == json!(null)
+            }),
+            TestAction::assert_with_op("({ a: undefined, b: 1 })", |v, ctx| {
+                v.to_json(ctx).unwrap() == json!({ "b": 1 })
+            }),
+            TestAction::assert_with_op("[undefined, 1, , 2]", |v, ctx| {
+                v.to_json(ctx).unwrap() == json!([null, 1, null, 2])
+            }),
+        ]);
+    }
+
+    #[test]
+    fn cyclic_object_to_json() {
+        run_test_actions([
+            TestAction::assert_with_op("const a = {}; a.self = a; a", |v, ctx| {
+                v.to_json(ctx).is_err()
+            }),
+            TestAction::assert_with_op("const b = []; b.push({ inner: [b] }); b", |v, ctx| {
+                v.to_json(ctx).is_err()
+            }),
+            // Shared references that are not cycles are converted every time they appear.
+            TestAction::assert_with_op("const c = { x: 1 }; [c, { c }]", |v, ctx| {
+                v.to_json(ctx).unwrap() == json!([{ "x": 1 }, { "c": { "x": 1 } }])
+            }),
+        ]);
+    }
+
     #[test]
     fn integer_ops_to_json() {
         run_test_actions([
@@ -1,8 +1,11 @@
 use std::borrow::Cow;
 
 use crate::{
-    builtins::promise::PromiseState, object::ObjectKind, property::PropertyDescriptor,
-    string::utf16, JsError, JsString,
+    builtins::{number::NumberFormattingMode, promise::PromiseState},
+    object::{JsObject, ObjectKind},
+    property::{PropertyDescriptor, PropertyKey},
+    string::utf16,
+    JsError, JsString,
 };
 
 use super::{fmt, Display, HashSet, JsValue};
@@ -12,6 +15,8 @@ use super::{fmt, Display, HashSet, JsValue};
 pub struct ValueDisplay<'value> {
     pub(super) value: &'value JsValue,
     pub(super) internals: bool,
+    pub(super) inspect: bool,
+    pub(super) number_formatting_mode: NumberFormattingMode,
 }
 
 impl ValueDisplay<'_> {
@@ -24,6 +29,30 @@ impl ValueDisplay<'_> {
         self.internals = yes;
         self
     }
+
+    /// Display the value similarly to the `util.inspect` function of Node.js.
+    ///
+    /// Objects are printed with their enumerable own properties, tagged with the name of their
+    /// constructor if they are not plain objects. References to an object that is already being
+    /// printed are shown as `[Circular]`.
+    ///
+    /// By default this is `false`.
+    #[inline]
+    #[must_use]
+    pub const fn inspect(mut self, yes: bool) -> Self {
+        self.inspect = yes;
+        self
+    }
+
+    /// Sets the format of the numbers displayed in [`inspect`][Self::inspect] mode.
+    ///
+    /// By default this is [`NumberFormattingMode::Shortest`].
+    #[inline]
+    #[must_use]
+    pub const fn number_formatting_mode(mut self, mode: NumberFormattingMode) -> Self {
+        self.number_formatting_mode = mode;
+        self
+    }
 }
 
 /// A helper macro for printing objects
@@ -194,37 +223,14 @@ pub(crate) fn log_string_from(x: &JsValue, print_internals: bool, print_children
                         format!("Set({size})")
                     }
                 }
-                ObjectKind::Error(_) => {
-                    let name: Cow<'static, str> = v
-                        .get_property(&utf16!("name").into())
-                        .as_ref()
-                        .and_then(PropertyDescriptor::value)
-                        .map_or_else(
-                            || "<error>".into(),
-                            |v| {
-                                v.as_string()
-                                    .map_or_else(
-                                        || v.display().to_string(),
-                                        JsString::to_std_string_escaped,
-                                    )
-                                    .into()
-                            },
-                        );
-                    let message = v
-                        .get_property(&utf16!("message").into())
-                        .as_ref()
-                        .and_then(PropertyDescriptor::value)
-                        .map(|v| {
-                            v.as_string().map_or_else(
-                                || v.display().to_string(),
-                                JsString::to_std_string_escaped,
-                            )
-                        })
-                        .unwrap_or_default();
+                ObjectKind::Error(..) => {
+                    let name = property_string(v, utf16!("name"))
+                        .unwrap_or_else(|| String::from("<error>"));
+                    let message = property_string(v, utf16!("message")).unwrap_or_default();
                     if name.is_empty() {
                         message
                     } else if message.is_empty() {
-                        name.to_string()
+                        name
                     } else {
                         format!("{name}: {message}")
                     }
@@ -309,6 +315,14 @@ impl JsValue {
 
 impl Display for ValueDisplay<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.inspect {
+            let mut inspector = Inspector {
+                stack: Vec::new(),
+                number_formatting_mode: self.number_formatting_mode,
+            };
+            return f.write_str(&inspector.inspect(self.value));
+        }
+
         match self.value {
             JsValue::Null => write!(f, "null"),
             JsValue::Undefined => write!(f, "undefined"),
@@ -340,3 +354,315 @@ fn format_rational(v: f64, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", buffer.format(v))
     }
 }
+
+/// The maximum number of array elements printed in [`inspect`][ValueDisplay::inspect] mode.
+const MAX_INSPECTED_ARRAY_ELEMENTS: usize = 100;
+
+/// The state of the [`inspect`][ValueDisplay::inspect] mode of [`ValueDisplay`].
+#[derive(Debug)]
+struct Inspector {
+    /// The objects that are currently being printed, used to detect cycles.
+    stack: Vec<JsObject>,
+
+    /// The format of the numbers.
+    number_formatting_mode: NumberFormattingMode,
+}
+
+impl Inspector {
+    /// Formats `value`.
+    pub(crate) fn inspect(&mut self, value: &JsValue) -> String {
+        match value {
+            JsValue::String(string) => quote(string),
+            JsValue::Object(object) => {
+                if self.stack.iter().any(|o| JsObject::equals(o, object)) {
+                    return String::from("[Circular]");
+                }
+
+                self.stack.push(object.clone());
+                let result = self.inspect_object(object);
+                self.stack.pop();
+
+                result
+            }
+            _ => match value.as_number() {
+                Some(number) if self.number_formatting_mode != NumberFormattingMode::Shortest => {
+                    self.number_formatting_mode.format(number)
+                }
+                _ => value.display().to_string(),
+            },
+        }
+    }
+
+    fn inspect_object(&mut self, object: &JsObject) -> String {
+        /// The data of the object that must be printed, extracted to avoid keeping the object
+        /// borrowed while printing its children.
+        enum Contents {
+            Array,
+            Map(Vec<(JsValue, JsValue)>),
+            Set(Vec<JsValue>),
+            Promise(PromiseState),
+            Error,
+            Ordinary,
+        }
+
+        let contents = match object.borrow().kind() {
+            ObjectKind::String(string) => return format!("[String: {}]", quote(string)),
+            ObjectKind::Boolean(boolean) => return format!("[Boolean: {boolean}]"),
+            ObjectKind::Number(number) => {
+                let number = JsValue::new(*number);
+                return format!("[Number: {}]", self.inspect(&number));
+            }
+            ObjectKind::Array => Contents::Array,
+            ObjectKind::Map(map) => Contents::Map(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            ),
+            ObjectKind::Set(set) => Contents::Set(set.iter().cloned().collect()),
+            ObjectKind::Promise(promise) => Contents::Promise(promise.state().clone()),
+            ObjectKind::Error(..) => Contents::Error,
+            _ => Contents::Ordinary,
+        };
+
+        match contents {
+            Contents::Array => {
+                let elements = self.inspect_array_elements(object);
+                let properties = self.inspect_properties(object, false);
+                braced("[", elements.into_iter().chain(properties), "]")
+            }
+            Contents::Map(entries) => {
+                let entries = entries
+                    .iter()
+                    .map(|(key, value)| format!("{} => {}", self.inspect(key), self.inspect(value)))
+                    .collect::<Vec<_>>();
+                format!("Map({}) {}", entries.len(), braced("{", entries, "}"))
+            }
+            Contents::Set(values) => {
+                let values = values
+                    .iter()
+                    .map(|value| self.inspect(value))
+                    .collect::<Vec<_>>();
+                format!("Set({}) {}", values.len(), braced("{", values, "}"))
+            }
+            Contents::Promise(state) => {
+                let state = match &state {
+                    PromiseState::Pending => String::from("<pending>"),
+                    PromiseState::Fulfilled(value) => self.inspect(value),
+                    PromiseState::Rejected(reason) => {
+                        format!("<rejected> {}", self.inspect(reason))
+                    }
+                };
+                format!("Promise {{ {state} }}")
+            }
+            Contents::Error => {
+                let name = property_string(object, utf16!("name")).unwrap_or_default();
+                let message = property_string(object, utf16!("message")).unwrap_or_default();
+                if message.is_empty() {
+                    format!("[{name}]")
+                } else {
+                    format!("[{name}: {message}]")
+                }
+            }
+            Contents::Ordinary => {
+                let properties = self.inspect_properties(object, true);
+
+                if object.is_callable() {
+                    let name = property_string(object, utf16!("name"))
+                        .filter(|name| !name.is_empty())
+                        .map_or_else(|| String::from(" (anonymous)"), |name| format!(": {name}"));
+                    let tag = format!("[Function{name}]");
+                    if properties.is_empty() {
+                        return tag;
+                    }
+                    return format!("{tag} {}", braced("{", properties, "}"));
+                }
+
+                let properties = braced("{", properties, "}");
+                match Self::tag(object) {
+                    Some(tag) => format!("{tag} {properties}"),
+                    None => properties,
+                }
+            }
+        }
+    }
+
+    /// Formats the elements of an array, collapsing consecutive holes.
+    ///
+    /// Only the present elements are visited, and at most [`MAX_INSPECTED_ARRAY_ELEMENTS`] items
+    /// are printed.
+    fn inspect_array_elements(&mut self, array: &JsObject) -> Vec<String> {
+        let len = array
+            .borrow()
+            .properties()
+            .get(&utf16!("length").into())
+            .and_then(|length| length.value().and_then(JsValue::as_number))
+            .unwrap_or_default() as u32;
+
+        let mut indices = array
+            .borrow()
+            .properties()
+            .index_property_keys()
+            .filter(|index| *index < len)
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+
+        let mut elements = Vec::new();
+        let mut next = 0;
+        for index in indices {
+            if elements.len() >= MAX_INSPECTED_ARRAY_ELEMENTS {
+                break;
+            }
+            if index > next {
+                elements.push(empty_items(index - next));
+                if elements.len() >= MAX_INSPECTED_ARRAY_ELEMENTS {
+                    next = index;
+                    break;
+                }
+            }
+            let element = array.borrow().properties().get(&index.into());
+            if let Some(element) = element {
+                elements.push(self.inspect_property_value(&element));
+            }
+            next = index + 1;
+        }
+
+        if next < len {
+            if elements.len() < MAX_INSPECTED_ARRAY_ELEMENTS {
+                elements.push(empty_items(len - next));
+            } else {
+                let remaining = len - next;
+                elements.push(format!(
+                    "... {remaining} more item{}",
+                    if remaining == 1 { "" } else { "s" }
+                ));
+            }
+        }
+
+        elements
+    }
+
+    /// Formats the enumerable own properties of an object as `key: value` pairs.
+    ///
+    /// The index properties are only included if `indices` is `true`.
+    fn inspect_properties(&mut self, object: &JsObject, indices: bool) -> Vec<String> {
+        let keys = {
+            let object = object.borrow();
+            let properties = object.properties();
+            let mut keys = Vec::new();
+            if indices {
+                keys.extend(properties.index_property_keys().map(PropertyKey::Index));
+            }
+            keys.extend(properties.shape.keys());
+            keys
+        };
+
+        let mut result = Vec::with_capacity(keys.len());
+        for key in keys {
+            let property = object.borrow().properties().get(&key);
+            let Some(property) = property else {
+                continue;
+            };
+            if property.enumerable() != Some(true) {
+                continue;
+            }
+
+            let key = match &key {
+                PropertyKey::String(string) => {
+                    let string = string.to_std_string_escaped();
+                    if is_identifier_name(&string) {
+                        string
+                    } else {
+                        quote_str(&string)
+                    }
+                }
+                PropertyKey::Symbol(symbol) => {
+                    format!("[{}]", symbol.descriptive_string().to_std_string_escaped())
+                }
+                PropertyKey::Index(index) => format!("'{index}'"),
+            };
+            result.push(format!("{key}: {}", self.inspect_property_value(&property)));
+        }
+
+        result
+    }
+
+    fn inspect_property_value(&mut self, property: &PropertyDescriptor) -> String {
+        if let Some(value) = property.value() {
+            return self.inspect(value);
+        }
+
+        let getter = property.get().map_or(false, |get| !get.is_undefined());
+        let setter = property.set().map_or(false, |set| !set.is_undefined());
+        match (getter, setter) {
+            (true, true) => String::from("[Getter/Setter]"),
+            (true, false) => String::from("[Getter]"),
+            (false, true) => String::from("[Setter]"),
+            (false, false) => String::from("undefined"),
+        }
+    }
+
+    /// Gets the tag printed before an ordinary object: the name of its constructor if it is not
+    /// `Object`, or a note that the object has a `null` prototype.
+    fn tag(object: &JsObject) -> Option<String> {
+        let Some(prototype) = object.prototype() else {
+            return Some(String::from("[Object: null prototype]"));
+        };
+
+        prototype
+            .get_property(&utf16!("constructor").into())
+            .and_then(|constructor| constructor.value().and_then(JsValue::as_object).cloned())
+            .and_then(|constructor| property_string(&constructor, utf16!("name")))
+            .filter(|name| !name.is_empty() && name != "Object")
+    }
+}
+
+/// Gets the value of a data property of `object` or its prototype chain as a string.
+///
+/// Strings are printed without quotes, and other values with their [`ValueDisplay`].
+fn property_string(object: &JsObject, key: &[u16]) -> Option<String> {
+    object.get_property(&key.into()).and_then(|property| {
+        property.value().map(|value| {
+            value.as_string().map_or_else(
+                || value.display().to_string(),
+                JsString::to_std_string_escaped,
+            )
+        })
+    })
+}
+
+/// Joins `items` between `open` and `close`, separated by commas.
+fn braced<I>(open: &str, items: I, close: &str) -> String
+where
+    I: IntoIterator<Item = String>,
+{
+    let items = items.into_iter().collect::<Vec<_>>();
+    if items.is_empty() {
+        format!("{open}{close}")
+    } else {
+        format!("{open} {} {close}", items.join(", "))
+    }
+}
+
+fn empty_items(count: u32) -> String {
+    if count == 1 {
+        String::from("<1 empty item>")
+    } else {
+        format!("<{count} empty items>")
+    }
+}
+
+fn quote(string: &JsString) -> String {
+    quote_str(&string.to_std_string_escaped())
+}
+
+fn quote_str(string: &str) -> String {
+    format!("'{}'", string.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn is_identifier_name(string: &str) -> bool {
+    let mut chars = string.chars();
+    chars
+        .next()
+        .map_or(false, |c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
@@ -0,0 +1,57 @@
+use crate::JsString;
+use std::ops::RangeInclusive;
+
+/// A cache of the string representations of small integers.
+///
+/// Numbers are stored inline in [`JsValue`][crate::JsValue], so the only allocations that
+/// integer-heavy code makes for them are their conversions to strings, like the ones done by string
+/// concatenation. The cache shares a single [`JsString`] for every integer of its range, created
+/// the first time the integer is converted.
+///
+/// The cache is disabled by default, and can be enabled with
+/// [`Context::set_integer_cache_range`][crate::Context::set_integer_cache_range].
+#[derive(Debug)]
+pub(crate) struct IntegerCache {
+    range: RangeInclusive<i32>,
+    strings: Vec<Option<JsString>>,
+}
+
+impl Default for IntegerCache {
+    fn default() -> Self {
+        Self {
+            #[allow(clippy::reversed_empty_ranges)]
+            range: 1..=0,
+            strings: Vec::new(),
+        }
+    }
+}
+
+impl IntegerCache {
+    /// Gets the range of the cached integers.
+    pub(crate) fn range(&self) -> RangeInclusive<i32> {
+        self.range.clone()
+    }
+
+    /// Sets the range of the cached integers, dropping the cached strings.
+    pub(crate) fn set_range(&mut self, range: RangeInclusive<i32>) {
+        let len = if range.is_empty() {
+            0
+        } else {
+            (i64::from(*range.end()) - i64::from(*range.start()) + 1) as usize
+        };
+        self.strings = vec![None; len];
+        self.range = range;
+    }
+
+    /// Gets the string representation of `value`, shared if `value` is in the cached range.
+    pub(crate) fn get(&mut self, value: i32) -> JsString {
+        if !self.range.contains(&value) {
+            return value.to_string().into();
+        }
+
+        let index = (i64::from(value) - i64::from(*self.range.start())) as usize;
+        self.strings[index]
+            .get_or_insert_with(|| value.to_string().into())
+            .clone()
+    }
+}
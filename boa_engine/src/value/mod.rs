@@ -7,6 +7,7 @@ pub(crate) mod display;
 mod equality;
 mod hash;
 mod integer;
+mod integer_cache;
 mod operations;
 mod r#type;
 
@@ -15,7 +16,7 @@ mod tests;
 
 use crate::{
     builtins::{
-        number::{f64_to_int32, f64_to_uint32},
+        number::{f64_to_int32, f64_to_uint32, NumberFormattingMode},
         Number,
     },
     error::JsNativeError,
@@ -44,7 +45,9 @@ pub use self::{
 #[doc(inline)]
 pub use boa_macros::TryFromJs;
 
-pub(crate) use self::{conversions::IntoOrUndefined, integer::IntegerOrNan};
+pub(crate) use self::{
+    conversions::IntoOrUndefined, integer::IntegerOrNan, integer_cache::IntegerCache,
+};
 
 static TWO_E_64: Lazy<BigInt> = Lazy::new(|| {
     const TWO_E_64: u128 = 2u128.pow(64);
@@ -322,6 +325,13 @@ impl JsValue {
     /// The abstract operation `ToPrimitive` takes an input argument and an optional argument
     /// `PreferredType`.
     ///
+    /// Primitive values are returned unchanged. Objects are converted by calling their
+    /// `Symbol.toPrimitive` method with the hint `"default"`, `"string"` or `"number"`, or by
+    /// calling `valueOf` and `toString` if the object doesn't have that method.
+    ///
+    /// This is the conversion used by operators like `+` and `<`, which use
+    /// [`PreferredType::Default`] and [`PreferredType::Number`] respectively.
+    ///
     /// <https://tc39.es/ecma262/#sec-toprimitive>
     pub fn to_primitive(
         &self,
@@ -435,6 +445,8 @@ impl JsValue {
         ValueDisplay {
             value: self,
             internals: false,
+            inspect: false,
+            number_formatting_mode: NumberFormattingMode::Shortest,
         }
     }
 
@@ -447,7 +459,7 @@ impl JsValue {
             Self::Undefined => Ok("undefined".into()),
             Self::Boolean(boolean) => Ok(boolean.to_string().into()),
             Self::Rational(rational) => Ok(Number::to_native_string(*rational).into()),
-            Self::Integer(integer) => Ok(integer.to_string().into()),
+            Self::Integer(integer) => Ok(context.integer_cache.get(*integer)),
             Self::String(string) => Ok(string.clone()),
             Self::Symbol(_) => Err(JsNativeError::typ()
                 .with_message("can't convert symbol to string")
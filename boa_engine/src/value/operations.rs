@@ -4,9 +4,8 @@ use crate::{
         Number,
     },
     error::JsNativeError,
-    js_string,
     value::{JsSymbol, Numeric, PreferredType},
-    Context, JsBigInt, JsResult, JsValue,
+    Context, JsBigInt, JsResult, JsString, JsValue,
 };
 
 impl JsValue {
@@ -24,15 +23,19 @@ impl JsValue {
             (Self::BigInt(ref x), Self::BigInt(ref y)) => Self::new(JsBigInt::add(x, y)),
 
             // String concat
-            (Self::String(ref x), Self::String(ref y)) => Self::from(js_string!(x, y)),
+            (Self::String(ref x), Self::String(ref y)) => Self::from(JsString::concat_rope(x, y)),
 
             // Slow path:
             (_, _) => match (
                 self.to_primitive(context, PreferredType::Default)?,
                 other.to_primitive(context, PreferredType::Default)?,
             ) {
-                (Self::String(ref x), ref y) => Self::from(js_string!(x, &y.to_string(context)?)),
-                (ref x, Self::String(ref y)) => Self::from(js_string!(&x.to_string(context)?, y)),
+                (Self::String(ref x), ref y) => {
+                    Self::from(JsString::concat_rope(x, &y.to_string(context)?))
+                }
+                (ref x, Self::String(ref y)) => {
+                    Self::from(JsString::concat_rope(&x.to_string(context)?, y))
+                }
                 (x, y) => match (x.to_numeric(context)?, y.to_numeric(context)?) {
                     (Numeric::Number(x), Numeric::Number(y)) => Self::new(x + y),
                     (Numeric::BigInt(ref x), Numeric::BigInt(ref y)) => {
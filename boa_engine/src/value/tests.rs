@@ -2,7 +2,7 @@ use boa_macros::utf16;
 use indoc::indoc;
 
 use super::*;
-use crate::{run_test_actions, TestAction};
+use crate::{js_string, run_test_actions, Source, TestAction};
 
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -50,6 +50,72 @@ fn number_is_true() {
     assert!(!JsValue::nan().to_boolean());
 }
 
+#[test]
+fn integer_arithmetic_stays_inline() {
+    // Small integers are stored inline in `JsValue::Integer`, so integer-heavy loops never
+    // allocate for their intermediate values.
+    run_test_actions([
+        TestAction::assert_with_op(
+            indoc! {r#"
+                let sum = 0;
+                for (let i = -128; i <= 127; i++) {
+                    sum += i * 2;
+                }
+                sum
+            "#},
+            |v, _| matches!(v, JsValue::Integer(-256)),
+        ),
+        TestAction::assert_with_op(
+            indoc! {r#"
+                function fib(n) {
+                    return n < 2 ? n : fib(n - 1) + fib(n - 2);
+                }
+                fib(20)
+            "#},
+            |v, _| matches!(v, JsValue::Integer(6765)),
+        ),
+        TestAction::assert_eq("2147483647 + 1", 2_147_483_648.0),
+    ]);
+}
+
+#[test]
+fn integer_cache() {
+    let code = indoc! {r#"
+        let keys = [];
+        let sum = 0;
+        for (let i = -130; i <= 130; i++) {
+            keys.push("key" + i);
+            sum += i * 3;
+        }
+        keys.join() + "|" + sum + "|" + (0 + "") + (-0 + "") + (2147483647 + "")
+    "#};
+
+    let expected = Context::default()
+        .eval_script(Source::from_bytes(code))
+        .unwrap();
+
+    let context = &mut Context::default();
+    context.set_integer_cache_range(-128..=127);
+    assert_eq!(context.integer_cache_range(), -128..=127);
+    let value = context.eval_script(Source::from_bytes(code)).unwrap();
+    assert_eq!(value, expected);
+
+    let first = JsValue::new(100).to_string(context).unwrap();
+    let second = JsValue::new(100).to_string(context).unwrap();
+    assert_eq!(first, js_string!("100"));
+    assert!(std::ptr::eq(first.as_ptr(), second.as_ptr()));
+
+    let first = JsValue::new(1000).to_string(context).unwrap();
+    let second = JsValue::new(1000).to_string(context).unwrap();
+    assert_eq!(first, second);
+    assert!(!std::ptr::eq(first.as_ptr(), second.as_ptr()));
+
+    #[allow(clippy::reversed_empty_ranges)]
+    context.set_integer_cache_range(1..=0);
+    let value = context.eval_script(Source::from_bytes(code)).unwrap();
+    assert_eq!(value, expected);
+}
+
 // https://developer.mozilla.org/en-US/docs/Web/JavaScript/Equality_comparisons_and_sameness
 #[test]
 fn abstract_equality_comparison() {
@@ -472,6 +538,54 @@ fn to_primitive() {
     ]);
 }
 
+#[test]
+fn to_primitive_hints() {
+    let context = &mut Context::default();
+    let object = context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            ({
+                [Symbol.toPrimitive](hint) {
+                    return hint;
+                }
+            })
+        "#}))
+        .unwrap();
+
+    for (preferred_type, hint) in [
+        (PreferredType::Default, "default"),
+        (PreferredType::String, "string"),
+        (PreferredType::Number, "number"),
+    ] {
+        assert_eq!(
+            object.to_primitive(context, preferred_type).unwrap(),
+            JsValue::from(hint)
+        );
+    }
+
+    let date = context
+        .eval_script(Source::from_bytes("new Date(0)"))
+        .unwrap();
+    let string = context
+        .eval_script(Source::from_bytes("String(new Date(0))"))
+        .unwrap();
+    assert_eq!(
+        date.to_primitive(context, PreferredType::Default).unwrap(),
+        string
+    );
+    assert_eq!(
+        date.to_primitive(context, PreferredType::Number).unwrap(),
+        JsValue::from(0)
+    );
+
+    let primitive = JsValue::from(js_string!("primitive"));
+    assert_eq!(
+        primitive
+            .to_primitive(context, PreferredType::Number)
+            .unwrap(),
+        primitive
+    );
+}
+
 #[test]
 fn object_to_property_key() {
     let source = r#"
@@ -17,6 +17,7 @@ pub(crate) struct EnvStackEntry {
     exit: u32,
     kind: EnvEntryKind,
     env_num: usize,
+    stack_height: usize,
 }
 
 impl Default for EnvStackEntry {
@@ -26,6 +27,7 @@ impl Default for EnvStackEntry {
             exit: u32::MAX,
             kind: EnvEntryKind::Global,
             env_num: 0,
+            stack_height: 0,
         }
     }
 }
@@ -39,6 +41,7 @@ impl EnvStackEntry {
             exit: exit_address,
             kind: EnvEntryKind::Global,
             env_num: 0,
+            stack_height: 0,
         }
     }
 
@@ -76,6 +79,12 @@ impl EnvStackEntry {
         self.start = start_address;
         self
     }
+
+    /// Returns calling `EnvStackEntry` with the height of the value stack when it was entered.
+    pub(crate) const fn with_stack_height(mut self, stack_height: usize) -> Self {
+        self.stack_height = stack_height;
+        self
+    }
 }
 
 /// ---- `EnvStackEntry` interaction methods ----
@@ -118,6 +127,13 @@ impl EnvStackEntry {
         self.kind == EnvEntryKind::Finally
     }
 
+    /// Returns the height of the value stack when this entry was entered.
+    ///
+    /// Abrupt completions that jump to the code of this entry truncate the stack to this height.
+    pub(crate) const fn stack_height(&self) -> usize {
+        self.stack_height
+    }
+
     /// Returns the current environment number for this entry.
     pub(crate) const fn env_num(&self) -> usize {
         self.env_num
@@ -5,7 +5,7 @@
 mod abrupt_record;
 mod env_stack;
 
-use crate::{builtins::promise::PromiseCapability, object::JsObject, vm::CodeBlock};
+use crate::{builtins::promise::PromiseCapability, object::JsObject, vm::CodeBlock, JsValue};
 use boa_gc::{Finalize, Gc, Trace};
 use thin_vec::ThinVec;
 
@@ -20,9 +20,17 @@ pub struct CallFrame {
     pub(crate) fp: usize,
     #[unsafe_ignore_trace]
     pub(crate) abrupt_completion: Option<AbruptCompletionRecord>,
+    // The thrown value or the returned value of the abrupt completion that runs the finally blocks,
+    // which are entered with the stack height of their try statements.
+    pub(crate) abrupt_completion_value: Option<JsValue>,
+    // The value of the last evaluated expression statement of the code, which is the result of the
+    // frame if it completes normally.
+    pub(crate) completion_value: JsValue,
     #[unsafe_ignore_trace]
     pub(crate) r#yield: bool,
-    pub(crate) pop_on_return: usize,
+    // If the frame completed with a `return` completion, instead of a normal one.
+    #[unsafe_ignore_trace]
+    pub(crate) returned: bool,
     // Tracks the number of environments in environment entry.
     // On abrupt returns this is used to decide how many environments need to be pop'ed.
     #[unsafe_ignore_trace]
@@ -39,6 +47,14 @@ pub struct CallFrame {
 
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<(JsObject, bool)>,
+
+    // If a `TailCall` of the function of the frame can run it again in place of the frame.
+    // Only calls of functions that are neither async nor generators allow it.
+    #[unsafe_ignore_trace]
+    pub(crate) tail_call_allowed: bool,
+
+    // The `this` value and the arguments of the pending tail call of the function of the frame.
+    pub(crate) tail_call: Option<(JsValue, Vec<JsValue>)>,
 }
 
 /// ---- `CallFrame` public API ----
@@ -59,16 +75,20 @@ impl CallFrame {
             code_block,
             pc: 0,
             fp: 0,
-            pop_on_return: 0,
             env_stack: Vec::from([EnvStackEntry::new(0, max_length)]),
             abrupt_completion: None,
+            abrupt_completion_value: None,
+            completion_value: JsValue::undefined(),
             r#yield: false,
+            returned: false,
             param_count: 0,
             arg_count: 0,
             generator_resume_kind: GeneratorResumeKind::Normal,
             promise_capability: None,
             async_generator: None,
             iterators: ThinVec::new(),
+            tail_call_allowed: false,
+            tail_call: None,
         }
     }
 
@@ -8,7 +8,9 @@ use crate::{
         function::{arguments::Arguments, ConstructorKind, Function, FunctionKind, ThisMode},
         generator::{Generator, GeneratorContext, GeneratorState},
         promise::PromiseCapability,
+        regexp::RegExpSite,
     },
+    bytecompiler::LazyFunction,
     context::intrinsics::StandardConstructors,
     environments::{BindingLocator, CompileTimeEnvironment},
     error::JsNativeError,
@@ -16,24 +18,21 @@ use crate::{
     property::PropertyDescriptor,
     realm::Realm,
     string::utf16,
-    vm::CallFrame,
+    vm::{CallFrame, InlineCache, Opcode},
     Context, JsError, JsResult, JsString, JsValue,
 };
 use boa_ast::{
     expression::Identifier,
     function::{FormalParameterList, PrivateName},
+    Position,
 };
 use boa_gc::{Finalize, Gc, GcRefCell, Trace};
-use boa_interner::Sym;
+use boa_interner::{Interner, Sym, ToInternedString};
 use boa_profiler::Profiler;
+use rustc_hash::FxHashSet;
 use std::{collections::VecDeque, mem::size_of};
 use thin_vec::ThinVec;
 
-#[cfg(any(feature = "trace", feature = "flowgraph"))]
-use crate::vm::Opcode;
-#[cfg(any(feature = "trace", feature = "flowgraph"))]
-use boa_interner::{Interner, ToInternedString};
-
 /// This represents whether a value can be read from [`CodeBlock`] code.
 ///
 /// # Safety
@@ -89,6 +88,10 @@ pub struct CodeBlock {
     /// Literals
     pub(crate) literals: Box<[JsValue]>,
 
+    /// Regular expression literal sites.
+    #[unsafe_ignore_trace]
+    pub(crate) regexps: Box<[RegExpSite]>,
+
     /// Property field names.
     #[unsafe_ignore_trace]
     pub(crate) names: Box<[Identifier]>,
@@ -97,6 +100,9 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) private_names: Box<[PrivateName]>,
 
+    /// Inline caches of the `GetPropertyByName` and `SetPropertyByName` instructions.
+    pub(crate) inline_caches: Box<[InlineCache]>,
+
     /// Locators for all bindings in the codeblock.
     #[unsafe_ignore_trace]
     pub(crate) bindings: Box<[BindingLocator]>,
@@ -114,6 +120,10 @@ pub struct CodeBlock {
     /// Compile time environments in this function.
     pub(crate) compile_environments: Box<[Gc<GcRefCell<CompileTimeEnvironment>>]>,
 
+    /// The kind of code compiled into this code block.
+    #[unsafe_ignore_trace]
+    pub(crate) kind: CodeKind,
+
     /// The `[[IsClassConstructor]]` internal slot.
     pub(crate) is_class_constructor: bool,
 
@@ -130,12 +140,87 @@ pub struct CodeBlock {
     /// The number of bindings in the parameters environment.
     pub(crate) parameters_env_bindings: Option<usize>,
 
+    /// The source text of this function, if it was retained during compilation.
+    pub(crate) source_text: Option<JsString>,
+
+    /// The name of the source code this code block was compiled from, like the path of a script
+    /// file, if known.
+    pub(crate) source_name: Option<JsString>,
+
+    /// The source positions of the statements and calls in this code block, sorted by the
+    /// bytecode offset of their first instruction.
+    #[unsafe_ignore_trace]
+    pub(crate) source_positions: Box<[(u32, Position)]>,
+
+    /// The maximum number of values on the operand stack while executing this code block,
+    /// including the arguments the function is entered with.
+    pub(crate) max_stack_size: u32,
+
+    /// The deferred compilation of this function, if it is compiled on its first call.
+    pub(crate) lazy: Option<Gc<LazyFunction>>,
+
     #[cfg(feature = "trace")]
     /// Trace instruction execution to `stdout`.
     #[unsafe_ignore_trace]
     pub(crate) trace: std::cell::Cell<bool>,
 }
 
+/// The kind of code compiled into a [`CodeBlock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CodeKind {
+    /// The code of a script, a module or an `eval` call.
+    Script,
+
+    /// The code of an ordinary function, method or arrow function.
+    Function,
+
+    /// The code of an async function or async arrow function.
+    Async,
+
+    /// The code of a generator function.
+    Generator,
+
+    /// The code of an async generator function.
+    AsyncGenerator,
+}
+
+/// Bytecode and memory statistics of a [`CodeBlock`], as returned by [`CodeBlock::stats`].
+///
+/// The `total_*` fields include the code block itself and all the functions nested in it,
+/// counting every distinct code block once.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CodeBlockStats {
+    /// The length of the bytecode in bytes.
+    pub bytecode_len: usize,
+
+    /// The number of literals.
+    pub literals: usize,
+
+    /// The number of property field names.
+    pub names: usize,
+
+    /// The number of binding locators.
+    pub bindings: usize,
+
+    /// The number of functions directly nested in the code block.
+    pub functions: usize,
+
+    /// The number of distinct code blocks, including this one.
+    pub total_code_blocks: usize,
+
+    /// The total length of the bytecode in bytes.
+    pub total_bytecode_len: usize,
+
+    /// The total number of literals.
+    pub total_literals: usize,
+
+    /// The total number of property field names.
+    pub total_names: usize,
+
+    /// The total number of binding locators.
+    pub total_bindings: usize,
+}
+
 /// ---- `CodeBlock` public API ----
 impl CodeBlock {
     /// Creates a new `CodeBlock`.
@@ -144,8 +229,10 @@ impl CodeBlock {
         Self {
             bytecode: Box::default(),
             literals: Box::default(),
+            regexps: Box::default(),
             names: Box::default(),
             private_names: Box::default(),
+            inline_caches: Box::default(),
             bindings: Box::default(),
             num_bindings: 0,
             functions: Box::default(),
@@ -157,10 +244,16 @@ impl CodeBlock {
             params: FormalParameterList::default(),
             arguments_binding: None,
             compile_environments: Box::default(),
+            kind: CodeKind::Script,
             is_class_constructor: false,
             class_field_initializer_name: None,
             function_environment_push_location: 0,
             parameters_env_bindings: None,
+            source_text: None,
+            source_name: None,
+            source_positions: Box::default(),
+            max_stack_size: 0,
+            lazy: None,
             #[cfg(feature = "trace")]
             trace: std::cell::Cell::new(false),
         }
@@ -172,6 +265,127 @@ impl CodeBlock {
         self.name
     }
 
+    /// Gets the compile time environment the function of this code block was compiled in.
+    ///
+    /// The environments of a function are popped last, so the outermost one is the last entry.
+    pub(crate) fn outer_compile_environment(
+        &self,
+    ) -> Option<Gc<GcRefCell<CompileTimeEnvironment>>> {
+        if let Some(lazy) = &self.lazy {
+            return Some(lazy.outer_env());
+        }
+        self.compile_environments.last()?.borrow().outer()
+    }
+
+    /// Gets the source position of the instruction a frame is executing, when its program counter
+    /// is `pc`.
+    ///
+    /// The program counter is already past the opcode of the executing instruction, so this is the
+    /// position of the last statement or call compiled before `pc`.
+    pub(crate) fn source_position(&self, pc: u32) -> Option<Position> {
+        let index = self
+            .source_positions
+            .partition_point(|(offset, _)| *offset < pc);
+        index
+            .checked_sub(1)
+            .map(|index| self.source_positions[index].1)
+    }
+
+    /// Returns `true` if this code block is executed with strict mode semantics.
+    #[must_use]
+    pub const fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Returns `true` if the bytecode of this code block is available, which is only `false` for
+    /// functions whose compilation is deferred until they are first called.
+    ///
+    /// See [`Context::intern_and_compile_lazy`].
+    #[must_use]
+    pub fn is_compiled(&self) -> bool {
+        self.lazy.as_ref().map_or(true, |lazy| lazy.is_compiled())
+    }
+
+    /// Returns the compiled code block of `code`, compiling it first if its compilation was
+    /// deferred until its first call.
+    pub(crate) fn compiled(code: Gc<Self>, context: &mut Context<'_>) -> JsResult<Gc<Self>> {
+        match &code.lazy {
+            Some(lazy) => lazy.compile(context),
+            None => Ok(code),
+        }
+    }
+
+    /// Creates a copy of this code block with the name `name`, without recompiling it.
+    ///
+    /// The name is used by the function objects created from the code block and by the stack
+    /// traces, so this can be used to give a better name to an anonymous function. The nested
+    /// functions are shared with this code block. If the compilation of this code block was
+    /// deferred, the copy keeps the name once it is compiled.
+    #[must_use]
+    pub fn with_name(&self, name: Sym) -> Self {
+        let mut code = self.clone();
+        code.name = name;
+        code.lazy = self.lazy.as_ref().map(|lazy| Gc::new(lazy.with_name(name)));
+        code
+    }
+
+    /// Retrieves the source text of this code block, if it was retained during compilation.
+    #[must_use]
+    pub const fn source_text(&self) -> Option<&JsString> {
+        self.source_text.as_ref()
+    }
+
+    /// Retrieves the maximum depth of the operand stack reached while executing this code block.
+    ///
+    /// The depth includes the formal parameters, but not the extra arguments a function may be
+    /// called with. Stack frames reserve this capacity before the execution starts.
+    #[must_use]
+    pub const fn max_stack_size(&self) -> u32 {
+        self.max_stack_size
+    }
+
+    /// Computes the bytecode and memory statistics of this code block and its nested functions.
+    ///
+    /// Nested functions shared between code blocks are only counted once.
+    #[must_use]
+    pub fn stats(&self) -> CodeBlockStats {
+        let mut stats = CodeBlockStats {
+            bytecode_len: self.bytecode.len(),
+            literals: self.literals.len(),
+            names: self.names.len(),
+            bindings: self.bindings.len(),
+            functions: self.functions.len(),
+            ..CodeBlockStats::default()
+        };
+
+        let mut visited = FxHashSet::default();
+        let mut pending = vec![self];
+        while let Some(code) = pending.pop() {
+            if !visited.insert(std::ptr::addr_of!(*code)) {
+                continue;
+            }
+
+            stats.total_code_blocks += 1;
+            stats.total_bytecode_len += code.bytecode.len();
+            stats.total_literals += code.literals.len();
+            stats.total_names += code.names.len();
+            stats.total_bindings += code.bindings.len();
+            pending.extend(code.functions.iter().map(|function| &**function));
+        }
+
+        stats
+    }
+
+    /// Disassembles the bytecode of this code block into a human readable listing.
+    ///
+    /// The listing uses the same `Location Count Opcode Operands` layout as the `trace` output,
+    /// followed by the literals, bindings and functions of the code block. The `interner` must
+    /// be the interner of the [`Context`] that compiled this code block.
+    #[must_use]
+    pub fn disassemble(&self, interner: &Interner) -> String {
+        self.to_interned_string(interner)
+    }
+
     /// Enable or disable instruction tracing to `stdout`.
     #[cfg(feature = "trace")]
     #[inline]
@@ -222,7 +436,6 @@ impl CodeBlock {
     /// Modifies the `pc` to point to the next instruction.
     ///
     /// Returns an empty `String` if no operands are present.
-    #[cfg(any(feature = "trace", feature = "flowgraph"))]
     pub(crate) fn instruction_operands(&self, pc: &mut usize, interner: &Interner) -> String {
         let opcode: Opcode = self.bytecode[*pc].try_into().expect("invalid opcode");
         *pc += size_of::<Opcode>();
@@ -280,6 +493,7 @@ impl CodeBlock {
             | Opcode::Coalesce
             | Opcode::CallEval
             | Opcode::Call
+            | Opcode::TailCall
             | Opcode::New
             | Opcode::SuperCall
             | Opcode::IteratorUnwrapNextOrJump
@@ -315,6 +529,16 @@ impl CodeBlock {
                 *pc += size_of::<u32>();
                 format!("{operand1}, {operand2}, {operand3}")
             }
+            Opcode::PushRegExp => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                let regexp = &self.regexps[operand as usize];
+                format!(
+                    "{operand:04}: /{}/{}",
+                    regexp.pattern().to_std_string_escaped(),
+                    regexp.flags().to_std_string_escaped()
+                )
+            }
             Opcode::GetArrowFunction
             | Opcode::GetAsyncArrowFunction
             | Opcode::GetFunction
@@ -347,9 +571,15 @@ impl CodeBlock {
                     interner.resolve_expect(self.bindings[operand as usize].name().sym()),
                 )
             }
-            Opcode::GetPropertyByName
-            | Opcode::GetMethod
-            | Opcode::SetPropertyByName
+            Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                let operand = self.read::<u32>(*pc);
+                *pc += size_of::<u32>();
+                format!(
+                    "{operand:04}: '{}'",
+                    interner.resolve_expect(self.inline_caches[operand as usize].name.sym()),
+                )
+            }
+            Opcode::GetMethod
             | Opcode::DefineOwnPropertyByName
             | Opcode::DefineClassStaticMethodByName
             | Opcode::DefineClassMethodByName
@@ -386,7 +616,7 @@ impl CodeBlock {
                 )
             }
             Opcode::Pop
-            | Opcode::PopIfThrown
+            | Opcode::SetCompletionValue
             | Opcode::Dup
             | Opcode::Swap
             | Opcode::PushZero
@@ -480,8 +710,6 @@ impl CodeBlock {
             | Opcode::PushElisionToArray
             | Opcode::PushIteratorToArray
             | Opcode::PushNewArray
-            | Opcode::PopOnReturnAdd
-            | Opcode::PopOnReturnSub
             | Opcode::Yield
             | Opcode::GeneratorNext
             | Opcode::PushClassField
@@ -492,6 +720,7 @@ impl CodeBlock {
             | Opcode::CallEvalSpread
             | Opcode::CallSpread
             | Opcode::NewSpread
+            | Opcode::ImportCall
             | Opcode::SuperCallSpread
             | Opcode::SetPrototype
             | Opcode::PushObjectEnvironment
@@ -501,7 +730,6 @@ impl CodeBlock {
     }
 }
 
-#[cfg(any(feature = "trace", feature = "flowgraph"))]
 impl ToInternedString for CodeBlock {
     fn to_interned_string(&self, interner: &Interner) -> String {
         let name = interner.resolve_expect(self.name);
@@ -706,6 +934,21 @@ pub(crate) fn create_function_object_fast(
 
     let function = Function::new(function, context.realm().clone());
 
+    create_function_object_from_template(function, name, length, r#async, arrow || method, context)
+}
+
+/// Creates the object of `function` from the pre-initialized object template of its kind.
+///
+/// Functions that are not `async` and are not `non_constructor` also get a new `prototype`
+/// object, like the ordinary functions created by [`create_function_object_fast`].
+pub(crate) fn create_function_object_from_template(
+    function: Function,
+    name: JsValue,
+    length: JsValue,
+    r#async: bool,
+    non_constructor: bool,
+    context: &mut Context<'_>,
+) -> JsObject {
     let data = ObjectData::function(function);
 
     if r#async {
@@ -714,7 +957,7 @@ pub(crate) fn create_function_object_fast(
             .templates()
             .async_function()
             .create(data, vec![length, name])
-    } else if arrow || method {
+    } else if non_constructor {
         context
             .intrinsics()
             .templates()
@@ -846,7 +1089,9 @@ pub(crate) fn create_generator_function_object(
     constructor
 }
 
-struct ContextCleanupGuard<'a, 'host> {
+/// A guard that restores the previously active realm and active function of a context when
+/// dropped.
+pub(crate) struct ContextCleanupGuard<'a, 'host> {
     context: &'a mut Context<'host>,
     old_realm: Realm,
     old_active_function: Option<JsObject>,
@@ -854,9 +1099,14 @@ struct ContextCleanupGuard<'a, 'host> {
 
 impl<'a, 'host> ContextCleanupGuard<'a, 'host> {
     /// Creates a new guard that resets the realm of the context on exit.
-    fn new(context: &'a mut Context<'host>, realm: Realm, active_function: JsObject) -> Self {
+    pub(crate) fn new(
+        context: &'a mut Context<'host>,
+        realm: Realm,
+        active_function: Option<JsObject>,
+    ) -> Self {
         let old_realm = context.enter_realm(realm);
-        let old_active_function = context.vm.active_function.replace(active_function);
+        let old_active_function =
+            std::mem::replace(&mut context.vm.active_function, active_function);
         Self {
             context,
             old_realm,
@@ -896,13 +1146,15 @@ impl JsObject {
         args: &[JsValue],
         context: &mut Context<'_>,
     ) -> JsResult<JsValue> {
+        context.vm.check_stack_limits()?;
+
         let this_function_object = self.clone();
         let active_function = self.clone();
         let object = self.borrow();
         let function_object = object.as_function().expect("not a function");
         let realm = function_object.realm().clone();
 
-        let context = &mut ContextCleanupGuard::new(context, realm, active_function);
+        let context = &mut ContextCleanupGuard::new(context, realm, Some(active_function));
 
         let (code, mut environments, class_object, async_, gen) = match function_object.kind() {
             FunctionKind::Native {
@@ -982,6 +1234,8 @@ impl JsObject {
 
         drop(object);
 
+        let code = CodeBlock::compiled(code, context)?;
+
         let promise_capability = (async_ && !gen).then(|| {
             PromiseCapability::new(
                 &context.intrinsics().constructors().promise().constructor(),
@@ -990,122 +1244,147 @@ impl JsObject {
             .expect("cannot  fail per spec")
         });
 
-        std::mem::swap(&mut environments, &mut context.vm.environments);
-
-        let lexical_this_mode = code.this_mode == ThisMode::Lexical;
+        // A tail call of the function to itself runs the function again in place of this call,
+        // instead of nesting a new call for it.
+        let mut tail_call: Option<(JsValue, Vec<JsValue>)> = None;
 
-        let this = if lexical_this_mode {
-            None
-        } else if code.strict {
-            Some(this.clone())
-        } else if this.is_null_or_undefined() {
-            Some(context.global_object().into())
-        } else {
-            Some(
-                this.to_object(context)
-                    .expect("conversion cannot fail")
-                    .into(),
-            )
-        };
-
-        let mut last_env = code.compile_environments.len() - 1;
-
-        if let Some(class_object) = class_object {
-            let index = context
-                .vm
-                .environments
-                .push_declarative(1, code.compile_environments[last_env].clone());
-            context
-                .vm
-                .environments
-                .put_declarative_value(index, 0, class_object.into());
-            last_env -= 1;
-        }
+        let (result, call_frame, stack) = loop {
+            let (this, args) = tail_call
+                .as_ref()
+                .map_or((this, args), |(this, args)| (this, args.as_slice()));
 
-        if code.has_binding_identifier {
-            let index = context
-                .vm
-                .environments
-                .push_declarative(1, code.compile_environments[last_env].clone());
+            let environments_len = environments.len();
+            std::mem::swap(&mut environments, &mut context.vm.environments);
             context
                 .vm
                 .environments
-                .put_declarative_value(index, 0, self.clone().into());
-            last_env -= 1;
-        }
-
-        context.vm.environments.push_function(
-            code.num_bindings,
-            code.compile_environments[last_env].clone(),
-            this,
-            self.clone(),
-            None,
-            lexical_this_mode,
-        );
+                .swap_bindings_pool(&mut environments);
 
-        if let Some(bindings) = code.parameters_env_bindings {
-            last_env -= 1;
-            context
-                .vm
-                .environments
-                .push_declarative(bindings, code.compile_environments[last_env].clone());
-        }
+            let lexical_this_mode = code.this_mode == ThisMode::Lexical;
 
-        if let Some(binding) = code.arguments_binding {
-            let arguments_obj = if code.strict || !code.params.is_simple() {
-                Arguments::create_unmapped_arguments_object(args, context)
+            let this = if lexical_this_mode {
+                None
+            } else if code.strict {
+                Some(this.clone())
+            } else if this.is_null_or_undefined() {
+                Some(context.global_object().into())
             } else {
-                let env = context.vm.environments.current();
-                Arguments::create_mapped_arguments_object(
-                    &this_function_object,
-                    &code.params,
-                    args,
-                    env.declarative_expect(),
-                    context,
+                Some(
+                    this.to_object(context)
+                        .expect("conversion cannot fail")
+                        .into(),
                 )
             };
-            context.vm.environments.put_declarative_value(
-                binding.environment_index(),
-                binding.binding_index(),
-                arguments_obj.into(),
+
+            let mut last_env = code.compile_environments.len() - 1;
+
+            if let Some(class_object) = &class_object {
+                let index = context
+                    .vm
+                    .environments
+                    .push_declarative(1, code.compile_environments[last_env].clone());
+                context.vm.environments.put_declarative_value(
+                    index,
+                    0,
+                    class_object.clone().into(),
+                );
+                last_env -= 1;
+            }
+
+            if code.has_binding_identifier {
+                let index = context
+                    .vm
+                    .environments
+                    .push_declarative(1, code.compile_environments[last_env].clone());
+                context
+                    .vm
+                    .environments
+                    .put_declarative_value(index, 0, self.clone().into());
+                last_env -= 1;
+            }
+
+            context.vm.environments.push_function(
+                code.num_bindings,
+                code.compile_environments[last_env].clone(),
+                this,
+                self.clone(),
+                None,
+                lexical_this_mode,
             );
-        }
 
-        let arg_count = args.len();
+            if let Some(bindings) = code.parameters_env_bindings {
+                last_env -= 1;
+                context
+                    .vm
+                    .environments
+                    .push_declarative(bindings, code.compile_environments[last_env].clone());
+            }
 
-        // Push function arguments to the stack.
-        let mut args = if code.params.as_ref().len() > args.len() {
-            let mut v = args.to_vec();
-            v.extend(vec![
-                JsValue::Undefined;
-                code.params.as_ref().len() - args.len()
-            ]);
-            v
-        } else {
-            args.to_vec()
-        };
-        args.reverse();
-        let mut stack = args;
+            if let Some(binding) = code.arguments_binding {
+                let arguments_obj = if code.strict || !code.params.is_simple() {
+                    Arguments::create_unmapped_arguments_object(args, context)
+                } else {
+                    let env = context.vm.environments.current();
+                    Arguments::create_mapped_arguments_object(
+                        &this_function_object,
+                        &code.params,
+                        args,
+                        env.declarative_expect(),
+                        context,
+                    )
+                };
+                context.vm.environments.put_declarative_value(
+                    binding.environment_index(),
+                    binding.binding_index(),
+                    arguments_obj.into(),
+                );
+            }
+
+            let arg_count = args.len();
+            let param_count = code.params.as_ref().len();
 
-        std::mem::swap(&mut context.vm.stack, &mut stack);
+            // Push function arguments to the stack, reserving the space needed by the function body.
+            let mut stack = Vec::with_capacity(
+                code.max_stack_size() as usize + arg_count.saturating_sub(param_count),
+            );
+            stack.extend(
+                std::iter::repeat(JsValue::Undefined).take(param_count.saturating_sub(arg_count)),
+            );
+            stack.extend(args.iter().rev().cloned());
 
-        let param_count = code.params.as_ref().len();
+            std::mem::swap(&mut context.vm.stack, &mut stack);
 
-        let mut frame = CallFrame::new(code)
-            .with_param_count(param_count)
-            .with_arg_count(arg_count);
-        frame.promise_capability = promise_capability.clone();
+            let mut frame = CallFrame::new(code.clone())
+                .with_param_count(param_count)
+                .with_arg_count(arg_count);
+            frame.promise_capability = promise_capability.clone();
+            frame.tail_call_allowed = !async_ && !gen;
 
-        context.vm.push_frame(frame);
+            context.vm.push_frame(frame);
 
-        let result = context
-            .run()
-            .consume()
-            .map_err(|err| err.inject_realm(context.realm().clone()));
+            let result = context
+                .run()
+                .consume()
+                .map_err(|err| err.inject_realm(context.realm().clone()));
+
+            let mut call_frame = context.vm.pop_frame().expect("frame must exist");
+            std::mem::swap(&mut environments, &mut context.vm.environments);
+            std::mem::swap(&mut context.vm.stack, &mut stack);
+
+            // The environments of generators are kept alive by the generator object.
+            if !gen {
+                environments.truncate(environments_len);
+            }
+            context
+                .vm
+                .environments
+                .swap_bindings_pool(&mut environments);
 
-        let call_frame = context.vm.pop_frame().expect("frame must exist");
-        std::mem::swap(&mut environments, &mut context.vm.environments);
-        std::mem::swap(&mut context.vm.stack, &mut stack);
+            match call_frame.tail_call.take() {
+                Some(next) if result.is_ok() => tail_call = Some(next),
+                _ => break (result, call_frame, stack),
+            }
+        };
 
         if let Some(promise_capability) = promise_capability {
             Ok(promise_capability.promise().clone().into())
@@ -1182,13 +1461,15 @@ impl JsObject {
         this_target: &JsValue,
         context: &mut Context<'_>,
     ) -> JsResult<Self> {
+        context.vm.check_stack_limits()?;
+
         let this_function_object = self.clone();
         let active_function = self.clone();
         let object = self.borrow();
         let function_object = object.as_function().expect("not a function");
         let realm = function_object.realm().clone();
 
-        let context = &mut ContextCleanupGuard::new(context, realm, active_function);
+        let context = &mut ContextCleanupGuard::new(context, realm, Some(active_function));
         match function_object.kind() {
             FunctionKind::Native {
                 function,
@@ -1239,6 +1520,8 @@ impl JsObject {
                 let constructor_kind = *constructor_kind;
                 drop(object);
 
+                let code = CodeBlock::compiled(code, context)?;
+
                 let this = if constructor_kind.is_base() {
                     // If the prototype of the constructor is not an object, then use the default object
                     // prototype as prototype for the new object
@@ -1319,6 +1602,12 @@ impl JsObject {
 
                 let arg_count = args.len();
 
+                // Reserve the space needed by the constructor body before pushing the arguments.
+                context.vm.stack.reserve(
+                    code.max_stack_size() as usize
+                        + arg_count.saturating_sub(code.params.as_ref().len()),
+                );
+
                 // Push function arguments to the stack.
                 let args = if code.params.as_ref().len() > args.len() {
                     let mut v = args.to_vec();
@@ -5,11 +5,17 @@ use crate::{JsError, JsResult, JsValue};
 /// An implementation of the ECMAScript's `CompletionRecord` [specification] for
 /// Boa's VM output Completion and Result.
 ///
+/// This is returned by [`Context::execute_with_completion`](crate::Context::execute_with_completion)
+/// to tell apart the different ways a `CodeBlock` can complete.
+///
 /// [specification]: https://tc39.es/ecma262/#sec-completion-record-specification-type
 #[derive(Debug, Clone)]
-pub(crate) enum CompletionRecord {
+pub enum CompletionRecord {
+    /// The code completed normally with its completion value.
     Normal(JsValue),
+    /// The code completed with a `return` completion and its returned value.
     Return(JsValue),
+    /// The code completed by throwing an error.
     Throw(JsError),
 }
 
@@ -19,14 +25,15 @@ impl CompletionRecord {
         matches!(self, CompletionRecord::Throw(_))
     }
 
-    /// This function will consume the current `CompletionRecord` and return a `JsResult<JsValue>`
+    /// This function will consume the current `CompletionRecord` and return a `JsResult<JsValue>`,
+    /// where both normal and `return` completions are `Ok`.
     // NOTE: rustc bug around evaluating destructors that prevents this from being a const function.
     // Related issue(s):
     //   - https://github.com/rust-lang/rust-clippy/issues/4041
     //   - https://github.com/rust-lang/rust/issues/60964
     //   - https://github.com/rust-lang/rust/issues/73255
     #[allow(clippy::missing_const_for_fn)]
-    pub(crate) fn consume(self) -> JsResult<JsValue> {
+    pub fn consume(self) -> JsResult<JsValue> {
         match self {
             Self::Throw(error) => Err(error),
             Self::Normal(value) | Self::Return(value) => Ok(value),
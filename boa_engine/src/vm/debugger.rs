@@ -0,0 +1,266 @@
+//! Step-debugging support for the VM.
+//!
+//! A [`DebuggerHook`] set with [`Context::set_debugger_hook`] is consulted before the execution
+//! of every instruction, and decides with a [`DebuggerAction`] how the execution continues. The
+//! hook can inspect the state of the VM through the [`DebugFrame`] it receives, and can use a
+//! [`Breakpoints`] set to cheaply check if it must stop at the current instruction.
+//!
+//! An execution paused by the hook blocks until it is resumed with the [`ResumeHandle`] obtained
+//! with [`Context::debugger_resume_handle`], which can be sent to the thread of the debugger.
+
+use crate::{
+    vm::{CodeBlock, Opcode},
+    Context, JsResult, JsString, JsValue,
+};
+use boa_ast::expression::Identifier;
+use boa_gc::Gc;
+use boa_interner::Interner;
+use rustc_hash::FxHashSet;
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+
+/// The action taken by the VM after consulting the [`DebuggerHook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebuggerAction {
+    /// Executes the instruction and continues running.
+    Continue,
+
+    /// Executes the instruction and marks the next executed instruction as a step, even if it is
+    /// in a function called by this instruction.
+    ///
+    /// See [`DebugFrame::is_step`].
+    StepInto,
+
+    /// Doesn't execute the instruction, blocks until the execution is resumed with the
+    /// [`ResumeHandle`] of the context, and then consults the hook again for the same instruction.
+    ///
+    /// This allows a debugger to keep the execution paused while it waits for commands, without
+    /// blocking inside a single call to the hook.
+    Pause,
+}
+
+/// A hook that is consulted by the VM before executing every instruction.
+pub trait DebuggerHook {
+    /// Called before the VM executes the instruction at [`DebugFrame::pc`].
+    fn on_instruction(&self, frame: &mut DebugFrame<'_, '_>) -> DebuggerAction;
+}
+
+/// A handle that resumes an execution of the [`Context`] it was obtained from that was paused
+/// with [`DebuggerAction::Pause`].
+///
+/// A resume requested while the hook is being consulted applies to the pause returned by the
+/// hook. A resume requested while the execution is running is ignored.
+#[derive(Debug, Clone, Default)]
+pub struct ResumeHandle {
+    state: Arc<(Mutex<ResumeState>, Condvar)>,
+}
+
+/// The state of the execution controlled by a [`ResumeHandle`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum ResumeState {
+    #[default]
+    Running,
+    Paused,
+    Resumed,
+}
+
+impl ResumeHandle {
+    /// Resumes the paused execution, which consults the hook again for the paused instruction.
+    ///
+    /// Returns `false` without doing anything if the execution is not paused.
+    pub fn resume(&self) -> bool {
+        let (state, condvar) = &*self.state;
+        let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        if *state != ResumeState::Paused {
+            return false;
+        }
+        *state = ResumeState::Resumed;
+        drop(state);
+        condvar.notify_one();
+        true
+    }
+
+    /// Marks the execution as paused before the hook is consulted, so a resume requested by the
+    /// debugger before the hook returns [`DebuggerAction::Pause`] is not lost.
+    pub(crate) fn pause(&self) {
+        *self.state.0.lock().unwrap_or_else(PoisonError::into_inner) = ResumeState::Paused;
+    }
+
+    /// Marks the execution as running after the hook let it continue.
+    pub(crate) fn run(&self) {
+        *self.state.0.lock().unwrap_or_else(PoisonError::into_inner) = ResumeState::Running;
+    }
+
+    /// Blocks the current thread until [`ResumeHandle::resume`] is called.
+    pub(crate) fn wait(&self) {
+        let (state, condvar) = &*self.state;
+        let state = state.lock().unwrap_or_else(PoisonError::into_inner);
+        drop(
+            condvar
+                .wait_while(state, |state| *state == ResumeState::Paused)
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+    }
+}
+
+/// The identity of a [`CodeBlock`], used to set [`Breakpoints`].
+///
+/// The identity is only unique while the code block is alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CodeBlockId(usize);
+
+impl CodeBlockId {
+    /// Gets the identity of `code_block`.
+    #[must_use]
+    pub fn new(code_block: &Gc<CodeBlock>) -> Self {
+        Self(std::ptr::addr_of!(**code_block) as usize)
+    }
+}
+
+/// A set of breakpoints, identified by the code block and the bytecode offset of the instruction.
+#[derive(Debug, Clone, Default)]
+pub struct Breakpoints {
+    breakpoints: FxHashSet<(CodeBlockId, u32)>,
+}
+
+impl Breakpoints {
+    /// Creates an empty set of breakpoints.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a breakpoint at the instruction at `pc` of the code block `id`.
+    ///
+    /// Returns `false` if the breakpoint was already set.
+    pub fn insert(&mut self, id: CodeBlockId, pc: u32) -> bool {
+        self.breakpoints.insert((id, pc))
+    }
+
+    /// Removes the breakpoint at the instruction at `pc` of the code block `id`.
+    ///
+    /// Returns `false` if the breakpoint was not set.
+    pub fn remove(&mut self, id: CodeBlockId, pc: u32) -> bool {
+        self.breakpoints.remove(&(id, pc))
+    }
+
+    /// Returns `true` if there is a breakpoint at the instruction at `pc` of the code block `id`.
+    #[must_use]
+    pub fn contains(&self, id: CodeBlockId, pc: u32) -> bool {
+        self.breakpoints.contains(&(id, pc))
+    }
+
+    /// Returns `true` if there is a breakpoint at the current instruction of `frame`.
+    #[must_use]
+    pub fn is_hit(&self, frame: &DebugFrame<'_, '_>) -> bool {
+        !self.breakpoints.is_empty() && self.contains(frame.code_block_id(), frame.pc())
+    }
+
+    /// Removes all the breakpoints.
+    pub fn clear(&mut self) {
+        self.breakpoints.clear();
+    }
+}
+
+/// A view of the state of the VM before the execution of an instruction.
+#[derive(Debug)]
+pub struct DebugFrame<'a, 'host> {
+    context: &'a mut Context<'host>,
+    step: bool,
+}
+
+impl<'a, 'host> DebugFrame<'a, 'host> {
+    pub(crate) fn new(context: &'a mut Context<'host>, step: bool) -> Self {
+        Self { context, step }
+    }
+
+    /// Gets the code block that is being executed.
+    #[must_use]
+    pub fn code_block(&self) -> &Gc<CodeBlock> {
+        &self.context.vm.frame().code_block
+    }
+
+    /// Gets the identity of the code block that is being executed.
+    #[must_use]
+    pub fn code_block_id(&self) -> CodeBlockId {
+        CodeBlockId::new(self.code_block())
+    }
+
+    /// Gets the bytecode offset of the instruction that is about to be executed.
+    #[must_use]
+    pub fn pc(&self) -> u32 {
+        self.context.vm.frame().pc as u32
+    }
+
+    /// Gets the opcode of the instruction that is about to be executed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bytecode of the code block is invalid.
+    #[must_use]
+    pub fn opcode(&self) -> Opcode {
+        let code = self.code_block();
+        Opcode::try_from(code.bytecode[self.context.vm.frame().pc]).expect("invalid opcode")
+    }
+
+    /// Returns `true` if the hook returned [`DebuggerAction::StepInto`] for the previously
+    /// executed instruction.
+    #[must_use]
+    pub const fn is_step(&self) -> bool {
+        self.step
+    }
+
+    /// Gets the number of frames on the call stack.
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.context.vm.frames.len()
+    }
+
+    /// Gets the operand stack of the current frame, with the top of the stack as last element.
+    #[must_use]
+    pub fn stack(&self) -> &[JsValue] {
+        // The arguments pushed below the frame pointer are consumed by the function prologue.
+        let frame = self.context.vm.frame();
+        let base = frame
+            .fp
+            .saturating_sub(frame.arg_count.max(frame.param_count));
+        self.context.vm.stack.get(base..).unwrap_or_default()
+    }
+
+    /// Gets the value of the binding `name` that is visible from the current instruction.
+    ///
+    /// Returns `None` if the binding doesn't exist or is not initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if getting the value of the binding throws, like for a getter of the
+    /// global object.
+    pub fn binding(&mut self, name: &str) -> JsResult<Option<JsValue>> {
+        let Some(name) = self.context.interner().get(name) else {
+            return Ok(None);
+        };
+        let mut locator = self
+            .context
+            .vm
+            .environments
+            .current_compile_environment()
+            .borrow()
+            .get_binding_recursive(Identifier::new(name));
+        self.context.find_runtime_binding(&mut locator)?;
+        self.context.get_binding(locator)
+    }
+
+    /// Gets the variables that are visible from the current instruction, with their current
+    /// values.
+    ///
+    /// See [`Context::scope_variables`].
+    #[must_use]
+    pub fn scope_variables(&self) -> Vec<(JsString, JsValue)> {
+        self.context.scope_variables()
+    }
+
+    /// Gets the interner of the context, used to resolve the names of the code block.
+    #[must_use]
+    pub fn interner(&self) -> &Interner {
+        self.context.interner()
+    }
+}
@@ -0,0 +1,56 @@
+//! Deoptimization diagnostics for the VM.
+//!
+//! The VM reports a [`DeoptEvent`] to the hook set with [`Context::trace_deopts`] every time an
+//! instruction has to leave an optimized path it was previously able to take, like an inline
+//! cache that stops matching the objects accessed by its instruction.
+//!
+//! [`Context::trace_deopts`]: crate::Context::trace_deopts
+
+use crate::vm::CodeBlock;
+use boa_gc::Gc;
+
+/// The reason of a [`DeoptEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeoptReason {
+    /// A property access by name missed its inline cache, because the accessed object doesn't
+    /// have the shape cached by a previous access.
+    InlineCacheMiss,
+}
+
+/// A deoptimization of an instruction, reported to the hook set with
+/// [`Context::trace_deopts`](crate::Context::trace_deopts).
+#[derive(Debug, Clone)]
+pub struct DeoptEvent {
+    code_block: Gc<CodeBlock>,
+    pc: u32,
+    reason: DeoptReason,
+}
+
+impl DeoptEvent {
+    /// Creates a new deoptimization event.
+    pub(crate) const fn new(code_block: Gc<CodeBlock>, pc: u32, reason: DeoptReason) -> Self {
+        Self {
+            code_block,
+            pc,
+            reason,
+        }
+    }
+
+    /// Gets the code block of the deoptimized instruction.
+    #[must_use]
+    pub const fn code_block(&self) -> &Gc<CodeBlock> {
+        &self.code_block
+    }
+
+    /// Gets the bytecode offset of the deoptimized instruction.
+    #[must_use]
+    pub const fn pc(&self) -> u32 {
+        self.pc
+    }
+
+    /// Gets the reason of the deoptimization.
+    #[must_use]
+    pub const fn reason(&self) -> DeoptReason {
+        self.reason
+    }
+}
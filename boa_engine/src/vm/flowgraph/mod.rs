@@ -96,6 +96,21 @@ impl CodeBlock {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
+                Opcode::PushRegExp => {
+                    let operand = self.read::<u32>(pc);
+                    pc += size_of::<u32>();
+                    let regexp = &self.regexps[operand as usize];
+                    let operand_str = format!(
+                        "/{}/{}",
+                        regexp.pattern().to_std_string_escaped(),
+                        regexp.flags().to_std_string_escaped()
+                    );
+                    let operand_str = operand_str.escape_debug();
+                    let label = format!("{opcode_str} {operand_str}");
+
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
+                }
                 Opcode::Jump => {
                     let operand = self.read::<u32>(pc);
                     pc += size_of::<u32>();
@@ -270,6 +285,7 @@ impl CodeBlock {
                 Opcode::CatchStart
                 | Opcode::CallEval
                 | Opcode::Call
+                | Opcode::TailCall
                 | Opcode::New
                 | Opcode::SuperCall
                 | Opcode::ConcatToString => {
@@ -415,9 +431,17 @@ impl CodeBlock {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
-                Opcode::GetPropertyByName
-                | Opcode::GetMethod
-                | Opcode::SetPropertyByName
+                Opcode::GetPropertyByName | Opcode::SetPropertyByName => {
+                    let operand = self.read::<u32>(pc);
+                    pc += size_of::<u32>();
+                    let label = format!(
+                        "{opcode_str} '{}'",
+                        interner.resolve_expect(self.inline_caches[operand as usize].name.sym()),
+                    );
+                    graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
+                    graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
+                }
+                Opcode::GetMethod
                 | Opcode::DefineOwnPropertyByName
                 | Opcode::DefineClassStaticMethodByName
                 | Opcode::DefineClassMethodByName
@@ -461,7 +485,7 @@ impl CodeBlock {
                     }
                 }
                 Opcode::Pop
-                | Opcode::PopIfThrown
+                | Opcode::SetCompletionValue
                 | Opcode::Dup
                 | Opcode::Swap
                 | Opcode::PushZero
@@ -551,8 +575,6 @@ impl CodeBlock {
                 | Opcode::PushElisionToArray
                 | Opcode::PushIteratorToArray
                 | Opcode::PushNewArray
-                | Opcode::PopOnReturnAdd
-                | Opcode::PopOnReturnSub
                 | Opcode::Yield
                 | Opcode::GeneratorNext
                 | Opcode::PushClassField
@@ -562,6 +584,7 @@ impl CodeBlock {
                 | Opcode::CallEvalSpread
                 | Opcode::CallSpread
                 | Opcode::NewSpread
+                | Opcode::ImportCall
                 | Opcode::SuperCallSpread
                 | Opcode::SuperCallPrepare
                 | Opcode::SetPrototype
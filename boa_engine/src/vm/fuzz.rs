@@ -0,0 +1,185 @@
+//! Entry points to fuzz the VM with arbitrary bytecode.
+//!
+//! Unlike the fuzzers that generate source code, these entry points execute raw bytecode, which
+//! exercises the instructions in orders the [`ByteCompiler`](crate::bytecompiler::ByteCompiler)
+//! never emits. The bytecode is validated before its execution, and then executed in a fresh
+//! [`Context`] with strict resource limits.
+//!
+//! This module is only available if the `fuzz` feature is enabled.
+
+use crate::{
+    bytecompiler::{validate_bytecode, ByteCompiler, Tables},
+    vm::CodeBlock,
+    Context, JsValue,
+};
+use boa_ast::expression::Identifier;
+use boa_gc::Gc;
+use boa_interner::Sym;
+use std::{
+    any::Any,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// The error returned when arbitrary bytecode is rejected before its execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidBytecode {
+    pc: usize,
+    message: &'static str,
+}
+
+impl InvalidBytecode {
+    /// Gets the location of the offending instruction.
+    #[must_use]
+    pub const fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// Gets the reason why the bytecode was rejected.
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+}
+
+impl fmt::Display for InvalidBytecode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid bytecode: {} at {:06}", self.message, self.pc)
+    }
+}
+
+impl std::error::Error for InvalidBytecode {}
+
+impl CodeBlock {
+    /// Creates a script code block from arbitrary bytecode, with the given literals and names.
+    ///
+    /// The names must be interned in the interner of the [`Context`] that executes the code block.
+    /// Instructions that reference bindings, functions, private names or environments are not
+    /// supported, since they need tables that cannot be built from arbitrary data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytecode contains invalid opcodes, truncated operands, addresses
+    /// that don't point to an instruction, indices outside of the literals and names, or
+    /// instructions that pop more values than the stack holds.
+    pub fn from_fuzz_bytecode(
+        bytecode: &[u8],
+        literals: Vec<JsValue>,
+        names: Vec<Sym>,
+    ) -> Result<Self, InvalidBytecode> {
+        // The name operands are only replaced by the indices of the inline caches afterwards.
+        let tables = Tables {
+            literals: &literals,
+            regexps: None,
+            names: names.len(),
+            inline_caches: names.len(),
+            bindings: None,
+            functions: None,
+            private_names: None,
+            compile_environments: None,
+        };
+        let max_stack_size =
+            validate_bytecode(bytecode, 0, &tables).map_err(|error| InvalidBytecode {
+                pc: error.pc,
+                message: error.message,
+            })?;
+
+        let names = names.into_iter().map(Identifier::new).collect::<Vec<_>>();
+        let mut bytecode = bytecode.to_vec();
+        let inline_caches = ByteCompiler::create_inline_caches(&mut bytecode, &names);
+
+        let mut code = Self::new(Sym::MAIN, 0, false);
+        code.bytecode = bytecode.into_boxed_slice();
+        code.literals = literals.into_boxed_slice();
+        code.names = names.into_boxed_slice();
+        code.inline_caches = inline_caches;
+        code.max_stack_size = max_stack_size;
+        Ok(code)
+    }
+}
+
+/// The resource limits of the execution of arbitrary bytecode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzLimits {
+    /// The maximum number of executed instructions.
+    pub instructions: usize,
+
+    /// The maximum number of nested call frames.
+    pub recursion: usize,
+}
+
+impl Default for FuzzLimits {
+    fn default() -> Self {
+        Self {
+            instructions: 1 << 16,
+            recursion: 64,
+        }
+    }
+}
+
+/// The outcome of the execution of arbitrary bytecode with [`execute_fuzz_bytecode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FuzzOutcome {
+    /// The bytecode was rejected before its execution.
+    Rejected(InvalidBytecode),
+
+    /// The bytecode completed normally.
+    Completed,
+
+    /// The bytecode threw an error, including the errors thrown when a limit is reached.
+    Threw(String),
+
+    /// The VM panicked, with the given panic message.
+    ///
+    /// This is the only outcome that should be reported as a failure by a fuzzer.
+    Panicked(String),
+}
+
+/// Validates and executes arbitrary bytecode in a new [`Context`], catching the panics of the VM.
+///
+/// The `names` are interned in the new context, and the bytecode is executed as a script with
+/// the given resource `limits`. See [`CodeBlock::from_fuzz_bytecode`] for the supported bytecode.
+///
+/// Note that the panics are still reported by the panic hook of the current thread.
+#[must_use]
+pub fn execute_fuzz_bytecode(
+    bytecode: &[u8],
+    literals: Vec<JsValue>,
+    names: &[&str],
+    limits: FuzzLimits,
+) -> FuzzOutcome {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut context = Context::builder()
+            .instructions_remaining(limits.instructions)
+            .build()
+            .expect("building the default context should not fail");
+        context.set_max_call_depth(limits.recursion);
+
+        let names = names
+            .iter()
+            .map(|name| context.interner_mut().get_or_intern(*name))
+            .collect();
+        let code = match CodeBlock::from_fuzz_bytecode(bytecode, literals, names) {
+            Ok(code) => code,
+            Err(error) => return FuzzOutcome::Rejected(error),
+        };
+
+        match context.execute(Gc::new(code)) {
+            Ok(_) => FuzzOutcome::Completed,
+            Err(error) => FuzzOutcome::Threw(error.to_string()),
+        }
+    }));
+
+    result.unwrap_or_else(|payload| FuzzOutcome::Panicked(panic_message(&*payload)))
+}
+
+/// Extracts the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
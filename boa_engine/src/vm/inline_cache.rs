@@ -0,0 +1,167 @@
+//! Inline caches for property accesses by name.
+//!
+//! Every `GetPropertyByName` and `SetPropertyByName` instruction of a [`CodeBlock`] has its own
+//! monomorphic [`InlineCache`], which remembers the [`SharedShape`] of the last object that had
+//! the accessed property as an own data property, and the index of the property in the storage of
+//! the object.
+//!
+//! Shared shapes are immutable, so any object with the cached shape has the property at the same
+//! index with the same attributes. Any change to the layout of the object, like adding, deleting
+//! or reconfiguring a property, transitions the object to a different shape, which makes the
+//! cache miss until it is updated by the slow path. Unique shapes are mutated in place, so they
+//! are never cached.
+//!
+//! [`CodeBlock`]: crate::vm::CodeBlock
+
+use crate::{
+    object::{
+        internal_methods::{
+            InternalObjectMethods, ARRAY_EXOTIC_INTERNAL_METHODS, CONSTRUCTOR_INTERNAL_METHODS,
+            FUNCTION_INTERNAL_METHODS, ORDINARY_INTERNAL_METHODS,
+        },
+        shape::{slot::SlotAttributes, SharedShape},
+        JsObject,
+    },
+    property::PropertyKey,
+    JsValue,
+};
+use boa_ast::expression::Identifier;
+use boa_gc::{Finalize, GcRefCell, Trace};
+
+/// The cached location of a property.
+#[derive(Clone, Trace, Finalize)]
+struct CachedSlot {
+    /// The shape of the objects that have the property at `index`.
+    shape: SharedShape,
+
+    /// The internal methods of the cached object.
+    #[unsafe_ignore_trace]
+    internal_methods: &'static InternalObjectMethods,
+
+    /// The index of the property in the object storage.
+    index: u32,
+}
+
+impl std::fmt::Debug for CachedSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let internal_methods: *const _ = self.internal_methods;
+        f.debug_struct("CachedSlot")
+            .field("shape", &self.shape)
+            .field("internal_methods", &internal_methods)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+/// A monomorphic inline cache of a `GetPropertyByName` or `SetPropertyByName` instruction.
+#[derive(Clone, Debug, Trace, Finalize)]
+pub(crate) struct InlineCache {
+    /// The name of the accessed property.
+    #[unsafe_ignore_trace]
+    pub(crate) name: Identifier,
+
+    /// The last cached location of the property.
+    slot: GcRefCell<Option<CachedSlot>>,
+}
+
+impl InlineCache {
+    /// Creates a new empty inline cache for the property `name`.
+    pub(crate) const fn new(name: Identifier) -> Self {
+        Self {
+            name,
+            slot: GcRefCell::new(None),
+        }
+    }
+
+    /// Returns `true` if the cache has a cached location, which means that a miss deoptimizes
+    /// its instruction.
+    pub(crate) fn is_cached(&self) -> bool {
+        self.slot.borrow().is_some()
+    }
+
+    /// Returns the storage index of the property if `object` matches the cached shape.
+    fn lookup(&self, object: &JsObject) -> Option<usize> {
+        let slot = self.slot.borrow();
+        let slot = slot.as_ref()?;
+        if !std::ptr::eq(object.vtable(), slot.internal_methods) {
+            return None;
+        }
+        let object = object.borrow();
+        let shape = object.properties().shape.as_shared()?;
+        (shape.to_addr_usize() == slot.shape.to_addr_usize()).then_some(slot.index as usize)
+    }
+
+    /// Gets the value of the property from `object`, if the cache hits.
+    pub(crate) fn get(&self, object: &JsObject) -> Option<JsValue> {
+        let index = self.lookup(object)?;
+        object.borrow().properties().storage.get(index).cloned()
+    }
+
+    /// Sets the value of the property of `object`, returning `false` if the cache misses.
+    pub(crate) fn set(&self, object: &JsObject, value: &JsValue) -> bool {
+        let Some(index) = self.lookup(object) else {
+            return false;
+        };
+        let mut object = object.borrow_mut();
+        let Some(element) = object.properties_mut().storage.get_mut(index) else {
+            return false;
+        };
+        *element = value.clone();
+        true
+    }
+
+    /// Caches the location of the property `key` of `object` for a `GetPropertyByName`.
+    ///
+    /// Only own data properties of objects with an ordinary `[[Get]]` are cached.
+    pub(crate) fn update_get(&self, object: &JsObject, key: &PropertyKey) {
+        let methods = object.vtable();
+        if [
+            &ORDINARY_INTERNAL_METHODS,
+            &FUNCTION_INTERNAL_METHODS,
+            &CONSTRUCTOR_INTERNAL_METHODS,
+            &ARRAY_EXOTIC_INTERNAL_METHODS,
+        ]
+        .into_iter()
+        .any(|ordinary| std::ptr::eq(methods, ordinary))
+        {
+            self.update(object, key, SlotAttributes::empty());
+        }
+    }
+
+    /// Caches the location of the property `key` of `object` for a `SetPropertyByName`.
+    ///
+    /// Only writable own data properties of objects with an ordinary `[[Set]]` and
+    /// `[[DefineOwnProperty]]` are cached.
+    pub(crate) fn update_set(&self, object: &JsObject, key: &PropertyKey) {
+        let methods = object.vtable();
+        if [
+            &ORDINARY_INTERNAL_METHODS,
+            &FUNCTION_INTERNAL_METHODS,
+            &CONSTRUCTOR_INTERNAL_METHODS,
+        ]
+        .into_iter()
+        .any(|ordinary| std::ptr::eq(methods, ordinary))
+        {
+            self.update(object, key, SlotAttributes::WRITABLE);
+        }
+    }
+
+    /// Caches the location of the data property `key` of `object` if it has the `required`
+    /// attributes, or clears the cache otherwise.
+    fn update(&self, object: &JsObject, key: &PropertyKey, required: SlotAttributes) {
+        let internal_methods = object.vtable();
+        let object = object.borrow();
+        let shape = &object.properties().shape;
+        let slot = shape.as_shared().and_then(|shared| {
+            let slot = shape.lookup(key)?;
+            (!slot.attributes.is_accessor_descriptor() && slot.attributes.contains(required)).then(
+                || CachedSlot {
+                    shape: shared.clone(),
+                    internal_methods,
+                    index: slot.index,
+                },
+            )
+        });
+        *self.slot.borrow_mut() = slot;
+    }
+}
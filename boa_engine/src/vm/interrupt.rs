@@ -0,0 +1,46 @@
+//! Interruption of long-running executions.
+//!
+//! An [`InterruptHandle`] obtained with [`Context::interrupt_handle`] can be sent to another
+//! thread to abort the execution of a script, for example one stuck in an infinite loop.
+//!
+//! [`Context::interrupt_handle`]: crate::Context::interrupt_handle
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// A handle that interrupts the execution of the [`Context`](crate::Context) it was obtained
+/// from.
+///
+/// The VM checks the handle when an execution starts and every
+/// [`interrupt_check_interval`](crate::Context::interrupt_check_interval) instructions. Once it
+/// is interrupted, the execution unwinds with an error for which [`JsError::is_interrupted`]
+/// returns `true`. Scripts cannot catch this error, and `finally` blocks are not run.
+///
+/// The handle stays interrupted until [`InterruptHandle::reset`] is called, so every execution
+/// started in the meantime is interrupted too.
+///
+/// [`JsError::is_interrupted`]: crate::JsError::is_interrupted
+#[derive(Debug, Clone, Default)]
+pub struct InterruptHandle {
+    interrupted: Arc<AtomicBool>,
+}
+
+impl InterruptHandle {
+    /// Requests the interruption of the execution.
+    pub fn interrupt(&self) {
+        self.interrupted.store(true, Ordering::Relaxed);
+    }
+
+    /// Clears a previous interruption request, allowing the context to execute code again.
+    pub fn reset(&self) {
+        self.interrupted.store(false, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the interruption of the execution was requested.
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+}
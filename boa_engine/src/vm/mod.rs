@@ -4,14 +4,14 @@
 //! This module will provide an instruction set for the AST to use, various traits,
 //! plus an interpreter to execute those instructions
 
+#[cfg(feature = "fuzz")]
+use crate::JsNativeErrorKind;
 use crate::{
     builtins::async_generator::{AsyncGenerator, AsyncGeneratorState},
-    environments::{DeclarativeEnvironment, DeclarativeEnvironmentStack},
+    environments::{BindingLookupStats, DeclarativeEnvironment, DeclarativeEnvironmentStack},
     vm::code_block::Readable,
-    Context, JsError, JsObject, JsResult, JsValue,
+    Context, JsError, JsNativeError, JsObject, JsResult, JsValue,
 };
-#[cfg(feature = "fuzz")]
-use crate::{JsNativeError, JsNativeErrorKind};
 use boa_gc::Gc;
 use boa_profiler::Profiler;
 use std::{convert::TryInto, mem::size_of};
@@ -19,27 +19,59 @@ use std::{convert::TryInto, mem::size_of};
 #[cfg(feature = "trace")]
 use boa_interner::ToInternedString;
 #[cfg(feature = "trace")]
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 mod call_frame;
 mod code_block;
 mod completion_record;
+mod debugger;
+mod deopt;
+mod inline_cache;
+mod interrupt;
 mod opcode;
+mod serialization;
+mod stack_trace;
 
 #[cfg(feature = "flowgraph")]
 pub mod flowgraph;
 
-pub use {call_frame::CallFrame, code_block::CodeBlock, opcode::Opcode};
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
+pub use {
+    call_frame::CallFrame,
+    code_block::{CodeBlock, CodeBlockStats},
+    completion_record::CompletionRecord,
+    debugger::{Breakpoints, CodeBlockId, DebugFrame, DebuggerAction, DebuggerHook, ResumeHandle},
+    deopt::{DeoptEvent, DeoptReason},
+    interrupt::InterruptHandle,
+    opcode::Opcode,
+    stack_trace::StackFrameInfo,
+};
 
 pub(crate) use {
     call_frame::GeneratorResumeKind,
-    code_block::{create_function_object, create_generator_function_object},
-    completion_record::CompletionRecord,
+    code_block::{
+        create_function_object, create_function_object_fast, create_function_object_from_template,
+        create_generator_function_object, CodeKind, ContextCleanupGuard,
+    },
+    inline_cache::InlineCache,
     opcode::BindingOpcode,
+    stack_trace::format_stack_trace,
 };
 
 #[cfg(test)]
 mod tests;
+
+/// The default maximum number of nested call frames.
+///
+/// Every nested call uses some native stack, so the default is lower on platforms that usually
+/// have smaller stacks.
+#[cfg(not(target_family = "wasm"))]
+const DEFAULT_MAX_CALL_DEPTH: usize = 512;
+#[cfg(target_family = "wasm")]
+const DEFAULT_MAX_CALL_DEPTH: usize = 128;
+
 /// Virtual Machine.
 #[derive(Debug)]
 pub struct Vm {
@@ -49,8 +81,23 @@ pub struct Vm {
     pub(crate) environments: DeclarativeEnvironmentStack,
     #[cfg(feature = "trace")]
     pub(crate) trace: bool,
+    #[cfg(feature = "trace")]
+    pub(crate) opcode_timings: Option<Box<[Duration]>>,
+    #[cfg(feature = "instcount")]
+    pub(crate) opcode_counts: Box<[u64; Opcode::COUNT]>,
     pub(crate) stack_size_limit: usize,
+    pub(crate) value_stack_limit: usize,
+    pub(crate) max_call_depth: usize,
     pub(crate) active_function: Option<JsObject>,
+    pub(crate) gc_between_frames: bool,
+    pub(crate) interrupt: InterruptHandle,
+    pub(crate) interrupt_check_interval: u32,
+    pub(crate) interrupt_countdown: u32,
+    pub(crate) instruction_limit: Option<u64>,
+    pub(crate) instruction_budget: u64,
+    pub(crate) instructions_remaining: u64,
+    pub(crate) instructions_executed: u64,
+    pub(crate) binding_lookups: BindingLookupStats,
 }
 
 impl Vm {
@@ -63,8 +110,23 @@ impl Vm {
             err: None,
             #[cfg(feature = "trace")]
             trace: false,
+            #[cfg(feature = "trace")]
+            opcode_timings: None,
+            #[cfg(feature = "instcount")]
+            opcode_counts: Box::new([0; Opcode::COUNT]),
             stack_size_limit: 1024,
+            value_stack_limit: usize::MAX,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
             active_function: None,
+            gc_between_frames: false,
+            interrupt: InterruptHandle::default(),
+            interrupt_check_interval: 1000,
+            interrupt_countdown: 0,
+            instruction_limit: None,
+            instruction_budget: u64::MAX,
+            instructions_remaining: u64::MAX,
+            instructions_executed: 0,
+            binding_lookups: BindingLookupStats::default(),
         }
     }
 
@@ -86,9 +148,41 @@ impl Vm {
         self.stack.pop().expect("stack was empty")
     }
 
+    /// Pops the environments pushed by the current frame, without running any `finally` block.
+    ///
+    /// This is used to unwind a frame for an error that cannot be caught, leaving the
+    /// environments as if the frame threw an uncaught exception.
+    pub(crate) fn unwind_environments(&mut self) {
+        let mut env_to_pop = 0;
+        while let Some(env_entry) = self.frame_mut().env_stack.last_mut() {
+            env_to_pop += env_entry.env_num();
+            if env_entry.is_global_env() {
+                env_entry.clear_env_num();
+                break;
+            }
+            self.frame_mut().env_stack.pop();
+        }
+
+        let env_truncation_len = self.environments.len().saturating_sub(env_to_pop);
+        self.environments.truncate(env_truncation_len);
+        self.frame_mut().iterators.clear();
+    }
+
+    /// Reads an operand of the current instruction, advancing the program counter past it.
+    ///
+    /// Under the `fuzz` feature every read is bounds checked, so a malformed operand stream that
+    /// got past validation panics instead of reading out of bounds.
     #[track_caller]
     pub(crate) fn read<T: Readable>(&mut self) -> T {
+        #[cfg(feature = "fuzz")]
         let value = self.frame().code_block.read::<T>(self.frame().pc);
+
+        #[cfg(not(feature = "fuzz"))]
+        // SAFETY: The bytecode of every code block is checked to only contain instructions with
+        // complete operands, when it's compiled or deserialized, so reading an operand of the
+        // current instruction is always in bounds.
+        let value = unsafe { self.frame().code_block.read_unchecked::<T>(self.frame().pc) };
+
         self.frame_mut().pc += size_of::<T>();
         value
     }
@@ -113,12 +207,36 @@ impl Vm {
         self.frames.last_mut().expect("no frame found")
     }
 
+    /// Checks that a new call frame or environment can be pushed without exceeding the maximum call
+    /// depth, which also bounds the environment stack, or the stack size limit.
+    ///
+    /// This must be called before doing any work for the call, so that nothing has to be undone
+    /// if it fails.
+    pub(crate) fn check_stack_limits(&self) -> JsResult<()> {
+        if self.frames.len() >= self.max_call_depth
+            || self.environments.len() >= self.max_call_depth
+            || self.stack.len() >= self.stack_size_limit
+        {
+            return Err(JsNativeError::range()
+                .with_message("Maximum call stack size exceeded")
+                .into());
+        }
+        Ok(())
+    }
+
     pub(crate) fn push_frame(&mut self, frame: CallFrame) {
+        if self.gc_between_frames {
+            boa_gc::safe_point();
+        }
         self.frames.push(frame);
     }
 
     pub(crate) fn pop_frame(&mut self) -> Option<CallFrame> {
-        self.frames.pop()
+        let frame = self.frames.pop();
+        if self.gc_between_frames {
+            boa_gc::safe_point();
+        }
+        frame
     }
 }
 
@@ -140,6 +258,27 @@ impl Context<'_> {
             opcode
         };
 
+        // Group opcodes by category first, so flamegraphs nest each instruction
+        // under the kind of work it performs.
+        let _category_timer =
+            Profiler::global().start_event(opcode.category().as_str(), "vm opcode category");
+
+        #[cfg(feature = "instcount")]
+        {
+            self.vm.opcode_counts[opcode as usize] += 1;
+        }
+
+        #[cfg(feature = "trace")]
+        if self.vm.opcode_timings.is_some() {
+            let event = Profiler::global().start_measured_event(opcode.as_instruction_str(), "vm");
+            let result = opcode.execute(self);
+            let duration = event.finish();
+            if let Some(timings) = &mut self.vm.opcode_timings {
+                timings[opcode as usize] += duration;
+            }
+            return result;
+        }
+
         let _timer = Profiler::global().start_event(opcode.as_instruction_str(), "vm");
 
         opcode.execute(self)
@@ -159,6 +298,10 @@ impl Context<'_> {
 
         let _timer = Profiler::global().start_event("run", "vm");
 
+        // The garbage collector is shared by all the contexts of the thread, so the setting of
+        // this context is restored when the execution returns to the caller.
+        let _gc_mode = boa_gc::set_safe_points_only(self.vm.gc_between_frames);
+
         #[cfg(feature = "trace")]
         if self.vm.trace {
             let msg = if self.vm.frames.last().is_some() {
@@ -189,6 +332,10 @@ impl Context<'_> {
         let current_stack_length = self.vm.stack.len();
         self.vm.frame_mut().set_frame_pointer(current_stack_length);
 
+        // Check the interrupt handle before the first instruction, instead of carrying over the
+        // countdown of a previous execution.
+        self.vm.interrupt_countdown = 0;
+
         // If the current executing function is an async function we have to resolve/reject it's promise at the end.
         // The relevant spec section is 3. in [AsyncBlockStart](https://tc39.es/ecma262/#sec-asyncblockstart).
         let promise_capability = self.vm.frame().promise_capability.clone();
@@ -209,6 +356,40 @@ impl Context<'_> {
                 self.instructions_remaining -= 1;
             }
 
+            if let Some(countdown) = self.vm.interrupt_countdown.checked_sub(1) {
+                self.vm.interrupt_countdown = countdown;
+            } else {
+                self.vm.interrupt_countdown = self.vm.interrupt_check_interval - 1;
+                if self.vm.interrupt.is_interrupted() {
+                    self.vm.unwind_environments();
+                    self.vm.err = Some(JsNativeError::interrupted().into());
+                    break CompletionType::Throw;
+                }
+            }
+
+            if let Some(hook) = self.debugger_hook.clone() {
+                let action = loop {
+                    let step = self.debugger_step;
+                    self.debugger_resume.pause();
+                    let action = hook.on_instruction(&mut DebugFrame::new(self, step));
+                    if action != DebuggerAction::Pause {
+                        self.debugger_resume.run();
+                        break action;
+                    }
+                    self.debugger_resume.wait();
+                };
+                self.debugger_step = action == DebuggerAction::StepInto;
+            }
+
+            // Without a limit the budget is `u64::MAX`, which cannot be exhausted in practice.
+            if let Some(remaining) = self.vm.instructions_remaining.checked_sub(1) {
+                self.vm.instructions_remaining = remaining;
+            } else {
+                self.vm.unwind_environments();
+                self.vm.err = Some(JsNativeError::instruction_limit_exceeded().into());
+                break CompletionType::Throw;
+            }
+
             // 1. Run the next instruction.
             #[cfg(feature = "trace")]
             let result = if self.vm.trace || self.vm.frame().code_block.trace.get() {
@@ -250,6 +431,17 @@ impl Context<'_> {
             #[cfg(not(feature = "trace"))]
             let result = self.execute_instruction();
 
+            // The value stack limit is checked after every instruction, so the error is thrown
+            // by the instruction that pushed the value that exceeded it.
+            let result = match result {
+                Ok(CompletionType::Normal) if self.vm.stack.len() > self.vm.value_stack_limit => {
+                    Err(JsNativeError::range()
+                        .with_message("Maximum value stack size exceeded")
+                        .into())
+                }
+                result => result,
+            };
+
             // 2. Evaluate the result of executing the instruction.
             match result {
                 Ok(CompletionType::Normal) => {}
@@ -273,7 +465,18 @@ impl Context<'_> {
                         }
                     }
 
-                    self.vm.err = Some(err);
+                    // Uncatchable errors bubble up to the (Rust) caller without running the catch
+                    // and finally blocks of the frame.
+                    if err.is_uncatchable() {
+                        self.vm.unwind_environments();
+                        self.vm.err = Some(err);
+                        break CompletionType::Throw;
+                    }
+
+                    // Native errors thrown by the instruction get the stack trace of the point
+                    // where they are thrown, which populates the `stack` property of the error
+                    // object they are converted to.
+                    self.vm.err = Some(err.inject_stack_trace(self));
 
                     // If this frame has not evaluated the throw as an AbruptCompletion, then evaluate it
                     let evaluation = Opcode::Throw
@@ -330,12 +533,9 @@ impl Context<'_> {
             let result = self.vm.pop();
             self.vm.stack.truncate(self.vm.frame().fp);
             result
-        } else if self.vm.stack.len() <= self.vm.frame().fp {
-            JsValue::undefined()
         } else {
-            let result = self.vm.pop();
             self.vm.stack.truncate(self.vm.frame().fp);
-            result
+            std::mem::take(&mut self.vm.frame_mut().completion_value)
         };
 
         if let Some(promise) = promise_capability {
@@ -398,6 +598,8 @@ impl Context<'_> {
         }
 
         // Any valid return statement is re-evaluated as a normal completion vs. return (yield).
+        // The frame keeps track of it for the callers that need to observe it.
+        self.vm.frame_mut().returned = execution_completion == CompletionType::Return;
         if execution_completion == CompletionType::Throw {
             return CompletionRecord::Throw(
                 self.vm
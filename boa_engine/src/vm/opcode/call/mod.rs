@@ -1,6 +1,8 @@
 use crate::{
-    builtins::function::FunctionKind,
+    builtins::{function::FunctionKind, promise::PromiseCapability},
     error::JsNativeError,
+    module::ModuleGraph,
+    object::JsObject,
     vm::{opcode::Operation, CompletionType},
     Context, JsResult, JsValue,
 };
@@ -17,11 +19,7 @@ impl Operation for CallEval {
     const INSTRUCTION: &'static str = "INST - CallEval";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
         let argument_count = context.vm.read::<u32>();
         let mut arguments = Vec::with_capacity(argument_count as usize);
         for _ in 0..argument_count {
@@ -77,11 +75,7 @@ impl Operation for CallEvalSpread {
     const INSTRUCTION: &'static str = "INST - CallEvalSpread";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
 
         // Get the arguments that are stored as an array object on the stack.
         let arguments_array = context.vm.pop();
@@ -143,29 +137,16 @@ impl Operation for Call {
     const INSTRUCTION: &'static str = "INST - Call";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
         let argument_count = context.vm.read::<u32>();
-        let mut arguments = Vec::with_capacity(argument_count as usize);
-        for _ in 0..argument_count {
-            arguments.push(context.vm.pop());
-        }
-        arguments.reverse();
+        Self::call(argument_count, context)
+    }
+}
 
-        let func = context.vm.pop();
-        let this = context.vm.pop();
-
-        let object = match func {
-            JsValue::Object(ref object) if object.is_callable() => object.clone(),
-            _ => {
-                return Err(JsNativeError::typ()
-                    .with_message("not a callable function")
-                    .into());
-            }
-        };
+impl Call {
+    /// Pops the arguments, the function and the `this` value from the stack, and calls the function.
+    fn call(argument_count: u32, context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let (this, object, arguments) = pop_call_operands(argument_count, context)?;
 
         let result = object.__call__(&this, &arguments, context)?;
 
@@ -174,6 +155,63 @@ impl Operation for Call {
     }
 }
 
+/// Pops the arguments, the function and the `this` value of a call from the stack.
+fn pop_call_operands(
+    argument_count: u32,
+    context: &mut Context<'_>,
+) -> JsResult<(JsValue, JsObject, Vec<JsValue>)> {
+    let mut arguments = Vec::with_capacity(argument_count as usize);
+    for _ in 0..argument_count {
+        arguments.push(context.vm.pop());
+    }
+    arguments.reverse();
+
+    let func = context.vm.pop();
+    let this = context.vm.pop();
+
+    match func {
+        JsValue::Object(object) if object.is_callable() => Ok((this, object, arguments)),
+        _ => Err(JsNativeError::typ()
+            .with_message("not a callable function")
+            .into()),
+    }
+}
+
+/// `TailCall` implements the Opcode Operation for `Opcode::TailCall`
+///
+/// Operation:
+///  - Call a function in tail position, reusing the current call if it calls its own function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TailCall;
+
+impl Operation for TailCall {
+    const NAME: &'static str = "TailCall";
+    const INSTRUCTION: &'static str = "INST - TailCall";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        context.vm.check_stack_limits()?;
+        let argument_count = context.vm.read::<u32>();
+
+        let func_index = context.vm.stack.len() - argument_count as usize - 1;
+        let self_call = context.vm.frame().tail_call_allowed
+            && matches!(
+                (&context.vm.active_function, &context.vm.stack[func_index]),
+                (Some(active), JsValue::Object(object)) if JsObject::equals(active, object)
+            );
+
+        if !self_call {
+            return Call::call(argument_count, context);
+        }
+
+        let (this, _, arguments) = pop_call_operands(argument_count, context)?;
+
+        // The caller of the frame runs the function again, after returning from it.
+        context.vm.frame_mut().tail_call = Some((this, arguments));
+        context.vm.push(JsValue::undefined());
+        Ok(CompletionType::Return)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct CallSpread;
 
@@ -182,11 +220,7 @@ impl Operation for CallSpread {
     const INSTRUCTION: &'static str = "INST - CallSpread";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
 
         // Get the arguments that are stored as an array object on the stack.
         let arguments_array = context.vm.pop();
@@ -218,3 +252,58 @@ impl Operation for CallSpread {
         Ok(CompletionType::Normal)
     }
 }
+
+/// `ImportCall` implements the Opcode Operation for `Opcode::ImportCall`
+///
+/// Operation:
+///  - Dynamically import a module.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ImportCall;
+
+impl ImportCall {
+    /// Loads the module graph of a dynamic import.
+    ///
+    /// Errors are not thrown by the `import()` call itself; they reject the returned promise.
+    fn load(specifier: &JsValue, options: &JsValue, context: &mut Context<'_>) -> JsResult<()> {
+        let specifier = specifier.to_string(context)?;
+
+        if !options.is_undefined() && !options.is_object() {
+            return Err(JsNativeError::typ()
+                .with_message("the options of a dynamic import must be an object")
+                .into());
+        }
+
+        ModuleGraph::build(&specifier.to_std_string_escaped(), context)?;
+
+        // TODO: Resolve the promise with the namespace object after implementing module evaluation.
+        Err(JsNativeError::typ()
+            .with_message("evaluating dynamically imported modules is unimplemented")
+            .into())
+    }
+}
+
+impl Operation for ImportCall {
+    const NAME: &'static str = "ImportCall";
+    const INSTRUCTION: &'static str = "INST - ImportCall";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let options = context.vm.pop();
+        let specifier = context.vm.pop();
+
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail per spec");
+
+        if let Err(err) = Self::load(&specifier, &options, context) {
+            let err = err.to_opaque(context);
+            capability
+                .reject()
+                .call(&JsValue::undefined(), &[err], context)?;
+        }
+
+        context.vm.push(capability.promise().clone());
+        Ok(CompletionType::Normal)
+    }
+}
@@ -17,12 +17,13 @@ impl Operation for CatchStart {
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
         let start = context.vm.frame().pc as u32 - 1;
         let finally = context.vm.read::<u32>();
+        let stack_height = context.vm.stack.len();
 
-        context
-            .vm
-            .frame_mut()
-            .env_stack
-            .push(EnvStackEntry::new(start, finally - 1).with_catch_flag());
+        context.vm.frame_mut().env_stack.push(
+            EnvStackEntry::new(start, finally - 1)
+                .with_catch_flag()
+                .with_stack_height(stack_height),
+        );
 
         context.vm.frame_mut().abrupt_completion = None;
         Ok(CompletionType::Normal)
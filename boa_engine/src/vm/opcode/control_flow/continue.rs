@@ -45,9 +45,13 @@ impl Operation for Continue {
         let env_truncation_len = context.vm.environments.len().saturating_sub(envs_to_pop);
         context.vm.environments.truncate(env_truncation_len);
 
-        // 2. Register target address in AbruptCompletionRecord.
-        let new_record = AbruptCompletionRecord::new_continue().with_initial_target(target_address);
-        context.vm.frame_mut().abrupt_completion = Some(new_record);
+        // 2. Register target address in AbruptCompletionRecord, if the continue runs finally blocks
+        //    before reaching it.
+        if jump_address != target_address {
+            let new_record =
+                AbruptCompletionRecord::new_continue().with_initial_target(target_address);
+            context.vm.frame_mut().abrupt_completion = Some(new_record);
+        }
 
         // 3. Set program counter and finally return fields.
         context.vm.frame_mut().pc = jump_address as usize;
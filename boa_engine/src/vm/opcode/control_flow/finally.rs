@@ -45,9 +45,9 @@ impl Operation for FinallyEnd {
             env.is_finally_env() && context.vm.frame().pc < (env.start_address() as usize)
         });
 
-        let next_finally = match finally_candidates.last() {
-            Some(env) => env.start_address(),
-            _ => u32::MAX,
+        let (next_finally, next_finally_stack_height) = match finally_candidates.last() {
+            Some(env) => (env.start_address(), env.stack_height()),
+            _ => (u32::MAX, context.vm.stack.len()),
         };
 
         let mut envs_to_pop = 0;
@@ -66,6 +66,7 @@ impl Operation for FinallyEnd {
 
                 let env_truncation_len = context.vm.environments.len().saturating_sub(envs_to_pop);
                 context.vm.environments.truncate(env_truncation_len);
+                context.vm.stack.truncate(next_finally_stack_height);
             }
             Some(record)
                 if record.is_break() && context.vm.frame().pc < record.target() as usize =>
@@ -74,6 +75,8 @@ impl Operation for FinallyEnd {
                 context.vm.frame_mut().pc = record.target() as usize;
                 while let Some(env_entry) = context.vm.frame().env_stack.last() {
                     if record.target() == env_entry.exit_address() {
+                        let stack_height = env_entry.stack_height();
+                        context.vm.stack.truncate(stack_height);
                         break;
                     }
 
@@ -82,6 +85,7 @@ impl Operation for FinallyEnd {
                 }
 
                 context.vm.frame_mut().abrupt_completion = None;
+                context.vm.frame_mut().abrupt_completion_value = None;
 
                 let env_truncation_len = context.vm.environments.len().saturating_sub(envs_to_pop);
                 context.vm.environments.truncate(env_truncation_len);
@@ -93,6 +97,8 @@ impl Operation for FinallyEnd {
                 context.vm.frame_mut().pc = record.target() as usize;
                 while let Some(env_entry) = context.vm.frame().env_stack.last() {
                     if env_entry.start_address() == record.target() {
+                        let stack_height = env_entry.stack_height();
+                        context.vm.stack.truncate(stack_height);
                         break;
                     }
                     envs_to_pop += env_entry.env_num();
@@ -100,10 +106,18 @@ impl Operation for FinallyEnd {
                 }
 
                 context.vm.frame_mut().abrupt_completion = None;
+                context.vm.frame_mut().abrupt_completion_value = None;
                 let env_truncation_len = context.vm.environments.len().saturating_sub(envs_to_pop);
                 context.vm.environments.truncate(env_truncation_len);
             }
             Some(record) if record.is_return() => {
+                let value = context
+                    .vm
+                    .frame_mut()
+                    .abrupt_completion_value
+                    .take()
+                    .unwrap_or_default();
+                context.vm.push(value);
                 return Ok(CompletionType::Return);
             }
             Some(record)
@@ -114,12 +128,22 @@ impl Operation for FinallyEnd {
                 while let Some(env_entry) = context.vm.frame_mut().env_stack.pop() {
                     envs_to_pop += env_entry.env_num();
                     if env_entry.start_address() == record.target() {
+                        context.vm.stack.truncate(env_entry.stack_height());
                         break;
                     }
                 }
                 context.vm.frame_mut().abrupt_completion = None;
                 let env_truncation_len = context.vm.environments.len().saturating_sub(envs_to_pop);
                 context.vm.environments.truncate(env_truncation_len);
+
+                // The catch block is entered with the thrown value on top of the stack.
+                let err = context
+                    .vm
+                    .frame_mut()
+                    .abrupt_completion_value
+                    .take()
+                    .unwrap_or_default();
+                context.vm.push(err);
             }
             Some(record) if !record.is_throw_with_target() => {
                 let current_stack = context
@@ -136,8 +160,13 @@ impl Operation for FinallyEnd {
                     .saturating_sub(current_stack.env_num());
                 context.vm.environments.truncate(env_truncation_len);
 
-                let err = JsError::from_opaque(context.vm.pop());
-                context.vm.err = Some(err);
+                let err = context
+                    .vm
+                    .frame_mut()
+                    .abrupt_completion_value
+                    .take()
+                    .unwrap_or_default();
+                context.vm.err = Some(JsError::from_opaque(err));
                 return Ok(CompletionType::Throw);
             }
             _ => {
@@ -17,11 +17,12 @@ impl Operation for LabelledStart {
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
         let start = context.vm.frame().pc as u32 - 1;
         let end = context.vm.read::<u32>();
-        context
-            .vm
-            .frame_mut()
-            .env_stack
-            .push(EnvStackEntry::new(start, end).with_labelled_flag());
+        let stack_height = context.vm.stack.len();
+        context.vm.frame_mut().env_stack.push(
+            EnvStackEntry::new(start, end)
+                .with_labelled_flag()
+                .with_stack_height(stack_height),
+        );
         Ok(CompletionType::Normal)
     }
 }
@@ -20,11 +20,12 @@ impl Operation for Return {
         let mut finally_address = None;
         while let Some(env_entry) = context.vm.frame().env_stack.last() {
             if env_entry.is_finally_env() {
-                if (env_entry.start_address() as usize) < current_address {
-                    finally_address = Some(env_entry.exit_address() as usize);
+                let address = if (env_entry.start_address() as usize) < current_address {
+                    env_entry.exit_address() as usize
                 } else {
-                    finally_address = Some(env_entry.start_address() as usize);
-                }
+                    env_entry.start_address() as usize
+                };
+                finally_address = Some((address, env_entry.stack_height()));
                 break;
             }
 
@@ -42,7 +43,11 @@ impl Operation for Return {
         let record = AbruptCompletionRecord::new_return();
         context.vm.frame_mut().abrupt_completion = Some(record);
 
-        if let Some(finally) = finally_address {
+        if let Some((finally, stack_height)) = finally_address {
+            // The returned value is carried in the frame while the finally block runs.
+            let value = context.vm.pop();
+            context.vm.stack.truncate(stack_height);
+            context.vm.frame_mut().abrupt_completion_value = Some(value);
             context.vm.frame_mut().pc = finally;
             return Ok(CompletionType::Normal);
         }
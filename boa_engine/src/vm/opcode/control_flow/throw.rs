@@ -50,6 +50,7 @@ impl Operation for Throw {
 
             let mut env_to_pop = 0;
             let mut target_address = u32::MAX;
+            let mut stack_height = context.vm.stack.len();
             while context.vm.frame().env_stack.len() > 1 {
                 let env_entry = context
                     .vm
@@ -60,6 +61,7 @@ impl Operation for Throw {
 
                 if env_entry.is_try_env() && env_entry.start_address() < env_entry.exit_address() {
                     target_address = env_entry.start_address();
+                    stack_height = env_entry.stack_height();
                     env_to_pop += env_entry.env_num();
                     context.vm.frame_mut().env_stack.pop();
                     break;
@@ -69,6 +71,7 @@ impl Operation for Throw {
                     } else {
                         target_address = env_entry.start_address();
                     }
+                    stack_height = env_entry.stack_height();
                     break;
                 }
                 env_to_pop += env_entry.env_num();
@@ -84,15 +87,17 @@ impl Operation for Throw {
                 context.vm.frame_mut().pc = target_address as usize;
             };
 
-            for _ in 0..context.vm.frame().pop_on_return {
-                context.vm.pop();
-            }
-
-            context.vm.frame_mut().pop_on_return = 0;
+            // The catch block is entered with the thrown value on top of the stack of the try
+            // statement, while finally blocks carry the value in the frame.
+            context.vm.stack.truncate(stack_height);
             let record = AbruptCompletionRecord::new_throw().with_initial_target(catch_target);
             context.vm.frame_mut().abrupt_completion = Some(record);
             let err = error.to_opaque(context);
-            context.vm.push(err);
+            if target_address == catch_target {
+                context.vm.push(err);
+            } else {
+                context.vm.frame_mut().abrupt_completion_value = Some(err);
+            }
             return Ok(CompletionType::Normal);
         }
 
@@ -102,9 +107,11 @@ impl Operation for Throw {
         for env_entry in context.vm.frame_mut().env_stack.iter_mut().rev() {
             if env_entry.is_finally_env() {
                 if (env_entry.start_address() as usize) < current_address {
-                    target_address = Some(env_entry.exit_address() as usize);
+                    target_address =
+                        Some((env_entry.exit_address() as usize, env_entry.stack_height()));
                 } else {
-                    target_address = Some(env_entry.start_address() as usize);
+                    target_address =
+                        Some((env_entry.start_address() as usize, env_entry.stack_height()));
                 }
                 break;
             };
@@ -121,25 +128,18 @@ impl Operation for Throw {
         let record = AbruptCompletionRecord::new_throw();
         context.vm.frame_mut().abrupt_completion = Some(record);
 
-        if let Some(address) = target_address {
+        if let Some((address, stack_height)) = target_address {
             for _ in 0..env_stack_to_pop {
                 context.vm.frame_mut().env_stack.pop();
             }
 
             let env_truncation_len = context.vm.environments.len().saturating_sub(env_to_pop);
             context.vm.environments.truncate(env_truncation_len);
-
-            let previous_stack_size = context
-                .vm
-                .stack
-                .len()
-                .saturating_sub(context.vm.frame().pop_on_return);
-            context.vm.stack.truncate(previous_stack_size);
-            context.vm.frame_mut().pop_on_return = 0;
+            context.vm.stack.truncate(stack_height);
 
             context.vm.frame_mut().pc = address;
             let err = error.to_opaque(context);
-            context.vm.push(err);
+            context.vm.frame_mut().abrupt_completion_value = Some(err);
             return Ok(CompletionType::Normal);
         }
 
@@ -17,21 +17,23 @@ impl Operation for TryStart {
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
         let catch = context.vm.read::<u32>();
         let finally = context.vm.read::<u32>();
+        let stack_height = context.vm.stack.len();
 
         // If a finally exists, push the env to the stack before the try.
         if finally != u32::MAX {
             context.vm.frame_mut().env_stack.push(
                 EnvStackEntry::default()
                     .with_finally_flag()
-                    .with_start_address(finally),
+                    .with_start_address(finally)
+                    .with_stack_height(stack_height),
             );
         }
 
-        context
-            .vm
-            .frame_mut()
-            .env_stack
-            .push(EnvStackEntry::new(catch, finally).with_try_flag());
+        context.vm.frame_mut().env_stack.push(
+            EnvStackEntry::new(catch, finally)
+                .with_try_flag()
+                .with_stack_height(stack_height),
+        );
 
         Ok(CompletionType::Normal)
     }
@@ -41,7 +41,12 @@ impl Operation for GeneratorNext {
                     .filter(|entry| entry.is_finally_env());
                 if let Some(next_finally) = finally_entries.rev().next() {
                     if context.vm.frame().pc < next_finally.start_address() as usize {
-                        context.vm.frame_mut().pc = next_finally.start_address() as usize;
+                        let finally_address = next_finally.start_address() as usize;
+                        let stack_height = next_finally.stack_height();
+                        let value = context.vm.pop();
+                        context.vm.stack.truncate(stack_height);
+                        context.vm.frame_mut().abrupt_completion_value = Some(value);
+                        context.vm.frame_mut().pc = finally_address;
                         let return_record = AbruptCompletionRecord::new_return();
                         context.vm.frame_mut().abrupt_completion = Some(return_record);
                         return Ok(CompletionType::Normal);
@@ -1,7 +1,7 @@
 use crate::{
     error::JsNativeError,
     vm::{opcode::Operation, CompletionType},
-    Context, JsResult,
+    Context, JsResult, JsValue,
 };
 
 /// `GetName` implements the Opcode Operation for `Opcode::GetName`
@@ -37,6 +37,7 @@ impl Operation for GetName {
 ///
 /// Operation:
 ///  - Find a binding on the environment chain and push its value. If the binding does not exist push undefined.
+///    Bindings that exist but are not initialized yet (in their temporal dead zone) still throw.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct GetNameOrUndefined;
 
@@ -51,7 +52,20 @@ impl Operation for GetNameOrUndefined {
 
         context.find_runtime_binding(&mut binding_locator)?;
 
-        let value = context.get_binding(binding_locator)?.unwrap_or_default();
+        let value = if let Some(value) = context.get_binding(binding_locator)? {
+            value
+        } else if binding_locator.is_global() {
+            JsValue::undefined()
+        } else {
+            // Declarative bindings can only be missing a value if they are not initialized yet.
+            let name = context
+                .interner()
+                .resolve_expect(binding_locator.name().sym())
+                .to_string();
+            return Err(JsNativeError::reference()
+                .with_message(format!("{name} is not initialized"))
+                .into());
+        };
 
         context.vm.push(value);
         Ok(CompletionType::Normal)
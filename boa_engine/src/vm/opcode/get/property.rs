@@ -1,7 +1,7 @@
 use crate::{
     js_string,
     property::PropertyKey,
-    vm::{opcode::Operation, CompletionType},
+    vm::{opcode::Operation, CompletionType, DeoptReason},
     Context, JsResult, JsValue,
 };
 
@@ -17,6 +17,7 @@ impl Operation for GetPropertyByName {
     const INSTRUCTION: &'static str = "INST - GetPropertyByName";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let pc = context.vm.frame().pc as u32 - 1;
         let index = context.vm.read::<u32>();
 
         let value = context.vm.pop();
@@ -26,10 +27,27 @@ impl Operation for GetPropertyByName {
             value.to_object(context)?
         };
 
-        let name = context.vm.frame().code_block.names[index as usize];
+        // Fast path:
+        if cfg!(feature = "inline-caches") {
+            let cache = &context.vm.frame().code_block.inline_caches[index as usize];
+            if let Some(result) = cache.get(&object) {
+                context.vm.push(result);
+                return Ok(CompletionType::Normal);
+            }
+            if context.deopt_hook.is_some() && cache.is_cached() {
+                context.report_deopt(pc, DeoptReason::InlineCacheMiss);
+            }
+        }
+
+        // Slow path:
+        let name = context.vm.frame().code_block.inline_caches[index as usize].name;
         let key: PropertyKey = context.interner().resolve_expect(name.sym()).utf16().into();
         let result = object.__get__(&key, value, context)?;
 
+        if cfg!(feature = "inline-caches") {
+            context.vm.frame().code_block.inline_caches[index as usize].update_get(&object, &key);
+        }
+
         context.vm.push(result);
         Ok(CompletionType::Normal)
     }
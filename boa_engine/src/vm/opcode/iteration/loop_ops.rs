@@ -17,12 +17,13 @@ impl Operation for LoopStart {
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
         let start = context.vm.read::<u32>();
         let exit = context.vm.read::<u32>();
+        let stack_height = context.vm.stack.len();
 
-        context
-            .vm
-            .frame_mut()
-            .env_stack
-            .push(EnvStackEntry::new(start, exit).with_loop_flag());
+        context.vm.frame_mut().env_stack.push(
+            EnvStackEntry::new(start, exit)
+                .with_loop_flag()
+                .with_stack_height(stack_height),
+        );
         Ok(CompletionType::Normal)
     }
 }
@@ -61,11 +62,12 @@ impl Operation for LoopContinue {
         }
 
         // 2. Push a new clean EnvStack.
-        context
-            .vm
-            .frame_mut()
-            .env_stack
-            .push(EnvStackEntry::new(start, exit).with_loop_flag());
+        let stack_height = context.vm.stack.len();
+        context.vm.frame_mut().env_stack.push(
+            EnvStackEntry::new(start, exit)
+                .with_loop_flag()
+                .with_stack_height(stack_height),
+        );
 
         Ok(CompletionType::Normal)
     }
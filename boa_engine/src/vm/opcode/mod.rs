@@ -123,6 +123,10 @@ macro_rules! generate_impl {
                 $($Variant::NAME),*
             ];
 
+            /// The number of opcodes in the instruction set.
+            #[cfg(any(feature = "trace", feature = "instcount"))]
+            pub(crate) const COUNT: usize = Self::NAMES.len();
+
             /// Name of this opcode.
             #[must_use]
             pub const fn as_str(self) -> &'static str {
@@ -164,7 +168,7 @@ pub(crate) trait Operation {
 }
 
 generate_impl! {
-    #[derive(Debug, Clone, Copy, TryFromPrimitive)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TryFromPrimitive)]
     #[repr(u8)]
     pub enum Opcode {
         /// Pop the top value from the stack.
@@ -174,12 +178,12 @@ generate_impl! {
         /// Stack: value **=>**
         Pop = 0,
 
-        /// Pop the top value from the stack if the last try block has thrown a value.
+        /// Pop the top value from the stack and set it as the completion value of the frame.
         ///
         /// Operands:
         ///
         /// Stack: value **=>**
-        PopIfThrown,
+        SetCompletionValue,
 
         /// Push a copy of the top value on the stack.
         ///
@@ -316,6 +320,15 @@ generate_impl! {
         /// Stack: **=>** (`literals[index]`)
         PushLiteral,
 
+        /// Push a new `RegExp` object created from a regular expression literal on the stack.
+        ///
+        /// The index operand is used to index into the `regexps` array to get the literal.
+        ///
+        /// Operands: index: `u32`
+        ///
+        /// Stack: **=>** regexp
+        PushRegExp,
+
         /// Push empty object `{}` value on the stack.
         ///
         /// Operands:
@@ -1313,6 +1326,16 @@ generate_impl! {
         /// Stack: this, func, arguments_array **=>** result
         CallSpread,
 
+        /// Call a function in tail position of the current function.
+        ///
+        /// If the called function is the function of the current frame, it's run again in place
+        /// of the current frame, which returns immediately. Otherwise, it's a regular call.
+        ///
+        /// Operands: argument_count: `u32`
+        ///
+        /// Stack: this, func, argument_1, ... argument_n **=>** result
+        TailCall,
+
         /// Call construct on a function.
         ///
         /// Operands: argument_count: `u32`
@@ -1327,6 +1350,15 @@ generate_impl! {
         /// Stack: arguments_array, func **=>** result
         NewSpread,
 
+        /// Dynamically import a module.
+        ///
+        /// Failing to load the module rejects the returned promise instead of throwing.
+        ///
+        /// Operands:
+        ///
+        /// Stack: specifier, options **=>** promise
+        ImportCall,
+
         /// Return from a function.
         ///
         /// Operands:
@@ -1505,20 +1537,6 @@ generate_impl! {
         /// Stack: `argument_1` .. `argument_n` **=>**
         RestParameterPop,
 
-        /// Add one to the pop on return count.
-        ///
-        /// Operands:
-        ///
-        /// Stack: **=>**
-        PopOnReturnAdd,
-
-        /// Subtract one from the pop on return count.
-        ///
-        /// Operands:
-        ///
-        /// Stack: **=>**
-        PopOnReturnSub,
-
         /// Yield from the current execution.
         ///
         /// Operands:
@@ -1620,3 +1638,235 @@ pub(crate) enum BindingOpcode {
     InitConst,
     SetName,
 }
+
+/// Coarse groups of opcodes, used to emit profiler spans that aggregate
+/// related instructions in flamegraphs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum OpcodeCategory {
+    Arithmetic,
+    PropertyAccess,
+    Call,
+    Environment,
+    ControlFlow,
+    Iteration,
+    Generator,
+    Stack,
+}
+
+impl OpcodeCategory {
+    /// Name of the profiler event for this category.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Arithmetic => "Arithmetic",
+            Self::PropertyAccess => "Property access",
+            Self::Call => "Call",
+            Self::Environment => "Environment",
+            Self::ControlFlow => "Control flow",
+            Self::Iteration => "Iteration",
+            Self::Generator => "Generator",
+            Self::Stack => "Stack",
+        }
+    }
+}
+
+impl Opcode {
+    /// The category this opcode belongs to.
+    pub(crate) const fn category(self) -> OpcodeCategory {
+        match self {
+            Self::Add
+            | Self::Sub
+            | Self::Div
+            | Self::Mul
+            | Self::Mod
+            | Self::Pow
+            | Self::ShiftRight
+            | Self::ShiftLeft
+            | Self::UnsignedShiftRight
+            | Self::BitOr
+            | Self::BitAnd
+            | Self::BitXor
+            | Self::BitNot
+            | Self::In
+            | Self::InPrivate
+            | Self::Eq
+            | Self::StrictEq
+            | Self::NotEq
+            | Self::StrictNotEq
+            | Self::GreaterThan
+            | Self::GreaterThanOrEq
+            | Self::LessThan
+            | Self::LessThanOrEq
+            | Self::InstanceOf
+            | Self::TypeOf
+            | Self::Void
+            | Self::LogicalNot
+            | Self::Pos
+            | Self::Neg
+            | Self::Inc
+            | Self::IncPost
+            | Self::Dec
+            | Self::DecPost
+            | Self::ConcatToString
+            | Self::ToBoolean
+            | Self::ToPropertyKey => OpcodeCategory::Arithmetic,
+            Self::GetPropertyByName
+            | Self::GetMethod
+            | Self::GetPropertyByValue
+            | Self::GetPropertyByValuePush
+            | Self::SetPropertyByName
+            | Self::SetFunctionName
+            | Self::DefineOwnPropertyByName
+            | Self::DefineClassStaticMethodByName
+            | Self::DefineClassMethodByName
+            | Self::SetPropertyByValue
+            | Self::DefineOwnPropertyByValue
+            | Self::DefineClassStaticMethodByValue
+            | Self::DefineClassMethodByValue
+            | Self::SetPropertyGetterByName
+            | Self::DefineClassStaticGetterByName
+            | Self::DefineClassGetterByName
+            | Self::SetPropertyGetterByValue
+            | Self::DefineClassStaticGetterByValue
+            | Self::DefineClassGetterByValue
+            | Self::SetPropertySetterByName
+            | Self::DefineClassStaticSetterByName
+            | Self::DefineClassSetterByName
+            | Self::SetPropertySetterByValue
+            | Self::DefineClassStaticSetterByValue
+            | Self::DefineClassSetterByValue
+            | Self::SetPrivateField
+            | Self::DefinePrivateField
+            | Self::SetPrivateMethod
+            | Self::SetPrivateSetter
+            | Self::SetPrivateGetter
+            | Self::GetPrivateField
+            | Self::PushClassField
+            | Self::PushClassFieldPrivate
+            | Self::PushClassPrivateGetter
+            | Self::PushClassPrivateSetter
+            | Self::PushClassPrivateMethod
+            | Self::DeletePropertyByName
+            | Self::DeletePropertyByValue
+            | Self::DeleteSuperThrow
+            | Self::CopyDataProperties
+            | Self::SetClassPrototype
+            | Self::SetHomeObject
+            | Self::SetPrototype => OpcodeCategory::PropertyAccess,
+            Self::SuperCallPrepare
+            | Self::SuperCall
+            | Self::SuperCallSpread
+            | Self::SuperCallDerived
+            | Self::CallEval
+            | Self::CallEvalSpread
+            | Self::Call
+            | Self::CallSpread
+            | Self::TailCall
+            | Self::New
+            | Self::NewSpread
+            | Self::ImportCall
+            | Self::Return
+            | Self::GetArrowFunction
+            | Self::GetAsyncArrowFunction
+            | Self::GetFunction
+            | Self::GetFunctionAsync
+            | Self::GetGenerator
+            | Self::GetGeneratorAsync
+            | Self::RestParameterInit
+            | Self::RestParameterPop => OpcodeCategory::Call,
+            Self::DefInitArg
+            | Self::DefVar
+            | Self::DefInitVar
+            | Self::DefLet
+            | Self::DefInitLet
+            | Self::DefInitConst
+            | Self::GetName
+            | Self::GetNameOrUndefined
+            | Self::SetName
+            | Self::DeleteName
+            | Self::This
+            | Self::Super
+            | Self::PushNewTarget
+            | Self::PushDeclarativeEnvironment
+            | Self::PushObjectEnvironment
+            | Self::PushFunctionEnvironment
+            | Self::PopEnvironment => OpcodeCategory::Environment,
+            Self::LogicalAnd
+            | Self::LogicalOr
+            | Self::Coalesce
+            | Self::Jump
+            | Self::JumpIfTrue
+            | Self::JumpIfFalse
+            | Self::JumpIfNotUndefined
+            | Self::JumpIfNullOrUndefined
+            | Self::Throw
+            | Self::ThrowNewTypeError
+            | Self::TryStart
+            | Self::TryEnd
+            | Self::CatchStart
+            | Self::CatchEnd
+            | Self::CatchEnd2
+            | Self::FinallyStart
+            | Self::FinallyEnd
+            | Self::Break
+            | Self::Continue
+            | Self::Case
+            | Self::Default
+            | Self::LoopStart
+            | Self::LoopContinue
+            | Self::LoopEnd
+            | Self::LabelledStart
+            | Self::LabelledEnd => OpcodeCategory::ControlFlow,
+            Self::CreateForInIterator
+            | Self::GetIterator
+            | Self::GetAsyncIterator
+            | Self::IteratorNext
+            | Self::IteratorNextSetDone
+            | Self::IteratorUnwrapNext
+            | Self::IteratorUnwrapValue
+            | Self::IteratorUnwrapNextOrJump
+            | Self::IteratorToArray
+            | Self::IteratorClosePush
+            | Self::IteratorClosePop
+            | Self::PushIteratorToArray => OpcodeCategory::Iteration,
+            Self::Yield
+            | Self::GeneratorNext
+            | Self::GeneratorResumeReturn
+            | Self::AsyncGeneratorNext
+            | Self::GeneratorAsyncResumeYield
+            | Self::GeneratorNextDelegate
+            | Self::GeneratorAsyncDelegateNext
+            | Self::GeneratorAsyncDelegateResume
+            | Self::Await => OpcodeCategory::Generator,
+            Self::Pop
+            | Self::SetCompletionValue
+            | Self::Dup
+            | Self::Swap
+            | Self::RotateLeft
+            | Self::RotateRight
+            | Self::PushZero
+            | Self::PushOne
+            | Self::PushInt8
+            | Self::PushInt16
+            | Self::PushInt32
+            | Self::PushRational
+            | Self::PushNaN
+            | Self::PushPositiveInfinity
+            | Self::PushNegativeInfinity
+            | Self::PushNull
+            | Self::PushTrue
+            | Self::PushFalse
+            | Self::PushUndefined
+            | Self::PushLiteral
+            | Self::PushRegExp
+            | Self::PushEmptyObject
+            | Self::PushClassPrototype
+            | Self::PushNewArray
+            | Self::PushValueToArray
+            | Self::PushElisionToArray
+            | Self::RequireObjectCoercible
+            | Self::ValueNotNullOrUndefined
+            | Self::IsObject
+            | Self::Nop => OpcodeCategory::Stack,
+        }
+    }
+}
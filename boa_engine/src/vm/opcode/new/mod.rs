@@ -16,11 +16,7 @@ impl Operation for New {
     const INSTRUCTION: &'static str = "INST - New";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
         let argument_count = context.vm.read::<u32>();
         let mut arguments = Vec::with_capacity(argument_count as usize);
         for _ in 0..argument_count {
@@ -55,11 +51,7 @@ impl Operation for NewSpread {
     const INSTRUCTION: &'static str = "INST - NewSpread";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        if context.vm.stack_size_limit <= context.vm.stack.len() {
-            return Err(JsNativeError::range()
-                .with_message("Maximum call stack size exceeded")
-                .into());
-        }
+        context.vm.check_stack_limits()?;
         // Get the arguments that are stored as an array object on the stack.
         let arguments_array = context.vm.pop();
         let arguments_array_object = arguments_array
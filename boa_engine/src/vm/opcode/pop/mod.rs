@@ -20,25 +20,20 @@ impl Operation for Pop {
     }
 }
 
-/// `PopIfThrown` implements the Opcode Operation for `Opcode::PopIfThrown`
+/// `SetCompletionValue` implements the Opcode Operation for `Opcode::SetCompletionValue`
 ///
 /// Operation:
-///  - Pop the top value from the stack if the last try block has thrown a value.
+///  - Pop the top value from the stack and set it as the completion value of the frame.
 #[derive(Debug, Clone, Copy)]
-pub(crate) struct PopIfThrown;
+pub(crate) struct SetCompletionValue;
 
-impl Operation for PopIfThrown {
-    const NAME: &'static str = "PopIfThrown";
-    const INSTRUCTION: &'static str = "INST - PopIfThrown";
+impl Operation for SetCompletionValue {
+    const NAME: &'static str = "SetCompletionValue";
+    const INSTRUCTION: &'static str = "INST - SetCompletionValue";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        let frame = context.vm.frame();
-        match frame.abrupt_completion {
-            Some(record) if record.is_throw() => {
-                context.vm.pop();
-            }
-            _ => {}
-        };
+        let value = context.vm.pop();
+        context.vm.frame_mut().completion_value = value;
         Ok(CompletionType::Normal)
     }
 }
@@ -60,37 +55,3 @@ impl Operation for PopEnvironment {
         Ok(CompletionType::Normal)
     }
 }
-
-/// `PopReturnAdd` implements the Opcode Operation for `Opcode::PopReturnAdd`
-///
-/// Operation:
-///  - Add one to the pop on return count.
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct PopOnReturnAdd;
-
-impl Operation for PopOnReturnAdd {
-    const NAME: &'static str = "PopOnReturnAdd";
-    const INSTRUCTION: &'static str = "INST - PopOnReturnAdd";
-
-    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        context.vm.frame_mut().pop_on_return += 1;
-        Ok(CompletionType::Normal)
-    }
-}
-
-/// `PopOnReturnSub` implements the Opcode Operation for `Opcode::PopOnReturnSub`
-///
-/// Operation:
-///  - Subtract one from the pop on return count.
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct PopOnReturnSub;
-
-impl Operation for PopOnReturnSub {
-    const NAME: &'static str = "PopOnReturnSub";
-    const INSTRUCTION: &'static str = "INST - PopOnReturnSub";
-
-    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
-        context.vm.frame_mut().pop_on_return -= 1;
-        Ok(CompletionType::Normal)
-    }
-}
@@ -15,6 +15,7 @@ impl Operation for PushDeclarativeEnvironment {
     const INSTRUCTION: &'static str = "INST - PushDeclarativeEnvironment";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        context.vm.check_stack_limits()?;
         let num_bindings = context.vm.read::<u32>();
         let compile_environments_index = context.vm.read::<u32>();
         let compile_environment = context.vm.frame().code_block.compile_environments
@@ -41,6 +42,7 @@ impl Operation for PushFunctionEnvironment {
     const INSTRUCTION: &'static str = "INST - PushFunctionEnvironment";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        context.vm.check_stack_limits()?;
         let num_bindings = context.vm.read::<u32>();
         let compile_environments_index = context.vm.read::<u32>();
         let compile_environment = context.vm.frame().code_block.compile_environments
@@ -66,6 +68,7 @@ impl Operation for PushObjectEnvironment {
     const INSTRUCTION: &'static str = "INST - PushObjectEnvironment";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        context.vm.check_stack_limits()?;
         let object = context.vm.pop();
         let object = object.to_object(context)?;
 
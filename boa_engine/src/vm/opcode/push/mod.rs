@@ -10,6 +10,7 @@ pub(crate) mod literal;
 pub(crate) mod new_target;
 pub(crate) mod numbers;
 pub(crate) mod object;
+pub(crate) mod regexp;
 
 pub(crate) use array::*;
 pub(crate) use class::*;
@@ -18,6 +19,7 @@ pub(crate) use literal::*;
 pub(crate) use new_target::*;
 pub(crate) use numbers::*;
 pub(crate) use object::*;
+pub(crate) use regexp::*;
 
 macro_rules! implement_push_generics {
     ($name:ident, $push_value:expr, $doc_string:literal) => {
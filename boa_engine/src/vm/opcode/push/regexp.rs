@@ -0,0 +1,24 @@
+use crate::{
+    vm::{opcode::Operation, CompletionType},
+    Context, JsResult,
+};
+
+/// `PushRegExp` implements the Opcode Operation for `Opcode::PushRegExp`
+///
+/// Operation:
+///  - Push a new `RegExp` object created from a regular expression literal on the stack.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushRegExp;
+
+impl Operation for PushRegExp {
+    const NAME: &'static str = "PushRegExp";
+    const INSTRUCTION: &'static str = "INST - PushRegExp";
+
+    fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let index = context.vm.read::<u32>() as usize;
+        let code_block = context.vm.frame().code_block.clone();
+        let regexp = code_block.regexps[index].create(context)?;
+        context.vm.push(regexp);
+        Ok(CompletionType::Normal)
+    }
+}
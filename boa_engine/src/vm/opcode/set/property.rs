@@ -3,7 +3,7 @@ use boa_macros::utf16;
 use crate::{
     builtins::function::set_function_name,
     property::{PropertyDescriptor, PropertyKey},
-    vm::{opcode::Operation, CompletionType},
+    vm::{opcode::Operation, CompletionType, DeoptReason},
     Context, JsNativeError, JsResult, JsString, JsValue,
 };
 
@@ -19,6 +19,7 @@ impl Operation for SetPropertyByName {
     const INSTRUCTION: &'static str = "INST - SetPropertyByName";
 
     fn execute(context: &mut Context<'_>) -> JsResult<CompletionType> {
+        let pc = context.vm.frame().pc as u32 - 1;
         let index = context.vm.read::<u32>();
 
         let value = context.vm.pop();
@@ -30,7 +31,23 @@ impl Operation for SetPropertyByName {
             object.to_object(context)?
         };
 
-        let name = context.vm.frame().code_block.names[index as usize];
+        // Fast path:
+        let cacheable = cfg!(feature = "inline-caches") && receiver.as_object() == Some(&object);
+        if cacheable
+            && context.vm.frame().code_block.inline_caches[index as usize].set(&object, &value)
+        {
+            context.vm.stack.push(value);
+            return Ok(CompletionType::Normal);
+        }
+        if cacheable
+            && context.deopt_hook.is_some()
+            && context.vm.frame().code_block.inline_caches[index as usize].is_cached()
+        {
+            context.report_deopt(pc, DeoptReason::InlineCacheMiss);
+        }
+
+        // Slow path:
+        let name = context.vm.frame().code_block.inline_caches[index as usize].name;
         let name: PropertyKey = context.interner().resolve_expect(name.sym()).utf16().into();
 
         let succeeded = object.__set__(name.clone(), value.clone(), receiver, context)?;
@@ -39,6 +56,9 @@ impl Operation for SetPropertyByName {
                 .with_message(format!("cannot set non-writable property: {name}"))
                 .into());
         }
+        if cacheable {
+            context.vm.frame().code_block.inline_caches[index as usize].update_set(&object, &name);
+        }
         context.vm.stack.push(value);
         Ok(CompletionType::Normal)
     }
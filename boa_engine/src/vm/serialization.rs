@@ -0,0 +1,735 @@
+//! Binary serialization of [`CodeBlock`]s.
+//!
+//! This allows embedders to cache the bytecode of a script and skip the parsing and compilation
+//! steps when the same script is executed again.
+//!
+//! The format starts with a magic header and a version byte, followed by the table of compile
+//! time environments referenced by the code blocks and finally the code blocks themselves.
+//! All integers, including the operands of the bytecode, are stored in little endian and all
+//! strings are stored as UTF-16 code units.
+//!
+//! The loaded bytecode is validated like the bytecode of the fuzzer, and the global bindings of
+//! the serialized code are only added to the realm once the whole input has been validated.
+
+use crate::{
+    builtins::{function::ThisMode, regexp::RegExpSite},
+    bytecompiler::{swap_operands_byte_order, validate_bytecode, Tables},
+    environments::{BindingLocator, CompileTimeBinding, CompileTimeEnvironment},
+    error::JsNativeError,
+    vm::{CodeBlock, CodeKind, InlineCache},
+    Context, JsBigInt, JsResult, JsString, JsValue,
+};
+use boa_ast::{
+    declaration::{Binding, Variable},
+    expression::{literal::Literal, Identifier},
+    function::{FormalParameter, FormalParameterList},
+    Position,
+};
+use boa_gc::{Gc, GcRefCell};
+use boa_interner::{Interner, Sym};
+
+/// The magic header of a serialized [`CodeBlock`].
+const MAGIC: &[u8; 4] = b"BOAB";
+
+/// The version of the serialization format.
+///
+/// This must be bumped every time the format or the bytecode itself changes in an incompatible way.
+const VERSION: u8 = 11;
+
+/// Marks the absence of an outer environment in the environment table.
+const NO_OUTER: u32 = u32::MAX;
+
+/// Tags of the serialized literal values.
+mod literal_tag {
+    pub(super) const UNDEFINED: u8 = 0;
+    pub(super) const NULL: u8 = 1;
+    pub(super) const FALSE: u8 = 2;
+    pub(super) const TRUE: u8 = 3;
+    pub(super) const INTEGER: u8 = 4;
+    pub(super) const RATIONAL: u8 = 5;
+    pub(super) const STRING: u8 = 6;
+    pub(super) const BIGINT: u8 = 7;
+}
+
+/// Tags of the serialized binding locators.
+mod locator_tag {
+    pub(super) const DECLARATIVE: u8 = 0;
+    pub(super) const GLOBAL: u8 = 1;
+    pub(super) const MUTATE_IMMUTABLE: u8 = 2;
+    pub(super) const SILENT: u8 = 3;
+}
+
+/// Creates the error returned when a code block cannot be serialized.
+fn unsupported(message: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!("cannot serialize code block: {message}"))
+}
+
+/// Creates the error returned when serialized bytecode cannot be loaded.
+fn invalid(message: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!("invalid serialized code block: {message}"))
+}
+
+/// Converts a `usize` into the `u32` used by the serialization format.
+fn to_u32(value: usize) -> JsResult<u32> {
+    u32::try_from(value).map_err(|_| unsupported("size exceeds the format limits").into())
+}
+
+struct Writer<'a> {
+    bytes: Vec<u8>,
+    interner: &'a Interner,
+    environments: Vec<Gc<GcRefCell<CompileTimeEnvironment>>>,
+}
+
+impl Writer<'_> {
+    fn u8(&mut self, value: u8) {
+        self.bytes.push(value);
+    }
+
+    fn bool(&mut self, value: bool) {
+        self.u8(u8::from(value));
+    }
+
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn len(&mut self, value: usize) -> JsResult<()> {
+        self.u32(to_u32(value)?);
+        Ok(())
+    }
+
+    fn utf16(&mut self, value: &[u16]) -> JsResult<()> {
+        self.len(value.len())?;
+        for unit in value {
+            self.bytes.extend_from_slice(&unit.to_le_bytes());
+        }
+        Ok(())
+    }
+
+    fn sym(&mut self, sym: Sym) -> JsResult<()> {
+        let interner = self.interner;
+        self.utf16(interner.resolve_expect(sym).utf16())
+    }
+
+    /// Returns the index of `env` in the environment table, adding it and all its outer
+    /// environments to the table if necessary.
+    fn environment(&mut self, env: &Gc<GcRefCell<CompileTimeEnvironment>>) -> u32 {
+        if let Some(index) = self.environments.iter().position(|e| Gc::ptr_eq(e, env)) {
+            return index as u32;
+        }
+        if let Some(outer) = env.borrow().outer() {
+            self.environment(&outer);
+        }
+        self.environments.push(env.clone());
+        (self.environments.len() - 1) as u32
+    }
+
+    fn locator(&mut self, locator: &BindingLocator) -> JsResult<()> {
+        if locator.is_global() {
+            self.u8(locator_tag::GLOBAL);
+        } else if locator.is_mutate_immutable() {
+            self.u8(locator_tag::MUTATE_IMMUTABLE);
+        } else if locator.is_silent() {
+            self.u8(locator_tag::SILENT);
+        } else {
+            self.u8(locator_tag::DECLARATIVE);
+            self.len(locator.environment_index())?;
+            self.len(locator.binding_index())?;
+        }
+        self.sym(locator.name().sym())
+    }
+
+    fn literal(&mut self, value: &JsValue) -> JsResult<()> {
+        match value {
+            JsValue::Undefined => self.u8(literal_tag::UNDEFINED),
+            JsValue::Null => self.u8(literal_tag::NULL),
+            JsValue::Boolean(false) => self.u8(literal_tag::FALSE),
+            JsValue::Boolean(true) => self.u8(literal_tag::TRUE),
+            JsValue::Integer(integer) => {
+                self.u8(literal_tag::INTEGER);
+                self.bytes.extend_from_slice(&integer.to_le_bytes());
+            }
+            JsValue::Rational(rational) => {
+                self.u8(literal_tag::RATIONAL);
+                self.bytes
+                    .extend_from_slice(&rational.to_bits().to_le_bytes());
+            }
+            JsValue::String(string) => {
+                self.u8(literal_tag::STRING);
+                self.utf16(string)?;
+            }
+            JsValue::BigInt(bigint) => {
+                self.u8(literal_tag::BIGINT);
+                let digits: Vec<u16> = bigint.to_string_radix(10).encode_utf16().collect();
+                self.utf16(&digits)?;
+            }
+            JsValue::Object(_) => {
+                return Err(unsupported("object literals are not supported").into())
+            }
+            JsValue::Symbol(_) => {
+                return Err(unsupported("symbol literals are not supported").into())
+            }
+        }
+        Ok(())
+    }
+
+    fn code_block(&mut self, code: &CodeBlock) -> JsResult<()> {
+        if !code.private_names.is_empty() {
+            return Err(unsupported("private names are not supported").into());
+        }
+        if code.lazy.is_some() {
+            return Err(unsupported("lazily compiled functions are not supported").into());
+        }
+
+        self.sym(code.name)?;
+        self.bool(code.has_binding_identifier);
+        self.u32(code.length);
+        self.bool(code.strict);
+        self.u8(match code.this_mode {
+            ThisMode::Lexical => 0,
+            ThisMode::Strict => 1,
+            ThisMode::Global => 2,
+        });
+
+        // Only the parameter names and whether the list is simple are needed at runtime.
+        self.bool(code.params.is_simple());
+        self.len(code.params.as_ref().len())?;
+        for param in code.params.as_ref() {
+            let name = match param.variable().binding() {
+                Binding::Identifier(ident) => ident.sym(),
+                Binding::Pattern(_) => Sym::EMPTY_STRING,
+            };
+            self.sym(name)?;
+        }
+
+        let mut bytecode = code.bytecode.to_vec();
+        swap_operands_byte_order(&mut bytecode).map_err(|error| unsupported(&error.to_string()))?;
+        self.len(bytecode.len())?;
+        self.bytes.extend_from_slice(&bytecode);
+
+        self.len(code.literals.len())?;
+        for literal in code.literals.iter() {
+            self.literal(literal)?;
+        }
+
+        self.len(code.regexps.len())?;
+        for regexp in code.regexps.iter() {
+            self.utf16(regexp.pattern())?;
+            self.utf16(regexp.flags())?;
+        }
+
+        self.len(code.names.len())?;
+        for name in code.names.iter() {
+            self.sym(name.sym())?;
+        }
+
+        self.len(code.inline_caches.len())?;
+        for cache in code.inline_caches.iter() {
+            self.sym(cache.name.sym())?;
+        }
+
+        self.len(code.bindings.len())?;
+        for locator in code.bindings.iter() {
+            self.locator(locator)?;
+        }
+
+        self.len(code.num_bindings)?;
+
+        self.len(code.functions.len())?;
+        for function in code.functions.iter() {
+            self.code_block(function)?;
+        }
+
+        match &code.arguments_binding {
+            Some(locator) => {
+                self.bool(true);
+                self.locator(locator)?;
+            }
+            None => self.bool(false),
+        }
+
+        self.len(code.compile_environments.len())?;
+        for env in code.compile_environments.iter() {
+            let index = self.environment(env);
+            self.u32(index);
+        }
+
+        self.u8(match code.kind {
+            CodeKind::Script => 0,
+            CodeKind::Function => 1,
+            CodeKind::Async => 2,
+            CodeKind::Generator => 3,
+            CodeKind::AsyncGenerator => 4,
+        });
+        self.bool(code.is_class_constructor);
+        match code.class_field_initializer_name {
+            Some(name) => {
+                self.bool(true);
+                self.sym(name)?;
+            }
+            None => self.bool(false),
+        }
+        self.u32(code.function_environment_push_location);
+        match code.parameters_env_bindings {
+            Some(bindings) => {
+                self.bool(true);
+                self.len(bindings)?;
+            }
+            None => self.bool(false),
+        }
+        match &code.source_text {
+            Some(source_text) => {
+                self.bool(true);
+                self.utf16(source_text)?;
+            }
+            None => self.bool(false),
+        }
+        match &code.source_name {
+            Some(source_name) => {
+                self.bool(true);
+                self.utf16(source_name)?;
+            }
+            None => self.bool(false),
+        }
+        self.len(code.source_positions.len())?;
+        for (pc, position) in code.source_positions.iter() {
+            self.u32(*pc);
+            self.u32(position.line_number());
+            self.u32(position.column_number());
+        }
+
+        Ok(())
+    }
+
+    /// Writes the environment table, whose entries are referenced by index from the code blocks.
+    fn environments(&self) -> JsResult<Vec<u8>> {
+        let mut writer = Writer {
+            bytes: Vec::new(),
+            interner: self.interner,
+            environments: Vec::new(),
+        };
+        writer.len(self.environments.len())?;
+        for env in &self.environments {
+            let env = env.borrow();
+            match env.outer() {
+                Some(outer) => {
+                    let index = self
+                        .environments
+                        .iter()
+                        .position(|e| Gc::ptr_eq(e, &outer))
+                        .expect("outer environments must be added before inner environments");
+                    writer.len(index)?;
+                }
+                None => writer.u32(NO_OUTER),
+            }
+            writer.bool(env.is_function());
+            let bindings = env.bindings();
+            writer.len(bindings.len())?;
+            for (name, binding) in bindings {
+                writer.sym(name.sym())?;
+                writer.len(binding.index)?;
+                writer.bool(binding.mutable);
+                writer.bool(binding.lex);
+                writer.bool(binding.strict);
+                writer.bool(binding.class);
+            }
+        }
+        Ok(writer.bytes)
+    }
+}
+
+struct Reader<'a, 'host> {
+    bytes: &'a [u8],
+    context: &'a mut Context<'host>,
+    environments: Vec<Gc<GcRefCell<CompileTimeEnvironment>>>,
+
+    /// The bindings that are added to the global environment once the input has been validated.
+    global_bindings: Vec<(Identifier, CompileTimeBinding)>,
+}
+
+impl Reader<'_, '_> {
+    fn take(&mut self, len: usize) -> JsResult<&[u8]> {
+        if self.bytes.len() < len {
+            return Err(invalid("unexpected end of input").into());
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn u8(&mut self) -> JsResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn bool(&mut self) -> JsResult<bool> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid("invalid boolean").into()),
+        }
+    }
+
+    fn u32(&mut self) -> JsResult<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn len(&mut self) -> JsResult<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    fn utf16(&mut self) -> JsResult<Vec<u16>> {
+        let len = self.len()?;
+        let bytes = self.take(
+            len.checked_mul(2)
+                .ok_or_else(|| invalid("invalid length"))?,
+        )?;
+        Ok(bytes
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect())
+    }
+
+    fn sym(&mut self) -> JsResult<Sym> {
+        let string = self.utf16()?;
+        Ok(self.context.interner_mut().get_or_intern(&string[..]))
+    }
+
+    fn environment(&mut self) -> JsResult<Gc<GcRefCell<CompileTimeEnvironment>>> {
+        let index = self.len()?;
+        self.environments
+            .get(index)
+            .cloned()
+            .ok_or_else(|| invalid("invalid environment index").into())
+    }
+
+    fn locator(&mut self) -> JsResult<BindingLocator> {
+        let tag = self.u8()?;
+        let indices = if tag == locator_tag::DECLARATIVE {
+            Some((self.len()?, self.len()?))
+        } else {
+            None
+        };
+        let name = Identifier::new(self.sym()?);
+        match (tag, indices) {
+            (locator_tag::DECLARATIVE, Some((environment_index, binding_index))) => Ok(
+                BindingLocator::declarative(name, environment_index, binding_index),
+            ),
+            (locator_tag::GLOBAL, _) => Ok(BindingLocator::global(name)),
+            (locator_tag::MUTATE_IMMUTABLE, _) => Ok(BindingLocator::mutate_immutable(name)),
+            (locator_tag::SILENT, _) => Ok(BindingLocator::silent(name)),
+            _ => Err(invalid("invalid binding locator").into()),
+        }
+    }
+
+    fn literal(&mut self) -> JsResult<JsValue> {
+        match self.u8()? {
+            literal_tag::UNDEFINED => Ok(JsValue::undefined()),
+            literal_tag::NULL => Ok(JsValue::null()),
+            literal_tag::FALSE => Ok(JsValue::new(false)),
+            literal_tag::TRUE => Ok(JsValue::new(true)),
+            literal_tag::INTEGER => {
+                let bytes = self.take(4)?;
+                Ok(JsValue::Integer(i32::from_le_bytes([
+                    bytes[0], bytes[1], bytes[2], bytes[3],
+                ])))
+            }
+            literal_tag::RATIONAL => {
+                let bytes = self.take(8)?;
+                let mut bits = [0; 8];
+                bits.copy_from_slice(bytes);
+                Ok(JsValue::Rational(f64::from_bits(u64::from_le_bytes(bits))))
+            }
+            literal_tag::STRING => Ok(JsValue::String(JsString::from(&self.utf16()?[..]))),
+            literal_tag::BIGINT => {
+                let digits = String::from_utf16(&self.utf16()?)
+                    .map_err(|_| invalid("invalid bigint literal"))?;
+                JsBigInt::from_string(&digits)
+                    .map(JsValue::BigInt)
+                    .ok_or_else(|| invalid("invalid bigint literal").into())
+            }
+            _ => Err(invalid("invalid literal").into()),
+        }
+    }
+
+    /// Reads the environment table.
+    ///
+    /// The outermost environment is the global environment, which is merged into the compile
+    /// time environment of the current realm.
+    fn environments(&mut self) -> JsResult<()> {
+        let count = self.len()?;
+        for _ in 0..count {
+            let outer = self.u32()?;
+            let function_scope = self.bool()?;
+            let env = if outer == NO_OUTER {
+                self.context.realm().environment().compile_env()
+            } else {
+                let outer = self
+                    .environments
+                    .get(outer as usize)
+                    .cloned()
+                    .ok_or_else(|| invalid("invalid environment index"))?;
+                Gc::new(GcRefCell::new(CompileTimeEnvironment::new(
+                    outer,
+                    function_scope,
+                )))
+            };
+
+            let bindings = self.len()?;
+            for _ in 0..bindings {
+                let name = Identifier::new(self.sym()?);
+                let binding = CompileTimeBinding {
+                    index: self.len()?,
+                    mutable: self.bool()?,
+                    lex: self.bool()?,
+                    strict: self.bool()?,
+                    class: self.bool()?,
+                };
+
+                if outer == NO_OUTER {
+                    self.stage_global_binding(&env, name, binding)?;
+                    continue;
+                }
+
+                let mut env = env.borrow_mut();
+                match env.binding(name) {
+                    Some(existing) if existing.index == binding.index => {}
+                    None if binding.index == env.num_bindings() => {
+                        env.insert_binding(name, binding);
+                    }
+                    _ => return Err(invalid("incompatible environment bindings").into()),
+                }
+            }
+
+            self.environments.push(env);
+        }
+        Ok(())
+    }
+
+    /// Checks that a binding of the global environment `env` is compatible with its existing
+    /// bindings, and stages it to be added after the validation.
+    fn stage_global_binding(
+        &mut self,
+        env: &Gc<GcRefCell<CompileTimeEnvironment>>,
+        name: Identifier,
+        binding: CompileTimeBinding,
+    ) -> JsResult<()> {
+        let env = env.borrow();
+        let existing = env.binding(name).or_else(|| {
+            self.global_bindings
+                .iter()
+                .find(|(staged, _)| *staged == name)
+                .map(|(_, binding)| *binding)
+        });
+        match existing {
+            Some(existing) if existing.index == binding.index => Ok(()),
+            None if binding.index == env.num_bindings() + self.global_bindings.len() => {
+                self.global_bindings.push((name, binding));
+                Ok(())
+            }
+            _ => Err(invalid("incompatible environment bindings").into()),
+        }
+    }
+
+    fn code_block(&mut self) -> JsResult<Gc<CodeBlock>> {
+        let name = self.sym()?;
+        let has_binding_identifier = self.bool()?;
+        let length = self.u32()?;
+        let strict = self.bool()?;
+
+        let mut code = CodeBlock::new(name, length, strict);
+        code.has_binding_identifier = has_binding_identifier;
+        code.this_mode = match self.u8()? {
+            0 => ThisMode::Lexical,
+            1 => ThisMode::Strict,
+            2 => ThisMode::Global,
+            _ => return Err(invalid("invalid this mode").into()),
+        };
+
+        // Non-simple parameter lists are restored with initializers, which keeps them non-simple.
+        let simple = self.bool()?;
+        let params = self.len()?;
+        let params = (0..params)
+            .map(|_| {
+                let init = (!simple).then(|| Literal::Undefined.into());
+                let variable = Variable::from_identifier(Identifier::new(self.sym()?), init);
+                Ok(FormalParameter::new(variable, false))
+            })
+            .collect::<JsResult<Vec<_>>>()?;
+        code.params = FormalParameterList::from_parameters(params);
+
+        let len = self.len()?;
+        let mut bytecode = self.take(len)?.to_vec();
+        swap_operands_byte_order(&mut bytecode).map_err(|error| invalid(&error.to_string()))?;
+        code.bytecode = bytecode.into_boxed_slice();
+
+        let len = self.len()?;
+        code.literals = (0..len).map(|_| self.literal()).collect::<JsResult<_>>()?;
+
+        let len = self.len()?;
+        code.regexps = (0..len)
+            .map(|_| {
+                let pattern = JsString::from(&self.utf16()?[..]);
+                let flags = JsString::from(&self.utf16()?[..]);
+                Ok(RegExpSite::new(pattern, flags))
+            })
+            .collect::<JsResult<_>>()?;
+
+        let len = self.len()?;
+        code.names = (0..len)
+            .map(|_| self.sym().map(Identifier::new))
+            .collect::<JsResult<_>>()?;
+
+        let len = self.len()?;
+        code.inline_caches = (0..len)
+            .map(|_| {
+                self.sym()
+                    .map(|name| InlineCache::new(Identifier::new(name)))
+            })
+            .collect::<JsResult<_>>()?;
+
+        let len = self.len()?;
+        code.bindings = (0..len).map(|_| self.locator()).collect::<JsResult<_>>()?;
+
+        code.num_bindings = self.len()?;
+
+        let len = self.len()?;
+        code.functions = (0..len)
+            .map(|_| self.code_block())
+            .collect::<JsResult<_>>()?;
+
+        if self.bool()? {
+            code.arguments_binding = Some(self.locator()?);
+        }
+
+        let len = self.len()?;
+        code.compile_environments = (0..len)
+            .map(|_| self.environment())
+            .collect::<JsResult<_>>()?;
+
+        code.kind = match self.u8()? {
+            0 => CodeKind::Script,
+            1 => CodeKind::Function,
+            2 => CodeKind::Async,
+            3 => CodeKind::Generator,
+            4 => CodeKind::AsyncGenerator,
+            _ => return Err(invalid("invalid code kind").into()),
+        };
+        code.is_class_constructor = self.bool()?;
+        if self.bool()? {
+            code.class_field_initializer_name = Some(self.sym()?);
+        }
+        code.function_environment_push_location = self.u32()?;
+        if self.bool()? {
+            code.parameters_env_bindings = Some(self.len()?);
+        }
+        if self.bool()? {
+            code.source_text = Some(JsString::from(&self.utf16()?[..]));
+        }
+        if self.bool()? {
+            code.source_name = Some(JsString::from(&self.utf16()?[..]));
+        }
+        let len = self.len()?;
+        code.source_positions = (0..len)
+            .map(|_| {
+                let pc = self.u32()?;
+                let line = self.u32()?;
+                let column = self.u32()?;
+                if line == 0 || column == 0 {
+                    return Err(invalid("invalid source position").into());
+                }
+                Ok((pc, Position::new(line, column)))
+            })
+            .collect::<JsResult<_>>()?;
+
+        let tables = Tables {
+            literals: &code.literals,
+            regexps: Some(code.regexps.len()),
+            names: code.names.len(),
+            inline_caches: code.inline_caches.len(),
+            bindings: Some(code.bindings.len()),
+            functions: Some(code.functions.len()),
+            private_names: Some(code.private_names.len()),
+            compile_environments: Some(code.compile_environments.len()),
+        };
+        code.max_stack_size =
+            validate_bytecode(&code.bytecode, code.params.as_ref().len(), &tables)
+                .map_err(|error| invalid(&error.to_string()))?;
+
+        Ok(Gc::new(code))
+    }
+}
+
+impl CodeBlock {
+    /// Serializes this code block and all its nested functions into a versioned binary format.
+    ///
+    /// The `interner` must be the interner of the [`Context`] that compiled this code block.
+    /// Use [`CodeBlock::deserialize_from_bytes`] to load the returned bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code block contains values that cannot be serialized, like object
+    /// literals or private names.
+    pub fn serialize_to_bytes(&self, interner: &Interner) -> JsResult<Vec<u8>> {
+        let mut writer = Writer {
+            bytes: Vec::new(),
+            interner,
+            environments: Vec::new(),
+        };
+        writer.code_block(self)?;
+
+        let environments = writer.environments()?;
+        let mut bytes =
+            Vec::with_capacity(MAGIC.len() + 1 + environments.len() + writer.bytes.len());
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&environments);
+        bytes.extend_from_slice(&writer.bytes);
+        Ok(bytes)
+    }
+
+    /// Loads a code block serialized with [`CodeBlock::serialize_to_bytes`].
+    ///
+    /// The global bindings of the serialized code block are added to the current realm of the
+    /// `context`, so the returned code block can be executed with [`Context::execute`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` was not produced by a compatible version of
+    /// [`CodeBlock::serialize_to_bytes`], if its bytecode is invalid, or if its global bindings
+    /// conflict with the bindings of the current realm. The realm is left unchanged on error.
+    pub fn deserialize_from_bytes(bytes: &[u8], context: &mut Context<'_>) -> JsResult<Gc<Self>> {
+        let Some(bytes) = bytes.strip_prefix(MAGIC) else {
+            return Err(invalid("missing header").into());
+        };
+        let Some((&version, bytes)) = bytes.split_first() else {
+            return Err(invalid("missing version").into());
+        };
+        if version != VERSION {
+            return Err(invalid(&format!(
+                "unsupported version {version}, expected {VERSION}"
+            ))
+            .into());
+        }
+
+        let mut reader = Reader {
+            bytes,
+            context,
+            environments: Vec::new(),
+            global_bindings: Vec::new(),
+        };
+        reader.environments()?;
+        let code = reader.code_block()?;
+        if !reader.bytes.is_empty() {
+            return Err(invalid("unexpected trailing bytes").into());
+        }
+
+        let global_env = reader.context.realm().environment().compile_env();
+        let mut global_env = global_env.borrow_mut();
+        for (name, binding) in reader.global_bindings {
+            global_env.insert_binding(name, binding);
+        }
+
+        Ok(code)
+    }
+}
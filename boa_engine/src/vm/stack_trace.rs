@@ -0,0 +1,96 @@
+//! JavaScript stack traces.
+//!
+//! A stack trace is captured with [`Context::capture_stack_trace`], and can be attached to a
+//! native error with [`JsNativeError::with_stack_trace`] to populate the `stack` property of the
+//! thrown error object. Error objects created by the `Error` constructors capture their stack
+//! trace on construction, and native errors thrown while executing a script capture it when they
+//! are thrown.
+//!
+//! [`Context::capture_stack_trace`]: crate::Context::capture_stack_trace
+//! [`JsNativeError::with_stack_trace`]: crate::JsNativeError::with_stack_trace
+
+use crate::JsString;
+use boa_ast::Position;
+use std::fmt;
+
+/// A frame of a JavaScript stack trace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackFrameInfo {
+    name: JsString,
+    source_name: Option<JsString>,
+    position: Option<Position>,
+}
+
+impl StackFrameInfo {
+    /// Creates a new stack frame.
+    pub(crate) const fn new(
+        name: JsString,
+        source_name: Option<JsString>,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            name,
+            source_name,
+            position,
+        }
+    }
+
+    /// Gets the name of the function executed by this frame.
+    ///
+    /// This is `<main>` for the top level code of scripts, and `<anonymous>` for functions
+    /// without a name.
+    #[must_use]
+    pub const fn name(&self) -> &JsString {
+        &self.name
+    }
+
+    /// Gets the name of the source code of the function executed by this frame, like the path of
+    /// the script file it was read from, if known.
+    #[must_use]
+    pub const fn source_name(&self) -> Option<&JsString> {
+        self.source_name.as_ref()
+    }
+
+    /// Gets the position in the source code of the call this frame is currently executing, if
+    /// known.
+    ///
+    /// The position is best-effort: it is the start of the last statement or call that was
+    /// compiled before the current instruction of the frame.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for StackFrameInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name.to_std_string_escaped())?;
+        match (&self.source_name, self.position) {
+            (Some(source_name), Some(position)) => write!(
+                f,
+                " ({}:{}:{})",
+                source_name.to_std_string_escaped(),
+                position.line_number(),
+                position.column_number()
+            ),
+            (Some(source_name), None) => write!(f, " ({})", source_name.to_std_string_escaped()),
+            (None, Some(position)) => write!(
+                f,
+                " ({}:{})",
+                position.line_number(),
+                position.column_number()
+            ),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Formats a V8-like stack trace, with `header` on the first line and a line for each of the
+/// `frames`.
+pub(crate) fn format_stack_trace(header: &str, frames: &[StackFrameInfo]) -> String {
+    let mut stack = header.to_owned();
+    for frame in frames {
+        stack.push_str(&format!("\n    at {frame}"));
+    }
+    stack
+}
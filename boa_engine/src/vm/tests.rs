@@ -1,5 +1,25 @@
-use crate::{builtins::error::ErrorKind, run_test_actions, JsValue, TestAction};
+use crate::{
+    builtins::error::ErrorKind,
+    js_string,
+    optimizer::OptimizerOptions,
+    property::PropertyKey,
+    run_test_actions,
+    vm::{
+        opcode::OpcodeCategory, Breakpoints, CodeBlock, CodeBlockId, DebugFrame, DebuggerAction,
+        DebuggerHook, InlineCache, Opcode,
+    },
+    Context, JsNativeError, JsNativeErrorKind, JsValue, NativeFunction, Source, TestAction,
+};
+use boa_ast::expression::Identifier;
+use boa_gc::Gc;
+use boa_interner::Sym;
 use indoc::indoc;
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    sync::mpsc,
+    thread,
+};
 
 #[test]
 fn typeof_string() {
@@ -37,7 +57,7 @@ fn basic_op() {
 
 #[test]
 fn try_catch_finally_from_init() {
-    // the initialisation of the array here emits a PopOnReturnAdd op
+    // the array being initialised here is on the stack when the call throws
     //
     // here we test that the stack is not popped more than intended due to multiple catches in the
     // same function, which could lead to VM stack corruption
@@ -137,6 +157,35 @@ fn finally_block_binding_env() {
     )]);
 }
 
+#[test]
+fn break_leaves_balanced_stack() {
+    run_test_actions([
+        TestAction::assert_eq(
+            indoc! {r#"
+                1;
+                for (;;) {
+                    break;
+                }
+                try {
+                } finally {
+                }
+            "#},
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                outer: for (const a of [1]) {
+                    for (const b in { x: 2 }) {
+                        break outer;
+                    }
+                }
+                [1, 2].map((x) => x * 2).join();
+            "#},
+            "2,4",
+        ),
+    ]);
+}
+
 #[test]
 fn run_super_method_in_object() {
     run_test_actions([TestAction::assert_eq(
@@ -237,3 +286,1282 @@ fn order_of_execution_in_assigment_with_comma_expressions() {
         "1234",
     )]);
 }
+
+#[cfg(feature = "trace")]
+#[test]
+fn opcode_timings_histogram() {
+    use crate::{optimizer::OptimizerOptions, vm::Opcode, Context, Source};
+
+    let context = &mut Context::default();
+
+    // Disable optimizations so the arithmetic isn't constant folded.
+    context.set_optimizer_options(OptimizerOptions::empty());
+    context.set_opcode_timing(true);
+
+    context
+        .eval_script(Source::from_bytes(include_str!(
+            "../../benches/bench_scripts/arithmetic_operations.js"
+        )))
+        .unwrap();
+
+    let histogram = context.opcode_timings();
+    let add = histogram
+        .iter()
+        .find(|(opcode, _)| matches!(opcode, Opcode::Add));
+    assert!(matches!(add, Some((_, duration)) if !duration.is_zero()));
+
+    context.set_opcode_timing(false);
+    assert!(context.opcode_timings().is_empty());
+}
+
+#[cfg(feature = "instcount")]
+#[test]
+fn opcode_histogram() {
+    use crate::{optimizer::OptimizerOptions, vm::Opcode, Context, Source};
+
+    let context = &mut Context::default();
+
+    // Disable optimizations so the arithmetic isn't constant folded.
+    context.set_optimizer_options(OptimizerOptions::empty());
+    context.reset_opcode_histogram();
+
+    context
+        .eval_script(Source::from_bytes(
+            "let x = 0; for (let i = 0; i < 10; i++) { x = x + i; }",
+        ))
+        .unwrap();
+
+    let histogram = context.opcode_histogram();
+    assert_eq!(histogram.get(&Opcode::Add), Some(&10));
+    assert_eq!(histogram.get(&Opcode::IncPost), Some(&10));
+    assert!(histogram.values().all(|count| *count != 0));
+
+    context.reset_opcode_histogram();
+    assert!(context.opcode_histogram().is_empty());
+}
+
+#[test]
+fn code_block_serialization_round_trip() {
+    let source = indoc! {r#"
+        const greeting = "hello";
+        let counter = 0;
+        function add(a, b = 2) {
+            return a + b;
+        }
+        function makeCounter() {
+            return () => ++counter;
+        }
+        const next = makeCounter();
+        next();
+        next();
+        `${greeting} ${add(1)} ${add(1.5, 10n === 10n ? 1 : 0)} ${counter} ${add.length}`
+    "#};
+
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes(source))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+    let bytes = code
+        .serialize_to_bytes(context.interner())
+        .expect("code block should serialize");
+
+    let mut context = Context::default();
+    let code = CodeBlock::deserialize_from_bytes(&bytes, &mut context)
+        .expect("code block should deserialize");
+    assert_eq!(
+        context.execute(code).expect("code block should execute"),
+        JsValue::from("hello 3 2.5 2 1")
+    );
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("greeting + counter"))
+            .expect("globals should be defined"),
+        JsValue::from("hello2")
+    );
+}
+
+#[test]
+fn code_block_serialization_rejects_incompatible_bytes() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes("1 + 1"))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+    let bytes = code
+        .serialize_to_bytes(context.interner())
+        .expect("code block should serialize");
+
+    let mut bad_header = bytes.clone();
+    bad_header[0] = b'X';
+    let mut bad_version = bytes.clone();
+    bad_version[4] = bad_version[4].wrapping_add(1);
+    let truncated = &bytes[..bytes.len() - 1];
+
+    for bytes in [&bad_header[..], &bad_version[..], truncated, &[][..]] {
+        let err = CodeBlock::deserialize_from_bytes(bytes, &mut context)
+            .expect_err("incompatible bytes must be rejected");
+        assert!(matches!(
+            err.as_native().map(|err| &err.kind),
+            Some(JsNativeErrorKind::Type)
+        ));
+    }
+}
+
+#[test]
+fn code_block_serialization_validates_bytecode() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes("let staged = 1; staged"))
+        .expect("script should parse");
+    let mut code = CodeBlock::clone(
+        &context
+            .compile_script(&script)
+            .expect("script should compile"),
+    );
+    let name = code.bindings[0].name();
+
+    // The bytecode still references the removed binding.
+    code.bindings = Box::default();
+    let bytes = code
+        .serialize_to_bytes(context.interner())
+        .expect("code block should serialize");
+
+    let mut context = Context::default();
+    let err = CodeBlock::deserialize_from_bytes(&bytes, &mut context)
+        .expect_err("invalid bytecode must be rejected");
+    assert!(err.to_string().contains("invalid table index"));
+    assert!(context
+        .realm()
+        .environment()
+        .compile_env()
+        .borrow()
+        .binding(name)
+        .is_none());
+}
+
+#[test]
+fn code_block_disassemble() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes("let a = 5; a + 20"))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+
+    let listing = code.disassemble(context.interner());
+    assert!(listing.contains("Location  Count   Opcode                     Operands"));
+    assert!(listing.contains("000000    0000    "));
+    assert!(listing.contains("PushInt8                   20"));
+    assert!(listing.contains("Add"));
+    assert!(listing.contains("Bindings:\n    0000: a\n"));
+}
+
+#[test]
+fn code_block_max_stack_size() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes(
+            "function f(a, b, c) { return a; }
+            var x = 1;
+            x + (x + (x + (x + x)));",
+        ))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+
+    assert!(code.max_stack_size() >= 5);
+    assert!(code.functions[0].max_stack_size() >= 3);
+
+    let bytes = code
+        .serialize_to_bytes(context.interner())
+        .expect("code block should serialize");
+    let loaded =
+        CodeBlock::deserialize_from_bytes(&bytes, &mut context).expect("bytes should load");
+    assert_eq!(loaded.max_stack_size(), code.max_stack_size());
+    assert_eq!(
+        loaded.functions[0].max_stack_size(),
+        code.functions[0].max_stack_size()
+    );
+}
+
+#[test]
+fn capture_stack_trace() {
+    const SOURCE: &str = indoc! {r#"
+        function inner() {
+            const named = function () { return (() => fail())(); };
+            return named();
+        }
+        let stack;
+        try {
+            inner();
+        } catch (e) {
+            stack = e.stack;
+        }
+    "#};
+
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            context
+                .register_global_callable(
+                    "fail",
+                    0,
+                    NativeFunction::from_fn_ptr(|_, _, context| {
+                        Err(JsNativeError::typ()
+                            .with_message("boom")
+                            .with_stack_trace(context.capture_stack_trace())
+                            .into())
+                    }),
+                )
+                .expect("fail should be registered");
+        }),
+        TestAction::run(SOURCE),
+        TestAction::assert_eq(
+            "stack",
+            js_string!(
+                "TypeError: boom\n    at <anonymous> (2:47)\n    at named (2:40)\n    at inner (3:12)\n    at <main> (7:5)"
+            ),
+        ),
+        TestAction::inspect_context(|context| {
+            assert!(context.capture_stack_trace().is_empty());
+        }),
+    ]);
+
+    // The source positions survive the serialization of the code block.
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes(SOURCE))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+    let bytes = code
+        .serialize_to_bytes(context.interner())
+        .expect("code block should serialize");
+    let loaded =
+        CodeBlock::deserialize_from_bytes(&bytes, &mut context).expect("bytes should load");
+    assert_eq!(loaded.source_positions, code.source_positions);
+    assert_eq!(
+        loaded.functions[0].source_positions,
+        code.functions[0].source_positions
+    );
+}
+
+#[test]
+fn stack_trace_source_name() {
+    let mut context = Context::default();
+    let source = b"function f() {\n    return null.x;\n}\nf();";
+    let error = context
+        .eval_script(Source::from_reader(
+            &source[..],
+            Some(std::path::Path::new("test.js")),
+        ))
+        .unwrap_err();
+
+    let frames = error
+        .as_native()
+        .and_then(JsNativeError::stack_trace)
+        .expect("the thrown error should have a stack trace")
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(frames, ["f (test.js:2:5)", "<main> (test.js:4:1)"]);
+}
+
+#[test]
+fn code_block_stats() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes(
+            "function f() { function g() { return 'a'; } return g; }
+            var x = { y: 1 };",
+        ))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+
+    let stats = code.stats();
+    let f = &code.functions[0];
+    let g = &f.functions[0];
+    assert_eq!(stats.bytecode_len, code.bytecode.len());
+    assert_eq!(stats.functions, 1);
+    assert_eq!(stats.total_code_blocks, 3);
+    assert_eq!(
+        stats.total_bytecode_len,
+        code.bytecode.len() + f.bytecode.len() + g.bytecode.len()
+    );
+    assert_eq!(
+        stats.total_literals,
+        code.literals.len() + f.literals.len() + g.literals.len()
+    );
+    assert_eq!(f.stats().total_code_blocks, 2);
+
+    let mut shared = CodeBlock::new(Sym::MAIN, 0, false);
+    shared.functions = vec![g.clone(), g.clone()].into_boxed_slice();
+    let stats = shared.stats();
+    assert_eq!(stats.functions, 2);
+    assert_eq!(stats.total_code_blocks, 2);
+    assert_eq!(stats.total_bytecode_len, g.bytecode.len());
+}
+
+#[test]
+fn code_block_with_name() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes("(function () { return 1; })"))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+
+    let name = context.interner_mut().get_or_intern("callback");
+    let renamed = code.with_name(name);
+    assert_eq!(renamed.name(), name);
+    assert_eq!(renamed.bytecode, code.bytecode);
+    assert!(Gc::ptr_eq(&renamed.functions[0], &code.functions[0]));
+
+    let anonymous = &code.functions[0];
+    assert_eq!(anonymous.name(), Sym::EMPTY_STRING);
+    let renamed = Gc::new(anonymous.with_name(name));
+    let function =
+        crate::vm::create_function_object(renamed, false, false, None, false, &mut context);
+    assert_eq!(
+        function.get(js_string!("name"), &mut context).unwrap(),
+        JsValue::new(js_string!("callback"))
+    );
+    assert_eq!(
+        function
+            .call(&JsValue::undefined(), &[], &mut context)
+            .unwrap(),
+        JsValue::new(1)
+    );
+
+    // A renamed copy of a function whose compilation was deferred keeps its name once compiled.
+    let code = context
+        .intern_and_compile_lazy(Source::from_bytes("(function () { return 1; })"))
+        .expect("script should compile");
+    let renamed = Gc::new(code.functions[0].with_name(name));
+    assert!(!renamed.is_compiled());
+    let compiled = CodeBlock::compiled(renamed, &mut context).expect("function should compile");
+    assert_eq!(compiled.name(), name);
+    assert_eq!(
+        CodeBlock::compiled(code.functions[0].clone(), &mut context)
+            .expect("function should compile")
+            .name(),
+        Sym::EMPTY_STRING
+    );
+}
+
+#[test]
+fn lazy_function_compilation() {
+    fn compiled(code: &CodeBlock) -> usize {
+        code.functions.iter().filter(|f| f.is_compiled()).count()
+    }
+
+    let mut context = Context::default();
+    let mut source = String::from(indoc! {r#"
+        function called(a, b) {
+            const inner = () => a;
+            return inner() + b;
+        }
+    "#});
+    for i in 0..100 {
+        source.push_str(&format!(
+            "function never{i}(x, y, z) {{ return x + {i}; }}\n"
+        ));
+    }
+    source.push_str("called(1, 2)");
+
+    let code = context
+        .intern_and_compile_lazy(Source::from_bytes(&source))
+        .expect("script should compile");
+    assert_eq!(code.functions.len(), 101);
+    assert_eq!(compiled(&code), 0);
+
+    let result = context.execute(code.clone()).unwrap();
+    assert_eq!(result, JsValue::new(3));
+    assert_eq!(compiled(&code), 1);
+
+    let called = code
+        .functions
+        .iter()
+        .find(|f| f.is_compiled())
+        .expect("the called function must be compiled");
+    assert_eq!(
+        context.interner().resolve_expect(called.name()).to_string(),
+        "called"
+    );
+
+    // Function objects of functions that were never called still have their properties.
+    let result = context
+        .eval_script(Source::from_bytes(
+            "never7.length === 3 && never7.name === 'never7'",
+        ))
+        .unwrap();
+    assert_eq!(result, JsValue::new(true));
+    assert_eq!(compiled(&code), 1);
+
+    let result = context
+        .eval_script(Source::from_bytes("never7(1) + called(2, 3)"))
+        .unwrap();
+    assert_eq!(result, JsValue::new(13));
+    assert_eq!(compiled(&code), 2);
+}
+
+#[test]
+fn debugger_hook() {
+    /// The value of `x`, the stack and the call depth at a breakpoint.
+    type Hit = (Option<JsValue>, Vec<JsValue>, usize);
+
+    #[derive(Default)]
+    struct Debugger {
+        trace: RefCell<Vec<(CodeBlockId, u32, Opcode)>>,
+        breakpoints: RefCell<Breakpoints>,
+        hits: RefCell<Vec<Hit>>,
+        pauses: Cell<usize>,
+        paused: RefCell<Option<mpsc::Sender<()>>>,
+        steps: RefCell<Vec<Opcode>>,
+    }
+
+    impl DebuggerHook for Debugger {
+        fn on_instruction(&self, frame: &mut DebugFrame<'_, '_>) -> DebuggerAction {
+            self.trace
+                .borrow_mut()
+                .push((frame.code_block_id(), frame.pc(), frame.opcode()));
+            if frame.is_step() {
+                self.steps.borrow_mut().push(frame.opcode());
+                return DebuggerAction::Continue;
+            }
+            if !self.breakpoints.borrow().is_hit(frame) {
+                return DebuggerAction::Continue;
+            }
+            if self.pauses.get() < 2 {
+                self.pauses.set(self.pauses.get() + 1);
+                if let Some(paused) = &*self.paused.borrow() {
+                    paused.send(()).unwrap();
+                }
+                return DebuggerAction::Pause;
+            }
+            let x = frame.binding("x").unwrap();
+            self.hits
+                .borrow_mut()
+                .push((x, frame.stack().to_vec(), frame.depth()));
+            DebuggerAction::StepInto
+        }
+    }
+
+    let mut context = Context::default();
+    context.set_optimizer_options(OptimizerOptions::empty());
+    let script = context
+        .parse_script(Source::from_bytes(
+            "function f(x) { return x + 1; } f(1) + f(41)",
+        ))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+    let f = CodeBlockId::new(&code.functions[0]);
+
+    let debugger = Rc::new(Debugger::default());
+    let hook: Rc<dyn DebuggerHook> = debugger.clone();
+    context.set_debugger_hook(Some(hook));
+
+    // Find the addition of `f` by tracing a first execution.
+    assert_eq!(context.execute(code.clone()).unwrap(), JsValue::new(44));
+    let add = debugger
+        .trace
+        .borrow()
+        .iter()
+        .find(|(id, _, opcode)| *id == f && *opcode == Opcode::Add)
+        .map(|(_, pc, _)| *pc)
+        .expect("f must add");
+
+    // Paused executions are resumed from another thread.
+    let resume = context.debugger_resume_handle();
+    assert!(!resume.resume(), "nothing is paused");
+    let (sender, receiver) = mpsc::channel();
+    let resumer = thread::spawn(move || {
+        for () in receiver {
+            assert!(resume.resume());
+        }
+    });
+    *debugger.paused.borrow_mut() = Some(sender);
+
+    debugger.breakpoints.borrow_mut().insert(f, add);
+    assert_eq!(context.execute(code.clone()).unwrap(), JsValue::new(44));
+
+    debugger.paused.borrow_mut().take();
+    resumer.join().unwrap();
+    assert_eq!(debugger.pauses.get(), 2);
+    let hits = debugger.hits.borrow();
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].0, Some(JsValue::new(1)));
+    assert_eq!(hits[1].0, Some(JsValue::new(41)));
+    assert_eq!(hits[1].1, [JsValue::new(41), JsValue::new(1)]);
+    assert_eq!(hits[1].2, 2);
+    assert_eq!(debugger.steps.borrow().len(), 2);
+
+    context.set_debugger_hook(None);
+    debugger.trace.borrow_mut().clear();
+    assert_eq!(context.execute(code).unwrap(), JsValue::new(44));
+    assert!(debugger.trace.borrow().is_empty());
+}
+
+#[test]
+fn deduplicated_literals() {
+    let mut context = Context::default();
+    let script = context
+        .parse_script(Source::from_bytes(
+            r#"function f() {
+                var a = ["x", "x", "x", "x", "x"];
+                var o = { x: "x", y: "x" };
+                f.x = "x";
+                print("x", 1.5, 1.5, 10n, 10n);
+                return "x" === a[0] ? "x" : 1.5;
+            }"#,
+        ))
+        .expect("script should parse");
+    let code = context
+        .compile_script(&script)
+        .expect("script should compile");
+
+    let f = &code.functions[0];
+    let strings = f
+        .literals
+        .iter()
+        .filter(|literal| literal.as_string().is_some())
+        .collect::<Vec<_>>();
+    assert_eq!(strings.len(), 1);
+    assert_eq!(strings[0], &JsValue::new(js_string!("x")));
+    assert_eq!(
+        f.literals
+            .iter()
+            .filter(|literal| literal.is_bigint())
+            .count(),
+        1
+    );
+    assert!(!f.literals.iter().any(JsValue::is_number));
+}
+
+/// Compiles `source` with only the given optimizations enabled.
+fn compile_with_options(
+    source: &str,
+    options: OptimizerOptions,
+    context: &mut Context<'_>,
+) -> Gc<CodeBlock> {
+    context.set_optimizer_options(options);
+    let script = context
+        .parse_script(Source::from_bytes(source))
+        .expect("script should parse");
+    context
+        .compile_script(&script)
+        .expect("script should compile")
+}
+
+/// Returns the locations of the instructions in `listing`, and the address operands of its jumps.
+fn instruction_locations(listing: &str) -> (Vec<u32>, Vec<u32>) {
+    let mut locations = Vec::new();
+    let mut targets = Vec::new();
+    for line in listing
+        .lines()
+        .skip_while(|line| !line.starts_with("Location"))
+        .skip(2)
+        .take_while(|line| !line.is_empty())
+    {
+        let mut columns = line.split_whitespace();
+        locations.push(columns.next().unwrap().parse().unwrap());
+        let opcode = columns.nth(1).unwrap();
+        if opcode.starts_with("Jump") || opcode.starts_with("Loop") || opcode == "TryStart" {
+            targets.extend(
+                columns.map(|operand| operand.trim_end_matches(',').parse::<u32>().unwrap()),
+            );
+        }
+    }
+    (locations, targets)
+}
+
+#[test]
+fn peephole_folds_double_not() {
+    let source = "var x = 5; !!x";
+    let context = &mut Context::default();
+
+    let before = compile_with_options(source, OptimizerOptions::empty(), context);
+    let before_listing = before.disassemble(context.interner());
+    assert_eq!(before_listing.matches("LogicalNot").count(), 2);
+    assert!(!before_listing.contains("ToBoolean"));
+
+    let after = compile_with_options(source, OptimizerOptions::PEEPHOLE, context);
+    let after_listing = after.disassemble(context.interner());
+    assert!(!after_listing.contains("LogicalNot"));
+    assert!(after_listing.contains("ToBoolean"));
+    assert_eq!(after.bytecode.len(), before.bytecode.len() - 1);
+
+    assert_eq!(context.execute(after).unwrap(), JsValue::new(true));
+}
+
+#[test]
+fn peephole_relocates_jumps() {
+    let source = indoc! {r#"
+        var log = [];
+        outer: for (var i = 0; i < 3; i++) {
+            if (!!i) log.push(i); else ;
+            try {
+                if (i == 2) break outer; else ;
+            } finally {
+                log.push("f" + i);
+            }
+        }
+        log.join()
+    "#};
+    let context = &mut Context::default();
+
+    let before = compile_with_options(source, OptimizerOptions::empty(), context);
+    let after = compile_with_options(source, OptimizerOptions::PEEPHOLE, context);
+    assert!(after.bytecode.len() < before.bytecode.len());
+
+    let after_listing = after.disassemble(context.interner());
+    assert!(!after_listing.contains("LogicalNot"));
+
+    // Every jump of the optimized code must still land on the start of an instruction.
+    let (locations, targets) = instruction_locations(&after_listing);
+    for target in targets {
+        assert!(
+            target == u32::MAX
+                || target as usize == after.bytecode.len()
+                || locations.contains(&target),
+            "jump to {target} is not an instruction boundary:\n{after_listing}"
+        );
+    }
+
+    let expected = JsValue::new(js_string!("f0,1,f1,2,f2"));
+    assert_eq!(context.execute(before).unwrap(), expected);
+    assert_eq!(context.execute(after).unwrap(), expected);
+}
+
+#[test]
+fn peephole_removes_popped_constants() {
+    let source = "1; 'a'; null; 2";
+    let context = &mut Context::default();
+
+    let before = compile_with_options(source, OptimizerOptions::empty(), context);
+    let before_listing = before.disassemble(context.interner());
+    let before_pops = before_listing.matches(" Pop ").count();
+    assert!(before_pops >= 3);
+
+    let after = compile_with_options(source, OptimizerOptions::PEEPHOLE, context);
+    let after_listing = after.disassemble(context.interner());
+    assert_eq!(after_listing.matches(" Pop ").count(), before_pops - 3);
+    assert!(!after_listing.contains("PushOne"));
+    assert!(!after_listing.contains("PushLiteral"));
+    assert!(!after_listing.contains("PushNull"));
+    assert!(after.bytecode.len() < before.bytecode.len());
+
+    assert_eq!(context.execute(after).unwrap(), JsValue::new(2));
+}
+
+#[test]
+fn peephole_preserves_bench_results() {
+    let scripts = concat!(env!("CARGO_MANIFEST_DIR"), "/benches/bench_scripts");
+    let mut paths = std::fs::read_dir(scripts)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect::<Vec<_>>();
+    paths.sort();
+    assert!(!paths.is_empty());
+
+    let run = |source: &[u8], options| {
+        let context = &mut Context::default();
+        context.set_optimizer_options(options);
+        match context.eval_script(Source::from_bytes(source)) {
+            Ok(value) => value.display().to_string(),
+            Err(err) => format!("Uncaught {err}"),
+        }
+    };
+
+    for path in paths {
+        let source = std::fs::read(&path).unwrap();
+        assert_eq!(
+            run(&source, OptimizerOptions::empty()),
+            run(&source, OptimizerOptions::PEEPHOLE),
+            "peephole optimizations changed the result of {}",
+            path.display()
+        );
+    }
+}
+
+#[test]
+fn compiler_constant_folding() {
+    let cases = [
+        "60 * 60 * 24",
+        "0.1 + 0.2",
+        "2147483647 + 1",
+        "2147483647 * 2",
+        "(0 - 2147483647 - 1) - 1",
+        "'a' + 1 + 2",
+        "1 + 2 + 'a'",
+        "'3' * '4'",
+        "'x' - 1",
+        "(1 / 0) - (1 / 0)",
+        "(0 / 0) + 1",
+        "(0 - 0) * (0 - 1)",
+        "7 % 0",
+        "2 ** 0.5",
+        "1 << 31",
+        "4294967295 >>> 0",
+        "(5 & 3) | (8 ^ 2)",
+        "var x = 2; x * 3 + (4 - 1) * 5",
+        "var x = 'b'; 'a' + (1 + 2) + x + (3 - 1)",
+        "var x = 1; (2 ** 3) - (x - (6 / 2))",
+    ];
+
+    for source in cases {
+        let context = &mut Context::default();
+        context.set_optimizer_options(OptimizerOptions::empty());
+        let script = context
+            .parse_script(Source::from_bytes(source))
+            .expect("script should parse");
+        let unfolded = context
+            .compile_script(&script)
+            .expect("script should compile");
+        context.set_optimizer_options(OptimizerOptions::CONSTANT_FOLDING);
+        let folded = context
+            .compile_script(&script)
+            .expect("script should compile");
+
+        let listing = folded.disassemble(context.interner());
+
+        // Folded constants can take more bytes than the operations they replace, so compare the
+        // number of executed instructions instead of the length of the bytecode.
+        let start = context.instructions_executed();
+        let expected = context.execute(unfolded).unwrap();
+        let unfolded_instructions = context.instructions_executed() - start;
+        let start = context.instructions_executed();
+        let result = context.execute(folded).unwrap();
+        let folded_instructions = context.instructions_executed() - start;
+        assert!(
+            folded_instructions < unfolded_instructions,
+            "`{source}` was not folded:\n{listing}"
+        );
+        assert!(
+            JsValue::same_value(&expected, &result),
+            "`{source}` evaluated to {} instead of {}",
+            result.display(),
+            expected.display()
+        );
+    }
+}
+
+#[test]
+fn value_stack_limit() {
+    let arguments = vec!["1"; 100].join(", ");
+    run_test_actions([
+        TestAction::inspect_context(|context| context.set_value_stack_limit(64)),
+        TestAction::run(format!(
+            "function sum(...args) {{ return args.length; }}
+            function many() {{ return sum({arguments}); }}"
+        )),
+        TestAction::assert_eq("sum(1, 2, 3)", 3),
+        TestAction::assert_native_error(
+            "many()",
+            ErrorKind::Range,
+            "Maximum value stack size exceeded",
+        ),
+        TestAction::assert(indoc! {r#"
+            let caught;
+            try {
+                many();
+            } catch (e) {
+                caught = e;
+            }
+            caught instanceof RangeError
+        "#}),
+        TestAction::inspect_context(|context| context.set_value_stack_limit(usize::MAX)),
+        TestAction::assert_eq("many()", 100),
+    ]);
+}
+
+#[test]
+fn inline_cache_shape_transitions() {
+    let context = &mut Context::default();
+    let mut object = |source: &'static str| {
+        context
+            .eval_script(Source::from_bytes(source))
+            .unwrap()
+            .as_object()
+            .unwrap()
+            .clone()
+    };
+    let first = object("({ x: 1, y: 2 })");
+    let second = object("({ x: 3, y: 4 })");
+    let frozen = object("Object.freeze({ x: 5, y: 6 })");
+
+    let cache = InlineCache::new(Identifier::new(context.interner_mut().get_or_intern("y")));
+    let key = PropertyKey::from(js_string!("y"));
+    let get = |object| cache.get(object).and_then(|value| value.as_number());
+
+    assert_eq!(get(&first), None);
+    cache.update_get(&first, &key);
+    assert_eq!(get(&first), Some(2.0));
+    assert_eq!(get(&second), Some(4.0));
+    assert_eq!(get(&frozen), None);
+
+    assert!(cache.set(&second, &JsValue::new(7)));
+    assert_eq!(
+        second.get(key.clone(), context).unwrap().as_number(),
+        Some(7.0)
+    );
+
+    second
+        .create_data_property_or_throw(js_string!("z"), 8, context)
+        .unwrap();
+    assert_eq!(get(&second), None);
+    assert_eq!(get(&first), Some(2.0));
+
+    cache.update_set(&frozen, &key);
+    assert!(!cache.set(&frozen, &JsValue::new(9)));
+    assert_eq!(frozen.get(key, context).unwrap().as_number(), Some(6.0));
+}
+
+#[test]
+fn inline_cache_property_access() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function get(o) { return o.x; }
+            function set(o, v) { o.x = v; return o.x; }
+            const proto = { x: "proto" };
+            const accessor = { get x() { return "getter"; }, set x(v) {} };
+            const objects = [
+                { x: 1 },
+                { y: 0, x: 2 },
+                Object.create(proto),
+                accessor,
+                Object.freeze({ x: 3 }),
+                [],
+                "string",
+            ];
+        "#}),
+        TestAction::assert_eq(
+            "objects.map(get).concat(objects.map(get)).join()",
+            "1,2,proto,getter,3,,,1,2,proto,getter,3,,",
+        ),
+        TestAction::assert_eq(
+            "objects.slice(0, 5).map((o, i) => set(o, i + 10)).join()",
+            "10,11,12,getter,3",
+        ),
+        TestAction::assert_eq("proto.x", "proto"),
+        TestAction::assert_eq(
+            indoc! {r#"
+                const o = { x: 1, y: 2 };
+                get(o);
+                delete o.x;
+                o.x = 5;
+                Object.defineProperty(o, "x", { get() { return 6; } });
+                get(o)
+            "#},
+            6,
+        ),
+    ]);
+}
+
+#[test]
+fn interrupt_execution() {
+    let context = &mut Context::default();
+    let handle = context.interrupt_handle();
+    context.set_interrupt_check_interval(10);
+    assert_eq!(context.interrupt_check_interval(), 10);
+
+    let interrupt_later = || {
+        let handle = handle.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            handle.interrupt();
+        })
+    };
+
+    let interrupter = interrupt_later();
+    let error = context
+        .eval_script(Source::from_bytes("while (true) {}"))
+        .unwrap_err();
+    interrupter.join().unwrap();
+    assert!(error.is_interrupted());
+    assert!(handle.is_interrupted());
+
+    // An interrupted handle stops every execution until it is reset.
+    let error = context
+        .eval_script(Source::from_bytes("1 + 1"))
+        .unwrap_err();
+    assert!(error.is_interrupted());
+    handle.reset();
+
+    // The interrupt cannot be caught by scripts, even through native calls.
+    let interrupter = interrupt_later();
+    let error = context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            var caught = false;
+            var finalized = false;
+            {
+                let x = 1;
+                try {
+                    [1].forEach(() => { while (true) {} });
+                } catch {
+                    caught = true;
+                } finally {
+                    finalized = true;
+                }
+            }
+        "#}))
+        .unwrap_err();
+    interrupter.join().unwrap();
+    assert!(error.is_interrupted());
+
+    handle.reset();
+    assert!(!handle.is_interrupted());
+    let result = context
+        .eval_script(Source::from_bytes(
+            "let y = typeof x; [caught, finalized, y].join()",
+        ))
+        .unwrap();
+    assert_eq!(result, js_string!("false,false,undefined").into());
+
+    // The handle is checked when an execution starts, even if the previous one ran fewer
+    // instructions than the check interval.
+    context.set_interrupt_check_interval(1000);
+    context.eval_script(Source::from_bytes("1 + 1")).unwrap();
+    handle.interrupt();
+    let error = context
+        .eval_script(Source::from_bytes("1 + 1"))
+        .unwrap_err();
+    assert!(error.is_interrupted());
+}
+
+#[test]
+fn instruction_limit() {
+    let context = &mut Context::default();
+    assert_eq!(context.instruction_limit(), None);
+
+    context.eval_script(Source::from_bytes("1 + 1")).unwrap();
+    let executed = context.instructions_executed();
+    assert!(executed > 0);
+
+    context.set_instruction_limit(Some(1000));
+    assert_eq!(context.instruction_limit(), Some(1000));
+    let error = context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            var finalized = false;
+            try {
+                while (true) {}
+            } catch {
+            } finally {
+                finalized = true;
+            }
+        "#}))
+        .unwrap_err();
+    assert!(error.is_instruction_limit_exceeded());
+    assert_eq!(context.instructions_executed(), executed + 1000);
+
+    // The exhausted budget stops every execution until a new limit is set.
+    let error = context.eval_script(Source::from_bytes("1")).unwrap_err();
+    assert!(error.is_instruction_limit_exceeded());
+
+    context.set_instruction_limit(None);
+    assert_eq!(
+        context
+            .eval_script(Source::from_bytes("finalized"))
+            .unwrap(),
+        JsValue::new(false)
+    );
+    assert!(context.instructions_executed() > executed + 1000);
+}
+
+#[test]
+fn max_call_depth() {
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            assert!(context.max_call_depth() > 0);
+            context.set_max_call_depth(32);
+            assert_eq!(context.max_call_depth(), 32);
+        }),
+        TestAction::run(indoc! {r#"
+            function depth(n) { return n === 0 ? 0 : 1 + depth(n - 1); }
+            function infinite() { return infinite(); }
+            function Infinite() { new Infinite(); }
+            var code = "eval(code)";
+            function scopes(n) {
+                with ({}) with ({}) with ({}) with ({}) with ({})
+                with ({}) with ({}) with ({}) with ({}) with ({}) {
+                    return n === 0 ? 0 : 1 + scopes(n - 1);
+                }
+            }
+            function nested(n) {
+                eval("with ({}) ".repeat(n) + "var result = n;");
+                return result;
+            }
+        "#}),
+        TestAction::assert_eq("depth(20)", 20),
+        TestAction::assert_native_error(
+            "infinite()",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_native_error(
+            "new Infinite()",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_native_error(
+            "eval(code)",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_eq(
+            "try { depth(100) } catch (e) { e instanceof RangeError }",
+            true,
+        ),
+        TestAction::assert_eq("scopes(2)", 2),
+        TestAction::assert_eq("depth(20)", 20),
+        TestAction::assert_eq("nested(10)", 10),
+        TestAction::assert_native_error(
+            "nested(40)",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+    ]);
+}
+
+#[test]
+fn stack_size_limit() {
+    let arguments = |count: usize| vec!["0"; count].join(", ");
+    run_test_actions([
+        TestAction::inspect_context(|context| {
+            assert_eq!(context.stack_size_limit(), 1024);
+            context.set_stack_size_limit(64);
+            assert_eq!(context.stack_size_limit(), 64);
+        }),
+        TestAction::run("function count() { return arguments.length; }"),
+        TestAction::assert_eq(format!("count({})", arguments(8)), 8),
+        TestAction::assert_native_error(
+            format!("count({})", arguments(100)),
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_eq(format!("count({})", arguments(8)), 8),
+    ]);
+}
+
+#[test]
+fn tail_calls() {
+    run_test_actions([
+        TestAction::inspect_context(|context| context.set_max_call_depth(32)),
+        TestAction::run(indoc! {r#"
+            "use strict";
+            function sum(n, acc) {
+                if (n === 0) {
+                    return acc;
+                }
+                return sum(n - 1, acc + n);
+            }
+            function args(n) {
+                if (n === 0) return arguments.length;
+                return args(n - 1, 1, 2);
+            }
+            function strictThis(n) {
+                if (n === 0) return this;
+                return strictThis(n - 1);
+            }
+            function inTry(n) {
+                try {
+                    if (n === 0) return 0;
+                    return inTry(n - 1);
+                } finally {}
+            }
+            function Ctor(n) {
+                if (n === 0) return;
+                return Ctor(n - 1);
+            }
+            function reassigned(n) { return reassigned(n); }
+            const original = reassigned;
+            reassigned = function (n) { return n * 2; };
+        "#}),
+        TestAction::assert_eq("sum(10000, 0)", 50_005_000),
+        TestAction::assert_eq("args(100)", 3),
+        TestAction::assert_eq("strictThis.call({}, 100)", JsValue::undefined()),
+        TestAction::assert_native_error(
+            "inTry(100)",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+        TestAction::assert_eq("typeof new Ctor(100)", "object"),
+        TestAction::assert_eq("original(21)", 42),
+        // Functions that are not strict can observe their callers, so they never use tail calls.
+        TestAction::run(indoc! {r#"
+            function sloppy(n) {
+                if (n === 0) return 0;
+                return sloppy(n - 1);
+            }
+        "#}),
+        TestAction::assert_native_error(
+            "sloppy(100)",
+            ErrorKind::Range,
+            "Maximum call stack size exceeded",
+        ),
+    ]);
+}
+
+#[cfg(feature = "inline-caches")]
+#[test]
+fn trace_deopts_on_shape_change() {
+    use crate::vm::DeoptReason;
+
+    let context = &mut Context::default();
+    let deopts = Rc::new(RefCell::new(Vec::new()));
+    let reported = deopts.clone();
+    context.trace_deopts(move |event, _| {
+        let opcode = Opcode::try_from(event.code_block().bytecode[event.pc() as usize])
+            .expect("invalid opcode");
+        reported
+            .borrow_mut()
+            .push((event.code_block().name(), opcode, event.reason()));
+    });
+
+    context
+        .eval_script(Source::from_bytes(indoc! {r#"
+            function get(o) { return o.x; }
+            get({ x: 1 });
+            get({ x: 2 });
+        "#}))
+        .unwrap();
+    assert!(deopts.borrow().is_empty());
+
+    context
+        .eval_script(Source::from_bytes("get({ y: 1, x: 3 })"))
+        .unwrap();
+    assert_eq!(
+        *deopts.borrow(),
+        vec![(
+            Sym::GET,
+            Opcode::GetPropertyByName,
+            DeoptReason::InlineCacheMiss
+        )]
+    );
+}
+
+#[cfg(feature = "fuzz")]
+#[test]
+fn execute_fuzz_bytecode() {
+    use crate::vm::fuzz::{execute_fuzz_bytecode, FuzzLimits, FuzzOutcome};
+
+    let u32_operand = |opcode: Opcode, operand: u32| {
+        let mut bytes = vec![opcode as u8];
+        bytes.extend_from_slice(&operand.to_ne_bytes());
+        bytes
+    };
+    let run = |bytecode: &[u8]| {
+        execute_fuzz_bytecode(
+            bytecode,
+            vec![js_string!("boom").into(), JsValue::new(1)],
+            &["name"],
+            FuzzLimits::default(),
+        )
+    };
+    let rejection = |bytecode: &[u8]| match run(bytecode) {
+        FuzzOutcome::Rejected(error) => error.message(),
+        outcome => panic!("expected a rejection, got {outcome:?}"),
+    };
+
+    let add = [
+        Opcode::PushInt8 as u8,
+        1,
+        Opcode::PushInt8 as u8,
+        2,
+        Opcode::Add as u8,
+    ];
+    assert_eq!(run(&add), FuzzOutcome::Completed);
+
+    let FuzzOutcome::Threw(message) = run(&u32_operand(Opcode::ThrowNewTypeError, 0)) else {
+        panic!("expected a thrown error");
+    };
+    assert!(message.contains("boom"));
+
+    // Infinite loops are stopped by the instruction limit.
+    assert!(matches!(
+        run(&u32_operand(Opcode::Jump, 0)),
+        FuzzOutcome::Threw(_)
+    ));
+
+    assert_eq!(rejection(&[u8::MAX]), "invalid opcode");
+    assert_eq!(rejection(&[Opcode::Pop as u8]), "stack underflow");
+    assert_eq!(
+        rejection(&u32_operand(Opcode::GetName, 0)),
+        "unsupported instruction"
+    );
+    assert_eq!(
+        rejection(&u32_operand(Opcode::PushLiteral, 2)),
+        "invalid table index"
+    );
+    assert_eq!(
+        rejection(&u32_operand(Opcode::ThrowNewTypeError, 1)),
+        "invalid table index"
+    );
+    let mut get_property = vec![Opcode::PushEmptyObject as u8];
+    get_property.extend(u32_operand(Opcode::GetPropertyByName, 1));
+    assert_eq!(rejection(&get_property), "invalid table index");
+
+    let mut jump_inside = u32_operand(Opcode::Jump, 6);
+    jump_inside.extend(u32_operand(Opcode::JumpIfTrue, 0));
+    assert!(matches!(run(&jump_inside), FuzzOutcome::Rejected(_)));
+}
+
+#[test]
+fn max_stack_size_of_unbalanced_bytecode() {
+    use crate::bytecompiler::max_stack_size;
+
+    // Only the path that doesn't take the jump pushes a value before `last`.
+    let bytecode = |last: Opcode| {
+        let mut bytecode = vec![Opcode::PushTrue as u8, Opcode::JumpIfFalse as u8];
+        bytecode.extend_from_slice(&8u32.to_ne_bytes());
+        bytecode.extend([Opcode::PushInt8 as u8, 1, last as u8]);
+        bytecode
+    };
+
+    assert_eq!(
+        max_stack_size(&bytecode(Opcode::PushNull), 0).map_err(|err| err.message),
+        Err("stack height mismatch")
+    );
+    assert_eq!(
+        max_stack_size(&bytecode(Opcode::Pop), 0).map_err(|err| err.message),
+        Err("stack underflow")
+    );
+}
+
+#[test]
+fn opcode_categories() {
+    assert_eq!(Opcode::Add.category(), OpcodeCategory::Arithmetic);
+    assert_eq!(Opcode::TypeOf.category(), OpcodeCategory::Arithmetic);
+    assert_eq!(
+        Opcode::GetPropertyByName.category(),
+        OpcodeCategory::PropertyAccess
+    );
+    assert_eq!(
+        Opcode::SetPrivateField.category(),
+        OpcodeCategory::PropertyAccess
+    );
+    assert_eq!(Opcode::Call.category(), OpcodeCategory::Call);
+    assert_eq!(Opcode::TailCall.category(), OpcodeCategory::Call);
+    assert_eq!(Opcode::GetName.category(), OpcodeCategory::Environment);
+    assert_eq!(
+        Opcode::PopEnvironment.category(),
+        OpcodeCategory::Environment
+    );
+    assert_eq!(Opcode::JumpIfFalse.category(), OpcodeCategory::ControlFlow);
+    assert_eq!(Opcode::Nop.category(), OpcodeCategory::Stack);
+
+    assert_eq!(OpcodeCategory::PropertyAccess.as_str(), "Property access");
+    assert_eq!(OpcodeCategory::ControlFlow.as_str(), "Control flow");
+}
@@ -163,6 +163,11 @@ fn main() {
         delay(200).then(print);
         delay(600).then(print);
         delay(30).then(print);
+
+        (async () => {
+            const elapsed = await delay(100);
+            console.log(`Awaited. elapsed time: ${elapsed * 1000} ms`);
+        })();
     "#;
 
     let now = Instant::now();
@@ -180,7 +185,9 @@ fn main() {
     // Delaying for 200 milliseconds ...
     // Delaying for 600 milliseconds ...
     // Delaying for 30 milliseconds ...
+    // Delaying for 100 milliseconds ...
     // Finished. elapsed time: 30.073821000000002 ms
+    // Awaited. elapsed time: 100.080711 ms
     // Finished. elapsed time: 200.079116 ms
     // Finished. elapsed time: 500.10745099999997 ms
     // Finished. elapsed time: 600.098433 ms
@@ -100,6 +100,7 @@ use internals::{EphemeronBox, ErasedEphemeronBox, ErasedWeakMapBox, WeakMapBox};
 use std::{
     cell::{Cell, RefCell},
     collections::HashMap,
+    marker::PhantomData,
     mem,
     ptr::NonNull,
 };
@@ -127,6 +128,7 @@ thread_local!(static BOA_GC: RefCell<BoaGc> = RefCell::new( BoaGc {
 struct GcConfig {
     threshold: usize,
     used_space_percentage: usize,
+    safe_points_only: bool,
 }
 
 // Setting the defaults to an arbitrary value currently.
@@ -137,6 +139,7 @@ impl Default for GcConfig {
         Self {
             threshold: 1024,
             used_space_percentage: 80,
+            safe_points_only: false,
         }
     }
 }
@@ -265,6 +268,12 @@ impl Allocator {
     }
 
     fn manage_state(gc: &mut BoaGc) {
+        if !gc.config.safe_points_only {
+            Self::collect_over_threshold(gc);
+        }
+    }
+
+    fn collect_over_threshold(gc: &mut BoaGc) {
         if gc.runtime.bytes_allocated > gc.config.threshold {
             Collector::collect(gc);
 
@@ -555,6 +564,59 @@ pub fn force_collect() {
     });
 }
 
+/// Sets whether garbage collections can only run at safe points, until the returned guard is
+/// dropped.
+///
+/// By default, a collection can run on any allocation once the allocated memory exceeds the
+/// collection threshold. When this is enabled, allocations never trigger a collection; instead,
+/// the pending collection runs on the next call to [`safe_point`] or [`force_collect`]. This
+/// applies to the garbage collector of the current thread, and the previous setting is restored
+/// when the guard is dropped, so guards can be nested.
+#[must_use = "the previous setting is restored when the guard is dropped"]
+pub fn set_safe_points_only(value: bool) -> SafePointsOnlyGuard {
+    let previous = BOA_GC
+        .with(|current| mem::replace(&mut current.borrow_mut().config.safe_points_only, value));
+    SafePointsOnlyGuard {
+        previous,
+        _not_send: PhantomData,
+    }
+}
+
+/// Restores the previous value of [`safe_points_only`] when dropped.
+///
+/// This is returned by [`set_safe_points_only`].
+#[derive(Debug)]
+pub struct SafePointsOnlyGuard {
+    previous: bool,
+
+    // The setting belongs to the garbage collector of the thread that created the guard.
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for SafePointsOnlyGuard {
+    fn drop(&mut self) {
+        BOA_GC.with(|current| current.borrow_mut().config.safe_points_only = self.previous);
+    }
+}
+
+/// Returns `true` if garbage collections can only run at safe points.
+#[must_use]
+pub fn safe_points_only() -> bool {
+    BOA_GC.with(|current| current.borrow().config.safe_points_only)
+}
+
+/// Marks a safe point, running a garbage collection if the allocated memory exceeds the
+/// collection threshold.
+pub fn safe_point() {
+    BOA_GC.with(|current| Allocator::collect_over_threshold(&mut current.borrow_mut()));
+}
+
+/// Returns the number of garbage collections that ran on the current thread.
+#[must_use]
+pub fn collections() -> usize {
+    BOA_GC.with(|current| current.borrow().runtime.collections)
+}
+
 #[cfg(test)]
 mod test;
 
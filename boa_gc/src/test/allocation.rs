@@ -1,5 +1,5 @@
 use super::{run_test, Harness};
-use crate::{force_collect, Gc, GcRefCell};
+use crate::{force_collect, safe_point, safe_points_only, set_safe_points_only, Gc, GcRefCell};
 
 #[test]
 fn gc_basic_cell_allocation() {
@@ -29,3 +29,28 @@ fn gc_basic_pointer_alloc() {
         Harness::assert_empty_gc();
     });
 }
+
+#[test]
+fn gc_safe_points_only() {
+    run_test(|| {
+        let guard = set_safe_points_only(true);
+        assert!(safe_points_only());
+
+        let values = (0..1024).map(Gc::new).collect::<Vec<_>>();
+        Harness::assert_collections(0);
+
+        drop(values);
+        safe_point();
+        Harness::assert_collections(1);
+        Harness::assert_empty_gc();
+
+        // Nested guards restore the setting they replaced.
+        let inner = set_safe_points_only(false);
+        assert!(!safe_points_only());
+        drop(inner);
+        assert!(safe_points_only());
+
+        drop(guard);
+        assert!(!safe_points_only());
+    });
+}
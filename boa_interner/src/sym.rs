@@ -141,5 +141,7 @@ static_syms! {
     "__proto__",
     "name",
     "await",
-    ("*default*", DEFAULT_EXPORT)
+    ("*default*", DEFAULT_EXPORT),
+    "assert",
+    "meta"
 }
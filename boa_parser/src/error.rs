@@ -150,6 +150,17 @@ impl Error {
     pub(crate) const fn lex(e: LexError) -> Self {
         Self::Lex { err: e }
     }
+
+    /// Gets the position of the source code where the error occurred, if any.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { span, .. } | Self::Unexpected { span, .. } => Some(span.start()),
+            Self::General { position, .. } => Some(*position),
+            Self::Lex { err } => err.position(),
+            Self::AbruptEnd => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -1,4 +1,5 @@
 //! Boa's lexer cursor that manages the input byte stream.
+use crate::warning::Warning;
 use boa_ast::Position;
 use boa_profiler::Profiler;
 use std::io::{self, Bytes, Error, ErrorKind, Read};
@@ -10,6 +11,7 @@ pub(super) struct Cursor<R> {
     pos: Position,
     module: bool,
     strict: bool,
+    warnings: Vec<Warning>,
 }
 
 impl<R> Cursor<R> {
@@ -51,6 +53,16 @@ impl<R> Cursor<R> {
         self.module = module;
         self.strict = module;
     }
+
+    /// Reports a warning about the lexed source code.
+    pub(super) fn warn(&mut self, warning: Warning) {
+        self.warnings.push(warning);
+    }
+
+    /// Takes the warnings reported since the last call.
+    pub(super) fn take_warnings(&mut self) -> Vec<Warning> {
+        std::mem::take(&mut self.warnings)
+    }
 }
 
 impl<R> Cursor<R>
@@ -64,6 +76,7 @@ where
             pos: Position::new(1, 1),
             strict: false,
             module: false,
+            warnings: Vec::new(),
         }
     }
 
@@ -74,6 +87,7 @@ where
             pos,
             strict: false,
             module: false,
+            warnings: Vec::new(),
         }
     }
 
@@ -221,7 +235,14 @@ where
     pub(super) fn fill_bytes(&mut self, buf: &mut [u8]) -> io::Result<()> {
         let _timer = Profiler::global().start_event("cursor::fill_bytes()", "Lexing");
 
-        self.iter.fill_bytes(buf)
+        self.iter.fill_bytes(buf)?;
+
+        // Keep the position in sync with the consumed characters.
+        for _ in buf.iter().filter(|byte| utf8_is_first_byte(**byte)) {
+            self.next_column();
+        }
+
+        Ok(())
     }
 
     /// Retrieves the next byte.
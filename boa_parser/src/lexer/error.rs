@@ -41,6 +41,15 @@ impl Error {
     {
         Self::Syntax(err.into(), pos.into())
     }
+
+    /// Gets the position of the source code where the error occurred, if any.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        match self {
+            Self::Syntax(_, position) => Some(*position),
+            Self::IO(_) => None,
+        }
+    }
 }
 
 impl fmt::Display for Error {
@@ -41,6 +41,7 @@ use self::{
     string::StringLiteral,
     template::TemplateLiteral,
 };
+use crate::warning::Warning;
 use boa_ast::{Position, Punctuator, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -101,6 +102,11 @@ impl<R> Lexer<R> {
         self.cursor.set_module(module);
     }
 
+    /// Takes the warnings reported by the lexer since the last call.
+    pub(crate) fn take_warnings(&mut self) -> Vec<Warning> {
+        self.cursor.take_warnings()
+    }
+
     /// Creates a new lexer.
     pub fn new(reader: R) -> Self
     where
@@ -1,6 +1,9 @@
 //! This module implements lexing for number literals (123, 787) used in ECMAScript.
 
-use crate::lexer::{token::Numeric, Cursor, Error, Token, TokenKind, Tokenizer};
+use crate::{
+    lexer::{token::Numeric, Cursor, Error, Token, TokenKind, Tokenizer},
+    warning::{Warning, WarningKind},
+};
 use boa_ast::{Position, Span};
 use boa_interner::Interner;
 use boa_profiler::Profiler;
@@ -407,9 +410,11 @@ impl<R> Tokenizer<R> for NumberLiteral {
             }
         };
 
-        Ok(Token::new(
-            TokenKind::NumericLiteral(num),
-            Span::new(start_pos, cursor.pos()),
-        ))
+        let span = Span::new(start_pos, cursor.pos());
+        if legacy_octal && kind == NumericKind::Integer(8) {
+            cursor.warn(Warning::new(WarningKind::LegacyOctalLiteral, span));
+        }
+
+        Ok(Token::new(TokenKind::NumericLiteral(num), span))
     }
 }
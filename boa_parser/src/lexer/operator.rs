@@ -15,7 +15,6 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             }
             Some(_) => $op,
@@ -26,12 +25,10 @@ macro_rules! vop {
             None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
-                $cursor.next_column();
                 $assign_op
             },
             $($case => {
                 $cursor.next_byte()?.expect("Token vanished");
-                $cursor.next_column();
                 $block
             })+,
             _ => $op,
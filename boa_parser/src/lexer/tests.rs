@@ -422,6 +422,44 @@ fn check_positions_codepoint() {
     );
 }
 
+#[test]
+fn check_positions_escapes() {
+    let s = r#""\u0041\x42" x"#;
+    // --------123456789012345
+    let mut lexer = Lexer::new(s.as_bytes());
+    let interner = &mut Interner::default();
+
+    // String token spans the escape sequences.
+    assert_eq!(
+        lexer.next(interner).unwrap().unwrap().span(),
+        span((1, 1), (1, 13))
+    );
+
+    // Identifier token starts on column 14.
+    assert_eq!(
+        lexer.next(interner).unwrap().unwrap().span(),
+        span((1, 14), (1, 15))
+    );
+}
+
+#[test]
+fn check_positions_compound_operators() {
+    let s = "a += b => c";
+    let mut lexer = Lexer::new(s.as_bytes());
+    let interner = &mut Interner::default();
+
+    let spans = [
+        span((1, 1), (1, 2)),
+        span((1, 3), (1, 5)),
+        span((1, 6), (1, 7)),
+        span((1, 8), (1, 10)),
+        span((1, 11), (1, 12)),
+    ];
+    for expected in spans {
+        assert_eq!(lexer.next(interner).unwrap().unwrap().span(), expected);
+    }
+}
+
 #[test]
 fn check_line_numbers() {
     let s = "x\ny\n";
@@ -96,8 +96,10 @@ pub mod error;
 pub mod lexer;
 pub mod parser;
 mod source;
+pub mod warning;
 
 pub use error::Error;
 pub use lexer::Lexer;
 pub use parser::Parser;
 pub use source::Source;
+pub use warning::Warning;
@@ -1,6 +1,7 @@
 use crate::{
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::ParseResult,
+    warning::Warning,
     Error,
 };
 use boa_ast::Position;
@@ -116,6 +117,11 @@ where
         self.lexer.set_module(module);
     }
 
+    /// Takes the warnings reported by the lexer since the last call.
+    pub(super) fn take_warnings(&mut self) -> Vec<Warning> {
+        self.lexer.take_warnings()
+    }
+
     /// Fills the peeking buffer with the next token.
     ///
     /// It will not fill two line terminators one after the other.
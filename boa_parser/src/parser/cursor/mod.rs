@@ -4,9 +4,10 @@ mod buffered_lexer;
 use crate::{
     lexer::{InputElement, Lexer, Token, TokenKind},
     parser::{OrAbrupt, ParseResult},
+    warning::{Warning, WarningKind},
     Error,
 };
-use boa_ast::{Position, Punctuator};
+use boa_ast::{Position, Punctuator, Span};
 use boa_interner::Interner;
 use buffered_lexer::BufferedLexer;
 use std::io::Read;
@@ -36,6 +37,9 @@ pub(super) struct Cursor<R> {
 
     /// Indicate if the cursor is used in `JSON.parse`.
     json_parse: bool,
+
+    /// The warnings reported by the parser.
+    warnings: Vec<Warning>,
 }
 
 impl<R> Cursor<R>
@@ -50,6 +54,7 @@ where
             private_environment_root_index: 0,
             arrow: false,
             json_parse: false,
+            warnings: Vec::new(),
         }
     }
 
@@ -111,6 +116,20 @@ where
         self.buffered_lexer.peek(skip_n, true, interner)
     }
 
+    /// Reports a warning of the given kind for the source code at `span`.
+    pub(super) fn warn(&mut self, kind: WarningKind, span: Span) {
+        self.warnings.push(Warning::new(kind, span));
+    }
+
+    /// Takes the warnings reported by the parser and the lexer since the last call, in source
+    /// order.
+    pub(super) fn take_warnings(&mut self) -> Vec<Warning> {
+        let mut warnings = std::mem::take(&mut self.warnings);
+        warnings.extend(self.buffered_lexer.take_warnings());
+        warnings.sort_by_key(|warning| warning.span().start());
+        warnings
+    }
+
     /// Gets the current strict mode for the cursor.
     pub(super) const fn strict(&self) -> bool {
         self.buffered_lexer.strict()
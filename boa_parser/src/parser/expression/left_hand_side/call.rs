@@ -11,7 +11,9 @@ use super::arguments::Arguments;
 use crate::{
     lexer::TokenKind,
     parser::{
-        expression::{left_hand_side::template::TaggedTemplateLiteral, Expression},
+        expression::{
+            left_hand_side::template::TaggedTemplateLiteral, AssignmentExpression, Expression,
+        },
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     Error,
@@ -23,7 +25,7 @@ use boa_ast::{
         access::{PrivatePropertyAccess, SimplePropertyAccess},
         Call,
     },
-    Punctuator,
+    Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -40,14 +42,18 @@ pub(super) struct CallExpression {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     first_member_expr: ast::Expression,
+    start: Position,
 }
 
 impl CallExpression {
     /// Creates a new `CallExpression` parser.
+    ///
+    /// `start` is the start position of the first member expression.
     pub(super) fn new<Y, A>(
         allow_yield: Y,
         allow_await: A,
         first_member_expr: ast::Expression,
+        start: Position,
     ) -> Self
     where
         Y: Into<AllowYield>,
@@ -57,6 +63,7 @@ impl CallExpression {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
             first_member_expr,
+            start,
         }
     }
 }
@@ -72,10 +79,13 @@ where
 
         let token = cursor.peek(0, interner).or_abrupt()?;
 
-        let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+        let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+            let span = Span::new(self.start, token.span().end());
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::new(self.first_member_expr, args)
+                .with_span(span)
+                .into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -86,13 +96,68 @@ where
             ));
         };
 
+        CallExpressionTail::new(self.allow_yield, self.allow_await, lhs, self.start)
+            .parse(cursor, interner)
+    }
+}
+
+/// Parses the tail of a call expression, which are the calls, property accesses and tagged
+/// templates that follow its first call.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-CallExpression
+#[derive(Debug)]
+pub(super) struct CallExpressionTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    call: ast::Expression,
+    start: Position,
+}
+
+impl CallExpressionTail {
+    /// Creates a new `CallExpressionTail` parser.
+    ///
+    /// `start` is the start position of the first call expression.
+    pub(super) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        call: ast::Expression,
+        start: Position,
+    ) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            call,
+            start,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for CallExpressionTail
+where
+    R: Read,
+{
+    type Output = ast::Expression;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("CallExpressionTail", "Parsing");
+
+        let mut lhs = self.call;
+
         while let Some(tok) = cursor.peek(0, interner)? {
             let token = tok.clone();
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let span = Span::new(self.start, token.span().end());
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs = ast::Expression::from(Call::new(lhs, args).with_span(span));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.advance(interner);
@@ -156,3 +221,63 @@ where
         Ok(lhs)
     }
 }
+
+/// Parses an import call, like `import("./module.js")`.
+///
+/// The `import` keyword must already have been consumed.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportCall
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ImportCall {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ImportCall {
+    /// Creates a new `ImportCall` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ImportCall
+where
+    R: Read,
+{
+    type Output = ast::expression::ImportCall;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("ImportCall", "Parsing");
+
+        cursor.expect(Punctuator::OpenParen, "import call", interner)?;
+
+        let arg = AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+            .parse(cursor, interner)?;
+
+        let mut options = None;
+        if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+            && cursor.peek(0, interner).or_abrupt()?.kind()
+                != &TokenKind::Punctuator(Punctuator::CloseParen)
+        {
+            options = Some(
+                AssignmentExpression::new(None, true, self.allow_yield, self.allow_await)
+                    .parse(cursor, interner)?,
+            );
+            cursor.next_if(Punctuator::Comma, interner)?;
+        }
+
+        cursor.expect(Punctuator::CloseParen, "import call", interner)?;
+
+        Ok(ast::expression::ImportCall::new(arg, options))
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
         },
         AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
+    warning::WarningKind,
     Error,
 };
 use ast::function::PrivateName;
@@ -25,7 +26,7 @@ use boa_ast::{
         },
         Call, Identifier, New,
     },
-    Keyword, Punctuator,
+    Keyword, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -72,14 +73,16 @@ where
         cursor.set_goal(InputElement::RegExp);
 
         let token = cursor.peek(0, interner).or_abrupt()?;
+        let start = token.span().start();
         let mut lhs = match token.kind() {
-            TokenKind::Keyword((Keyword::New | Keyword::Super, true)) => {
+            TokenKind::Keyword((Keyword::New | Keyword::Super | Keyword::Import, true)) => {
                 return Err(Error::general(
                     "keyword must not contain escaped characters",
                     token.span().start(),
                 ));
             }
             TokenKind::Keyword((Keyword::New, false)) => {
+                let mut end = token.span().end();
                 cursor.advance(interner);
 
                 if cursor.next_if(Punctuator::Dot, interner)?.is_some() {
@@ -106,15 +109,47 @@ where
                 let lhs = self.parse(cursor, interner)?;
                 let args = match cursor.peek(0, interner)? {
                     Some(next) if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) => {
+                        end = next.span().end();
                         Arguments::new(self.allow_yield, self.allow_await)
                             .parse(cursor, interner)?
                     }
                     _ => Box::new([]),
                 };
-                let call_node = Call::new(lhs, args);
+                let call_node = Call::new(lhs, args).with_span(Span::new(start, end));
 
                 ast::Expression::from(New::from(call_node))
             }
+            TokenKind::Keyword((Keyword::Import, false)) => {
+                cursor.advance(interner);
+                cursor.expect(Punctuator::Dot, "import.meta", interner)?;
+                let token = cursor.next(interner).or_abrupt()?;
+                match token.kind() {
+                    TokenKind::IdentifierName((Sym::META, ContainsEscapeSequence(true))) => {
+                        return Err(Error::general(
+                            "'import.meta' must not contain escaped characters",
+                            token.span().start(),
+                        ));
+                    }
+                    TokenKind::IdentifierName((Sym::META, ContainsEscapeSequence(false))) => {}
+                    _ => {
+                        return Err(Error::expected(
+                            ["meta".to_owned()],
+                            token.to_string(interner),
+                            token.span(),
+                            "import.meta",
+                        ));
+                    }
+                }
+
+                if !cursor.module() {
+                    return Err(Error::general(
+                        "'import.meta' is only allowed in module code",
+                        start,
+                    ));
+                }
+
+                ast::Expression::ImportMeta
+            }
             TokenKind::Keyword((Keyword::Super, _)) => {
                 cursor.advance(interner);
                 let token = cursor.next(interner).or_abrupt()?;
@@ -185,6 +220,19 @@ where
 
                     let access = match token.kind() {
                         TokenKind::IdentifierName((name, _)) => {
+                            let arguments = matches!(
+                                &lhs,
+                                ast::Expression::Identifier(ident) if ident.sym() == Sym::ARGUMENTS
+                            );
+                            if arguments
+                                && !cursor.strict()
+                                && interner.get("callee") == Some(*name)
+                            {
+                                cursor.warn(
+                                    WarningKind::ArgumentsCallee,
+                                    Span::new(start, token.span().end()),
+                                );
+                            }
                             SimplePropertyAccess::new(lhs, *name).into()
                         }
                         TokenKind::Keyword((kw, _)) => {
@@ -20,17 +20,20 @@ use crate::{
     lexer::{InputElement, TokenKind},
     parser::{
         expression::left_hand_side::{
-            arguments::Arguments, call::CallExpression, member::MemberExpression,
+            arguments::Arguments,
+            call::{CallExpression, CallExpressionTail, ImportCall},
+            member::MemberExpression,
             optional::OptionalExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
+    warning::WarningKind,
 };
 use boa_ast::{
     expression::{Identifier, SuperCall},
     Expression, Keyword, Punctuator,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 use std::io::Read;
 
@@ -96,6 +99,26 @@ where
             }
             Ok(false)
         }
+
+        /// Checks if we need to parse an import call expression `import()`.
+        ///
+        /// Like [`is_super_call`], it checks that the next token is `import` and that the
+        /// second next token is the open parenthesis (`(`) punctuator.
+        fn is_import_call<R: Read>(
+            cursor: &mut Cursor<R>,
+            interner: &mut Interner,
+        ) -> ParseResult<bool> {
+            if let Some(next) = cursor.peek(0, interner)? {
+                if matches!(next.kind(), TokenKind::Keyword((Keyword::Import, false))) {
+                    if let Some(next) = cursor.peek(1, interner)? {
+                        if next.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+                            return Ok(true);
+                        }
+                    }
+                }
+            }
+            Ok(false)
+        }
         let _timer = Profiler::global().start_event("LeftHandSideExpression", "Parsing");
 
         cursor.set_goal(InputElement::TemplateTail);
@@ -105,13 +128,33 @@ where
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
             SuperCall::new(args).into()
+        } else if is_import_call(cursor, interner)? {
+            let start = cursor.peek(0, interner).or_abrupt()?.span().start();
+            cursor.advance(interner);
+            let call =
+                ImportCall::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
+            CallExpressionTail::new(self.allow_yield, self.allow_await, call.into(), start)
+                .parse(cursor, interner)?
         } else {
+            let span = cursor.peek(0, interner).or_abrupt()?.span();
             let mut member = MemberExpression::new(self.name, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
             if let Some(tok) = cursor.peek(0, interner)? {
                 if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                    member = CallExpression::new(self.allow_yield, self.allow_await, member)
-                        .parse(cursor, interner)?;
+                    let eval = matches!(
+                        &member,
+                        Expression::Identifier(ident) if ident.sym() == Sym::EVAL
+                    );
+                    if eval && !cursor.strict() {
+                        cursor.warn(WarningKind::SloppyDirectEval, span);
+                    }
+                    member = CallExpression::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        member,
+                        span.start(),
+                    )
+                    .parse(cursor, interner)?;
                 }
             }
             member
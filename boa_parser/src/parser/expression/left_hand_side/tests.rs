@@ -1,9 +1,12 @@
-use crate::parser::tests::check_script_parser;
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Parser, Source,
+};
 use boa_ast::{
-    expression::{access::SimplePropertyAccess, Call, Identifier},
-    Expression, Statement,
+    expression::{access::SimplePropertyAccess, literal::Literal, Call, Identifier, ImportCall},
+    Expression, ModuleItem, Statement, StatementListItem,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, ToInternedString};
 use boa_macros::utf16;
 
 macro_rules! check_call_property_identifier {
@@ -63,3 +66,81 @@ fn check_member_properties() {
     check_member_property_identifier!("null");
     check_member_property_identifier!("let");
 }
+
+#[test]
+fn check_import_call() {
+    let interner = &mut Interner::default();
+    let then = interner.get_or_intern_static("then", utf16!("then"));
+    let specifier = interner.get_or_intern_static("./module.js", utf16!("./module.js"));
+    check_script_parser(
+        "import('./module.js').then();",
+        vec![Statement::Expression(Expression::Call(Call::new(
+            Expression::PropertyAccess(
+                SimplePropertyAccess::new(
+                    ImportCall::new(Literal::from(specifier), None).into(),
+                    then,
+                )
+                .into(),
+            ),
+            Box::default(),
+        )))
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_import_call_options() {
+    let interner = &mut Interner::default();
+    let specifier = interner.get_or_intern_static("./module.js", utf16!("./module.js"));
+    let options = Identifier::new(interner.get_or_intern_static("options", utf16!("options")));
+    check_script_parser(
+        "import('./module.js', options,);",
+        vec![Statement::Expression(
+            ImportCall::new(Literal::from(specifier), Some(options.into())).into(),
+        )
+        .into()],
+        interner,
+    );
+}
+
+#[test]
+fn check_invalid_import_call() {
+    check_invalid_script("import();");
+    check_invalid_script("import('a', 'b', 'c');");
+    check_invalid_script("new import('a');");
+}
+
+#[test]
+fn check_import_meta() {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes("import.meta.foo;"))
+        .parse_module(interner)
+        .expect("failed to parse");
+
+    let foo = interner.get_or_intern_static("foo", utf16!("foo"));
+    let expected =
+        Expression::PropertyAccess(SimplePropertyAccess::new(Expression::ImportMeta, foo).into());
+    let [ModuleItem::StatementListItem(StatementListItem::Statement(Statement::Expression(expr)))] =
+        module.items()
+    else {
+        panic!("expected a single expression statement");
+    };
+    assert_eq!(expr, &expected);
+    assert_eq!(expr.to_interned_string(interner), "import.meta.foo");
+}
+
+#[test]
+fn check_invalid_import_meta() {
+    // `import.meta` is only valid in modules.
+    check_invalid_script("import.meta.foo;");
+
+    for source in ["import.m\\u0065ta;", "import.other;", "import;"] {
+        assert!(
+            Parser::new(Source::from_bytes(source))
+                .parse_module(&mut Interner::default())
+                .is_err(),
+            "{source} should not parse"
+        );
+    }
+}
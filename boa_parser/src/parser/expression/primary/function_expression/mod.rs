@@ -23,7 +23,7 @@ use boa_ast::{
     expression::Identifier,
     function::Function,
     operations::{bound_names, contains, top_level_lexically_declared_names, ContainsSymbol},
-    Keyword, Punctuator,
+    Keyword, Position, Punctuator, Span,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -40,15 +40,21 @@ use std::io::Read;
 #[derive(Debug, Clone, Copy)]
 pub(super) struct FunctionExpression {
     name: Option<Identifier>,
+    start: Position,
 }
 
 impl FunctionExpression {
     /// Creates a new `FunctionExpression` parser.
-    pub(in crate::parser) fn new<N>(name: N) -> Self
+    ///
+    /// `start` is the position of the `function` keyword that starts the expression.
+    pub(in crate::parser) fn new<N>(name: N, start: Position) -> Self
     where
         N: Into<Option<Identifier>>,
     {
-        Self { name: name.into() }
+        Self {
+            name: name.into(),
+            start,
+        }
     }
 }
 
@@ -88,7 +94,10 @@ where
 
         let body = FunctionBody::new(false, false).parse(cursor, interner)?;
 
-        cursor.expect(Punctuator::CloseBlock, "function expression", interner)?;
+        let end = cursor
+            .expect(Punctuator::CloseBlock, "function expression", interner)?
+            .span()
+            .end();
 
         // Early Error: If the source code matching FormalParameters is strict mode code,
         // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
@@ -141,7 +150,8 @@ where
         )?;
 
         let function =
-            Function::new_with_binding_identifier(name.or(self.name), params, body, name.is_some());
+            Function::new_with_binding_identifier(name.or(self.name), params, body, name.is_some())
+                .with_span(Span::new(self.start, end));
 
         if contains(&function, ContainsSymbol::Super) {
             return Err(Error::lex(LexError::Syntax(
@@ -1,10 +1,10 @@
-use crate::parser::tests::check_script_parser;
+use crate::{parser::tests::check_script_parser, Parser, Source};
 use boa_ast::{
     declaration::{LexicalDeclaration, Variable},
     expression::literal::Literal,
     function::{FormalParameterList, Function},
     statement::Return,
-    Declaration, Statement, StatementListItem,
+    Declaration, Expression, Position, Span, Statement, StatementListItem,
 };
 use boa_interner::Interner;
 use boa_macros::utf16;
@@ -152,3 +152,28 @@ fn check_function_non_reserved_keyword() {
         interner,
     );
 }
+
+#[test]
+fn check_function_expression_span() {
+    let interner = &mut Interner::default();
+    let script = Parser::new(Source::from_bytes(
+        "let a = 'x';\nlet f = function(x) {\n};",
+    ))
+    .parse_script(interner)
+    .expect("failed to parse");
+
+    let span = match &script.statements()[1] {
+        StatementListItem::Declaration(Declaration::Lexical(LexicalDeclaration::Let(list))) => {
+            match list.as_ref()[0].init() {
+                Some(Expression::Function(function)) => function.span(),
+                _ => panic!("expected a function expression"),
+            }
+        }
+        _ => panic!("expected a lexical declaration"),
+    };
+
+    assert_eq!(
+        span,
+        Some(Span::new(Position::new(2, 9), Position::new(3, 2)))
+    );
+}
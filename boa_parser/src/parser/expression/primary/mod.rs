@@ -44,7 +44,7 @@ use boa_ast::{
     expression::{
         literal::Literal,
         operator::{assign::AssignTarget, binary::BinaryOp},
-        Call, Identifier, New, Parenthesized,
+        Identifier, Parenthesized, RegExpLiteral,
     },
     function::{FormalParameter, FormalParameterList},
     operations::{contains, ContainsSymbol},
@@ -119,7 +119,7 @@ where
                         .parse(cursor, interner)
                         .map(Into::into)
                 } else {
-                    FunctionExpression::new(self.name)
+                    FunctionExpression::new(self.name, tok_position)
                         .parse(cursor, interner)
                         .map(Into::into)
                 }
@@ -240,10 +240,7 @@ where
                 Ok(node)
             }
             TokenKind::RegularExpressionLiteral(body, flags) => {
-                let node = ast::Expression::from(New::from(Call::new(
-                    Identifier::new(Sym::REGEXP).into(),
-                    vec![Literal::from(*body).into(), Literal::from(*flags).into()].into(),
-                )));
+                let node = ast::Expression::from(RegExpLiteral::new(*body, *flags));
                 cursor.advance(interner);
                 Ok(node)
             }
@@ -253,10 +250,7 @@ where
                 let tok = cursor.lex_regex(position, interner)?;
 
                 if let TokenKind::RegularExpressionLiteral(body, flags) = *tok.kind() {
-                    Ok(ast::Expression::from(New::from(Call::new(
-                        Identifier::new(Sym::REGEXP).into(),
-                        vec![Literal::from(body).into(), Literal::from(flags).into()].into(),
-                    ))))
+                    Ok(ast::Expression::from(RegExpLiteral::new(body, flags)))
                 } else {
                     // A regex was expected and nothing else.
                     Err(Error::unexpected(
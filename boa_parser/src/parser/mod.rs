@@ -14,11 +14,13 @@ use crate::{
     lexer::Error as LexError,
     parser::{
         cursor::Cursor,
+        expression::Expression,
         function::{FormalParameters, FunctionStatementList},
     },
-    Error, Source,
+    Error, Source, Warning,
 };
 use boa_ast::{
+    self as ast,
     expression::Identifier,
     function::FormalParameterList,
     operations::{
@@ -26,7 +28,7 @@ use boa_ast::{
         top_level_lexically_declared_names, top_level_var_declared_names, var_declared_names,
         ContainsSymbol,
     },
-    ModuleItemList, Position, StatementList,
+    ModuleItemList, Position, Punctuator, StatementList,
 };
 use boa_interner::Interner;
 use rustc_hash::FxHashSet;
@@ -200,9 +202,56 @@ impl<'a, R: Read> Parser<'a, R> {
     ) -> ParseResult<FormalParameterList> {
         FormalParameters::new(allow_yield, allow_await).parse(&mut self.cursor, interner)
     }
+
+    /// Parses the full input as an [ECMAScript `Expression`][spec] into the boa AST representation.
+    ///
+    /// The input is always parsed as an expression, even if it is also a valid statement, so
+    /// `{a: 1}` is parsed as an object literal instead of a block. A trailing semicolon is
+    /// allowed.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` on any parsing error, including invalid reads of the bytes being parsed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Expression
+    pub fn parse_expression(&mut self, interner: &mut Interner) -> ParseResult<ast::Expression> {
+        let start = self
+            .cursor
+            .peek(0, interner)?
+            .ok_or(Error::AbruptEnd)?
+            .span()
+            .start();
+        let expression =
+            Expression::new(None, true, false, false).parse(&mut self.cursor, interner)?;
+        self.cursor.next_if(Punctuator::Semicolon, interner)?;
+
+        if let Some(token) = self.cursor.peek(0, interner)? {
+            return Err(Error::unexpected(
+                token.to_string(interner),
+                token.span(),
+                "expected end of expression",
+            ));
+        }
+
+        if contains_invalid_object_literal(&expression) {
+            return Err(Error::general("invalid object literal in expression", start));
+        }
+
+        Ok(expression)
+    }
 }
 
 impl<R> Parser<'_, R> {
+    /// Takes the warnings reported while parsing since the last call, in source order.
+    ///
+    /// See [`WarningKind`](crate::warning::WarningKind) for the features that are reported.
+    pub fn take_warnings(&mut self) -> Vec<Warning>
+    where
+        R: Read,
+    {
+        self.cursor.take_warnings()
+    }
+
     /// Set the parser strict mode to true.
     pub fn set_strict(&mut self)
     where
@@ -20,6 +20,7 @@ use crate::{
 };
 use boa_ast::{
     declaration::{ExportDeclaration as AstExportDeclaration, ReExportKind},
+    expression::Identifier,
     Keyword, Punctuator,
 };
 use boa_interner::{Interner, Sym};
@@ -59,7 +60,7 @@ where
 
                 let next = cursor.peek(0, interner).or_abrupt()?;
 
-                match next.kind() {
+                let export = match next.kind() {
                     TokenKind::IdentifierName((Sym::AS, _)) => {
                         cursor.advance(interner);
                         let tok = cursor.next(interner).or_abrupt()?;
@@ -81,7 +82,9 @@ where
                             FromClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
-                            kind: ReExportKind::Namespaced { name: Some(alias) },
+                            kind: ReExportKind::Namespaced {
+                                name: Identifier::new(alias),
+                            },
                             specifier,
                         }
                     }
@@ -90,7 +93,7 @@ where
                             FromClause::new("export declaration").parse(cursor, interner)?;
 
                         AstExportDeclaration::ReExport {
-                            kind: ReExportKind::Namespaced { name: None },
+                            kind: ReExportKind::All,
                             specifier,
                         }
                     }
@@ -102,14 +105,16 @@ where
                             "export declaration",
                         ))
                     }
-                }
+                };
+                cursor.expect_semicolon("export declaration", interner)?;
+                export
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let names = NamedExports.parse(cursor, interner)?;
 
                 let next = cursor.peek(0, interner).or_abrupt()?;
 
-                if matches!(
+                let export = if matches!(
                     next.kind(),
                     TokenKind::IdentifierName((Sym::FROM, ContainsEscapeSequence(false)))
                 ) {
@@ -121,7 +126,9 @@ where
                     }
                 } else {
                     AstExportDeclaration::List(names)
-                }
+                };
+                cursor.expect_semicolon("export declaration", interner)?;
+                export
             }
             TokenKind::Keyword((Keyword::Var, false)) => VariableStatement::new(false, true)
                 .parse(cursor, interner)
@@ -165,10 +172,12 @@ where
                             ClassDeclaration::new(false, true, true).parse(cursor, interner)?,
                         )
                     }
-                    _ => AstExportDeclaration::DefaultAssignmentExpression(
-                        AssignmentExpression::new(None, true, false, true)
-                            .parse(cursor, interner)?,
-                    ),
+                    _ => {
+                        let expression = AssignmentExpression::new(None, true, false, true)
+                            .parse(cursor, interner)?;
+                        cursor.expect_semicolon("export declaration", interner)?;
+                        AstExportDeclaration::DefaultAssignmentExpression(expression)
+                    }
                 }
             }
             _ => AstExportDeclaration::Declaration(
@@ -201,9 +210,10 @@ where
         let mut list = Vec::new();
 
         loop {
-            let tok = cursor.next(interner).or_abrupt()?;
+            let tok = cursor.peek(0, interner).or_abrupt()?;
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                    cursor.advance(interner);
                     break;
                 }
                 TokenKind::Punctuator(Punctuator::Comma) => {
@@ -219,6 +229,7 @@ where
                             "export declaration",
                         ));
                     }
+                    cursor.advance(interner);
                 }
                 TokenKind::StringLiteral(_) | TokenKind::IdentifierName(_) => {
                     list.push(ExportSpecifier.parse(cursor, interner)?);
@@ -5,7 +5,7 @@ use crate::parser::{
     statement::declaration::hoistable::{parse_callable_declaration, CallableDeclaration},
     AllowAwait, AllowDefault, AllowYield, Cursor, ParseResult, TokenParser,
 };
-use boa_ast::{function::Function, Keyword};
+use boa_ast::{function::Function, Keyword, Span};
 use boa_interner::Interner;
 use std::io::Read;
 
@@ -75,10 +75,13 @@ where
     type Output = Function;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
-        cursor.expect((Keyword::Function, false), "function declaration", interner)?;
+        let start = cursor
+            .expect((Keyword::Function, false), "function declaration", interner)?
+            .span()
+            .start();
 
         let result = parse_callable_declaration(&self, cursor, interner)?;
 
-        Ok(Function::new(Some(result.0), result.1, result.2))
+        Ok(Function::new(Some(result.0), result.1, result.2).with_span(Span::new(start, result.3)))
     }
 }
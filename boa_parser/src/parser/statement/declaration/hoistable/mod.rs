@@ -30,7 +30,7 @@ use boa_ast::{
     expression::Identifier,
     function::FormalParameterList,
     operations::{bound_names, contains, top_level_lexically_declared_names, ContainsSymbol},
-    Declaration, Keyword, Punctuator, StatementList,
+    Declaration, Keyword, Position, Punctuator, StatementList,
 };
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
@@ -148,7 +148,7 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
     c: &C,
     cursor: &mut Cursor<R>,
     interner: &mut Interner,
-) -> ParseResult<(Identifier, FormalParameterList, StatementList)> {
+) -> ParseResult<(Identifier, FormalParameterList, StatementList, Position)> {
     let token = cursor.peek(0, interner).or_abrupt()?;
     let name_span = token.span();
     let name = match token.kind() {
@@ -180,7 +180,10 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
     let body =
         FunctionBody::new(c.body_allow_yield(), c.body_allow_await()).parse(cursor, interner)?;
 
-    cursor.expect(Punctuator::CloseBlock, c.error_context(), interner)?;
+    let end = cursor
+        .expect(Punctuator::CloseBlock, c.error_context(), interner)?
+        .span()
+        .end();
 
     // If the source text matched by FormalParameters is strict mode code,
     // the Early Error rules for UniqueFormalParameters : FormalParameters are applied.
@@ -258,5 +261,5 @@ fn parse_callable_declaration<R: Read, C: CallableDeclaration>(
         }
     }
 
-    Ok((name, params, body))
+    Ok((name, params, body, end))
 }
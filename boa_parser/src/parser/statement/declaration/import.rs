@@ -10,7 +10,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
 
 use crate::{
-    lexer::TokenKind,
+    lexer::{token::ContainsEscapeSequence, TokenKind},
     parser::{
         cursor::Cursor,
         statement::{declaration::FromClause, BindingIdentifier},
@@ -19,8 +19,8 @@ use crate::{
 };
 use boa_ast::{
     declaration::{
-        ImportDeclaration as AstImportDeclaration, ImportKind,
-        ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
+        ImportAttribute as AstImportAttribute, ImportDeclaration as AstImportDeclaration,
+        ImportKind, ImportSpecifier as AstImportSpecifier, ModuleSpecifier,
     },
     expression::Identifier,
     Keyword, Punctuator,
@@ -56,12 +56,14 @@ where
                 let module_identifier = *module_identifier;
 
                 cursor.advance(interner);
+                let attributes = ImportAttributes.parse(cursor, interner)?;
                 cursor.expect_semicolon("import declaration", interner)?;
 
                 return Ok(AstImportDeclaration::new(
                     None,
                     ImportKind::DefaultOrUnnamed,
                     ModuleSpecifier::new(module_identifier),
+                    attributes,
                 ));
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
@@ -124,8 +126,10 @@ where
         };
 
         let module_identifier = FromClause::new("import declaration").parse(cursor, interner)?;
+        let attributes = ImportAttributes.parse(cursor, interner)?;
+        cursor.expect_semicolon("import declaration", interner)?;
 
-        Ok(import_clause.with_specifier(module_identifier))
+        Ok(import_clause.with_specifier(module_identifier, attributes))
     }
 }
 
@@ -171,9 +175,10 @@ where
         let mut list = Vec::new();
 
         loop {
-            let tok = cursor.next(interner).or_abrupt()?;
+            let tok = cursor.peek(0, interner).or_abrupt()?;
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
+                    cursor.advance(interner);
                     break;
                 }
                 TokenKind::Punctuator(Punctuator::Comma) => {
@@ -189,6 +194,7 @@ where
                             "import declaration",
                         ));
                     }
+                    cursor.advance(interner);
                 }
                 TokenKind::StringLiteral(_) | TokenKind::IdentifierName(_) => {
                     list.push(ImportSpecifier.parse(cursor, interner)?);
@@ -226,19 +232,22 @@ enum ImportClause {
 impl ImportClause {
     #[inline]
     #[allow(clippy::missing_const_for_fn)]
-    fn with_specifier(self, specifier: ModuleSpecifier) -> AstImportDeclaration {
-        match self {
-            Self::Namespace(default, binding) => {
-                AstImportDeclaration::new(default, ImportKind::Namespaced { binding }, specifier)
-            }
+    fn with_specifier(
+        self,
+        specifier: ModuleSpecifier,
+        attributes: Box<[AstImportAttribute]>,
+    ) -> AstImportDeclaration {
+        let (default, kind) = match self {
+            Self::Namespace(default, binding) => (default, ImportKind::Namespaced { binding }),
             Self::ImportList(default, names) => {
                 if names.is_empty() {
-                    AstImportDeclaration::new(default, ImportKind::DefaultOrUnnamed, specifier)
+                    (default, ImportKind::DefaultOrUnnamed)
                 } else {
-                    AstImportDeclaration::new(default, ImportKind::Named { names }, specifier)
+                    (default, ImportKind::Named { names })
                 }
             }
-        }
+        };
+        AstImportDeclaration::new(default, kind, specifier, attributes)
     }
 }
 
@@ -276,7 +285,7 @@ where
 
                 let binding = ImportedBinding.parse(cursor, interner)?;
 
-                Ok(AstImportSpecifier::new(binding, *name))
+                Ok(AstImportSpecifier::from_string_literal(binding, *name))
             }
             TokenKind::IdentifierName((name, _)) => {
                 if cursor
@@ -325,3 +334,104 @@ where
         ImportedBinding.parse(cursor, interner)
     }
 }
+
+/// Parses the optional attributes of an import declaration.
+///
+/// Both the `with { ... }` form and the older `assert { ... }` form are accepted. Returns an empty
+/// list if the declaration has no attributes.
+///
+/// More information:
+///  - [Import Attributes proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-import-attributes/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct ImportAttributes;
+
+impl<R> TokenParser<R> for ImportAttributes
+where
+    R: Read,
+{
+    type Output = Box<[AstImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let Some(tok) = cursor.peek(0, interner)? else {
+            return Ok(Box::default());
+        };
+
+        match tok.kind() {
+            TokenKind::Keyword((Keyword::With, false)) => {}
+            // The older `assert` form doesn't allow a line terminator before the keyword.
+            TokenKind::IdentifierName((Sym::ASSERT, ContainsEscapeSequence(false))) => {
+                if cursor.peek_is_line_terminator(0, interner)? != Some(false) {
+                    return Ok(Box::default());
+                }
+            }
+            _ => return Ok(Box::default()),
+        }
+
+        cursor.advance(interner);
+        cursor.expect(Punctuator::OpenBlock, "import attributes", interner)?;
+
+        let mut attributes: Vec<AstImportAttribute> = Vec::new();
+
+        loop {
+            let tok = cursor.next(interner).or_abrupt()?;
+            let key = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::IdentifierName((name, _)) | TokenKind::StringLiteral((name, _)) => *name,
+                TokenKind::Keyword((keyword, _)) => keyword.to_sym(),
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::CloseBlock.to_string(),
+                            "string literal".to_owned(),
+                            "identifier".to_owned(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        "import attributes",
+                    ));
+                }
+            };
+
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    "duplicate key in import attributes",
+                    tok.span().start(),
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, "import attributes", interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    "import attributes",
+                ));
+            };
+            attributes.push(AstImportAttribute::new(key, *value));
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::Comma.to_string(),
+                            Punctuator::CloseBlock.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        "import attributes",
+                    ));
+                }
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
@@ -1,11 +1,20 @@
-use crate::parser::tests::{check_invalid_script, check_script_parser};
+use crate::{
+    parser::tests::{check_invalid_script, check_script_parser},
+    Parser, Source,
+};
 use boa_ast::{
-    declaration::{LexicalDeclaration, VarDeclaration, Variable},
-    expression::literal::Literal,
-    Declaration, Statement,
+    declaration::{
+        ExportDeclaration, ImportKind, LexicalDeclaration, ModuleSpecifier, ReExportKind,
+        VarDeclaration, Variable,
+    },
+    expression::{literal::Literal, Identifier},
+    visitor::{VisitWith, Visitor},
+    Declaration, ModuleItem, Statement,
 };
 use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
+use std::convert::Infallible;
+use std::ops::ControlFlow;
 
 /// Checks `var` declaration parsing.
 #[test]
@@ -349,3 +358,203 @@ fn multiple_const_declaration() {
         interner,
     );
 }
+
+/// Parses `js` as a module, returning the attributes of its import declarations as `(key, value)`
+/// pairs.
+fn import_attributes(js: &str, interner: &mut Interner) -> Option<Vec<Vec<(Sym, Sym)>>> {
+    let module = Parser::new(Source::from_bytes(js))
+        .parse_module(interner)
+        .ok()?;
+    Some(
+        module
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::ImportDeclaration(import) => Some(
+                    import
+                        .attributes()
+                        .iter()
+                        .map(|attribute| (attribute.key(), attribute.value()))
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Checks the `with` and `assert` forms of import attributes.
+#[test]
+fn import_attributes_forms() {
+    let interner = &mut Interner::default();
+    let attributes = import_attributes(
+        r#"
+        import data from "./x.json" with { type: "json" };
+        import other from "./y.json" assert { "type": "json", };
+        import "./z.js" with {};
+        import plain from "./w.js";
+        "#,
+        interner,
+    )
+    .expect("failed to parse");
+
+    let ty = interner.get_or_intern_static("type", utf16!("type"));
+    let json = interner.get_or_intern_static("json", utf16!("json"));
+    assert_eq!(
+        attributes,
+        vec![vec![(ty, json)], vec![(ty, json)], Vec::new(), Vec::new()]
+    );
+}
+
+/// Checks that invalid import attributes are rejected.
+#[test]
+fn import_attributes_invalid() {
+    let interner = &mut Interner::default();
+    // Duplicate keys, including a string literal key with the same value as an identifier key.
+    assert!(import_attributes(
+        r#"import data from "./x.json" with { type: "json", "type": "json" };"#,
+        interner,
+    )
+    .is_none());
+    // Attribute values must be string literals.
+    assert!(import_attributes(
+        r#"import data from "./x.json" with { type: json };"#,
+        interner
+    )
+    .is_none());
+    // The `assert` form can't be preceded by a line terminator.
+    assert!(import_attributes(
+        "import data from \"./x.json\"\nassert { type: \"json\" };",
+        interner,
+    )
+    .is_none());
+}
+
+/// Collects the identifiers and module specifiers reached by the visitor.
+#[derive(Default)]
+struct ReExportVisitor {
+    identifiers: Vec<Sym>,
+    specifiers: Vec<Sym>,
+}
+
+impl<'ast> Visitor<'ast> for ReExportVisitor {
+    type BreakTy = Infallible;
+
+    fn visit_identifier(&mut self, node: &'ast Identifier) -> ControlFlow<Self::BreakTy> {
+        self.identifiers.push(node.sym());
+        ControlFlow::Continue(())
+    }
+
+    fn visit_module_specifier(
+        &mut self,
+        node: &'ast ModuleSpecifier,
+    ) -> ControlFlow<Self::BreakTy> {
+        self.specifiers.push(node.sym());
+        ControlFlow::Continue(())
+    }
+}
+
+/// Checks that `export * as ns from "mod"` is parsed as a namespaced re-export.
+#[test]
+fn namespaced_re_export() {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(r#"export * as ns from "mod";"#))
+        .parse_module(interner)
+        .expect("failed to parse");
+
+    let ns = interner.get_or_intern_static("ns", utf16!("ns"));
+    let specifier = interner.get_or_intern_static("mod", utf16!("mod"));
+    let [ModuleItem::ExportDeclaration(ExportDeclaration::ReExport { kind, .. })] = module.items()
+    else {
+        panic!("expected a single re-export");
+    };
+    assert!(matches!(kind, ReExportKind::Namespaced { name } if name.sym() == ns));
+
+    let mut visitor = ReExportVisitor::default();
+    assert!(module.visit_with(&mut visitor).is_continue());
+    assert_eq!(visitor.identifiers, vec![ns]);
+    assert_eq!(visitor.specifiers, vec![specifier]);
+}
+
+/// Checks that `export * from "mod"` is distinguished from the namespaced form.
+#[test]
+fn bare_re_export() {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(r#"export * from "mod";"#))
+        .parse_module(interner)
+        .expect("failed to parse");
+
+    let specifier = interner.get_or_intern_static("mod", utf16!("mod"));
+    let [ModuleItem::ExportDeclaration(ExportDeclaration::ReExport { kind, .. })] = module.items()
+    else {
+        panic!("expected a single re-export");
+    };
+    assert!(matches!(kind, ReExportKind::All));
+
+    let mut visitor = ReExportVisitor::default();
+    assert!(module.visit_with(&mut visitor).is_continue());
+    assert!(visitor.identifiers.is_empty());
+    assert_eq!(visitor.specifiers, vec![specifier]);
+}
+
+/// Parses the module `js`, returning the export name, the binding and if the export name is a
+/// string literal for every named import specifier.
+fn import_specifiers(js: &str, interner: &mut Interner) -> Vec<(String, String, bool)> {
+    let module = Parser::new(Source::from_bytes(js))
+        .parse_module(interner)
+        .expect("failed to parse");
+    module
+        .items()
+        .iter()
+        .filter_map(|item| match item {
+            ModuleItem::ImportDeclaration(import) => match import.kind() {
+                ImportKind::Named { names } => Some(names.iter().copied()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .flatten()
+        .map(|specifier| {
+            (
+                interner.resolve_expect(specifier.export_name()).to_string(),
+                interner
+                    .resolve_expect(specifier.binding().sym())
+                    .to_string(),
+                specifier.is_string_literal_name(),
+            )
+        })
+        .collect()
+}
+
+/// Checks that string literal export names in import specifiers survive regenerating the code.
+#[test]
+fn import_string_literal_names_round_trip() {
+    let interner = &mut Interner::default();
+    let specifiers = import_specifiers(
+        r#"import { "some name" as a, "ünïcödé 名前" as b, c, d as e } from "mod";"#,
+        interner,
+    );
+    assert_eq!(
+        specifiers,
+        vec![
+            ("some name".to_owned(), "a".to_owned(), true),
+            ("ünïcödé 名前".to_owned(), "b".to_owned(), true),
+            ("c".to_owned(), "c".to_owned(), false),
+            ("d".to_owned(), "e".to_owned(), false),
+        ]
+    );
+
+    let regenerated = specifiers
+        .iter()
+        .map(|(export_name, binding, string_literal)| {
+            if *string_literal {
+                format!("{export_name:?} as {binding}")
+            } else {
+                format!("{export_name} as {binding}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let regenerated = format!(r#"import {{ {regenerated} }} from "mod";"#);
+    assert_eq!(import_specifiers(&regenerated, interner), specifiers);
+}
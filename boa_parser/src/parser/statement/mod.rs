@@ -39,10 +39,7 @@ use self::{
     with::WithStatement,
 };
 use crate::{
-    lexer::{
-        token::{ContainsEscapeSequence, EscapeSequence},
-        Error as LexError, InputElement, Token, TokenKind,
-    },
+    lexer::{token::EscapeSequence, Error as LexError, InputElement, Token, TokenKind},
     parser::{
         expression::{BindingIdentifier, Initializer, PropertyName},
         AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
@@ -58,7 +55,7 @@ use boa_ast::{
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
     Keyword, Punctuator,
 };
-use boa_interner::{Interner, Sym};
+use boa_interner::Interner;
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 use std::io::Read;
@@ -312,6 +309,7 @@ where
                 _ => {}
             }
 
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let item =
                 StatementListItem::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor, interner)?;
@@ -349,14 +347,15 @@ where
                 }
             }
 
-            items.push(item);
+            items.push((item, position));
         }
 
-        items.sort_by(ast::StatementListItem::hoistable_order);
+        items.sort_by(|(a, _), (b, _)| ast::StatementListItem::hoistable_order(a, b));
 
         cursor.set_strict(global_strict);
 
-        Ok(ast::StatementList::new(items, strict))
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        Ok(ast::StatementList::new(items, strict).with_positions(positions))
     }
 }
 
@@ -940,23 +939,23 @@ where
     type Output = boa_ast::ModuleItem;
 
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        // `import(` and `import.` start an import call or `import.meta` expression instead of an
+        // import declaration.
+        let import_expression = cursor.peek(1, interner)?.map_or(false, |tok| {
+            matches!(
+                tok.kind(),
+                TokenKind::Punctuator(Punctuator::OpenParen | Punctuator::Dot)
+            )
+        });
         let tok = cursor.peek(0, interner).or_abrupt()?;
 
         match tok.kind() {
-            TokenKind::IdentifierName((ident, ContainsEscapeSequence(false)))
-                if *ident == Sym::IMPORT =>
-            {
-                ImportDeclaration
-                    .parse(cursor, interner)
-                    .map(Self::Output::ImportDeclaration)
-            }
-            TokenKind::IdentifierName((ident, ContainsEscapeSequence(false)))
-                if *ident == Sym::EXPORT =>
-            {
-                ExportDeclaration
-                    .parse(cursor, interner)
-                    .map(Self::Output::ExportDeclaration)
-            }
+            TokenKind::Keyword((Keyword::Import, false)) if !import_expression => ImportDeclaration
+                .parse(cursor, interner)
+                .map(Self::Output::ImportDeclaration),
+            TokenKind::Keyword((Keyword::Export, false)) => ExportDeclaration
+                .parse(cursor, interner)
+                .map(Self::Output::ExportDeclaration),
             _ => StatementListItem::new(false, true, false)
                 .parse(cursor, interner)
                 .map(Self::Output::StatementListItem),
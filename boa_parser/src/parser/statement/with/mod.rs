@@ -5,6 +5,7 @@ use crate::{
         cursor::Cursor, expression::Expression, statement::Statement, AllowAwait, AllowReturn,
         AllowYield, ParseResult, TokenParser,
     },
+    warning::WarningKind,
     Error,
 };
 use boa_ast::{statement::With, Keyword, Punctuator};
@@ -56,18 +57,18 @@ where
     fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
         let _timer = Profiler::global().start_event("WithStatement", "Parsing");
 
-        let position = cursor
+        let span = cursor
             .expect((Keyword::With, false), "with statement", interner)?
-            .span()
-            .start();
+            .span();
 
         // It is a Syntax Error if the source text matched by this production is contained in strict mode code.
         if cursor.strict() {
             return Err(Error::general(
                 "with statement not allowed in strict mode",
-                position,
+                span.start(),
             ));
         }
+        cursor.warn(WarningKind::With, span);
 
         cursor.expect(Punctuator::OpenParen, "with statement", interner)?;
         let expression = Expression::new(None, true, self.allow_yield, self.allow_await)
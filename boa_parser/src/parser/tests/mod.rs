@@ -4,9 +4,9 @@ mod format;
 
 use std::convert::TryInto;
 
-use crate::{Parser, Source};
+use crate::{warning::WarningKind, Error, Parser, Source};
 use boa_ast::{
-    declaration::{Declaration, LexicalDeclaration, VarDeclaration, Variable},
+    declaration::{Declaration, LexicalDeclaration, ModuleSpecifier, VarDeclaration, Variable},
     expression::{
         access::SimplePropertyAccess,
         literal::{Literal, ObjectLiteral},
@@ -21,6 +21,10 @@ use boa_ast::{
     function::{
         ArrowFunction, FormalParameter, FormalParameterList, FormalParameterListFlags, Function,
     },
+    operations::{
+        collect_export_specifiers, collect_import_specifiers, contains_eval_call, eval_calls,
+        EvalCallKind, EvalCallSite,
+    },
     property::PropertyDefinition,
     statement::{If, Return},
     Expression, Statement, StatementList, StatementListItem,
@@ -601,3 +605,120 @@ fn hashbang_use_strict_with_with_statement() {
 fn hashbang_comment() {
     check_script_parser(r"#!Comment Here", vec![], &mut Interner::default());
 }
+
+#[test]
+fn sloppy_mode_warnings() {
+    let mut parser = Parser::new(Source::from_bytes(
+        "with ({}) {}\nfunction f() { return arguments.callee; }\neval('1');\nvar x = 010;\n\
+         var y = 09 + 0o10; obj.callee; foo.arguments.callee; (function() { 'use strict'; eval('2'); });",
+    ));
+    parser
+        .parse_script(&mut Interner::default())
+        .expect("failed to parse");
+
+    let warnings = parser
+        .take_warnings()
+        .into_iter()
+        .map(|warning| {
+            let start = warning.span().start();
+            let end = warning.span().end();
+            (
+                warning.kind(),
+                (start.line_number(), start.column_number()),
+                (end.line_number(), end.column_number()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        warnings,
+        [
+            (WarningKind::With, (1, 1), (1, 5)),
+            (WarningKind::ArgumentsCallee, (2, 23), (2, 39)),
+            (WarningKind::SloppyDirectEval, (3, 1), (3, 5)),
+            (WarningKind::LegacyOctalLiteral, (4, 9), (4, 12)),
+        ]
+    );
+    assert!(parser.take_warnings().is_empty());
+}
+
+#[test]
+fn collect_module_specifiers() {
+    let interner = &mut Interner::default();
+    let module = Parser::new(Source::from_bytes(
+        "import a from './a.js';\nimport * as b from './b.js';\nimport './c.js';\n\
+         export * from './d.js';\nexport { e } from './e.js';\nexport const f = 1;\n\
+         const g = await import('./g.js');\nfunction h(path) { return [import('./h.js'), import(path)]; }",
+    ))
+    .parse_module(interner)
+    .expect("failed to parse");
+
+    let resolve = |specifiers: Vec<ModuleSpecifier>| {
+        specifiers
+            .into_iter()
+            .map(|specifier| interner.resolve_expect(specifier.sym()).to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        resolve(collect_import_specifiers(&module)),
+        ["./a.js", "./b.js", "./c.js", "./g.js", "./h.js"]
+    );
+    assert_eq!(
+        resolve(collect_export_specifiers(&module)),
+        ["./d.js", "./e.js"]
+    );
+}
+
+#[test]
+fn eval_call_sites() {
+    let script = Parser::new(Source::from_bytes(
+        "eval('1');\n(0, eval)('2');\nwindow.eval('3');\nfunction f() { return (eval)('4'); }\n\
+         foo('5'); eval?.('6'); globalThis['eval']('7'); globalThis?.eval('8');",
+    ))
+    .parse_script(&mut Interner::default())
+    .expect("failed to parse");
+
+    let calls = eval_calls(&script)
+        .into_iter()
+        .map(|(kind, call)| {
+            let span = call.span().map(|span| {
+                (
+                    (span.start().line_number(), span.start().column_number()),
+                    (span.end().line_number(), span.end().column_number()),
+                )
+            });
+            (kind, matches!(call, EvalCallSite::Optional(_)), span)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        calls,
+        [
+            (EvalCallKind::Direct, false, Some(((4, 23), (4, 30)))),
+            (EvalCallKind::Direct, false, Some(((1, 1), (1, 6)))),
+            (EvalCallKind::Indirect, false, Some(((2, 1), (2, 11)))),
+            (EvalCallKind::Indirect, false, Some(((3, 1), (3, 13)))),
+            (EvalCallKind::Indirect, true, None),
+            (EvalCallKind::Indirect, false, Some(((5, 24), (5, 43)))),
+            (EvalCallKind::Indirect, true, None),
+        ]
+    );
+    assert!(contains_eval_call(&script));
+    assert!(!contains_eval_call(
+        &Parser::new(Source::from_bytes("foo(eval);"))
+            .parse_script(&mut Interner::default())
+            .expect("failed to parse")
+    ));
+}
+
+#[test]
+fn parse_expression_invalid_object_literal() {
+    let error = Parser::new(Source::from_bytes("\n  ({ a = 1 })"))
+        .parse_expression(&mut Interner::default())
+        .expect_err("a cover initialized name is not a valid expression");
+    assert!(matches!(error, Error::General { .. }));
+
+    let position = error.position().expect("the error must have a position");
+    assert_eq!((position.line_number(), position.column_number()), (2, 3));
+}
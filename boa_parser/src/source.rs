@@ -85,4 +85,22 @@ impl<'path, R: Read> Source<'path, R> {
     pub const fn from_reader(reader: R, path: Option<&'path Path>) -> Self {
         Self { reader, path }
     }
+
+    /// Reads the remaining source code into a [`String`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader fails, or if the source code is not valid UTF-8.
+    pub fn read_to_string(&mut self) -> io::Result<String> {
+        let mut text = String::new();
+        self.reader.read_to_string(&mut text)?;
+        Ok(text)
+    }
+}
+
+impl<'path, R> Source<'path, R> {
+    /// Gets the [`Path`] of the source code, if it was provided.
+    pub const fn path(&self) -> Option<&'path Path> {
+        self.path
+    }
 }
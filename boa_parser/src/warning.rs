@@ -0,0 +1,82 @@
+//! Warnings about the use of error-prone non-strict features.
+//!
+//! The parser reports a [`Warning`] for every use of a non-strict feature that is error-prone or
+//! that prevents optimizations. Warnings never stop the parsing, and can be retrieved with
+//! [`Parser::take_warnings`](crate::Parser::take_warnings) after parsing.
+
+use boa_ast::Span;
+use std::fmt;
+
+/// The kind of a [`Warning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// A `with` statement.
+    With,
+
+    /// A direct `eval` call in non-strict code, which can add bindings to the calling scope.
+    SloppyDirectEval,
+
+    /// An access to `arguments.callee` in non-strict code.
+    ArgumentsCallee,
+
+    /// A legacy octal integer literal, like `010`.
+    LegacyOctalLiteral,
+}
+
+impl WarningKind {
+    /// Gets the description of the warning.
+    #[must_use]
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::With => "the with statement is deprecated and prevents optimizations",
+            Self::SloppyDirectEval => {
+                "direct eval in non-strict code can add bindings to the calling scope"
+            }
+            Self::ArgumentsCallee => {
+                "arguments.callee is deprecated and not allowed in strict mode"
+            }
+            Self::LegacyOctalLiteral => {
+                "legacy octal literals are deprecated, use the 0o prefix instead"
+            }
+        }
+    }
+}
+
+/// A warning about the use of an error-prone non-strict feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Warning {
+    kind: WarningKind,
+    span: Span,
+}
+
+impl Warning {
+    /// Creates a new `Warning` of the given kind for the source code at `span`.
+    #[must_use]
+    pub const fn new(kind: WarningKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    /// Gets the kind of the warning.
+    #[must_use]
+    pub const fn kind(&self) -> WarningKind {
+        self.kind
+    }
+
+    /// Gets the span of the source code that caused the warning.
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at line {}, col {}",
+            self.kind.message(),
+            self.span.start().line_number(),
+            self.span.start().column_number()
+        )
+    }
+}
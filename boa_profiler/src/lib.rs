@@ -81,7 +81,7 @@
     clippy::pedantic,
     clippy::nursery,
 )]
-#![cfg_attr(not(feature = "profiler"), no_std)]
+#![cfg_attr(not(any(feature = "profiler", feature = "timing")), no_std)]
 
 use core::fmt::{self, Debug};
 
@@ -125,6 +125,16 @@ impl Profiler {
             .start_recording_interval_event(kind, id, thread_id)
     }
 
+    /// Start a new profiled event that also measures its duration.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    pub fn start_measured_event(&self, label: &str, category: &str) -> MeasuredEvent<'_> {
+        MeasuredEvent {
+            _guard: self.start_event(label, category),
+            start: std::time::Instant::now(),
+        }
+    }
+
     #[allow(clippy::significant_drop_tightening)]
     fn get_or_alloc_string(&self, s: &str) -> StringId {
         {
@@ -207,6 +217,17 @@ impl Profiler {
     #[allow(clippy::unused_unit)]
     pub const fn start_event(&self, _label: &str, _category: &str) -> () {}
 
+    /// Only measures the duration of the event.
+    #[cfg(feature = "timing")]
+    #[must_use]
+    pub fn start_measured_event(&self, label: &str, category: &str) -> MeasuredEvent<'static> {
+        self.start_event(label, category);
+        MeasuredEvent {
+            _profiler: core::marker::PhantomData,
+            start: std::time::Instant::now(),
+        }
+    }
+
     /// Does nothing.
     pub const fn drop(&self) {}
 
@@ -216,3 +237,35 @@ impl Profiler {
         Self
     }
 }
+
+/// A profiled event that also measures its duration, returned by
+/// [`Profiler::start_measured_event`].
+///
+/// The event ends when [`MeasuredEvent::finish`] is called, which allows aggregating the time
+/// spent in events with the same label.
+#[cfg(feature = "timing")]
+pub struct MeasuredEvent<'a> {
+    #[cfg(feature = "profiler")]
+    _guard: TimingGuard<'a>,
+    #[cfg(not(feature = "profiler"))]
+    _profiler: core::marker::PhantomData<&'a Profiler>,
+    start: std::time::Instant,
+}
+
+#[cfg(feature = "timing")]
+impl MeasuredEvent<'_> {
+    /// Ends the event, returning its duration.
+    #[must_use]
+    pub fn finish(self) -> std::time::Duration {
+        self.start.elapsed()
+    }
+}
+
+#[cfg(feature = "timing")]
+impl Debug for MeasuredEvent<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MeasuredEvent")
+            .field("start", &self.start)
+            .finish_non_exhaustive()
+    }
+}
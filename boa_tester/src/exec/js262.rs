@@ -41,11 +41,12 @@ pub(super) fn register_js262(context: &mut Context<'_>) -> JsObject {
 ///
 /// Creates a new ECMAScript Realm, defines this API on the new realm's global object, and
 /// returns the `$262` property of the new realm's global object.
-#[allow(clippy::unnecessary_wraps)]
-fn create_realm(_: &JsValue, _: &[JsValue], _: &mut Context<'_>) -> JsResult<JsValue> {
-    let context = &mut Context::default();
+fn create_realm(_: &JsValue, _: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
+    let realm = context.create_realm()?;
 
+    let old_realm = context.enter_realm(realm);
     let js262 = register_js262(context);
+    context.enter_realm(old_realm);
 
     Ok(JsValue::new(js262))
 }
@@ -88,20 +89,15 @@ fn detach_array_buffer(_: &JsValue, args: &[JsValue], _: &mut Context<'_>) -> Js
 
 /// The `$262.evalScript()` function.
 ///
-/// Accepts a string value as its first argument and executes it as an ECMAScript script.
+/// Accepts a string value as its first argument and executes it as an ECMAScript script in the
+/// realm of this `$262` object.
 fn eval_script(_this: &JsValue, args: &[JsValue], context: &mut Context<'_>) -> JsResult<JsValue> {
     args.get(0).and_then(JsValue::as_string).map_or_else(
         || Ok(JsValue::undefined()),
-        |source_text| match context
-            .parse_script(Source::from_bytes(&source_text.to_std_string_escaped()))
-        {
-            // TODO: check strict
-            Err(e) => Err(JsNativeError::typ()
-                .with_message(format!("Uncaught Syntax Error: {e}"))
-                .into()),
-            // Calling eval here parses the code a second time.
-            // TODO: We can fix this after we have have defined the public api for the vm executer.
-            Ok(_) => context.eval_script(Source::from_bytes(&source_text.to_std_string_escaped())),
+        |source_text| {
+            // The realm of this function was entered when calling it, so the script runs in the
+            // realm that owns this `$262` object.
+            context.eval_script(Source::from_bytes(&source_text.to_std_string_escaped()))
         },
     )
 }
@@ -116,3 +112,53 @@ fn gc(_this: &JsValue, _: &[JsValue], _context: &mut Context<'_>) -> JsResult<Js
     boa_gc::force_collect();
     Ok(JsValue::undefined())
 }
+
+#[cfg(test)]
+mod tests {
+    use boa_engine::{Context, JsValue, Source};
+
+    use super::register_js262;
+
+    #[test]
+    fn create_realm_and_eval_script() {
+        let context = &mut Context::default();
+        register_js262(context);
+
+        let result = context
+            .eval_script(Source::from_bytes(
+                r#"
+                var other = $262.createRealm();
+                var value = other.evalScript("var x = 5; x * 2");
+                value === 10 &&
+                    other.global.x === 5 &&
+                    typeof x === "undefined" &&
+                    other.global.Array !== Array &&
+                    other.evalScript("$262.global === globalThis")
+                "#,
+            ))
+            .unwrap();
+
+        assert_eq!(result, JsValue::new(true));
+    }
+
+    #[test]
+    fn eval_script_syntax_error() {
+        let context = &mut Context::default();
+        register_js262(context);
+
+        let result = context
+            .eval_script(Source::from_bytes(
+                r#"
+                try {
+                    $262.evalScript("var;");
+                    false
+                } catch (e) {
+                    e instanceof SyntaxError
+                }
+                "#,
+            ))
+            .unwrap();
+
+        assert_eq!(result, JsValue::new(true));
+    }
+}
@@ -1,4 +1,5 @@
 use boa_ast::{
+    expression::RegExpLiteral,
     visitor::{VisitWith, VisitorMut},
     Expression, StatementList,
 };
@@ -44,6 +45,13 @@ impl<'a> Arbitrary<'a> for FuzzData {
                         Err(e) => return ControlFlow::Break(e),
                     }
                 }
+                if let Expression::RegExpLiteral(regexp) = node {
+                    // Arbitrary flags are almost never valid, so only the pattern is kept.
+                    let mut pattern = regexp.pattern();
+                    let result = self.visit_sym_mut(&mut pattern);
+                    *node = RegExpLiteral::new(pattern, Sym::EMPTY_STRING).into();
+                    return result;
+                }
                 node.visit_with_mut(self)
             }
 
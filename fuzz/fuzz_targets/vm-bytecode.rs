@@ -0,0 +1,46 @@
+#![no_main]
+
+use boa_engine::{
+    vm::fuzz::{execute_fuzz_bytecode, FuzzLimits, FuzzOutcome},
+    JsValue,
+};
+use libfuzzer_sys::{
+    arbitrary::{self, Arbitrary, Unstructured},
+    fuzz_target,
+};
+
+/// Arbitrary bytecode, with the literals and names it can reference.
+#[derive(Debug)]
+struct FuzzBytecode {
+    bytecode: Vec<u8>,
+    numbers: Vec<f64>,
+    strings: Vec<String>,
+    names: Vec<String>,
+}
+
+impl<'a> Arbitrary<'a> for FuzzBytecode {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            numbers: u.arbitrary()?,
+            strings: u.arbitrary()?,
+            names: u.arbitrary()?,
+            bytecode: u.arbitrary_take_rest()?.to_vec(),
+        })
+    }
+}
+
+fuzz_target!(|data: FuzzBytecode| {
+    let literals = data
+        .numbers
+        .into_iter()
+        .map(JsValue::from)
+        .chain(data.strings.into_iter().map(JsValue::from))
+        .collect();
+    let names = data.names.iter().map(String::as_str).collect::<Vec<_>>();
+
+    if let FuzzOutcome::Panicked(message) =
+        execute_fuzz_bytecode(&data.bytecode, literals, &names, FuzzLimits::default())
+    {
+        panic!("the VM panicked: {message}");
+    }
+});